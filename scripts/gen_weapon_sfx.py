@@ -0,0 +1,83 @@
+#!/usr/bin/env python3
+"""
+Davenstein - by David Petnick
+
+Synthesizes the Weapon Sound Effects That Have No Wolfenstein 3-D Original.
+Standard Library Only, Seeded so Every Run Writes Byte-Identical Files
+
+Usage (From the Repository Root):
+    python3 scripts/gen_weapon_sfx.py
+"""
+
+import math
+import random
+import struct
+import wave
+
+RATE = 22050
+OUT_DIR = "assets/sounds/sfx/weapons"
+
+
+def write(path, samples):
+    peak = max(abs(s) for s in samples) or 1
+    with wave.open(path, "wb") as w:
+        w.setnchannels(1)
+        w.setsampwidth(2)
+        w.setframerate(RATE)
+        w.writeframes(b"".join(struct.pack("<h", int(s / peak * 0.8 * 32767)) for s in samples))
+
+
+def ricochet():
+    """Short Impact Crack Then a Falling Whine"""
+    rng = random.Random(1147)
+    n = int(RATE * 0.42)
+    s = [0.0] * n
+    phase = 0.0
+    for i in range(n):
+        t = i / RATE
+        crack = math.exp(-t * 180) * rng.uniform(-1, 1)
+        f = 2600 * math.exp(-t * 2.2) + 700
+        phase += 2 * math.pi * f / RATE
+        whine = 0.55 * math.sin(phase) * min(1.0, t * 200) * math.exp(-t * 7)
+        s[i] = crack + whine + 0.05 * rng.uniform(-1, 1) * math.exp(-t * 9)
+    return s
+
+
+def dry_fire():
+    """Hammer Falling on an Empty Chamber, a Sharp Click and a Softer Echo"""
+    rng = random.Random(1276)
+    n = int(RATE * 0.09)
+    s = [0.0] * n
+    for start, amp in ((0, 1.0), (int(RATE * 0.028), 0.45)):
+        for i in range(int(RATE * 0.03)):
+            if start + i >= n:
+                break
+            t = i / RATE
+            env = math.exp(-t * 260)
+            tone = 0.7 * math.sin(2 * math.pi * 3100 * t) + 0.4 * math.sin(2 * math.pi * 5200 * t)
+            s[start + i] += amp * env * (0.6 * rng.uniform(-1, 1) + tone)
+    return s
+
+
+def hit_confirm():
+    """Short Dull Thud, Felt More Than Heard Under the Gunfire"""
+    rng = random.Random(1278)
+    n = int(RATE * 0.07)
+    s = [0.0] * n
+    for i in range(n):
+        t = i / RATE
+        env = math.exp(-t * 70)
+        s[i] = env * (math.sin(2 * math.pi * (180 - 900 * t) * t) + 0.25 * rng.uniform(-1, 1) * math.exp(-t * 400))
+    return s
+
+
+SOUNDS = {
+    "ricochet.wav": ricochet,
+    "dry_fire.wav": dry_fire,
+    "hit_confirm.wav": hit_confirm,
+}
+
+
+if __name__ == "__main__":
+    for name, make in SOUNDS.items():
+        write(f"{OUT_DIR}/{name}", make())
@@ -64,14 +64,16 @@ use crate::map::{
     MapGrid,
     Tile,
 };
+use crate::pathfind::ChasePath;
 use crate::player::{
+    GamePaused,
     Player,
     PlayerControlLock,
     PlayerDeathLatch,
 };
 
-const AI_TIC_SECS: f32 = 1.0 / 70.0;
-const DOOR_OPEN_SECS: f32 = 4.5;
+const AI_TIC_SECS: f32 = crate::tics::TIC;
+const DOOR_OPEN_SECS: f32 = crate::tics::tics(crate::tics::DOOR_OPEN_TICS);
 const CLAIM_TILE_EARLY: bool = true;
 
 // Shooting Constants
@@ -93,6 +95,15 @@ const AI_DODGE_WHEN_VISIBLE: bool = true;
 // on Actual Sight (WOLFSRC/WL_ACT2.C, WOLFSRC/WL_DEF.H)
 const AMBUSHTILE: u16 = 106;
 
+// Alert Propagation Bounds. Gunfire Only Carries NOISE_RADIUS_TILES Within a
+// Connected Area, an Actor That Actually Sees the Player Calls Out to Others
+// Within CALLOUT_RADIUS_TILES (Who Do Not Call Out in Turn), and at Most
+// MAX_CHASE_WAKES_PER_TIC Actors Commit to the Chase on Any One Tic so a Single
+// Shot Never Turns a Whole Wing Loose at Once
+const NOISE_RADIUS_TILES: i32 = 16;
+const CALLOUT_RADIUS_TILES: i32 = 2;
+const MAX_CHASE_WAKES_PER_TIC: u32 = 3;
+
 // Set True by the Player's Gun Fire (Not the Knife) and Consumed Once per AI
 // Tic, Mirroring the Original Global `madenoise`. The Binary's Weapon System
 // Writes it; `capture_player_noise` Drains it Into AiSharedData for the Actors
@@ -103,6 +114,8 @@ pub struct PlayerNoise(pub bool);
 pub struct EnemyFire {
     pub kind: EnemyKind,
     pub damage: i32,
+    // Attacker World Position, Used for the Directional Damage Indicator
+    pub origin: Vec3,
 }
 
 #[derive(Clone, Copy, Debug, Message)]
@@ -181,11 +194,26 @@ struct AiSharedData {
     // Did the Player Fire a Gun This Tic? (Drained From PlayerNoise). Non-Ambush
     // Actors in a Connected Area Wake on This Even Without Line of Sight
     made_noise: bool,
+    // Tiles of Actors That Noticed the Player by Sight Last Tic. Standing Actors
+    // in the Same Area Within CALLOUT_RADIUS_TILES Wake on These Next Tic
+    sight_callouts: Vec<IVec2>,
     player_area: Option<i32>,
     cached_area_map: AreaMap,
     cached_area_generation: Option<u64>,
 }
 
+impl AiSharedData {
+    /// Reserve `dest` for an Actor Stepping off `from`. Each Living Actor Holds
+    /// One Tile, Callers Check `occupied` First so the First Claim This Tic Wins
+    fn claim_tile(&mut self, e: Entity, from: IVec2, dest: IVec2) {
+        self.scheduled_move.insert(e);
+        self.occupied.insert(dest);
+        if CLAIM_TILE_EARLY {
+            self.occupied.remove(&from);
+        }
+    }
+}
+
 #[allow(dead_code)]
 fn burst_profile(kind: EnemyKind) -> Option<(u8, u32, f32)> {
     match kind {
@@ -262,7 +290,7 @@ fn pick_chase_step(
 
         let Some(t) = tile_at(grid, dest) else { continue; };
 
-        if solid.is_solid(dest.x, dest.y) {
+        if solid.is_solid(dest.x, dest.y) || locked_shut(grid, dest) {
             continue;
         }
 
@@ -275,7 +303,11 @@ fn pick_chase_step(
 
     if last_step != IVec2::ZERO {
         let dest = my_tile + reverse;
-        if dest != player_tile && !occupied.contains(&dest) && !solid.is_solid(dest.x, dest.y) {
+        if dest != player_tile
+            && !occupied.contains(&dest)
+            && !solid.is_solid(dest.x, dest.y)
+            && !locked_shut(grid, dest)
+        {
             if let Some(t) = tile_at(grid, dest) {
                 match t {
                     Tile::Empty | Tile::DoorOpen => return ChasePick::MoveTo(dest),
@@ -397,6 +429,40 @@ fn select_dodge_step(
     None
 }
 
+/// Soldiers That Shuffle Sideways Between Shots. Dogs Have No Shot, and Bosses
+/// and Ghosts Keep Their Original Fixed Patterns
+fn side_steps(kind: EnemyKind) -> bool {
+    matches!(kind, EnemyKind::Guard | EnemyKind::Ss | EnemyKind::Officer | EnemyKind::Mutant)
+}
+
+/// One Tile Across the Line of Fire: a Cardinal Step Along the Minor Axis to the
+/// Player, Side Picked by the Actor's RNG. Only Plain Floor Qualifies -- No
+/// Doorways, Statics, Other Actors or the Player -- so a Boxed-in Actor Holds
+fn select_side_step(
+    grid: &MapGrid,
+    solid: &SolidStatics,
+    occupied: &HashSet<IVec2>,
+    my_tile: IVec2,
+    player_tile: IVec2,
+    rng: &mut TableRng,
+) -> Option<IVec2> {
+    let to_player = player_tile - my_tile;
+    let (a, b) = if to_player.x.abs() >= to_player.y.abs() {
+        (IVec2::new(0, 1), IVec2::new(0, -1))
+    } else {
+        (IVec2::new(1, 0), IVec2::new(-1, 0))
+    };
+    let order = if rng.us_rnd_t() < 128 { [a, b] } else { [b, a] };
+
+    order.into_iter().find(|&step| {
+        let dest = my_tile + step;
+        dest != player_tile
+            && !occupied.contains(&dest)
+            && !solid.is_solid(dest.x, dest.y)
+            && tile_at(grid, dest) == Some(Tile::Empty)
+    })
+}
+
 fn attach_enemy_ai(
     mut commands: Commands,
     grid: Res<MapGrid>,
@@ -464,7 +530,8 @@ fn tile_at(grid: &MapGrid, t: IVec2) -> Option<Tile> {
     Some(grid.tile(x, z))
 }
 
-fn has_line_of_sight(grid: &MapGrid, from: IVec2, to: IVec2) -> bool {
+/// Grid Ray Between Two Tile Centers, Blocked by Anything That Blocks Sight
+pub fn has_line_of_sight(grid: &MapGrid, from: IVec2, to: IVec2) -> bool {
     if from == to {
         return true;
     }
@@ -632,11 +699,25 @@ fn dir8_towards(from: IVec2, to: IVec2) -> Dir8 {
     Dir8(oct as u8)
 }
 
+/// A Closed Key Door, Which no Actor Can Open. Chasers Route Around it and
+/// Patrols Turn Back From it as if it Were Wall
+fn locked_shut(grid: &MapGrid, t: IVec2) -> bool {
+    tile_at(grid, t) == Some(Tile::DoorClosed) && grid.is_locked_door(t.x as usize, t.y as usize)
+}
+
+/// Asks the Door to Open the Same Way the Player's Use Does. Any Number of
+/// Actors May Ask: a Request Only Ever Sets want_open and Refreshes the Hold
+/// Timer, so the Door Never Flips Back Shut Under a Second Actor
 fn try_open_door_at(
+    grid: &MapGrid,
     door_tile: IVec2,
     q_doors: &mut Query<(&DoorTile, &mut DoorState, &GlobalTransform)>,
     sfx: &mut MessageWriter<PlaySfx>,
 ) {
+    if locked_shut(grid, door_tile) {
+        return;
+    }
+
     for (dt, mut ds, gt) in q_doors.iter_mut() {
         if dt.0 != door_tile {
             continue;
@@ -656,6 +737,10 @@ fn try_open_door_at(
     }
 }
 
+// Connected Floor Regions Bounded by Walls and Closed Doors, Which is What
+// Gunfire Carries Through. A Door Joins Two Areas Only Once it Reads DoorOpen,
+// the Same Fully-Open Threshold Bullets and Sight Traces Use, so a Door Still
+// Sliding (Either Way) Muffles a Shot Just as it Stops One
 #[derive(Debug, Default)]
 struct AreaMap {
     w: usize,
@@ -858,6 +943,39 @@ fn reaction_tics(kind: EnemyKind, rng: &mut TableRng) -> u32 {
     }
 }
 
+/// What Made a Standing / Patrolling Actor Notice the Player This Tic
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NoticeCause {
+    Sight,
+    Gunfire,
+    Callout,
+}
+
+// Only Sight Starts a Callout, so Actors Woken by Noise or by a Neighbor Never
+// Spread the Alert Further. Ambush (Deaf) Actors Ignore Everything But Sight
+fn notice_cause(
+    my_tile: IVec2,
+    player_tile: IVec2,
+    seen: bool,
+    made_noise: bool,
+    ambush: bool,
+    callouts: &[IVec2],
+) -> Option<NoticeCause> {
+    let within = |a: IVec2, b: IVec2, r: i32| (a - b).length_squared() <= r * r;
+
+    if seen {
+        Some(NoticeCause::Sight)
+    } else if ambush {
+        None
+    } else if made_noise && within(my_tile, player_tile, NOISE_RADIUS_TILES) {
+        Some(NoticeCause::Gunfire)
+    } else if callouts.iter().any(|&c| c != my_tile && within(my_tile, c, CALLOUT_RADIUS_TILES)) {
+        Some(NoticeCause::Callout)
+    } else {
+        None
+    }
+}
+
 /// True for Actors That Spawn Deaf (FL_AMBUSH) Regardless of Tile: Every Boss
 /// and Special Actor in the Original. The Five Standard Enemy Types Are Deaf
 /// Only When Placed on an AMBUSHTILE.
@@ -880,6 +998,68 @@ fn capture_player_noise(mut player_noise: ResMut<PlayerNoise>, mut shared: ResMu
     player_noise.0 = false;
 }
 
+/// Breadth-First Step Counts From the Player's Tile Over Every Non-Wall Tile
+/// (Closed Doors Included, Locked Ones Not), -1 Where Unreachable. Chasing
+/// Actors Step Downhill
+fn fill_chase_dist_map(grid: &MapGrid, solid: &SolidStatics, player_tile: IVec2, dist: &mut Vec<i32>) {
+    let w = grid.width as i32;
+    let h = grid.height as i32;
+    let in_bounds = |t: IVec2| t.x >= 0 && t.y >= 0 && t.x < w && t.y < h;
+    let idx = |t: IVec2| (t.y * w + t.x) as usize;
+
+    // Reuse Existing Buffer Instead of Allocating Fresh Vec Every Tic.
+    // clear()+resize() Keeps Prior Allocation When Grid Size is
+    // Unchanged (Common Case), Reallocating Only on Level Size Change
+    dist.clear();
+    dist.resize(grid.width * grid.height, -1i32);
+
+    if in_bounds(player_tile)
+        && !solid.is_solid(player_tile.x, player_tile.y)
+        && grid.tile(player_tile.x as usize, player_tile.y as usize) != Tile::Wall
+    {
+        dist[idx(player_tile)] = 0;
+
+        let mut queue: Vec<IVec2> = vec![player_tile];
+        let mut qh: usize = 0;
+
+        let dirs = [
+            IVec2::new(1, 0),
+            IVec2::new(-1, 0),
+            IVec2::new(0, 1),
+            IVec2::new(0, -1),
+        ];
+
+        while qh < queue.len() {
+            let cur = queue[qh];
+            qh += 1;
+
+            let base = dist[idx(cur)];
+            let next = base + 1;
+
+            for step in dirs {
+                let n = cur + step;
+                if !in_bounds(n) {
+                    continue;
+                }
+                let ni = idx(n);
+                if dist[ni] >= 0 {
+                    continue;
+                }
+
+                if solid.is_solid(n.x, n.y)
+                    || grid.tile(n.x as usize, n.y as usize) == Tile::Wall
+                    || locked_shut(grid, n)
+                {
+                    continue;
+                }
+
+                dist[ni] = next;
+                queue.push(n);
+            }
+        }
+    }
+}
+
 // SYSTEM 1: Prepare Shared Data and Handle Activation / Patrol
 fn enemy_ai_prepare_and_activate(
     mut commands: Commands,
@@ -929,6 +1109,7 @@ fn enemy_ai_prepare_and_activate(
     if grid.is_changed() || shared.cached_area_generation != Some(grid.generation) {
         shared.cached_area_map = AreaMap::compute(&grid);
         shared.cached_area_generation = Some(grid.generation);
+        shared.sight_callouts.clear();
     }
 
     // Move Cached Map Out of Shared so the Rest of This Tick Can Freely
@@ -943,58 +1124,17 @@ fn enemy_ai_prepare_and_activate(
     let in_bounds = |t: IVec2| t.x >= 0 && t.y >= 0 && t.x < w && t.y < h;
     let idx = |t: IVec2| (t.y * w + t.x) as usize;
 
-    // Reuse Existing Buffer Instead of Allocating Fresh Vec Every Tic.
-    // clear()+resize() Keeps Prior Allocation When Grid Size is
-    // Unchanged (Common Case), Reallocating Only on Level Size Change
-    shared.dist_map.clear();
-    shared.dist_map.resize(grid.width * grid.height, -1i32);
-
-    if in_bounds(player_tile)
-        && !solid.is_solid(player_tile.x, player_tile.y)
-        && grid.tile(player_tile.x as usize, player_tile.y as usize) != Tile::Wall
-    {
-        shared.dist_map[idx(player_tile)] = 0;
-
-        let mut queue: Vec<IVec2> = vec![player_tile];
-        let mut qh: usize = 0;
-
-        let dirs = [
-            IVec2::new(1, 0),
-            IVec2::new(-1, 0),
-            IVec2::new(0, 1),
-            IVec2::new(0, -1),
-        ];
-
-        while qh < queue.len() {
-            let cur = queue[qh];
-            qh += 1;
-
-            let base = shared.dist_map[idx(cur)];
-            let next = base + 1;
-
-            for step in dirs {
-                let n = cur + step;
-                if !in_bounds(n) {
-                    continue;
-                }
-                let ni = idx(n);
-                if shared.dist_map[ni] >= 0 {
-                    continue;
-                }
-
-                if solid.is_solid(n.x, n.y) || grid.tile(n.x as usize, n.y as usize) == Tile::Wall {
-                    continue;
-                }
-
-                shared.dist_map[ni] = next;
-                queue.push(n);
-            }
-        }
-    }
+    fill_chase_dist_map(&grid, &solid, player_tile, &mut shared.dist_map);
 
     shared.scheduled_move.clear();
     shared.occupied.clear();
 
+    // Last Tic's Sight Callouts Are Consumed Here; This Tic's Are Collected
+    // Fresh so Each Callout Wakes Neighbors Exactly Once
+    let callouts = std::mem::take(&mut shared.sight_callouts);
+    let mut new_callouts: Vec<IVec2> = Vec::new();
+    let mut chase_wakes: u32 = 0;
+
     {
         for (
             _e,
@@ -1042,9 +1182,15 @@ fn enemy_ai_prepare_and_activate(
             if ai.react_tics > 0 {
                 // Already Noticed the Player. Count the Reaction Delay Down
                 // Unconditionally (the Original Does Not Re-Check Sight Once
-                // temp2 is Armed) and Commit to the Chase When it Expires
-                ai.react_tics -= 1;
-                if ai.react_tics == 0 {
+                // temp2 is Armed) and Commit to the Chase When it Expires.
+                // The Last Tic of the Delay is Held Once the Per-Tic Wake Cap
+                // is Reached, Staggering a Large Group Over Several Tics
+                let held = ai.react_tics == 1 && chase_wakes >= MAX_CHASE_WAKES_PER_TIC;
+                if !held {
+                    ai.react_tics -= 1;
+                }
+                if !held && ai.react_tics == 0 {
+                    chase_wakes += 1;
                     ai.state = EnemyAiState::Chase;
                     // Allow One Turnaround on the First dodge After Noticing
                     ai.first_attack = true;
@@ -1060,8 +1206,9 @@ fn enemy_ai_prepare_and_activate(
                 let same_area = player_area.is_some() && areas.id(my_tile) == player_area;
                 if same_area {
                     // Original SightPlayer: an Actor in a Connected Area Wakes
-                    // on Sight; Non-Ambush Actors Also Wake on Gunfire Noise,
-                    // While Ambush (Deaf) Actors Ignore Noise and Need Sight
+                    // on Sight; Non-Ambush Actors Also Wake on Gunfire Within
+                    // Earshot or a Nearby Actor's Sight Callout, While Ambush
+                    // (Deaf) Actors Ignore Both and Need Sight
                     let seen = check_sight(
                         *dir8,
                         my_tile,
@@ -1070,12 +1217,20 @@ fn enemy_ai_prepare_and_activate(
                         player_pos,
                         &grid,
                     );
-                    let heard = made_noise && !ai.ambush;
-                    if seen || heard {
-                        if seen {
+                    let cause = notice_cause(
+                        my_tile,
+                        player_tile,
+                        seen,
+                        made_noise,
+                        ai.ambush,
+                        &callouts,
+                    );
+                    if let Some(cause) = cause {
+                        if cause == NoticeCause::Sight {
                             // The Original Clears FL_AMBUSH the First Time an
                             // Actor Actually Sees the Player
                             ai.ambush = false;
+                            new_callouts.push(my_tile);
                         }
                         // First Tic the Actor Notices the Player. Arm the
                         // Per-Class Reaction Delay Instead of Chasing Instantly
@@ -1163,9 +1318,13 @@ fn enemy_ai_prepare_and_activate(
                     dir8.0 = (dir8.0 + 4) & 7;
                     continue;
                 }
+                Tile::DoorClosed if grid.is_locked_door(dest.x as usize, dest.y as usize) => {
+                    dir8.0 = (dir8.0 + 4) & 7;
+                    continue;
+                }
                 Tile::DoorClosed => {
                     if !matches!(*kind, EnemyKind::Dog) {
-                        try_open_door_at(dest, &mut q_doors, &mut sfx);
+                        try_open_door_at(&grid, dest, &mut q_doors, &mut sfx);
                     }
                     continue;
                 }
@@ -1184,16 +1343,14 @@ fn enemy_ai_prepare_and_activate(
                         speed_tps: patrol_speed,
                     });
 
-                    shared.scheduled_move.insert(e);
-                    shared.occupied.insert(dest);
-                    if CLAIM_TILE_EARLY {
-                        shared.occupied.remove(&my_tile);
-                    }
+                    shared.claim_tile(e, my_tile, dest);
                 }
             }
         }
     }
 
+    shared.sight_callouts = new_callouts;
+
     // Restore Area Map Into Cache so Next Tic (This Frame or Later
     // One) Can Reuse it Without Recomputing
     shared.cached_area_map = areas;
@@ -1213,6 +1370,7 @@ fn enemy_ai_combat(
     mut bursts: Local<HashMap<Entity, BurstFire>>,
     mut los_hold: Local<HashMap<Entity, f32>>,
     tunings: Res<EnemyTunings>,
+    skill: Res<crate::skill::SkillLevel>,
     mut shared: ResMut<AiSharedData>,
     mut q_enemies: Query<
         (
@@ -1233,8 +1391,10 @@ fn enemy_ai_combat(
 ) {
     let dt = time.delta_secs();
 
+    // Past the Shot Animation the Wait Runs Down at the Skill's Fire Rate
     shoot_cd.retain(|_, t| {
-        *t -= dt;
+        let rate = if *t > GUARD_SHOOT_COOLDOWN_SECS { 1.0 } else { skill.fire_rate() };
+        *t -= dt * rate;
         *t > 0.0
     });
 
@@ -1346,7 +1506,7 @@ fn enemy_ai_combat(
                             *kind,
                             &mut actor_rng,
                         ) {
-                            enemy_fire.write(EnemyFire { kind: *kind, damage });
+                            enemy_fire.write(EnemyFire { kind: *kind, damage, origin: tf.translation });
                         }
 
                         // Insert / Update Chaingun Volley Component for View Tracking
@@ -1372,7 +1532,7 @@ fn enemy_ai_combat(
                             *kind,
                             &mut actor_rng,
                         ) {
-                            enemy_fire.write(EnemyFire { kind: *kind, damage });
+                            enemy_fire.write(EnemyFire { kind: *kind, damage, origin: tf.translation });
                         }
                     }
 
@@ -1605,21 +1765,28 @@ fn enemy_ai_combat(
                     // Regular Hitscan Shooting
                     shoot_cd.insert(e, GUARD_SHOOT_TOTAL_SECS);
 
-                    if let Some(damage) = wolf_t_shoot(
+                    let damage = wolf_t_shoot(
                         shoot_dist,
                         shared.player_running,
                         actor_visible,
                         *kind,
                         &mut actor_rng,
-                    ) {
-                        enemy_fire.write(EnemyFire { kind: *kind, damage });
+                    );
+
+                    // Guards Hold Their Rolled Shot and Deliver It (Hit + Gunshot)
+                    // on the Attack's Flash Frame, See tick_guard_attack
+                    let delivers_now = *kind != EnemyKind::Guard;
+
+                    if let (true, Some(damage)) = (delivers_now, damage) {
+                        enemy_fire.write(EnemyFire { kind: *kind, damage, origin: tf.translation });
                     }
 
                     match kind {
                         EnemyKind::Guard => {
-                            commands.entity(e).insert(crate::enemies::GuardShoot {
-                                timer: Timer::from_seconds(GUARD_SHOOT_PAUSE_SECS, TimerMode::Once),
-                            });
+                            commands.entity(e).insert(crate::enemies::GuardAttack::new(
+                                GUARD_SHOOT_PAUSE_SECS,
+                                damage,
+                            ));
                         }
                         EnemyKind::Mutant => {
                             commands.entity(e).insert(crate::enemies::MutantShoot {
@@ -1679,10 +1846,12 @@ fn enemy_ai_combat(
                         EnemyKind::Dog => {}
                     }
 
-                    sfx.write(PlaySfx {
-                        kind: SfxKind::EnemyShoot(*kind),
-                        pos: tf.translation,
-                    });
+                    if delivers_now {
+                        sfx.write(PlaySfx {
+                            kind: SfxKind::EnemyShoot(*kind),
+                            pos: tf.translation,
+                        });
+                    }
 
                     continue;
                 }
@@ -1709,6 +1878,7 @@ fn enemy_ai_movement(
     solid: Res<SolidStatics>,
     mut sfx: MessageWriter<PlaySfx>,
     tunings: Res<EnemyTunings>,
+    skill: Res<crate::skill::SkillLevel>,
     mut shared: ResMut<AiSharedData>,
     mut q_doors: Query<(&DoorTile, &mut DoorState, &GlobalTransform)>,
     mut q_enemies: Query<
@@ -1720,6 +1890,7 @@ fn enemy_ai_movement(
             &Transform,  // Changed from &mut Dir8 to just &Transform
             Option<&EnemyMove>,
             &mut TableRng,
+            Option<&mut ChasePath>,
         ),
         (With<EnemyKind>, Without<Dead>),
     >,
@@ -1738,7 +1909,16 @@ fn enemy_ai_movement(
         IVec2::new(0, -1),
     ];
 
-    for (e, kind, mut ai, mut occ, tf, moving, mut actor_rng) in q_enemies.iter_mut() {
+    // Claims Resolve in Spawn Order, so Two Actors Racing for One Tile Always
+    // Settle the Same Way: the Earlier Actor Steps in, the Later One Waits or
+    // Takes Another Neighbor
+    let mut order: Vec<Entity> = q_enemies.iter().map(|(e, ..)| e).collect();
+    order.sort_unstable();
+
+    for e in order {
+        let Ok((e, kind, mut ai, mut occ, tf, moving, mut actor_rng, route)) = q_enemies.get_mut(e) else {
+            continue;
+        };
         if !matches!(ai.state, EnemyAiState::Chase) {
             continue;
         }
@@ -1766,6 +1946,33 @@ fn enemy_ai_movement(
             if shoot_dist <= GUARD_SHOOT_MAX_DIST_TILES
                 && has_line_of_sight(&grid, my_tile, player_tile)
             {
+                // Between Shots a Soldier Now and Then Shuffles One Tile Across
+                // the Line of Fire, Still Facing the Player so it Can Shoot Again
+                if side_steps(*kind) && actor_rng.us_rnd_t() < skill.side_step_chance() {
+                    if let Some(step) = select_side_step(
+                        &grid,
+                        &solid,
+                        &shared.occupied,
+                        my_tile,
+                        player_tile,
+                        &mut actor_rng,
+                    ) {
+                        let dest = my_tile + step;
+                        commands.entity(e).insert(PendingDir8(dir8_towards(dest, player_tile)));
+
+                        if CLAIM_TILE_EARLY {
+                            occ.0 = dest;
+                        }
+
+                        let target = Vec3::new(dest.x as f32, tf.translation.y, dest.y as f32);
+                        commands.entity(e).insert(EnemyMove {
+                            target,
+                            speed_tps: speed,
+                        });
+
+                        shared.claim_tile(e, my_tile, dest);
+                    }
+                }
                 continue;
             }
         }
@@ -1811,15 +2018,48 @@ fn enemy_ai_movement(
                 speed_tps: speed,
             });
 
-            shared.scheduled_move.insert(e);
-            shared.occupied.insert(dest);
-            if CLAIM_TILE_EARLY {
-                shared.occupied.remove(&my_tile);
-            }
+            shared.claim_tile(e, my_tile, dest);
 
             moved_or_acted = true;
         }
 
+        // Dogs Cannot Open Doors, so Rather Than Walking the Distance Map Downhill
+        // Into a Closed Door and Stalling There They Follow a Door-Free Route
+        // Guards Stay on the Distance Map: it Already Runs Through the Doors They
+        // Open, One Flood Serves Every Chaser Where Routes Would Cost a Search
+        // Each, and Stepping Downhill Keeps the Original's Direct Chase
+        if !moved_or_acted && matches!(*kind, EnemyKind::Dog) {
+            let next = match route {
+                Some(mut route) => route.next_step(&grid, &solid, my_tile, player_tile),
+                None => {
+                    let mut route = ChasePath::default();
+                    let next = route.next_step(&grid, &solid, my_tile, player_tile);
+                    commands.entity(e).insert(route);
+                    next
+                }
+            };
+
+            if let Some(dest) = next.filter(|&d| d != player_tile && !shared.occupied.contains(&d)) {
+                let step = dest - my_tile;
+                commands.entity(e).insert(PendingDir8(dir8_from_step(step)));
+                ai.last_step = step;
+
+                if CLAIM_TILE_EARLY {
+                    occ.0 = dest;
+                }
+
+                let target = Vec3::new(dest.x as f32, tf.translation.y, dest.y as f32);
+                commands.entity(e).insert(EnemyMove {
+                    target,
+                    speed_tps: speed,
+                });
+
+                shared.claim_tile(e, my_tile, dest);
+
+                moved_or_acted = true;
+            }
+        }
+
         if !moved_or_acted && in_bounds(my_tile) {
             let my_d = shared.dist_map[idx(my_tile)];
             if my_d >= 0 {
@@ -1866,7 +2106,7 @@ fn enemy_ai_movement(
                 if let Some((_score, dest, tile)) = best {
                     if tile == Tile::DoorClosed {
                         if !matches!(*kind, EnemyKind::Dog) {
-                            try_open_door_at(dest, &mut q_doors, &mut sfx);
+                            try_open_door_at(&grid, dest, &mut q_doors, &mut sfx);
                             ai.last_step = IVec2::ZERO;
                             moved_or_acted = true;
                         }
@@ -1888,11 +2128,7 @@ fn enemy_ai_movement(
                             speed_tps: speed,
                         });
 
-                        shared.scheduled_move.insert(e);
-                        shared.occupied.insert(dest);
-                        if CLAIM_TILE_EARLY {
-                            shared.occupied.remove(&my_tile);
-                        }
+                        shared.claim_tile(e, my_tile, dest);
 
                         moved_or_acted = true;
                     }
@@ -1923,16 +2159,12 @@ fn enemy_ai_movement(
                             speed_tps: speed,
                         });
 
-                        shared.scheduled_move.insert(e);
-                        shared.occupied.insert(dest);
-                        if CLAIM_TILE_EARLY {
-                            shared.occupied.remove(&my_tile);
-                        }
+                        shared.claim_tile(e, my_tile, dest);
                     }
                 }
                 ChasePick::OpenDoor(door_tile) => {
                     if !matches!(*kind, EnemyKind::Dog) {
-                        try_open_door_at(door_tile, &mut q_doors, &mut sfx);
+                        try_open_door_at(&grid, door_tile, &mut q_doors, &mut sfx);
                         ai.last_step = IVec2::ZERO;
                     }
                 }
@@ -2007,11 +2239,12 @@ fn enemy_ai_move(
 
 fn player_can_be_targeted(
     lock: Res<PlayerControlLock>,
+    paused: Res<GamePaused>,
     latch: Res<PlayerDeathLatch>,
     map: Option<Res<MapGrid>>,
     plane1: Option<Res<crate::level::WolfPlane1>>,
 ) -> bool {
-    !lock.0 && !latch.0 && map.is_some() && plane1.is_some()
+    !lock.0 && !paused.0 && !latch.0 && map.is_some() && plane1.is_some()
 }
 
 fn world_ready(
@@ -2091,3 +2324,412 @@ impl Plugin for EnemyAiPlugin {
             );
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Hits and Worst-Case Damage Over One Shot From Every Table Position
+    fn t_shoot_over_table(dist: i32, running: bool, visible: bool, kind: EnemyKind) -> (usize, i32) {
+        let shots: Vec<i32> = (0..256)
+            .filter_map(|seed| wolf_t_shoot(dist, running, visible, kind, &mut TableRng::seeded(seed)))
+            .collect();
+        (shots.len(), shots.into_iter().max().unwrap_or(0))
+    }
+
+    #[test]
+    fn enemy_accuracy_and_damage_fall_off_with_distance() {
+        // Point-Blank at a Player Standing Still Who Cannot See the Shooter Always Hits
+        let (hits, max_dmg) = t_shoot_over_table(0, false, false, EnemyKind::Guard);
+        assert_eq!(hits, 256);
+        assert!(max_dmg <= 63);
+
+        // Farther, Running and Watching Each Cost Accuracy
+        let near = t_shoot_over_table(2, false, true, EnemyKind::Guard).0;
+        let far = t_shoot_over_table(8, false, true, EnemyKind::Guard).0;
+        let dodging = t_shoot_over_table(8, true, true, EnemyKind::Guard).0;
+        assert!(near > far && far > dodging);
+        assert_eq!(t_shoot_over_table(10, true, true, EnemyKind::Guard).0, 0);
+
+        // SS Shoot as if a Third Closer, and Long Shots Graze for at Most 15
+        assert!(t_shoot_over_table(9, false, true, EnemyKind::Ss).0 > t_shoot_over_table(9, false, true, EnemyKind::Guard).0);
+        assert!(t_shoot_over_table(6, false, false, EnemyKind::Guard).1 <= 15);
+    }
+
+    #[test]
+    fn single_shot_wakes_only_guards_in_earshot() {
+        let player = IVec2::new(0, 0);
+        let guards: Vec<IVec2> = (1..=40).map(|x| IVec2::new(x, 0)).collect();
+
+        let woken: Vec<IVec2> = guards
+            .iter()
+            .copied()
+            .filter(|&g| notice_cause(g, player, false, true, false, &[]).is_some())
+            .collect();
+
+        assert_eq!(woken.len(), NOISE_RADIUS_TILES as usize);
+        assert!(woken.iter().all(|g| g.x <= NOISE_RADIUS_TILES));
+
+        // Ambush Guards Stay Deaf Even Point Blank
+        assert_eq!(notice_cause(IVec2::new(1, 0), player, false, true, true, &[]), None);
+    }
+
+    #[test]
+    fn sight_callout_reaches_neighbors_but_does_not_chain() {
+        let player = IVec2::new(0, 0);
+        let spotter = IVec2::new(30, 0);
+        let callouts = [spotter];
+
+        let neighbor = IVec2::new(31, 1);
+        let far = IVec2::new(34, 0);
+        assert_eq!(
+            notice_cause(neighbor, player, false, false, false, &callouts),
+            Some(NoticeCause::Callout)
+        );
+        assert_eq!(notice_cause(far, player, false, false, false, &callouts), None);
+
+        // Only Sight Becomes a New Callout, so the Neighbor's Wake Stops There
+        assert_eq!(
+            notice_cause(spotter, player, true, false, false, &[]),
+            Some(NoticeCause::Sight)
+        );
+    }
+
+    #[test]
+    fn guard_sees_player_in_front_but_not_behind() {
+        let (grid, _, _) = MapGrid::from_ascii(&[
+            "###########",
+            "#.........#",
+            "###########",
+        ]);
+        let guard = IVec2::new(5, 1);
+        let facing_east = dir8_from_step(IVec2::new(1, 0));
+        let sees = |player: IVec2| {
+            check_sight(
+                facing_east,
+                guard,
+                player,
+                Vec3::new(guard.x as f32, 0.5, guard.y as f32),
+                Vec3::new(player.x as f32, 0.5, player.y as f32),
+                &grid,
+            )
+        };
+
+        // Clear Corridor Both Ways, Only the Half Ahead is Seen
+        assert!(sees(IVec2::new(9, 1)));
+        assert!(!sees(IVec2::new(1, 1)));
+
+        // Point Blank (Inside MINSIGHT) Noticed Regardless of Facing
+        assert!(sees(IVec2::new(4, 1)));
+    }
+
+    #[test]
+    fn closed_door_muffles_gunfire_and_open_door_carries_it() {
+        let (grid, _, _) = MapGrid::from_ascii(&[
+            "##########",
+            "#P...D..G#",
+            "##########",
+        ]);
+        let player = IVec2::new(1, 1);
+        let guard_tile = IVec2::new(8, 1);
+
+        let mut app = App::new();
+        app.add_message::<PlaySfx>()
+            .insert_resource(EnemyTunings::baseline())
+            .insert_resource(SolidStatics::new(grid.width, grid.height))
+            .init_resource::<PlayerIntent>()
+            .init_resource::<crate::level::WolfPlane1>()
+            .init_resource::<AiSharedData>()
+            .insert_resource(grid)
+            .add_systems(Update, enemy_ai_prepare_and_activate);
+
+        app.world_mut().spawn((
+            Player,
+            GlobalTransform::from_translation(Vec3::new(player.x as f32, 0.5, player.y as f32)),
+        ));
+        // Facing Away Down the Corridor, so Only Noise Can Wake it
+        let guard = app
+            .world_mut()
+            .spawn((
+                EnemyKind::Guard,
+                EnemyAi::default(),
+                OccupiesTile(guard_tile),
+                Dir8(2),
+                Transform::from_xyz(guard_tile.x as f32, 0.5, guard_tile.y as f32),
+                TableRng::default(),
+            ))
+            .id();
+
+        let fire = |app: &mut App| {
+            app.world_mut().resource_mut::<AiSharedData>().made_noise = true;
+            app.update();
+            app.world().get::<EnemyAi>(guard).unwrap().react_tics
+        };
+
+        assert_eq!(fire(&mut app), 0, "shot carried through a closed door");
+
+        app.world_mut().resource_mut::<MapGrid>().set_tile(5, 1, Tile::DoorOpen);
+        assert!(fire(&mut app) > 0, "shot muffled by an open door");
+    }
+
+    #[test]
+    fn guards_never_share_a_tile_through_a_chokepoint() {
+        // Two Guards Either Side of a One-Tile Gap, Player Around the Corner
+        let (grid, _, _) = MapGrid::from_ascii(&[
+            "#########",
+            "#.......#",
+            "#.......#",
+            "####.####",
+            "####.####",
+            "####.####",
+            "####.####",
+            "####....#",
+            "#########",
+        ]);
+        let player_tile = IVec2::new(7, 7);
+        let solid = SolidStatics::new(grid.width, grid.height);
+
+        let mut app = App::new();
+        app.add_message::<PlaySfx>()
+            .insert_resource(EnemyTunings::baseline())
+            .init_resource::<crate::skill::SkillLevel>()
+            .insert_resource(SolidStatics::new(grid.width, grid.height))
+            .init_resource::<AiSharedData>()
+            .add_systems(Update, enemy_ai_movement);
+
+        let guard = |tile: IVec2| {
+            (
+                EnemyKind::Guard,
+                EnemyAi { state: EnemyAiState::Chase, ..default() },
+                OccupiesTile(tile),
+                Transform::from_xyz(tile.x as f32, 0.5, tile.y as f32),
+                TableRng::default(),
+            )
+        };
+        let first = app.world_mut().spawn(guard(IVec2::new(3, 2))).id();
+        let second = app.world_mut().spawn(guard(IVec2::new(5, 2))).id();
+
+        let mut shared = AiSharedData { player_tile, ..default() };
+        fill_chase_dist_map(&grid, &solid, player_tile, &mut shared.dist_map);
+        app.insert_resource(shared).insert_resource(grid);
+
+        let tile_of = |app: &App, e: Entity| app.world().get::<OccupiesTile>(e).unwrap().0;
+
+        for tic in 0..12 {
+            // Prepare's Share of the Tic: Fresh Claims From Every Actor's Tile
+            let tiles = [tile_of(&app, first), tile_of(&app, second)];
+            {
+                let mut shared = app.world_mut().resource_mut::<AiSharedData>();
+                shared.scheduled_move.clear();
+                shared.occupied = tiles.into_iter().collect();
+            }
+
+            app.update();
+
+            let (a, b) = (tile_of(&app, first), tile_of(&app, second));
+            assert_ne!(a, b, "guards share {a} on tic {tic}");
+
+            // Both Want the Gap's Mouth at Once, the Earlier Spawn Gets it
+            if tic == 0 {
+                assert_eq!(a, IVec2::new(4, 2));
+            }
+
+            // Finish Each Step Before the Next Tic
+            for e in [first, second] {
+                let mut entity = app.world_mut().entity_mut(e);
+                if let Some(mv) = entity.take::<EnemyMove>() {
+                    entity.get_mut::<Transform>().unwrap().translation = mv.target;
+                }
+            }
+        }
+
+        // Both Made it Through Single File
+        assert!(tile_of(&app, first).y >= 4);
+        assert!(tile_of(&app, second).y >= 3);
+    }
+
+    #[test]
+    fn side_step_takes_an_open_flank_and_holds_when_boxed_in() {
+        let (grid, _, _) = MapGrid::from_ascii(&[
+            "#########",
+            "#.......#",
+            "#.......#",
+            "#.......#",
+            "#########",
+        ]);
+        let solid = SolidStatics::new(grid.width, grid.height);
+        let player = IVec2::new(7, 2);
+        let guard = IVec2::new(2, 2);
+
+        // Open Room: Either Draw Steps Across the Line of Fire, Never Toward it
+        for seed in [0, 1, 2, 200] {
+            let step = select_side_step(&grid, &solid, &HashSet::new(), guard, player, &mut TableRng::seeded(seed))
+                .expect("open flank");
+            assert_eq!(step.x, 0);
+            assert_eq!(step.y.abs(), 1);
+        }
+
+        // Both Flanks Taken by Other Actors: Holds Position
+        let boxed: HashSet<IVec2> = [IVec2::new(2, 1), IVec2::new(2, 3)].into_iter().collect();
+        assert_eq!(select_side_step(&grid, &solid, &boxed, guard, player, &mut TableRng::seeded(0)), None);
+
+        // Against a Wall With the Other Flank Taken: Still Holds
+        let top = IVec2::new(2, 1);
+        let below: HashSet<IVec2> = [IVec2::new(2, 2)].into_iter().collect();
+        assert_eq!(select_side_step(&grid, &solid, &below, top, IVec2::new(7, 1), &mut TableRng::seeded(0)), None);
+
+        // Easiest Skill Never Rolls a Side-Step
+        assert_eq!(crate::skill::SkillLevel(0).side_step_chance(), 0);
+    }
+
+    #[test]
+    fn chase_map_crosses_plain_doors_but_not_locked_ones() {
+        let (mut grid, _, _) = MapGrid::from_ascii(&["#####", "#.D.#", "#####"]);
+        let solid = SolidStatics::new(grid.width, grid.height);
+        let mut dist = Vec::new();
+
+        fill_chase_dist_map(&grid, &solid, IVec2::new(3, 1), &mut dist);
+        assert_eq!(dist[grid.idx(1, 1)], 2);
+
+        // A Gold Key Door Is a Wall to Every Actor Until the Player Opens it
+        grid.set_plane0_code(2, 1, 92);
+        fill_chase_dist_map(&grid, &solid, IVec2::new(3, 1), &mut dist);
+        assert_eq!(dist[grid.idx(1, 1)], -1);
+
+        grid.set_tile(2, 1, Tile::DoorOpen);
+        fill_chase_dist_map(&grid, &solid, IVec2::new(3, 1), &mut dist);
+        assert_eq!(dist[grid.idx(1, 1)], 2);
+    }
+
+    /// Runs the Door Opener Once per Actor per Update, as Every Guard Stood at
+    /// `door` Would in the Same Tic, and Counts the Open Sounds Each Update
+    fn door_opener_app(grid: MapGrid, door: IVec2, actors: usize) -> (App, Entity) {
+        let mut app = App::new();
+        app.add_message::<PlaySfx>()
+            .insert_resource(grid)
+            .add_systems(
+                Update,
+                move |grid: Res<MapGrid>,
+                      mut q_doors: Query<(&DoorTile, &mut DoorState, &GlobalTransform)>,
+                      mut sfx: MessageWriter<PlaySfx>| {
+                    for _ in 0..actors {
+                        try_open_door_at(&grid, door, &mut q_doors, &mut sfx);
+                    }
+                },
+            );
+        let entity = app
+            .world_mut()
+            .spawn((
+                DoorTile(door),
+                DoorState { open_timer: 0.0, want_open: false },
+                GlobalTransform::default(),
+            ))
+            .id();
+        (app, entity)
+    }
+
+    fn door_sounds(app: &App) -> usize {
+        app.world()
+            .resource::<Messages<PlaySfx>>()
+            .iter_current_update_messages()
+            .filter(|m| m.kind == SfxKind::DoorOpen)
+            .count()
+    }
+
+    #[test]
+    fn guard_never_opens_a_key_door() {
+        let (mut grid, _, _) = MapGrid::from_ascii(&["#####", "#.D.#", "#####"]);
+        let door = IVec2::new(2, 1);
+
+        for code in [92, 95] {
+            grid.set_plane0_code(2, 1, code);
+            let (mut app, entity) = door_opener_app(grid.clone(), door, 1);
+
+            app.update();
+
+            let state = app.world().get::<DoorState>(entity).unwrap();
+            assert!(!state.want_open, "guard opened key door {code}");
+            assert_eq!(state.open_timer, 0.0);
+            assert_eq!(door_sounds(&app), 0);
+        }
+    }
+
+    #[test]
+    fn two_guards_at_one_door_open_it_once_and_keep_it_open() {
+        let (grid, _, _) = MapGrid::from_ascii(&["#####", "#.D.#", "#####"]);
+        let (mut app, entity) = door_opener_app(grid, IVec2::new(2, 1), 2);
+
+        app.update();
+        assert_eq!(door_sounds(&app), 1);
+
+        // The Second Request Neither Toggles the Door Back Nor Sounds Again
+        for _ in 0..3 {
+            app.world_mut().get_mut::<DoorState>(entity).unwrap().open_timer = 0.5;
+            app.update();
+
+            let state = app.world().get::<DoorState>(entity).unwrap();
+            assert!(state.want_open);
+            assert_eq!(state.open_timer, DOOR_OPEN_SECS);
+            assert_eq!(door_sounds(&app), 0);
+        }
+    }
+
+    #[test]
+    fn patrol_turns_back_from_a_key_door() {
+        let (mut grid, _, _) = MapGrid::from_ascii(&["###########", "#.......D.#", "###########"]);
+        let player = IVec2::new(1, 1);
+        let guard_tile = IVec2::new(7, 1);
+        let door = IVec2::new(8, 1);
+        grid.set_plane0_code(8, 1, 92);
+
+        let mut app = App::new();
+        app.add_message::<PlaySfx>()
+            .insert_resource(EnemyTunings::baseline())
+            .insert_resource(SolidStatics::new(grid.width, grid.height))
+            .init_resource::<PlayerIntent>()
+            .insert_resource(crate::level::WolfPlane1(vec![0; grid.width * grid.height]))
+            .init_resource::<AiSharedData>()
+            .insert_resource(grid)
+            .add_systems(Update, enemy_ai_prepare_and_activate);
+
+        app.world_mut().spawn((
+            Player,
+            GlobalTransform::from_translation(Vec3::new(player.x as f32, 0.5, player.y as f32)),
+        ));
+        let door_entity = app
+            .world_mut()
+            .spawn((
+                DoorTile(door),
+                DoorState { open_timer: 0.0, want_open: false },
+                GlobalTransform::default(),
+            ))
+            .id();
+        // Walking East, Away From the Player, Straight Into the Door
+        let guard = app
+            .world_mut()
+            .spawn((
+                EnemyKind::Guard,
+                EnemyAi { state: EnemyAiState::Patrol, ..default() },
+                Patrol,
+                OccupiesTile(guard_tile),
+                Dir8(2),
+                Transform::from_xyz(guard_tile.x as f32, 0.5, guard_tile.y as f32),
+                TableRng::default(),
+            ))
+            .id();
+
+        app.update();
+
+        assert_eq!(*app.world().get::<Dir8>(guard).unwrap(), Dir8(6));
+        assert!(app.world().get::<EnemyMove>(guard).is_none());
+        assert!(!app.world().get::<DoorState>(door_entity).unwrap().want_open);
+
+        // The Same Door Without a Lock Is Opened Instead
+        app.world_mut().resource_mut::<MapGrid>().set_plane0_code(8, 1, 90);
+        *app.world_mut().get_mut::<Dir8>(guard).unwrap() = Dir8(2);
+        app.update();
+
+        assert_eq!(*app.world().get::<Dir8>(guard).unwrap(), Dir8(2));
+        assert!(app.world().get::<DoorState>(door_entity).unwrap().want_open);
+    }
+}
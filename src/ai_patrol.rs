@@ -77,3 +77,30 @@ pub fn spawn_dir_and_patrol_for_kind(kind: EnemyKind, code: u16) -> Option<(Dir8
         _ => None,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arrows_and_spawn_codes_decode_to_the_same_compass() {
+        // ICONARROWS Run East, NE, North, NW, West, SW, South, SE
+        let steps: Vec<IVec2> = (90..=97)
+            .map(|c| patrol_step_8way(patrol_dir_from_plane1(c).unwrap()))
+            .collect();
+        assert_eq!(steps[0], IVec2::new(1, 0));
+        assert_eq!(steps[2], IVec2::new(0, -1));
+        assert_eq!(steps[4], IVec2::new(-1, 0));
+        assert_eq!(steps[6], IVec2::new(0, 1));
+        assert_eq!(patrol_dir_from_plane1(98), None);
+
+        // Guard 108 Stands Facing East, 112 Patrols East, 147 Is Hard Band Stand South
+        assert_eq!(spawn_dir_and_patrol_for_kind(EnemyKind::Guard, 108), Some((Dir8(2), false)));
+        assert_eq!(spawn_dir_and_patrol_for_kind(EnemyKind::Guard, 112), Some((Dir8(2), true)));
+        assert_eq!(spawn_dir_and_patrol_for_kind(EnemyKind::Guard, 147), Some((Dir8(0), false)));
+        assert_eq!(spawn_dir_and_patrol_for_kind(EnemyKind::Guard, 124), None);
+
+        // An Arrow Pointing the Same Way as a Spawn Facing Yields the Same Dir8
+        assert_eq!(patrol_dir_from_plane1(92), spawn_dir_and_patrol_for_kind(EnemyKind::Ss, 127).map(|(d, _)| d));
+    }
+}
@@ -0,0 +1,235 @@
+/*
+Davenstein - by David Petnick
+
+Startup Validation of Assets the Game Cannot Run Without
+
+A Missing Wall Atlas, HUD Digit, Viewmodel Frame or Menu Font Otherwise Only
+Shows up as a Silent Invisible Quad Deep in Gameplay. Validation Runs Once at
+Startup Against the Active Asset Source (assets.pak Index When Packaged, the
+Configured Asset Directory Otherwise) and Logs Every Missing File in One Error
+
+Enemy Sprites are Non-Critical and Only Warn, a Missing Enemy Frame Leaves the
+Rest of the Game Playable
+
+Any Image That Still Fails to Load is Replaced With a Generated Checkerboard so
+the Missing Texture is Obvious on Screen Instead of Invisible
+*/
+
+use bevy::{
+	asset::{AssetLoadFailedEvent, RenderAssetUsages},
+	prelude::*,
+	render::render_resource::{Extent3d, TextureDimension, TextureFormat},
+};
+use std::path::{Path, PathBuf};
+
+use davelib::enemies::{
+	DOG_CORPSE_PATH,
+	GENERAL_CORPSE_PATH,
+	GHOST_HITLER_CORPSE_PATH,
+	GRETEL_CORPSE_PATH,
+	GUARD_CORPSE_PATH,
+	HANS_CORPSE_PATH,
+	HITLER_CORPSE_PATH,
+	MECHA_HITLER_CORPSE_PATH,
+	MUTANT_CORPSE_PATH,
+	OFFICER_CORPSE_PATH,
+	OTTO_CORPSE_PATH,
+	SCHABBS_CORPSE_PATH,
+	SS_CORPSE_PATH,
+};
+use davelib::world::WALL_ATLAS_PATH;
+
+use crate::pak_assets::PakFileIndex;
+use crate::ui::{
+	HUD_DIGIT_BLANK_PATH,
+	MENU_FONT_BLACK_PATH,
+	MENU_FONT_GRAY_PATH,
+	MENU_FONT_WHITE_PATH,
+	MENU_FONT_YELLOW_PATH,
+	VIEWMODEL_FRAMES,
+	VIEWMODEL_WEAPONS,
+	hud_digit_path,
+	viewmodel_frame_path,
+};
+
+// One Representative Sprite per Enemy, Enough to Catch a Missing Folder
+const OPTIONAL_ENEMY_ASSETS: &[&str] = &[
+	GUARD_CORPSE_PATH,
+	DOG_CORPSE_PATH,
+	SS_CORPSE_PATH,
+	OFFICER_CORPSE_PATH,
+	MUTANT_CORPSE_PATH,
+	HANS_CORPSE_PATH,
+	GRETEL_CORPSE_PATH,
+	HITLER_CORPSE_PATH,
+	MECHA_HITLER_CORPSE_PATH,
+	GHOST_HITLER_CORPSE_PATH,
+	SCHABBS_CORPSE_PATH,
+	OTTO_CORPSE_PATH,
+	GENERAL_CORPSE_PATH,
+];
+
+// Fallback Checkerboard Size and Colors (Classic Missing-Texture Magenta)
+const FALLBACK_SIZE: u32 = 8;
+const FALLBACK_A: [u8; 4] = [255, 0, 255, 255];
+const FALLBACK_B: [u8; 4] = [0, 0, 0, 255];
+
+// Configured Filesystem Asset Root (AssetPlugin::file_path)
+#[derive(Resource, Debug, Clone)]
+pub struct AssetRoot(pub PathBuf);
+
+pub struct AssetCheckPlugin {
+	pub root: PathBuf,
+}
+
+impl Plugin for AssetCheckPlugin {
+	fn build(&self, app: &mut App) {
+		app.insert_resource(AssetRoot(self.root.clone()))
+			.add_systems(Startup, validate_assets)
+			.add_systems(Update, substitute_failed_images);
+	}
+}
+
+// Assets Without Which Gameplay or Menus Cannot Render
+pub fn critical_assets() -> Vec<String> {
+	let mut paths = vec![WALL_ATLAS_PATH.to_string()];
+
+	paths.extend((0..10).map(hud_digit_path));
+	paths.push(HUD_DIGIT_BLANK_PATH.to_string());
+
+	for weapon in VIEWMODEL_WEAPONS {
+		paths.extend((0..VIEWMODEL_FRAMES).map(|i| viewmodel_frame_path(weapon, i)));
+	}
+
+	paths.extend(
+		[
+			MENU_FONT_WHITE_PATH,
+			MENU_FONT_GRAY_PATH,
+			MENU_FONT_YELLOW_PATH,
+			MENU_FONT_BLACK_PATH,
+		]
+		.map(str::to_string),
+	);
+
+	paths
+}
+
+// Paths From the List That the Existence Check Rejects, Order Preserved
+pub fn missing_assets<'a, S: AsRef<str>>(
+	paths: &'a [S],
+	exists: impl Fn(&str) -> bool,
+) -> Vec<&'a str> {
+	paths
+		.iter()
+		.map(AsRef::as_ref)
+		.filter(|p| !exists(p))
+		.collect()
+}
+
+// Existence Check Against the Configured Asset Directory
+pub fn exists_under(root: &Path) -> impl Fn(&str) -> bool + '_ {
+	move |p| root.join(p).is_file()
+}
+
+fn validate_assets(root: Res<AssetRoot>, pak: Option<Res<PakFileIndex>>) {
+	let exists: Box<dyn Fn(&str) -> bool + '_> = match pak.as_deref() {
+		Some(index) => Box::new(|p: &str| index.contains(p)),
+		None => Box::new(exists_under(&root.0)),
+	};
+	let source = if pak.is_some() {
+		"assets.pak".to_string()
+	} else {
+		root.0.display().to_string()
+	};
+
+	let critical = critical_assets();
+	let missing = missing_assets(&critical, &exists);
+	if !missing.is_empty() {
+		error!(
+			"{} required asset(s) missing from '{}', fallback textures will be shown:\n\t{}",
+			missing.len(),
+			source,
+			missing.join("\n\t"),
+		);
+	}
+
+	let missing_optional = missing_assets(OPTIONAL_ENEMY_ASSETS, &exists);
+	if !missing_optional.is_empty() {
+		warn!(
+			"{} enemy sprite(s) missing from '{}':\n\t{}",
+			missing_optional.len(),
+			source,
+			missing_optional.join("\n\t"),
+		);
+	}
+}
+
+// Generated Checkerboard Used in Place of Any Image That Failed to Load
+pub fn fallback_image() -> Image {
+	let mut data = Vec::with_capacity((FALLBACK_SIZE * FALLBACK_SIZE * 4) as usize);
+	for y in 0..FALLBACK_SIZE {
+		for x in 0..FALLBACK_SIZE {
+			let cell = ((x / 2) + (y / 2)) % 2 == 0;
+			data.extend_from_slice(if cell { &FALLBACK_A } else { &FALLBACK_B });
+		}
+	}
+
+	Image::new(
+		Extent3d {
+			width: FALLBACK_SIZE,
+			height: FALLBACK_SIZE,
+			depth_or_array_layers: 1,
+		},
+		TextureDimension::D2,
+		data,
+		TextureFormat::Rgba8UnormSrgb,
+		RenderAssetUsages::default(),
+	)
+}
+
+fn substitute_failed_images(
+	mut failed: MessageReader<AssetLoadFailedEvent<Image>>,
+	mut images: ResMut<Assets<Image>>,
+) {
+	for ev in failed.read() {
+		warn!("Image '{}' failed to load, using fallback texture", ev.path);
+		if images.insert(ev.id, fallback_image()).is_err() {
+			warn!("Could not substitute fallback texture for '{}'", ev.path);
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::fs;
+
+	#[test]
+	fn validation_reports_file_missing_from_asset_dir() {
+		let root = std::env::temp_dir()
+			.join(format!("davenstein_asset_check_{}", std::process::id()));
+		let _ = fs::remove_dir_all(&root);
+
+		// Lay Out Every Critical Asset Except the Wall Atlas
+		let critical = critical_assets();
+		for p in critical.iter().filter(|p| p.as_str() != WALL_ATLAS_PATH) {
+			let path = root.join(p);
+			fs::create_dir_all(path.parent().unwrap()).unwrap();
+			fs::write(&path, b"").unwrap();
+		}
+
+		let missing = missing_assets(&critical, exists_under(&root));
+		fs::remove_dir_all(&root).unwrap();
+
+		assert_eq!(missing, vec![WALL_ATLAS_PATH]);
+	}
+
+	#[test]
+	fn fallback_image_is_a_full_checkerboard() {
+		let img = fallback_image();
+		let data = img.data.as_ref().expect("fallback image has pixel data");
+		assert_eq!(data.len(), (FALLBACK_SIZE * FALLBACK_SIZE * 4) as usize);
+		assert_eq!(&data[0..4], &FALLBACK_A);
+		assert_eq!(&data[8..12], &FALLBACK_B);
+	}
+}
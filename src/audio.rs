@@ -5,18 +5,23 @@ Davenstein - by David Petnick
 use bevy::prelude::*;
 use bevy::audio::{
 	AudioPlayer,
+	AudioSink,
+	AudioSinkPlayback,
 	AudioSource,
     PlaybackMode,
 	PlaybackSettings,
     SpatialScale,
     Volume,
 };
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use rand::RngExt;
 
+use crate::actors::Dead;
+use crate::ai::{EnemyAi, EnemyAiState};
 use crate::enemies::EnemyKind;
 use crate::level::{CurrentLevel, LevelId};
-use crate::options::{SoundSettings, MusicTrack, SfxSound};
+use crate::map::MapGrid;
+use crate::options::{AudioBus, SoundSettings, MusicTrack, SfxSound, UiSound};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum SfxKind {
@@ -26,6 +31,7 @@ pub enum SfxKind {
     NoWay,
     Pushwall,
     ElevatorSwitch,
+    SecretFound,
 
     // Sfx - Menu / UI
     MenuMove,
@@ -47,7 +53,10 @@ pub enum SfxKind {
     PistolFire,
     MachineGunFire,
     ChaingunFire,
+    DryFire,
     RocketImpact,
+    BulletRicochet,
+    HitConfirm,
 
     // Pickups - Weapons
     PickupChaingun,
@@ -75,6 +84,84 @@ pub enum SfxKind {
     EnemyDeath(EnemyKind),
 }
 
+/// Enemy Kinds Each Get an Alert, Shoot and Death Sound Slot
+const SFX_ENEMY_KINDS: [EnemyKind; 13] = [
+    EnemyKind::Guard,
+    EnemyKind::Ss,
+    EnemyKind::Officer,
+    EnemyKind::Mutant,
+    EnemyKind::Dog,
+    EnemyKind::Hans,
+    EnemyKind::Gretel,
+    EnemyKind::Hitler,
+    EnemyKind::MechaHitler,
+    EnemyKind::GhostHitler,
+    EnemyKind::Schabbs,
+    EnemyKind::Otto,
+    EnemyKind::General,
+];
+
+impl SfxKind {
+    /// Every Sound Kind, Used to Preload the Library
+    pub fn all() -> Vec<SfxKind> {
+        let mut kinds = vec![
+            SfxKind::DoorOpen,
+            SfxKind::DoorClose,
+            SfxKind::NoWay,
+            SfxKind::Pushwall,
+            SfxKind::ElevatorSwitch,
+            SfxKind::SecretFound,
+            SfxKind::MenuMove,
+            SfxKind::MenuSelect,
+            SfxKind::MenuBack,
+            SfxKind::IntermissionTick,
+            SfxKind::IntermissionConfirm,
+            SfxKind::IntermissionNoBonus,
+            SfxKind::IntermissionPercent100,
+            SfxKind::IntermissionBonusApply,
+            SfxKind::EpisodeVictoryYea,
+            SfxKind::KnifeSwing,
+            SfxKind::PistolFire,
+            SfxKind::MachineGunFire,
+            SfxKind::ChaingunFire,
+            SfxKind::DryFire,
+            SfxKind::RocketImpact,
+            SfxKind::BulletRicochet,
+            SfxKind::HitConfirm,
+            SfxKind::PickupChaingun,
+            SfxKind::PickupMachineGun,
+            SfxKind::PickupAmmo,
+            SfxKind::PickupHealthFirstAid,
+            SfxKind::PickupHealthDinner,
+            SfxKind::PickupHealthDogFood,
+            SfxKind::PickupOneUp,
+            SfxKind::PickupTreasureCross,
+            SfxKind::PickupTreasureChalice,
+            SfxKind::PickupTreasureChest,
+            SfxKind::PickupTreasureCrown,
+            SfxKind::PickupKey,
+        ];
+        for kind in SFX_ENEMY_KINDS {
+            kinds.extend([
+                SfxKind::EnemyAlert(kind),
+                SfxKind::EnemyShoot(kind),
+                SfxKind::EnemyDeath(kind),
+            ]);
+        }
+        kinds
+    }
+
+    /// Mix Bus This Sound Plays Through
+    /// Only Menu Navigation Sounds Use the UI Bus; Intermission Stingers Are
+    /// Part of the Game Mix and Stay on the SFX Bus With Combat / World Sounds
+    pub fn bus(self) -> AudioBus {
+        match self {
+            SfxKind::MenuMove | SfxKind::MenuSelect | SfxKind::MenuBack => AudioBus::Ui,
+            _ => AudioBus::Sfx,
+        }
+    }
+}
+
 #[allow(non_camel_case_types)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum LevelTrack {
@@ -127,6 +214,13 @@ impl LevelTrack {
     }
 }
 
+/// Layered Over the Level's Own Track While Enemies Are Hunting the Player
+const COMBAT_TRACK: LevelTrack = LevelTrack::GETTHEM_MUS;
+/// Quiet Time With Nobody Chasing Before Combat Music Lets Go
+const COMBAT_CALM_SECS: f32 = 4.0;
+/// Length of the Crossfade Between the Level and Combat Tracks
+const MUSIC_CROSSFADE_SECS: f32 = 1.5;
+
 fn track_for_level(level: LevelId) -> LevelTrack {
     match level {
         LevelId::E1M1 | LevelId::E1M5 | LevelId::E4M1 | LevelId::E4M5 => LevelTrack::GETTHEM_MUS,
@@ -153,6 +247,130 @@ fn track_for_level(level: LevelId) -> LevelTrack {
     }
 }
 
+/// Asset Paths Behind Each Sound, One Clip or a Set Played at Random. Swapping
+/// a Sound Is a One-Line Change Here. The Match Has No Catch-All so a New Kind
+/// Cannot Compile Without a Row; an Empty Row Is a Deliberately Silent Sound
+pub fn sfx_assets(kind: SfxKind) -> &'static [&'static str] {
+    match kind {
+        // Doors / World
+        SfxKind::DoorOpen => &["sounds/sfx/door_open.ogg"],
+        SfxKind::DoorClose => &["sounds/sfx/door_close.ogg"],
+        SfxKind::NoWay => &["sounds/sfx/no_way.ogg"],
+        SfxKind::Pushwall => &["sounds/sfx/pushwall.ogg"],
+        SfxKind::ElevatorSwitch => &["sounds/sfx/elevator_switch.wav"],
+        // Secret Found Chime (Reuses the 100% Tally Stinger)
+        SfxKind::SecretFound => &["sounds/sfx/stats/percent_100.ogg"],
+
+        // Menu / UI
+        SfxKind::MenuMove => &["sounds/sfx/menu/menu_move.ogg"],
+        SfxKind::MenuSelect => &["sounds/sfx/menu/menu_select.ogg"],
+        SfxKind::MenuBack => &["sounds/sfx/menu/menu_back.ogg"],
+
+        // Intermission / Score Tally
+        SfxKind::IntermissionTick => &["sounds/sfx/stats/tally_tick_b.ogg"],
+        SfxKind::IntermissionConfirm => &["sounds/sfx/stats/tally_tick_a.ogg"],
+        SfxKind::IntermissionNoBonus => &["sounds/sfx/stats/no_bonus.ogg"],
+        SfxKind::IntermissionPercent100 => &["sounds/sfx/stats/percent_100.ogg"],
+        SfxKind::IntermissionBonusApply => &["sounds/sfx/stats/bonus_apply.ogg"],
+
+        // Episode End Victory Yell
+        SfxKind::EpisodeVictoryYea => &["sounds/sfx/victory.wav"],
+
+        // Weapon Attack
+        SfxKind::KnifeSwing => &["sounds/sfx/weapons/knife/knife_jab.ogg"],
+        SfxKind::PistolFire => &["sounds/sfx/weapons/pistol/pistol_fire.wav"],
+        SfxKind::MachineGunFire => &["sounds/sfx/weapons/machinegun/machinegun_fire.wav"],
+        SfxKind::ChaingunFire => &["sounds/sfx/weapons/chaingun/chaingun_fire.wav"],
+        // Empty Trigger Click
+        SfxKind::DryFire => &["sounds/sfx/weapons/dry_fire.wav"],
+        SfxKind::RocketImpact => &["sounds/sfx/weapons/rocket/rocket_impact.wav"],
+        // Bullet Wall Hit
+        SfxKind::BulletRicochet => &["sounds/sfx/weapons/ricochet.wav"],
+        // A Player Shot Drew Blood
+        SfxKind::HitConfirm => &["sounds/sfx/weapons/hit_confirm.wav"],
+
+        // Weapon / Ammo Pickups
+        SfxKind::PickupChaingun => &["sounds/sfx/weapons/chaingun/chaingun_pickup.ogg"],
+        SfxKind::PickupMachineGun => &["sounds/sfx/weapons/machinegun/machinegun_pickup.ogg"],
+        SfxKind::PickupAmmo => &["sounds/sfx/weapons/ammo/ammo_pickup.ogg"],
+
+        // Health Pickups
+        SfxKind::PickupHealthFirstAid => &["sounds/sfx/health/first_aid.ogg"],
+        SfxKind::PickupHealthDinner => &["sounds/sfx/health/dinner.ogg"],
+        SfxKind::PickupHealthDogFood => &["sounds/sfx/health/dog_food.ogg"],
+        SfxKind::PickupOneUp => &["sounds/sfx/health/oneup.ogg"],
+
+        // Treasure
+        SfxKind::PickupTreasureCross => &["sounds/sfx/treasure/cross.ogg"],
+        SfxKind::PickupTreasureChalice => &["sounds/sfx/treasure/chalice.ogg"],
+        SfxKind::PickupTreasureChest => &["sounds/sfx/treasure/chest.ogg"],
+        SfxKind::PickupTreasureCrown => &["sounds/sfx/treasure/crown.ogg"],
+
+        // Keys
+        SfxKind::PickupKey => &["sounds/sfx/pickups/key.ogg"],
+
+        // Enemy Alerts. Mutants Never Call Out, Like the Original
+        SfxKind::EnemyAlert(kind) => match kind {
+            EnemyKind::Guard => &["sounds/sfx/enemies/guard/guard_alert.ogg"],
+            EnemyKind::Ss => &["sounds/sfx/enemies/ss/ss_alert.ogg"],
+            EnemyKind::Officer => &["sounds/sfx/enemies/officer/officer_alert.ogg"],
+            EnemyKind::Mutant => &[],
+            EnemyKind::Dog => &["sounds/sfx/enemies/dog/dog_alert.ogg"],
+            EnemyKind::Hans => &["sounds/sfx/enemies/hans/hans_alert.ogg"],
+            EnemyKind::Gretel => &["sounds/sfx/enemies/gretel/gretel_alert.ogg"],
+            EnemyKind::Hitler => &["sounds/sfx/enemies/hitler/hitler_alert.wav"],
+            EnemyKind::MechaHitler => &["sounds/sfx/enemies/mecha_hitler/mecha_hitler_alert.wav"],
+            EnemyKind::GhostHitler => &["sounds/sfx/enemies/ghost_hitler/ghost_hitler_alert.wav"],
+            EnemyKind::Schabbs => &["sounds/sfx/enemies/schabbs/schabbs_alert.wav"],
+            EnemyKind::Otto => &["sounds/sfx/enemies/otto/otto_alert.wav"],
+            EnemyKind::General => &["sounds/sfx/enemies/general/general_alert.wav"],
+        },
+
+        // Enemy Attacks (Dog Bite, Schabbs Syringe Throw, Otto / General Rockets).
+        // Ghost Hitler's Fireballs Fly Without a Launch Sound
+        SfxKind::EnemyShoot(kind) => match kind {
+            EnemyKind::Guard => &["sounds/sfx/enemies/guard/guard_shoot.ogg"],
+            EnemyKind::Ss => &["sounds/sfx/enemies/ss/ss_shoot.ogg"],
+            EnemyKind::Officer => &["sounds/sfx/enemies/officer/officer_shoot.ogg"],
+            EnemyKind::Mutant => &["sounds/sfx/enemies/mutant/mutant_shoot.ogg"],
+            EnemyKind::Dog => &["sounds/sfx/enemies/dog/dog_bite.ogg"],
+            EnemyKind::Hans => &["sounds/sfx/enemies/hans/hans_shoot.ogg"],
+            EnemyKind::Gretel => &["sounds/sfx/enemies/gretel/gretel_shoot.ogg"],
+            EnemyKind::Hitler => &["sounds/sfx/enemies/hitler/hitler_shoot.wav"],
+            EnemyKind::MechaHitler => &["sounds/sfx/enemies/mecha_hitler/mecha_hitler_shoot.wav"],
+            EnemyKind::GhostHitler => &[],
+            EnemyKind::Schabbs => &["sounds/sfx/enemies/schabbs/schabbs_throw.wav"],
+            EnemyKind::Otto => &["sounds/sfx/enemies/otto/otto_shoot.wav"],
+            EnemyKind::General => &["sounds/sfx/enemies/general/general_shoot.wav"],
+        },
+
+        // Enemy Deaths. Guards Pick One of Seven at Random
+        SfxKind::EnemyDeath(kind) => match kind {
+            EnemyKind::Guard => &[
+                "sounds/sfx/enemies/guard/guard_death_0.ogg",
+                "sounds/sfx/enemies/guard/guard_death_1.ogg",
+                "sounds/sfx/enemies/guard/guard_death_2.ogg",
+                "sounds/sfx/enemies/guard/guard_death_3.ogg",
+                "sounds/sfx/enemies/guard/guard_death_4.ogg",
+                "sounds/sfx/enemies/guard/guard_death_5.ogg",
+                "sounds/sfx/enemies/guard/guard_death_6.ogg",
+            ],
+            EnemyKind::Ss => &["sounds/sfx/enemies/ss/ss_death.ogg"],
+            EnemyKind::Officer => &["sounds/sfx/enemies/officer/officer_death.ogg"],
+            EnemyKind::Mutant => &["sounds/sfx/enemies/mutant/mutant_death.ogg"],
+            EnemyKind::Dog => &["sounds/sfx/enemies/dog/dog_death.ogg"],
+            EnemyKind::Hans => &["sounds/sfx/enemies/hans/hans_death.ogg"],
+            EnemyKind::Gretel => &["sounds/sfx/enemies/gretel/gretel_death.ogg"],
+            EnemyKind::Hitler => &["sounds/sfx/enemies/hitler/hitler_death.wav"],
+            EnemyKind::MechaHitler => &["sounds/sfx/enemies/mecha_hitler/mecha_hitler_death.wav"],
+            EnemyKind::GhostHitler => &["sounds/sfx/enemies/ghost_hitler/ghost_hitler_death.wav"],
+            EnemyKind::Schabbs => &["sounds/sfx/enemies/schabbs/schabbs_death.wav"],
+            EnemyKind::Otto => &["sounds/sfx/enemies/otto/otto_death.wav"],
+            EnemyKind::General => &["sounds/sfx/enemies/general/general_death.wav"],
+        },
+    }
+}
+
 #[derive(Clone, Copy, Debug, Message)]
 pub struct PlaySfx {
     pub kind: SfxKind,
@@ -194,9 +412,35 @@ pub enum MusicModeKind {
     Menu,
     Scores,
     Gameplay,
+    /// Gameplay With Alerted Enemies Chasing, Entered and Left by update_combat_music
+    Combat,
     LevelEnd,
 }
 
+impl MusicModeKind {
+    /// In a Level, Calm or Fighting
+    pub fn is_gameplay(self) -> bool {
+        matches!(self, Self::Gameplay | Self::Combat)
+    }
+}
+
+/// The Current Level's Own Track
+#[derive(Component)]
+pub struct LevelMusic;
+
+/// The Combat Track Playing Over a Ducked LevelMusic
+#[derive(Component)]
+pub struct CombatMusic;
+
+/// Music Volume Ramp as a Fraction of the Music Bus. A Track Faded to Silence
+/// Is Despawned if `despawn` Is Set, Otherwise it Keeps its Place
+#[derive(Component, Debug, Clone, Copy)]
+pub struct MusicFade {
+    pub level: f32,
+    pub target: f32,
+    pub despawn: bool,
+}
+
 #[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
 pub struct MusicMode(pub MusicModeKind);
 
@@ -256,340 +500,14 @@ pub fn setup_audio(mut commands: Commands, asset_server: Res<AssetServer>) {
     // Default Boot Mode
     commands.insert_resource(MusicMode(MusicModeKind::Splash));
 
-    // Library That Supports 1 or Many Clips per SfxKind
+    // Library That Supports 1 or Many Clips per SfxKind. Every Kind Gets an
+    // Entry (Empty for the Deliberately Silent Ones) and Each Clip Loads Once
+    // Here, so Playback Is Just a Handle Lookup
     let mut lib = SfxLibrary::default();
-
-    // Menu / UI
-    lib.insert_one(
-        SfxKind::MenuMove,
-        asset_server.load("sounds/sfx/menu/menu_move.ogg"),
-    );
-    lib.insert_one(
-        SfxKind::MenuSelect,
-        asset_server.load("sounds/sfx/menu/menu_select.ogg"),
-    );
-    lib.insert_one(
-        SfxKind::MenuBack,
-        asset_server.load("sounds/sfx/menu/menu_back.ogg"),
-    );
-
-    // Intermission / Score Tally
-    lib.insert_one(
-        SfxKind::IntermissionTick,
-        asset_server.load("sounds/sfx/stats/tally_tick_b.ogg"),
-    );
-    lib.insert_one(
-        SfxKind::IntermissionConfirm,
-        asset_server.load("sounds/sfx/stats/tally_tick_a.ogg"),
-    );
-    lib.insert_one(
-        SfxKind::IntermissionNoBonus,
-        asset_server.load("sounds/sfx/stats/no_bonus.ogg"),
-    );
-    lib.insert_one(
-        SfxKind::IntermissionPercent100,
-        asset_server.load("sounds/sfx/stats/percent_100.ogg"),
-    );
-    lib.insert_one(
-        SfxKind::IntermissionBonusApply,
-        asset_server.load("sounds/sfx/stats/bonus_apply.ogg"),
-    );
-
-    // Episode End Victory Yell
-    lib.insert_one(
-        SfxKind::EpisodeVictoryYea,
-        asset_server.load("sounds/sfx/victory.wav"),
-    );
-
-    // Doors
-    lib.insert_one(SfxKind::DoorOpen, asset_server.load("sounds/sfx/door_open.ogg"));
-    lib.insert_one(SfxKind::DoorClose, asset_server.load("sounds/sfx/door_close.ogg"));
-    lib.insert_one(SfxKind::NoWay, asset_server.load("sounds/sfx/no_way.ogg"));
-    lib.insert_one(SfxKind::Pushwall, asset_server.load("sounds/sfx/pushwall.ogg"));
-    lib.insert_one(SfxKind::ElevatorSwitch, asset_server.load("sounds/sfx/elevator_switch.wav"));
-
-    // Weapon Attack
-    lib.insert_one(
-        SfxKind::KnifeSwing,
-        asset_server.load("sounds/sfx/weapons/knife/knife_jab.ogg"),
-    );
-    lib.insert_one(
-        SfxKind::PistolFire,
-        asset_server.load("sounds/sfx/weapons/pistol/pistol_fire.wav"),
-    );
-    lib.insert_one(
-        SfxKind::MachineGunFire,
-        asset_server.load("sounds/sfx/weapons/machinegun/machinegun_fire.wav"),
-    );
-    lib.insert_one(
-        SfxKind::ChaingunFire,
-        asset_server.load("sounds/sfx/weapons/chaingun/chaingun_fire.wav"),
-    );
-    // Rocket Impact
-    lib.insert_one(
-        SfxKind::RocketImpact,
-        asset_server.load("sounds/sfx/weapons/rocket/rocket_impact.wav"),
-    );
-
-    // Weapon / Ammo Pickups
-    lib.insert_one(
-        SfxKind::PickupChaingun,
-        asset_server.load("sounds/sfx/weapons/chaingun/chaingun_pickup.ogg"),
-    );
-    lib.insert_one(
-        SfxKind::PickupMachineGun,
-        asset_server.load("sounds/sfx/weapons/machinegun/machinegun_pickup.ogg"),
-    );
-    lib.insert_one(
-        SfxKind::PickupAmmo,
-        asset_server.load("sounds/sfx/weapons/ammo/ammo_pickup.ogg"),
-    );
-
-    // Health Pickups
-    lib.insert_one(SfxKind::PickupHealthFirstAid, asset_server.load("sounds/sfx/health/first_aid.ogg"));
-    lib.insert_one(SfxKind::PickupHealthDinner, asset_server.load("sounds/sfx/health/dinner.ogg"));
-    lib.insert_one(SfxKind::PickupHealthDogFood, asset_server.load("sounds/sfx/health/dog_food.ogg"));
-    lib.insert_one(SfxKind::PickupOneUp, asset_server.load("sounds/sfx/health/oneup.ogg"));
-
-    // Treasure
-    lib.insert_one(
-        SfxKind::PickupTreasureCross,
-        asset_server.load("sounds/sfx/treasure/cross.ogg"),
-    );
-    lib.insert_one(
-        SfxKind::PickupTreasureChalice,
-        asset_server.load("sounds/sfx/treasure/chalice.ogg"),
-    );
-    lib.insert_one(
-        SfxKind::PickupTreasureChest,
-        asset_server.load("sounds/sfx/treasure/chest.ogg"),
-    );
-    lib.insert_one(
-        SfxKind::PickupTreasureCrown,
-        asset_server.load("sounds/sfx/treasure/crown.ogg"),
-    );
-
-    // Keys
-    lib.insert_one(
-        SfxKind::PickupKey,
-        asset_server.load("sounds/sfx/pickups/key.ogg"),
-    );
-
-    // Guard Alert
-    lib.insert_one(
-        SfxKind::EnemyAlert(EnemyKind::Guard),
-        asset_server.load("sounds/sfx/enemies/guard/guard_alert.ogg"),
-    );
-    // SS Alert
-    lib.insert_one(
-        SfxKind::EnemyAlert(EnemyKind::Ss),
-        asset_server.load("sounds/sfx/enemies/ss/ss_alert.ogg"),
-    );
-    // Officer Alert
-    lib.insert_one(
-        SfxKind::EnemyAlert(EnemyKind::Officer),
-        asset_server.load("sounds/sfx/enemies/officer/officer_alert.ogg"),
-    );
-    // Dog Alert
-    lib.insert_one(
-        SfxKind::EnemyAlert(EnemyKind::Dog),
-        asset_server.load("sounds/sfx/enemies/dog/dog_alert.ogg"),
-    );
-    // Ghost Hitler Alert
-    lib.insert_one(
-        SfxKind::EnemyAlert(EnemyKind::GhostHitler),
-        asset_server.load("sounds/sfx/enemies/ghost_hitler/ghost_hitler_alert.wav"),
-    );
-
-    // Guard Shoot
-    lib.insert_one(
-        SfxKind::EnemyShoot(EnemyKind::Guard),
-        asset_server.load("sounds/sfx/enemies/guard/guard_shoot.ogg"),
-    );
-    // SS Shoot
-    lib.insert_one(
-        SfxKind::EnemyShoot(EnemyKind::Ss),
-        asset_server.load("sounds/sfx/enemies/ss/ss_shoot.ogg"),
-    );
-    // Officer Shoot
-    lib.insert_one(
-        SfxKind::EnemyShoot(EnemyKind::Officer),
-        asset_server.load("sounds/sfx/enemies/officer/officer_shoot.ogg"),
-    );
-    // Mutant Shoot
-    lib.insert_one(
-        SfxKind::EnemyShoot(EnemyKind::Mutant),
-        asset_server.load("sounds/sfx/enemies/mutant/mutant_shoot.ogg"),
-    );
-    // Dog Bite
-    lib.insert_one(
-        SfxKind::EnemyShoot(EnemyKind::Dog),
-        asset_server.load("sounds/sfx/enemies/dog/dog_bite.ogg"),
-    );
-
-    // Guard Death Set (Random Pick in play_sfx_events)
-    lib.insert_one(
-        SfxKind::EnemyDeath(EnemyKind::Guard),
-        asset_server.load("sounds/sfx/enemies/guard/guard_death_0.ogg"),
-    );
-    lib.insert_one(
-        SfxKind::EnemyDeath(EnemyKind::Guard),
-        asset_server.load("sounds/sfx/enemies/guard/guard_death_1.ogg"),
-    );
-    lib.insert_one(
-        SfxKind::EnemyDeath(EnemyKind::Guard),
-        asset_server.load("sounds/sfx/enemies/guard/guard_death_2.ogg"),
-    );
-    lib.insert_one(
-        SfxKind::EnemyDeath(EnemyKind::Guard),
-        asset_server.load("sounds/sfx/enemies/guard/guard_death_3.ogg"),
-    );
-    lib.insert_one(
-        SfxKind::EnemyDeath(EnemyKind::Guard),
-        asset_server.load("sounds/sfx/enemies/guard/guard_death_4.ogg"),
-    );
-    lib.insert_one(
-        SfxKind::EnemyDeath(EnemyKind::Guard),
-        asset_server.load("sounds/sfx/enemies/guard/guard_death_5.ogg"),
-    );
-    lib.insert_one(
-        SfxKind::EnemyDeath(EnemyKind::Guard),
-        asset_server.load("sounds/sfx/enemies/guard/guard_death_6.ogg"),
-    );
-    // SS Death
-    lib.insert_one(
-        SfxKind::EnemyDeath(EnemyKind::Ss),
-        asset_server.load("sounds/sfx/enemies/ss/ss_death.ogg"),
-    );
-    // Officer Death
-    lib.insert_one(
-        SfxKind::EnemyDeath(EnemyKind::Officer),
-        asset_server.load("sounds/sfx/enemies/officer/officer_death.ogg"),
-    );
-    // Mutant Death
-    lib.insert_one(
-        SfxKind::EnemyDeath(EnemyKind::Mutant),
-        asset_server.load("sounds/sfx/enemies/mutant/mutant_death.ogg"),
-    );
-    // Dog Death
-    lib.insert_one(
-        SfxKind::EnemyDeath(EnemyKind::Dog),
-        asset_server.load("sounds/sfx/enemies/dog/dog_death.ogg"),
-    );
-    // Ghost Hitler Death
-    lib.insert_one(
-        SfxKind::EnemyDeath(EnemyKind::GhostHitler),
-        asset_server.load("sounds/sfx/enemies/ghost_hitler/ghost_hitler_death.wav"),
-    );
-
-    // Bosses
-    // Hans Alert
-    lib.insert_one(
-        SfxKind::EnemyAlert(EnemyKind::Hans),
-        asset_server.load("sounds/sfx/enemies/hans/hans_alert.ogg"),
-    );
-    // Gretel Alert
-    lib.insert_one(
-        SfxKind::EnemyAlert(EnemyKind::Gretel),
-        asset_server.load("sounds/sfx/enemies/gretel/gretel_alert.ogg"),
-    );
-    // Hitler Alert
-    lib.insert_one(
-        SfxKind::EnemyAlert(EnemyKind::Hitler),
-        asset_server.load("sounds/sfx/enemies/hitler/hitler_alert.wav"),
-    );
-    // Mecha Hitler Alert
-    lib.insert_one(
-        SfxKind::EnemyAlert(EnemyKind::MechaHitler),
-        asset_server.load("sounds/sfx/enemies/mecha_hitler/mecha_hitler_alert.wav"),
-    );
-    // Dr Schabbs Alert
-    lib.insert_one(
-        SfxKind::EnemyAlert(EnemyKind::Schabbs),
-        asset_server.load("sounds/sfx/enemies/schabbs/schabbs_alert.wav"),
-    );
-    // Otto Giftmacher Alert
-    lib.insert_one(
-        SfxKind::EnemyAlert(EnemyKind::Otto),
-        asset_server.load("sounds/sfx/enemies/otto/otto_alert.wav"),
-    );
-    // General Fettgesicht Alert
-    lib.insert_one(
-        SfxKind::EnemyAlert(EnemyKind::General),
-        asset_server.load("sounds/sfx/enemies/general/general_alert.wav"),
-    );
-
-    // Hans Shoot
-    lib.insert_one(
-        SfxKind::EnemyShoot(EnemyKind::Hans),
-        asset_server.load("sounds/sfx/enemies/hans/hans_shoot.ogg"),
-    );
-    // Gretel Shoot
-    lib.insert_one(
-        SfxKind::EnemyShoot(EnemyKind::Gretel),
-        asset_server.load("sounds/sfx/enemies/gretel/gretel_shoot.ogg"),
-    );
-    // Hitler Shoot
-    lib.insert_one(
-        SfxKind::EnemyShoot(EnemyKind::Hitler),
-        asset_server.load("sounds/sfx/enemies/hitler/hitler_shoot.wav"),
-    );
-    // Mecha Hitler Shoot
-    lib.insert_one(
-        SfxKind::EnemyShoot(EnemyKind::MechaHitler),
-        asset_server.load("sounds/sfx/enemies/mecha_hitler/mecha_hitler_shoot.wav"),
-    );
-    // Dr Schabbs Throw Syringe
-    lib.insert_one(
-        SfxKind::EnemyShoot(EnemyKind::Schabbs),
-        asset_server.load("sounds/sfx/enemies/schabbs/schabbs_throw.wav"),
-    );
-    // Otto Giftmacher Fire Rocket
-    lib.insert_one(
-        SfxKind::EnemyShoot(EnemyKind::Otto),
-        asset_server.load("sounds/sfx/enemies/otto/otto_shoot.wav"),
-    );
-    // General Fettgesicht Fire Rocket
-    lib.insert_one(
-        SfxKind::EnemyShoot(EnemyKind::General),
-        asset_server.load("sounds/sfx/enemies/general/general_shoot.wav"),
-    );
-
-    // Hans Death
-    lib.insert_one(
-        SfxKind::EnemyDeath(EnemyKind::Hans),
-        asset_server.load("sounds/sfx/enemies/hans/hans_death.ogg"),
-    );
-    // Gretel Death
-    lib.insert_one(
-        SfxKind::EnemyDeath(EnemyKind::Gretel),
-        asset_server.load("sounds/sfx/enemies/gretel/gretel_death.ogg"),
-    );
-    // Hitler Death
-    lib.insert_one(
-        SfxKind::EnemyDeath(EnemyKind::Hitler),
-        asset_server.load("sounds/sfx/enemies/hitler/hitler_death.wav"),
-    );
-    // Mecha Hitler Death
-    lib.insert_one(
-        SfxKind::EnemyDeath(EnemyKind::MechaHitler),
-        asset_server.load("sounds/sfx/enemies/mecha_hitler/mecha_hitler_death.wav"),
-    );
-    // Dr Schabbs Death
-    lib.insert_one(
-        SfxKind::EnemyDeath(EnemyKind::Schabbs),
-        asset_server.load("sounds/sfx/enemies/schabbs/schabbs_death.wav"),
-    );
-    // Otto Giftmacher Death
-    lib.insert_one(
-        SfxKind::EnemyDeath(EnemyKind::Otto),
-        asset_server.load("sounds/sfx/enemies/otto/otto_death.wav"),
-    );
-    // General Fettgesicht Death
-    lib.insert_one(
-        SfxKind::EnemyDeath(EnemyKind::General),
-        asset_server.load("sounds/sfx/enemies/general/general_death.wav"),
-    );
+    for kind in SfxKind::all() {
+        let clips = lib.map.entry(kind).or_default();
+        clips.extend(sfx_assets(kind).iter().map(|&path| asset_server.load(path)));
+    }
 
     commands.insert_resource(lib);
 }
@@ -598,6 +516,7 @@ pub fn start_music(
     mut commands: Commands,
     audio: Res<GameAudio>,
     mode: Res<MusicMode>,
+    level: Res<CurrentLevel>,
     settings: Res<SoundSettings>,
     q_music: Query<(), With<Music>>,
 ) {
@@ -613,9 +532,9 @@ pub fn start_music(
         MusicModeKind::Splash => audio.music_splash.clone(),
         MusicModeKind::Menu => audio.music_main_menu.clone(),
         MusicModeKind::Scores => audio.music_scores_menu.clone(),
-        MusicModeKind::Gameplay => audio
+        MusicModeKind::Gameplay | MusicModeKind::Combat => audio
             .music_levels
-            .get(&LevelTrack::GETTHEM_MUS)
+            .get(&track_for_level(level.0))
             .cloned()
             .unwrap_or_default(),
         MusicModeKind::LevelEnd => audio.music_level_end.clone(),
@@ -625,7 +544,7 @@ pub fn start_music(
         Music,
         MusicTrack,
         AudioPlayer::new(clip),
-        PlaybackSettings::LOOP.with_volume(Volume::Linear(settings.effective_music_volume())),
+        PlaybackSettings::LOOP.with_volume(Volume::Linear(settings.bus_volume(AudioBus::Music))),
     ));
 }
 
@@ -637,12 +556,14 @@ pub fn sync_boot_music(
     q_music: Query<Entity, With<Music>>,
     mut last: Local<Option<MusicModeKind>>,
 ) {
-    if mode.0 == MusicModeKind::Gameplay {
+    if mode.0.is_gameplay() {
         *last = Some(MusicModeKind::Gameplay);
         return;
     }
 
-    if *last == Some(mode.0) {
+    // Same Mode Still Playing: Nothing to Do. A Mode Whose Track Was Skipped
+    // While Music Was Off Starts as Soon as Music is Switched Back On
+    if *last == Some(mode.0) && !q_music.is_empty() {
         return;
     }
 
@@ -660,14 +581,14 @@ pub fn sync_boot_music(
         MusicModeKind::Menu => audio.music_main_menu.clone(),
         MusicModeKind::Scores => audio.music_scores_menu.clone(),
         MusicModeKind::LevelEnd => audio.music_level_end.clone(),
-        MusicModeKind::Gameplay => unreachable!(),
+        MusicModeKind::Gameplay | MusicModeKind::Combat => unreachable!(),
     };
 
     commands.spawn((
         Music,
         MusicTrack,
         AudioPlayer::new(clip),
-        PlaybackSettings::LOOP.with_volume(Volume::Linear(settings.effective_music_volume())),
+        PlaybackSettings::LOOP.with_volume(Volume::Linear(settings.bus_volume(AudioBus::Music))),
     ));
 
     *last = Some(mode.0);
@@ -684,7 +605,7 @@ pub fn sync_level_music(
 ) {
     // If we're not in gameplay, clear the cached gameplay-level marker
     // Otherwise returning to gameplay on the same level won't restart the level music
-    if mode.0 != MusicModeKind::Gameplay {
+    if !mode.0.is_gameplay() {
         *last = None;
         return;
     }
@@ -710,10 +631,12 @@ pub fn sync_level_music(
         commands.spawn((
             Music,
             MusicTrack,
+            LevelMusic,
+            MusicFade { level: 1.0, target: 1.0, despawn: false },
             AudioPlayer::new(handle),
             PlaybackSettings {
                 mode: PlaybackMode::Loop,
-                volume: Volume::Linear(settings.effective_music_volume()),
+                volume: Volume::Linear(settings.bus_volume(AudioBus::Music)),
                 ..default()
             },
         ));
@@ -722,6 +645,100 @@ pub fn sync_level_music(
     *last = Some(level.0);
 }
 
+/// Gameplay Turns to Combat While Any Living Enemy Is Chasing, and Back Once
+/// Nobody Has Been for COMBAT_CALM_SECS. Menus and Intermissions Are Left Alone
+pub fn update_combat_music(
+    time: Res<Time>,
+    mut mode: ResMut<MusicMode>,
+    q_ai: Query<&EnemyAi, Without<Dead>>,
+    mut calm: Local<f32>,
+) {
+    if !mode.0.is_gameplay() {
+        *calm = 0.0;
+        return;
+    }
+
+    if q_ai.iter().any(|ai| ai.state == EnemyAiState::Chase) {
+        *calm = 0.0;
+        if mode.0 != MusicModeKind::Combat {
+            mode.0 = MusicModeKind::Combat;
+        }
+    } else if mode.0 == MusicModeKind::Combat {
+        *calm += time.delta_secs();
+        if *calm >= COMBAT_CALM_SECS {
+            *calm = 0.0;
+            mode.0 = MusicModeKind::Gameplay;
+        }
+    }
+}
+
+/// Crossfade Between the Level Track and the Combat Track. The Level Track Is
+/// Only Ducked, Never Stopped, so Calm Music Resumes Where it Left Off
+pub fn sync_combat_music(
+    mut commands: Commands,
+    audio: Res<GameAudio>,
+    mode: Res<MusicMode>,
+    settings: Res<SoundSettings>,
+    level: Res<CurrentLevel>,
+    mut q_level: Query<&mut MusicFade, (With<LevelMusic>, Without<CombatMusic>)>,
+    mut q_combat: Query<&mut MusicFade, (With<CombatMusic>, Without<LevelMusic>)>,
+) {
+    // A Level Already Scored With the Combat Track Has Nothing to Switch to
+    let fighting = mode.0 == MusicModeKind::Combat && track_for_level(level.0) != COMBAT_TRACK;
+
+    let Some(mut level_fade) = q_level.iter_mut().next() else { return; };
+    level_fade.target = if fighting { 0.0 } else { 1.0 };
+
+    let mut combat_playing = false;
+    for mut fade in q_combat.iter_mut() {
+        fade.target = if fighting { 1.0 } else { 0.0 };
+        fade.despawn = !fighting;
+        combat_playing = true;
+    }
+
+    if fighting && !combat_playing && settings.music_enabled {
+        if let Some(handle) = audio.music_levels.get(&COMBAT_TRACK).cloned() {
+            commands.spawn((
+                Music,
+                MusicTrack,
+                CombatMusic,
+                MusicFade { level: 0.0, target: 1.0, despawn: false },
+                AudioPlayer::new(handle),
+                PlaybackSettings {
+                    mode: PlaybackMode::Loop,
+                    volume: Volume::Linear(0.0),
+                    ..default()
+                },
+            ));
+        }
+    }
+}
+
+/// Step Every MusicFade Toward its Target and Write it to the Sink, Every Frame
+/// so a Settings Change Cannot Pop a Ducked Track Back to Full Volume
+pub fn tick_music_fades(
+    mut commands: Commands,
+    time: Res<Time>,
+    settings: Res<SoundSettings>,
+    mut q: Query<(Entity, &mut MusicFade, Option<&mut AudioSink>)>,
+) {
+    let step = time.delta_secs() / MUSIC_CROSSFADE_SECS;
+    let bus = settings.bus_volume(AudioBus::Music);
+
+    for (e, mut fade, sink) in q.iter_mut() {
+        let delta = (fade.target - fade.level).clamp(-step, step);
+        fade.level += delta;
+
+        if fade.despawn && fade.level <= 0.0 {
+            commands.entity(e).try_despawn();
+            continue;
+        }
+        if let Some(mut sink) = sink {
+            sink.set_volume(Volume::Linear(bus * fade.level));
+        }
+    }
+}
+
 fn is_pickup_kind(k: SfxKind) -> bool {
     matches!(
         k,
@@ -816,11 +833,79 @@ pub fn tick_auto_stop_sfx(
 	}
 }
 
+/// A Kind With No Library Entry Warns the First Time it Is Played Only
+fn warn_missing_sfx(warned: &mut HashSet<SfxKind>, kind: SfxKind) {
+    if warned.insert(kind) {
+        warn!("Missing SFX for {:?}", kind);
+    }
+}
+
+/// Gain for a Sound With Walls or Closed Doors Between it and the Listener
+pub const OCCLUDED_SFX_GAIN: f32 = 0.45;
+
+/// Farthest a World Sound Carries, in Tiles. Gunfire Carries Across a Floor,
+/// a Knife Swing or a Pickup Only Across a Room
+pub fn max_audible_dist(kind: SfxKind) -> f32 {
+    match kind {
+        SfxKind::PistolFire
+        | SfxKind::MachineGunFire
+        | SfxKind::ChaingunFire
+        | SfxKind::EnemyShoot(_)
+        | SfxKind::RocketImpact => 40.0,
+
+        SfxKind::EnemyAlert(_) | SfxKind::EnemyDeath(_) => 28.0,
+
+        SfxKind::DoorOpen
+        | SfxKind::DoorClose
+        | SfxKind::Pushwall
+        | SfxKind::ElevatorSwitch => 20.0,
+
+        SfxKind::BulletRicochet => 14.0,
+
+        SfxKind::KnifeSwing | SfxKind::NoWay | SfxKind::DryFire => 6.0,
+
+        _ => 10.0,
+    }
+}
+
+/// Distance Falloff for `kind` Heard From `dist` Tiles Away. Eases Out so
+/// Nearby Sounds Stay at Full Volume, Reaching Silence at max_audible_dist
+pub fn distance_gain(kind: SfxKind, dist: f32) -> f32 {
+    let t = (dist / max_audible_dist(kind)).clamp(0.0, 1.0);
+    1.0 - t * t
+}
+
+/// Muffling for a World Sound Heard From `listener`, Coming From Behind Walls
+pub fn occlusion_gain(grid: Option<&MapGrid>, listener: Vec3, source: Vec3) -> f32 {
+	let Some(grid) = grid else { return 1.0; };
+	let tile = |p: Vec3| IVec2::new((p.x + 0.5).floor() as i32, (p.z + 0.5).floor() as i32);
+
+	if crate::ai::has_line_of_sight(grid, tile(listener), tile(source)) {
+		1.0
+	} else {
+		OCCLUDED_SFX_GAIN
+	}
+}
+
+/// Combined Distance and Wall Gain for a World Sound. UI Sounds Are Emitted at
+/// Vec3::ZERO and Are Never Attenuated
+pub fn world_sfx_gain(grid: Option<&MapGrid>, kind: SfxKind, listener: Vec3, source: Vec3) -> f32 {
+	if source == Vec3::ZERO {
+		return 1.0;
+	}
+	let dist = Vec2::new(source.x - listener.x, source.z - listener.z).length();
+
+	distance_gain(kind, dist) * occlusion_gain(grid, listener, source)
+}
+
 pub fn play_sfx_events(
 	lib: Res<SfxLibrary>,
 	settings: Res<SoundSettings>,
+	grid: Option<Res<MapGrid>>,
+	q_listener: Query<&GlobalTransform, With<bevy::audio::SpatialListener>>,
 	mut commands: Commands,
 	mut ev: MessageReader<PlaySfx>,
+	mut warned: Local<HashSet<SfxKind>>,
 	q_active_pickup: Query<Entity, With<ActivePickupSfx>>,
 	q_active_enemy_voice: Query<Entity, With<ActiveEnemyVoiceSfx>>,
 	q_active_intermission: Query<Entity, With<ActiveIntermissionSfx>>,
@@ -867,11 +952,11 @@ pub fn play_sfx_events(
 				| SfxKind::IntermissionBonusApply
 		);
 
-		let is_menu = matches!(e.kind, SfxKind::MenuMove | SfxKind::MenuSelect | SfxKind::MenuBack);
+		let is_menu = e.kind.bus() == AudioBus::Ui;
 
 		if is_menu {
 			let Some(list) = lib.map.get(&e.kind) else {
-				warn!("Missing SFX for {:?}", e.kind);
+				warn_missing_sfx(&mut warned, e.kind);
 				continue;
 			};
 			if list.is_empty() {
@@ -885,14 +970,14 @@ pub fn play_sfx_events(
 			let i = rng.random_range(0..list.len());
 			let clip = list[i].clone();
 
-			let sfx_vol = settings.effective_sfx_volume();
+			let ui_vol = settings.bus_volume(AudioBus::Ui);
 			let playback_settings = PlaybackSettings::DESPAWN
 				.with_spatial(false)
-				.with_volume(Volume::Linear(sfx_vol));
+				.with_volume(Volume::Linear(ui_vol));
 
 			commands.spawn((
 				ActiveMenuSfx,
-				SfxSound,
+				UiSound,
 				Transform::from_translation(e.pos),
 				AudioPlayer::new(clip),
 				playback_settings,
@@ -903,7 +988,7 @@ pub fn play_sfx_events(
 
 		if is_intermission {
 			let Some(list) = lib.map.get(&e.kind) else {
-				warn!("Missing SFX for {:?}", e.kind);
+				warn_missing_sfx(&mut warned, e.kind);
 				continue;
 			};
 			if list.is_empty() {
@@ -917,7 +1002,7 @@ pub fn play_sfx_events(
 			let i = rng.random_range(0..list.len());
 			let clip = list[i].clone();
 
-			let sfx_vol = settings.effective_sfx_volume();
+			let sfx_vol = settings.bus_volume(e.kind.bus());
 			let playback_settings = PlaybackSettings::DESPAWN
 				.with_spatial(false)
 				.with_volume(Volume::Linear(sfx_vol));
@@ -939,7 +1024,7 @@ pub fn play_sfx_events(
 		}
 
 		let Some(list) = lib.map.get(&e.kind) else {
-			warn!("Missing SFX for {:?}", e.kind);
+			warn_missing_sfx(&mut warned, e.kind);
 			continue;
 		};
 		if list.is_empty() {
@@ -990,9 +1075,9 @@ if is_enemy_voice && !is_boss_voice {
 			}
 		}
 
-		let sfx_vol = settings.effective_sfx_volume();
+		let sfx_vol = settings.bus_volume(e.kind.bus());
 
-		let playback_settings = match e.kind {
+		let mut playback_settings = match e.kind {
 			SfxKind::DoorOpen
 			| SfxKind::DoorClose
 			| SfxKind::NoWay
@@ -1002,11 +1087,24 @@ if is_enemy_voice && !is_boss_voice {
 				.with_spatial_scale(SpatialScale::new(0.12))
 				.with_volume(Volume::Linear(1.0 * sfx_vol)),
 
+			SfxKind::SecretFound | SfxKind::DryFire => PlaybackSettings::DESPAWN
+				.with_spatial(false)
+				.with_volume(Volume::Linear(1.0 * sfx_vol)),
+
             SfxKind::RocketImpact => PlaybackSettings::DESPAWN
                 .with_spatial(true)
                 .with_spatial_scale(SpatialScale::new(0.10))
                 .with_volume(Volume::Linear(2.5 * sfx_vol)),
 
+            SfxKind::HitConfirm => PlaybackSettings::DESPAWN
+                .with_spatial(false)
+                .with_volume(Volume::Linear(0.6 * sfx_vol)),
+
+            SfxKind::BulletRicochet => PlaybackSettings::DESPAWN
+                .with_spatial(true)
+                .with_spatial_scale(SpatialScale::new(0.12))
+                .with_volume(Volume::Linear(0.35 * sfx_vol)),
+
 			SfxKind::KnifeSwing
 			| SfxKind::PistolFire
 			| SfxKind::MachineGunFire
@@ -1111,6 +1209,17 @@ if is_enemy_voice && !is_boss_voice {
 			| SfxKind::IntermissionBonusApply => PlaybackSettings::DESPAWN.with_spatial(false),
 		};
 
+		// Only Sounds Placed in the World Fade and Muffle, Flat UI / Boss Playback Never Does
+		if playback_settings.spatial {
+			if let (Some(listener), Volume::Linear(v)) = (q_listener.iter().next(), playback_settings.volume) {
+				let gain = world_sfx_gain(grid.as_deref(), e.kind, listener.translation(), e.pos);
+				if gain <= 0.0 {
+					continue;
+				}
+				playback_settings.volume = Volume::Linear(v * gain);
+			}
+		}
+
 		if is_enemy_voice {
             if is_boss_voice {
                 commands.spawn((
@@ -1178,7 +1287,7 @@ if is_enemy_voice && !is_boss_voice {
 	}
 
 	let Some(list) = lib.map.get(&e.kind) else {
-		warn!("Missing SFX for {:?}", e.kind);
+		warn_missing_sfx(&mut warned, e.kind);
 		return;
 	};
 	if list.is_empty() {
@@ -1194,7 +1303,7 @@ if is_enemy_voice && !is_boss_voice {
 	let i = rng.random_range(0..list.len());
 	let clip = list[i].clone();
 
-	let sfx_vol = settings.effective_sfx_volume();
+	let sfx_vol = settings.bus_volume(e.kind.bus());
 	let playback_settings = PlaybackSettings::DESPAWN
 		.with_spatial(true)
 		.with_spatial_scale(SpatialScale::new(0.12))
@@ -1207,3 +1316,233 @@ if is_enemy_voice && !is_boss_voice {
 		playback_settings,
 	));
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sounds_behind_walls_are_muffled() {
+        let (grid, _, _) = MapGrid::from_ascii(&[
+            "#######",
+            "#.....#",
+            "###D###",
+            "#.....#",
+            "#######",
+        ]);
+        let listener = Vec3::new(1.0, 0.5, 1.0);
+
+        assert_eq!(occlusion_gain(Some(&grid), listener, Vec3::new(5.0, 0.6, 1.0)), 1.0);
+        assert_eq!(occlusion_gain(Some(&grid), listener, Vec3::new(5.0, 0.6, 3.0)), OCCLUDED_SFX_GAIN);
+
+        // Before Any Level Exists Nothing Is Occluded
+        assert_eq!(occlusion_gain(None, listener, Vec3::new(5.0, 0.6, 3.0)), 1.0);
+    }
+
+    #[test]
+    fn knife_fades_out_before_gunfire_does() {
+        let listener = Vec3::new(1.0, 0.5, 1.0);
+        let source = Vec3::new(13.0, 0.6, 1.0);
+
+        assert_eq!(world_sfx_gain(None, SfxKind::KnifeSwing, listener, source), 0.0);
+        assert!(world_sfx_gain(None, SfxKind::PistolFire, listener, source) > 0.5);
+        assert!(world_sfx_gain(None, SfxKind::EnemyShoot(EnemyKind::Guard), listener, source) > 0.5);
+
+        // Falloff Is Full Up Close and Only Ever Drops With Distance
+        assert_eq!(distance_gain(SfxKind::PistolFire, 0.0), 1.0);
+        assert!(distance_gain(SfxKind::PistolFire, 30.0) < distance_gain(SfxKind::PistolFire, 10.0));
+
+        // Walls Stack on Top of the Distance Fade
+        let (grid, _, _) = MapGrid::from_ascii(&[
+            "#######",
+            "#.....#",
+            "###D###",
+            "#.....#",
+            "#######",
+        ]);
+        let behind_door = Vec3::new(5.0, 0.6, 3.0);
+        let open = world_sfx_gain(None, SfxKind::PistolFire, listener, behind_door);
+        let muffled = world_sfx_gain(Some(&grid), SfxKind::PistolFire, listener, behind_door);
+        assert_eq!(muffled, open * OCCLUDED_SFX_GAIN);
+
+        // UI Sounds at the Origin Bypass Both
+        assert_eq!(world_sfx_gain(Some(&grid), SfxKind::KnifeSwing, Vec3::new(90.0, 0.5, 90.0), Vec3::ZERO), 1.0);
+    }
+
+    #[test]
+    fn each_bus_gain_only_scales_its_own_sounds() {
+        let settings = SoundSettings {
+            music_volume: 0.2,
+            sfx_volume: 0.9,
+            ui_volume: 0.5,
+            ..Default::default()
+        };
+
+        // Menu Navigation Follows the UI Slider
+        assert_eq!(settings.bus_volume(SfxKind::MenuSelect.bus()), 0.5);
+
+        // Combat and World Sounds Follow the SFX Slider
+        assert_eq!(settings.bus_volume(SfxKind::ChaingunFire.bus()), 0.9);
+        assert_eq!(settings.bus_volume(SfxKind::DoorOpen.bus()), 0.9);
+
+        // Intermission Stingers Are Game Sounds, Not UI
+        assert_eq!(SfxKind::IntermissionConfirm.bus(), AudioBus::Sfx);
+        assert_eq!(settings.bus_volume(SfxKind::IntermissionTick.bus()), 0.9);
+
+        // Music Has its Own Bus
+        assert_eq!(settings.bus_volume(AudioBus::Music), 0.2);
+    }
+
+    #[test]
+    fn menu_music_starts_when_switched_back_on() {
+        let mut app = App::new();
+        app.insert_resource(GameAudio {
+            door_open: Handle::default(),
+            door_close: Handle::default(),
+            music_splash: Handle::default(),
+            music_main_menu: Handle::default(),
+            music_scores_menu: Handle::default(),
+            music_level_end: Handle::default(),
+            music_levels: HashMap::new(),
+        })
+        .insert_resource(MusicMode(MusicModeKind::Menu))
+        .insert_resource(SoundSettings { music_enabled: false, ..Default::default() })
+        .add_systems(Update, sync_boot_music);
+
+        let tracks = |app: &mut App| {
+            app.world_mut().query_filtered::<(), With<Music>>().iter(app.world()).count()
+        };
+
+        app.update();
+        assert_eq!(tracks(&mut app), 0);
+
+        app.world_mut().resource_mut::<SoundSettings>().music_enabled = true;
+        app.update();
+        app.update();
+        assert_eq!(tracks(&mut app), 1);
+    }
+
+    #[test]
+    fn chasing_enemies_bring_combat_music_until_things_calm_down() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .insert_resource(MusicMode(MusicModeKind::Gameplay))
+            .add_systems(Update, update_combat_music);
+
+        let guard = app.world_mut().spawn(EnemyAi::default()).id();
+        app.update();
+        assert_eq!(app.world().resource::<MusicMode>().0, MusicModeKind::Gameplay);
+
+        app.world_mut().get_mut::<EnemyAi>(guard).unwrap().state = EnemyAiState::Chase;
+        app.update();
+        assert_eq!(app.world().resource::<MusicMode>().0, MusicModeKind::Combat);
+
+        // Killed Guards Stop Counting, but the Fight Music Lingers a While
+        app.world_mut().entity_mut(guard).insert(Dead);
+        let tick = std::time::Duration::from_secs_f32(1.0);
+        for _ in 0..3 {
+            app.world_mut().resource_mut::<Time>().advance_by(tick);
+            app.update();
+        }
+        assert_eq!(app.world().resource::<MusicMode>().0, MusicModeKind::Combat);
+
+        app.world_mut().resource_mut::<Time>().advance_by(tick * 2);
+        app.update();
+        assert_eq!(app.world().resource::<MusicMode>().0, MusicModeKind::Gameplay);
+
+        // Menus Own the Mode Outright
+        app.world_mut().get_mut::<EnemyAi>(guard).unwrap().state = EnemyAiState::Chase;
+        app.world_mut().entity_mut(guard).remove::<Dead>();
+        app.world_mut().resource_mut::<MusicMode>().0 = MusicModeKind::Menu;
+        app.update();
+        assert_eq!(app.world().resource::<MusicMode>().0, MusicModeKind::Menu);
+    }
+
+    #[test]
+    fn every_sfx_kind_maps_to_sound_files_or_is_deliberately_silent() {
+        let silent = [
+            SfxKind::EnemyAlert(EnemyKind::Mutant),
+            SfxKind::EnemyShoot(EnemyKind::GhostHitler),
+        ];
+
+        let kinds = SfxKind::all();
+
+        for kind in kinds {
+            let paths = sfx_assets(kind);
+            if silent.contains(&kind) {
+                assert!(paths.is_empty(), "{kind:?} should stay silent");
+                continue;
+            }
+            assert!(!paths.is_empty(), "{kind:?} has no sound");
+            for path in paths {
+                assert!(path.starts_with("sounds/sfx/"), "{kind:?} -> {path}");
+                assert!(path.ends_with(".ogg") || path.ends_with(".wav"), "{kind:?} -> {path}");
+            }
+        }
+
+        assert_eq!(sfx_assets(SfxKind::EnemyDeath(EnemyKind::Guard)).len(), 7);
+    }
+
+    #[test]
+    fn sfx_all_lists_every_variant_exactly_once() {
+        // No Wildcard Arm, so a New Variant Stops This Test Compiling Until it
+        // Gets the Next Slot Here (and UNIT_SLOTS Grows to Match). Unit Variants
+        // Take One Slot, Enemy Variants Three per Kind
+        const UNIT_SLOTS: usize = 35;
+        const SLOTS: usize = UNIT_SLOTS + 3 * SFX_ENEMY_KINDS.len();
+        let enemy = |kind: EnemyKind| {
+            UNIT_SLOTS + 3 * SFX_ENEMY_KINDS.iter().position(|&k| k == kind).expect("enemy kind has sounds")
+        };
+        let slot = |kind: SfxKind| -> usize {
+            match kind {
+                SfxKind::DoorOpen => 0,
+                SfxKind::DoorClose => 1,
+                SfxKind::NoWay => 2,
+                SfxKind::Pushwall => 3,
+                SfxKind::ElevatorSwitch => 4,
+                SfxKind::SecretFound => 5,
+                SfxKind::MenuMove => 6,
+                SfxKind::MenuSelect => 7,
+                SfxKind::MenuBack => 8,
+                SfxKind::IntermissionTick => 9,
+                SfxKind::IntermissionConfirm => 10,
+                SfxKind::IntermissionNoBonus => 11,
+                SfxKind::IntermissionPercent100 => 12,
+                SfxKind::IntermissionBonusApply => 13,
+                SfxKind::EpisodeVictoryYea => 14,
+                SfxKind::KnifeSwing => 15,
+                SfxKind::PistolFire => 16,
+                SfxKind::MachineGunFire => 17,
+                SfxKind::ChaingunFire => 18,
+                SfxKind::DryFire => 19,
+                SfxKind::RocketImpact => 20,
+                SfxKind::BulletRicochet => 21,
+                SfxKind::HitConfirm => 22,
+                SfxKind::PickupChaingun => 23,
+                SfxKind::PickupMachineGun => 24,
+                SfxKind::PickupAmmo => 25,
+                SfxKind::PickupHealthFirstAid => 26,
+                SfxKind::PickupHealthDinner => 27,
+                SfxKind::PickupHealthDogFood => 28,
+                SfxKind::PickupOneUp => 29,
+                SfxKind::PickupTreasureCross => 30,
+                SfxKind::PickupTreasureChalice => 31,
+                SfxKind::PickupTreasureChest => 32,
+                SfxKind::PickupTreasureCrown => 33,
+                SfxKind::PickupKey => 34,
+                SfxKind::EnemyAlert(e) => enemy(e),
+                SfxKind::EnemyShoot(e) => enemy(e) + 1,
+                SfxKind::EnemyDeath(e) => enemy(e) + 2,
+            }
+        };
+
+        // Every Slot Filled Once: No Duplicates, and as Many Entries as Slots
+        let kinds = SfxKind::all();
+        let mut seen = HashSet::new();
+        for kind in &kinds {
+            assert!(slot(*kind) < SLOTS, "{kind:?} is past the last slot");
+            assert!(seen.insert(slot(*kind)), "{kind:?} listed twice in SfxKind::all()");
+        }
+        assert_eq!(kinds.len(), SLOTS);
+    }
+}
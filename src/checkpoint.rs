@@ -0,0 +1,260 @@
+/*
+Davenstein - by David Petnick
+
+Death Checkpoints (Optional, GameplaySettings::checkpoint_respawn)
+
+Walking Through a Door Tile for the First Time Records an In-Memory Snapshot
+With the Same Capture Slot Saves Use. Death Then Queues That Snapshot Through
+the Normal Load Path Alongside the Level Rebuild, so Enemies, Pickups, Doors and
+Pushwalls Return to Their Checkpoint-Time State Instead of the Level Start
+
+Lives Are Never Restored, the Death That Triggered the Respawn Still Counts
+Dying Before Any Checkpoint Falls Back to the Classic Level Restart
+*/
+
+use bevy::prelude::*;
+use std::collections::HashSet;
+
+use davelib::map::{MapGrid, Tile};
+use davelib::options::GameplaySettings;
+use davelib::player::{Player, PlayerControlLock};
+
+use crate::restart::LevelEntryState;
+use crate::save::{model::SaveGame, LoadGameRequested, SnapshotParams};
+use crate::ui::{
+    sync::{
+        AdvanceLevelRequested,
+        NewGameRequested,
+        RestartLevelRequested,
+        RestartRequested,
+    },
+    HudState,
+};
+
+/// Latest Checkpoint in the Current Level, Cleared Whenever a Different
+/// Level (or a Fresh Copy of This One) Is Built
+#[derive(Resource, Default)]
+pub struct LevelCheckpoint {
+    pub game: Option<SaveGame>,
+    /// Entry Loadout When the Checkpoint Was Taken. The Load Path Overwrites
+    /// LevelEntryState, This Puts it Back so Restart Level Still Means the Start
+    pub entry_hud: HudState,
+    /// Door Tiles Already Crossed This Level, Each Checkpoints Only Once
+    pub crossed: HashSet<IVec2>,
+    /// Set From the Death That Queued the Checkpoint Until the Load Finishes
+    pub restoring: bool,
+}
+
+impl LevelCheckpoint {
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+}
+
+/// Snapshot a Death Respawn Loads: the Checkpoint, Keeping the Lives the
+/// Player Has Now so the Death Is Not Refunded
+pub fn respawn_game(checkpoint: &SaveGame, hud: &HudState) -> SaveGame {
+    let mut game = checkpoint.clone();
+    game.run_state.lives = hud.lives;
+    game
+}
+
+pub struct CheckpointPlugin;
+
+impl Plugin for CheckpointPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<LevelCheckpoint>()
+            .add_systems(Update, record_checkpoints)
+            .add_systems(
+                Update,
+                use_checkpoint_on_death.after(crate::ui::sync::tick_death_delay_and_request_restart),
+            )
+            .add_systems(
+                PostUpdate,
+                clear_checkpoint_on_rebuild.before(crate::restart::restart_despawn_level),
+            )
+            .add_systems(
+                PostUpdate,
+                finish_checkpoint_restore
+                    .after(crate::restart::load_game_finish)
+                    .run_if(|cp: Res<LevelCheckpoint>| cp.restoring),
+            );
+    }
+}
+
+fn record_checkpoints(
+    gameplay: Res<GameplaySettings>,
+    lock: Res<PlayerControlLock>,
+    grid: Option<Res<MapGrid>>,
+    q_player: Query<&Transform, With<Player>>,
+    entry: Res<LevelEntryState>,
+    snapshot: SnapshotParams,
+    mut checkpoint: ResMut<LevelCheckpoint>,
+) {
+    if !gameplay.checkpoint_respawn || lock.0 {
+        return;
+    }
+    let Some(grid) = grid else { return; };
+    let Some(tf) = q_player.iter().next() else { return; };
+
+    // Tiles Centered on Integers, Boundaries at n +/- 0.5
+    let tile = IVec2::new(
+        (tf.translation.x + 0.5).floor() as i32,
+        (tf.translation.z + 0.5).floor() as i32,
+    );
+    if tile.x < 0 || tile.y < 0 || tile.x as usize >= grid.width || tile.y as usize >= grid.height {
+        return;
+    }
+    if !matches!(grid.tile(tile.x as usize, tile.y as usize), Tile::DoorOpen | Tile::DoorClosed) {
+        return;
+    }
+    if !checkpoint.crossed.insert(tile) {
+        return;
+    }
+
+    if let Some(game) = snapshot.capture(snapshot.level_name()) {
+        checkpoint.game = Some(game);
+        checkpoint.entry_hud = entry.hud.clone();
+        info!("Checkpoint at ({}, {})", tile.x, tile.y);
+    }
+}
+
+/// Turns the Death Restart Into a Checkpoint Load When One Exists. The Restart
+/// Request Still Drives the Rebuild, the Queued Load Then Lands on Top of it
+fn use_checkpoint_on_death(
+    gameplay: Res<GameplaySettings>,
+    restart: Res<RestartRequested>,
+    hud: Res<HudState>,
+    mut load: ResMut<LoadGameRequested>,
+    mut checkpoint: ResMut<LevelCheckpoint>,
+) {
+    if !restart.0 || checkpoint.restoring || load.0.is_some() || !gameplay.checkpoint_respawn {
+        return;
+    }
+    let Some(game) = checkpoint.game.as_ref() else { return; };
+
+    load.0 = Some(respawn_game(game, &hud));
+    checkpoint.restoring = true;
+}
+
+/// Any Rebuild Other Than a Checkpoint Respawn Starts the Level Over, so the
+/// Old Checkpoint No Longer Applies
+fn clear_checkpoint_on_rebuild(
+    restart: Res<RestartRequested>,
+    new_game: Res<NewGameRequested>,
+    advance: Res<AdvanceLevelRequested>,
+    restart_level: Res<RestartLevelRequested>,
+    load: Res<LoadGameRequested>,
+    mut checkpoint: ResMut<LevelCheckpoint>,
+) {
+    let rebuilding = restart.0 || new_game.0 || advance.0 || restart_level.0 || load.0.is_some();
+    if rebuilding && !checkpoint.restoring {
+        checkpoint.clear();
+    }
+}
+
+fn finish_checkpoint_restore(
+    mut entry: ResMut<LevelEntryState>,
+    mut checkpoint: ResMut<LevelCheckpoint>,
+) {
+    entry.hud = checkpoint.entry_hud.clone();
+    checkpoint.restoring = false;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::save::capture::{apply_player, capture_save_game};
+    use davelib::level::{CurrentLevel, LevelId};
+    use davelib::level_score::{EpisodeStats, LevelScore};
+    use davelib::player::{PlayerKeys, PlayerVitals};
+
+    fn checkpoint_at(tf: &Transform, hud: &HudState) -> SaveGame {
+        capture_save_game(
+            "E1M1".to_string(),
+            hud,
+            tf,
+            &PlayerVitals::default(),
+            &CurrentLevel(LevelId::E1M1),
+            &LevelScore::default(),
+            0,
+            false,
+            &EpisodeStats::default(),
+            Vec::new(),
+            Vec::new(),
+            Vec::new(),
+            Vec::new(),
+            Vec::new(),
+            Vec::new(),
+            true,
+            Vec::new(),
+            true,
+            Vec::new(),
+        )
+    }
+
+    fn death_app(checkpoint_respawn: bool, checkpoint: Option<SaveGame>, hud: HudState) -> App {
+        let mut app = App::new();
+        app.insert_resource(GameplaySettings {
+                checkpoint_respawn,
+                ..Default::default()
+            })
+            .insert_resource(RestartRequested(true))
+            .insert_resource(hud)
+            .init_resource::<LoadGameRequested>()
+            .insert_resource(LevelCheckpoint {
+                game: checkpoint,
+                ..Default::default()
+            })
+            .add_systems(Update, use_checkpoint_on_death);
+        app
+    }
+
+    #[test]
+    fn death_restores_player_to_checkpoint() {
+        let mut hud = HudState::default();
+        let at_checkpoint = Transform::from_xyz(12.0, 0.5, 30.0);
+        let checkpoint = checkpoint_at(&at_checkpoint, &hud);
+
+        // Walk on and Die Somewhere Else, Losing a Life
+        let mut tf = Transform::from_xyz(40.0, 0.5, 8.0);
+        hud.lives -= 1;
+        let lives = hud.lives;
+
+        let mut app = death_app(true, Some(checkpoint), hud);
+        app.update();
+
+        assert!(app.world().resource::<LevelCheckpoint>().restoring);
+        let game = app
+            .world_mut()
+            .resource_mut::<LoadGameRequested>()
+            .0
+            .take()
+            .expect("Death Should Queue the Checkpoint Load");
+
+        let mut vitals = PlayerVitals { hp: 0, ..PlayerVitals::default() };
+        let mut keys = PlayerKeys::default();
+        apply_player(&mut tf, &mut vitals, &mut keys, &game.player, &game.run_state);
+
+        assert_eq!(tf.translation, at_checkpoint.translation);
+        assert_eq!(vitals.hp, PlayerVitals::default().hp);
+        assert_eq!(game.run_state.lives, lives);
+    }
+
+    #[test]
+    fn death_without_checkpoint_or_option_restarts_the_level() {
+        let hud = HudState::default();
+        let checkpoint = checkpoint_at(&Transform::from_xyz(12.0, 0.5, 30.0), &hud);
+
+        for mut app in [
+            death_app(true, None, hud.clone()),
+            death_app(false, Some(checkpoint), hud.clone()),
+        ] {
+            app.update();
+
+            assert!(app.world().resource::<LoadGameRequested>().0.is_none());
+            assert!(!app.world().resource::<LevelCheckpoint>().restoring);
+            assert!(app.world().resource::<RestartRequested>().0);
+        }
+    }
+}
@@ -184,3 +184,104 @@ pub fn raycast_grid(
 
     None
 }
+
+/// Ray vs Upright Enemy Hitbox Cylinder, Returns Distance Along the Ray
+pub fn ray_hit_vertical_cylinder(
+    origin: Vec3,
+    dir: Vec3,
+    center: Vec3,
+    radius: f32,
+    half_h: f32,
+) -> Option<f32> {
+    // 2D Ray-Circle in XZ, Then Clamp by Y at T
+    let o = Vec2::new(origin.x, origin.z);
+    let d = Vec2::new(dir.x, dir.z);
+    let c = Vec2::new(center.x, center.z);
+
+    let a = d.dot(d);
+    if a < 0.0000001 {
+        return None;
+    }
+
+    let oc = o - c;
+    let b = 2.0 * oc.dot(d);
+    let cc = oc.dot(oc) - radius * radius;
+    let disc = b * b - 4.0 * a * cc;
+    if disc < 0.0 {
+        return None;
+    }
+
+    let sqrt_disc = disc.sqrt();
+    let t0 = (-b - sqrt_disc) / (2.0 * a);
+    let t1 = (-b + sqrt_disc) / (2.0 * a);
+
+    let t = if t0 >= 0.0 {
+        t0
+    } else if t1 >= 0.0 {
+        t1
+    } else {
+        return None;
+    };
+
+    let y_at = origin.y + dir.y * t;
+    let y_min = center.y - half_h;
+    let y_max = center.y + half_h;
+
+    if y_at >= y_min && y_at <= y_max {
+        Some(t)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shot_into_wall_reports_face_point_and_normal() {
+        let (grid, _, _) = MapGrid::from_ascii(&[
+            "#####",
+            "#...#",
+            "#####",
+        ]);
+        let solid = SolidStatics::new(grid.width, grid.height);
+
+        let origin = Vec3::new(1.0, 0.5, 1.0);
+        let hit = raycast_grid(&grid, &solid, origin, Vec3::X, 64.0)
+            .expect("shot should strike the east wall");
+
+        assert_eq!(hit.tile, Tile::Wall);
+        assert_eq!(hit.tile_coord, IVec2::new(4, 1));
+        assert!((hit.pos.x - 3.5).abs() < 1e-4);
+        assert!((hit.dist - 2.5).abs() < 1e-4);
+        assert_eq!(hit.normal, Vec3::NEG_X);
+    }
+
+    #[test]
+    fn auto_aim_setting_changes_grazing_shot_outcome() {
+        use davelib::options::AutoAim;
+
+        // Guard Hitbox (radius, half_h, center_y) From process_fire_shots
+        let (radius, half_h, center_y) = (0.48, 0.65, 0.50);
+        let center = Vec3::new(5.0, center_y, 0.0);
+        let hits = |aim: AutoAim, offset: f32| {
+            let (r, hh) = aim.apply(radius, half_h);
+            ray_hit_vertical_cylinder(Vec3::new(0.0, 0.5, offset), Vec3::X, center, r, hh).is_some()
+        };
+
+        // Grazing Shot Just Outside the Classic Cylinder
+        let graze = 0.55;
+        assert!(!hits(AutoAim::Off, graze));
+        assert!(!hits(AutoAim::Classic, graze));
+        assert!(hits(AutoAim::Generous, graze));
+
+        // Shot Inside Classic but Outside the Tight Off Cylinder
+        let near = 0.40;
+        assert!(!hits(AutoAim::Off, near));
+        assert!(hits(AutoAim::Classic, near));
+
+        // Off Still Lands a Shot Aimed at the Body
+        assert!(hits(AutoAim::Off, 0.10));
+    }
+}
@@ -7,7 +7,7 @@ pub mod projectiles;
 
 use bevy::prelude::*;
 
-use hitscan::raycast_grid;
+use hitscan::{raycast_grid, ray_hit_vertical_cylinder};
 use davelib::actors::{
     Dead,
     Health,
@@ -44,22 +44,27 @@ use davelib::enemies::{
     GeneralDying,
 };
 use davelib::map::MapGrid;
+use davelib::player::game_running;
 
 pub struct CombatPlugin;
 
 impl Plugin for CombatPlugin {
     fn build(&self, app: &mut App) {
         app.add_message::<FireShot>()
+        .add_message::<GuardHit>()
         .add_message::<projectiles::SpawnProjectile>()
         .add_systems(Startup, projectiles::setup_projectile_assets)
         .add_systems(Update, process_fire_shots.run_if(crate::world_ready))
-        .add_systems(FixedUpdate, projectiles::tick_smoke_puffs.run_if(crate::world_ready))
-        .add_systems(FixedUpdate, projectiles::tick_rocket_impacts.run_if(crate::world_ready))
-        .add_systems(FixedUpdate, projectiles::tick_projectiles.run_if(crate::world_ready))
-        .add_systems(FixedUpdate, process_enemy_fireball_shots.run_if(crate::world_ready))
-        .add_systems(FixedUpdate, process_enemy_syringe_shots.run_if(crate::world_ready))
-        .add_systems(FixedUpdate, process_enemy_rocket_shots.run_if(crate::world_ready))
-        .add_systems(FixedUpdate, projectiles::spawn_projectiles.run_if(crate::world_ready))
+        .add_systems(Update, spawn_death_blood.after(process_fire_shots).run_if(crate::world_ready))
+        .add_systems(Update, play_hit_confirm.after(process_fire_shots).run_if(crate::world_ready))
+        .add_systems(FixedUpdate, projectiles::tick_blood_drops.run_if(crate::world_ready).run_if(game_running))
+        .add_systems(FixedUpdate, projectiles::tick_smoke_puffs.run_if(crate::world_ready).run_if(game_running))
+        .add_systems(FixedUpdate, projectiles::tick_rocket_impacts.run_if(crate::world_ready).run_if(game_running))
+        .add_systems(FixedUpdate, projectiles::tick_projectiles.run_if(crate::world_ready).run_if(game_running))
+        .add_systems(FixedUpdate, process_enemy_fireball_shots.run_if(crate::world_ready).run_if(game_running))
+        .add_systems(FixedUpdate, process_enemy_syringe_shots.run_if(crate::world_ready).run_if(game_running))
+        .add_systems(FixedUpdate, process_enemy_rocket_shots.run_if(crate::world_ready).run_if(game_running))
+        .add_systems(FixedUpdate, projectiles::spawn_projectiles.run_if(crate::world_ready).run_if(game_running))
         .add_systems(
             PostUpdate,
             projectiles::update_projectile_views.run_if(crate::world_ready),
@@ -71,6 +76,10 @@ impl Plugin for CombatPlugin {
         .add_systems(
             PostUpdate,
             projectiles::update_rocket_impact_views.run_if(crate::world_ready),
+        )
+        .add_systems(
+            PostUpdate,
+            projectiles::update_blood_drop_views.run_if(crate::world_ready),
         );
     }
 }
@@ -91,6 +100,46 @@ pub struct FireShot {
     pub max_dist: f32,
 }
 
+/// A Player Shot That Took Health Off an Enemy. Written Once per Damaging Hit,
+/// 'killed' on the Hit That Drops it. Kill Score Is Awarded From These
+#[derive(Message, Debug, Clone, Copy, PartialEq)]
+pub struct GuardHit {
+    pub entity: Entity,
+    pub dmg: i32,
+    pub killed: bool,
+    /// Direction the Shot Was Travelling
+    pub dir: Vec3,
+}
+
+/// Blood Burst Over a Freshly Killed Enemy, Leaving the Corpse and Any Drop
+/// Untouched. Off With VideoSettings::gore
+fn spawn_death_blood(
+    video: Res<davelib::options::VideoSettings>,
+    assets: Option<Res<projectiles::ProjectileAssets>>,
+    mut hits: MessageReader<GuardHit>,
+    q_pos: Query<&GlobalTransform>,
+    mut commands: Commands,
+) {
+    let Some(assets) = assets.filter(|_| video.gore) else {
+        hits.clear();
+        return;
+    };
+
+    for hit in hits.read().filter(|h| h.killed) {
+        let Ok(gt) = q_pos.get(hit.entity) else { continue; };
+        let p = gt.translation();
+        projectiles::spawn_blood_burst(&mut commands, &assets, Vec3::new(p.x, 0.5, p.z), hit.dir);
+    }
+}
+
+/// One Hit-Confirm Tick per Frame Any Player Shot Draws Blood, However Many
+/// Enemies the Shot Hit. Played Flat, Like the Player's Own Dry Fire Click
+fn play_hit_confirm(mut hits: MessageReader<GuardHit>, mut sfx: MessageWriter<PlaySfx>) {
+    if hits.read().count() > 0 {
+        sfx.write(PlaySfx { kind: SfxKind::HitConfirm, pos: Vec3::ZERO });
+    }
+}
+
 fn process_enemy_fireball_shots(
     mut fireballs: MessageReader<EnemyFireballShot>,
     mut spawn: MessageWriter<projectiles::SpawnProjectile>,
@@ -135,6 +184,7 @@ fn process_fire_shots(
     solid: Option<Res<SolidStatics>>,
     mut shots: MessageReader<FireShot>,
     mut sfx: MessageWriter<PlaySfx>,
+    mut hits: MessageWriter<GuardHit>,
     mut commands: Commands,
     q_alive: Query<
         (Entity, &EnemyKind, &OccupiesTile, &GlobalTransform),
@@ -142,8 +192,9 @@ fn process_fire_shots(
     >,
     mut q_hp: Query<&mut Health, (With<EnemyKind>, Without<Dead>)>,
     mut q_ai: Query<&mut EnemyAi, (With<EnemyKind>, Without<Dead>)>,
-    mut level_score: ResMut<davelib::level_score::LevelScore>,
     mut rng: Local<davelib::ai::TableRng>,
+    puff_assets: Option<Res<projectiles::ProjectileAssets>>,
+    gameplay: Res<davelib::options::GameplaySettings>,
 ) {
     let (Some(grid), Some(solid)) = (grid, solid) else {
         return;
@@ -234,54 +285,6 @@ fn process_fire_shots(
         (rng.us_rnd_t() / 4).max(1)
     }
 
-    fn ray_hit_vertical_cylinder(
-        origin: Vec3,
-        dir: Vec3,
-        center: Vec3,
-        radius: f32,
-        half_h: f32,
-    ) -> Option<f32> {
-        // 2D Ray-Circle in XZ, Then Clamp by Y at T
-        let o = Vec2::new(origin.x, origin.z);
-        let d = Vec2::new(dir.x, dir.z);
-        let c = Vec2::new(center.x, center.z);
-
-        let a = d.dot(d);
-        if a < 0.0000001 {
-            return None;
-        }
-
-        let oc = o - c;
-        let b = 2.0 * oc.dot(d);
-        let cc = oc.dot(oc) - radius * radius;
-        let disc = b * b - 4.0 * a * cc;
-        if disc < 0.0 {
-            return None;
-        }
-
-        let sqrt_disc = disc.sqrt();
-        let t0 = (-b - sqrt_disc) / (2.0 * a);
-        let t1 = (-b + sqrt_disc) / (2.0 * a);
-
-        let t = if t0 >= 0.0 {
-            t0
-        } else if t1 >= 0.0 {
-            t1
-        } else {
-            return None;
-        };
-
-        let y_at = origin.y + dir.y * t;
-        let y_min = center.y - half_h;
-        let y_max = center.y + half_h;
-
-        if y_at >= y_min && y_at <= y_max {
-            Some(t)
-        } else {
-            None
-        }
-    }
-
     for shot in shots.read() {
         let dir = shot.dir.normalize_or_zero();
         if dir == Vec3::ZERO {
@@ -309,6 +312,7 @@ fn process_fire_shots(
         for (e, kind, occ, gt) in q_alive.iter() {
             let p = gt.translation();
             let (radius, half_h, center_y) = hitbox(*kind);
+            let (radius, half_h) = gameplay.auto_aim.apply(radius, half_h);
             let center = Vec3::new(p.x, center_y, p.z);
 
             let Some(t) = ray_hit_vertical_cylinder(
@@ -359,13 +363,11 @@ fn process_fire_shots(
 
             if let Ok(mut hp) = q_hp.get_mut(e) {
                 hp.cur -= dmg;
+                hits.write(GuardHit { entity: e, dmg, killed: hp.cur <= 0, dir });
 
                 if hp.cur <= 0 {
                     hp.cur = 0;
 
-                    // Kill Discovery Counts When Death is Latched
-                    level_score.kills_found += 1;
-
                     if let Ok((_, _, _, gt)) = q_alive.get(e) {
                         let p = gt.translation();
                         sfx.write(PlaySfx {
@@ -376,21 +378,23 @@ fn process_fire_shots(
 
                     commands.entity(e).insert(Dead);
 
+                    // A Kill Mid-Flinch Goes Straight to the Death Frames, the
+                    // Pain Sprite Must Not Linger Over the First of Them
                     match kind {
                         EnemyKind::Guard => {
-                            commands.entity(e).insert(GuardDying { frame: 0, tics: 0 });
+                            commands.entity(e).remove::<GuardPain>().insert(GuardDying { frame: 0, tics: 0 });
                         }
                         EnemyKind::Mutant => {
-                            commands.entity(e).insert(MutantDying { frame: 0, tics: 0 });
+                            commands.entity(e).remove::<MutantPain>().insert(MutantDying { frame: 0, tics: 0 });
                         }
                         EnemyKind::Ss => {
-                            commands.entity(e).insert(SsDying { frame: 0, tics: 0 });
+                            commands.entity(e).remove::<SsPain>().insert(SsDying { frame: 0, tics: 0 });
                         }
                         EnemyKind::Officer => {
-                            commands.entity(e).insert(OfficerDying { frame: 0, tics: 0 });
+                            commands.entity(e).remove::<OfficerPain>().insert(OfficerDying { frame: 0, tics: 0 });
                         }
                         EnemyKind::Dog => {
-                            commands.entity(e).insert(DogDying { frame: 0, tics: 0 });
+                            commands.entity(e).remove::<DogPain>().insert(DogDying { frame: 0, tics: 0 });
                         }
                         EnemyKind::Hans => {
                             commands.entity(e).insert(HansDying { frame: 0, tics: 0 });
@@ -418,7 +422,9 @@ fn process_fire_shots(
                         }
                     }
                 } else {
-                    let timer = Timer::from_seconds(0.20, TimerMode::Once);
+                    // Pain Frame Holds for 10 Tics, Like s_grdpain in WL_ACT2.C
+                    const PAIN_TICS: u32 = 10;
+                    let timer = Timer::from_seconds(davelib::tics::tics(PAIN_TICS), TimerMode::Once);
                     match kind {
                         EnemyKind::Guard => {
                             commands.entity(e).insert(GuardPain { timer });
@@ -465,5 +471,290 @@ fn process_fire_shots(
 
             continue;
         }
+
+        // Shot Missed Every Enemy. Guns Leave a Puff Where They Struck a Wall,
+        // the Knife Never Reaches One
+        if shot.weapon == WeaponSlot::Knife {
+            continue;
+        }
+
+        let Some(hit) = world_hit else {
+            continue;
+        };
+
+        // Door Panels Sit Mid-Tile, Away From the Boundary the Ray Reports
+        if hit.tile != davelib::map::Tile::Wall {
+            continue;
+        }
+
+        if let Some(assets) = puff_assets.as_deref() {
+            projectiles::spawn_bullet_puff(&mut commands, assets, hit.pos, hit.normal);
+        }
+
+        sfx.write(PlaySfx {
+            kind: SfxKind::BulletRicochet,
+            pos: hit.pos,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::HudState;
+
+    #[derive(Resource, Default)]
+    struct Hits(Vec<GuardHit>);
+
+    fn collect_hits(mut ev: MessageReader<GuardHit>, mut hits: ResMut<Hits>) {
+        hits.0.extend(ev.read().copied());
+    }
+
+    #[test]
+    fn killing_shot_confirms_one_hit_and_scores_the_guard() {
+        let (grid, _, _) = MapGrid::from_ascii(&["#####", "#...#", "#####"]);
+        let solid = SolidStatics::new(grid.width, grid.height);
+
+        let mut app = App::new();
+        app.insert_resource(grid)
+            .insert_resource(solid)
+            .init_resource::<davelib::level_score::LevelScore>()
+            .init_resource::<Assets<StandardMaterial>>()
+            .init_resource::<davelib::options::GameplaySettings>()
+            .init_resource::<HudState>()
+            .init_resource::<Hits>()
+            .add_message::<FireShot>()
+            .add_message::<GuardHit>()
+            .add_message::<PlaySfx>()
+            .add_systems(
+                Update,
+                (
+                    process_fire_shots,
+                    crate::ui::sync::award_kill_points,
+                    collect_hits,
+                    play_hit_confirm,
+                )
+                    .chain(),
+            );
+
+        let guard = app
+            .world_mut()
+            .spawn((
+                EnemyKind::Guard,
+                OccupiesTile(IVec2::new(2, 1)),
+                GlobalTransform::from_xyz(2.0, 0.0, 1.0),
+                Health { cur: 1, max: 25 },
+            ))
+            .id();
+        let score_before = app.world().resource::<HudState>().score;
+
+        // The Knife Always Deals at Least 1, Enough for a 1 HP Guard
+        app.world_mut().write_message(FireShot {
+            weapon: WeaponSlot::Knife,
+            origin: Vec3::new(1.0, 0.5, 1.0),
+            dir: Vec3::X,
+            max_dist: 1.5,
+        });
+        app.update();
+
+        let hits = &app.world().resource::<Hits>().0;
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].entity, guard);
+        assert!(hits[0].killed);
+        assert_eq!(hits[0].dir, Vec3::X);
+        assert_eq!(
+            app.world().resource::<HudState>().score,
+            score_before + EnemyKind::Guard.points(),
+        );
+        assert_eq!(app.world().resource::<davelib::level_score::LevelScore>().kills_found, 1);
+
+        let confirms = app
+            .world()
+            .resource::<Messages<PlaySfx>>()
+            .iter_current_update_messages()
+            .filter(|m| m.kind == SfxKind::HitConfirm)
+            .count();
+        assert_eq!(confirms, 1);
+    }
+
+    #[test]
+    fn kill_mid_flinch_drops_the_pain_frame_for_the_death_frames() {
+        let (grid, _, _) = MapGrid::from_ascii(&["#####", "#...#", "#####"]);
+        let solid = SolidStatics::new(grid.width, grid.height);
+
+        let mut app = App::new();
+        app.insert_resource(grid)
+            .insert_resource(solid)
+            .init_resource::<davelib::level_score::LevelScore>()
+            .init_resource::<Assets<StandardMaterial>>()
+            .init_resource::<davelib::options::GameplaySettings>()
+            .add_message::<FireShot>()
+            .add_message::<GuardHit>()
+            .add_message::<PlaySfx>()
+            .add_systems(Update, process_fire_shots);
+
+        // Still Flinching From an Earlier Hit
+        let guard = app
+            .world_mut()
+            .spawn((
+                EnemyKind::Guard,
+                OccupiesTile(IVec2::new(2, 1)),
+                GlobalTransform::from_xyz(2.0, 0.0, 1.0),
+                Health { cur: 1, max: 25 },
+                GuardPain { timer: Timer::from_seconds(0.2, TimerMode::Once) },
+            ))
+            .id();
+
+        app.world_mut().write_message(FireShot {
+            weapon: WeaponSlot::Knife,
+            origin: Vec3::new(1.0, 0.5, 1.0),
+            dir: Vec3::X,
+            max_dist: 1.5,
+        });
+        app.update();
+
+        let e = app.world().entity(guard);
+        assert!(!e.contains::<GuardPain>());
+        assert!(e.contains::<Dead>());
+        let dying = e.get::<GuardDying>().expect("killed guard starts dying");
+        assert_eq!((dying.frame, dying.tics), (0, 0));
+    }
+
+    #[test]
+    fn gunshot_into_a_wall_leaves_a_puff_that_animates_and_clears() {
+        let (grid, _, _) = MapGrid::from_ascii(&["#####", "#...#", "#####"]);
+        let solid = SolidStatics::new(grid.width, grid.height);
+
+        let mut app = App::new();
+        app.insert_resource(grid)
+            .insert_resource(solid)
+            .init_resource::<davelib::level_score::LevelScore>()
+            .init_resource::<Assets<StandardMaterial>>()
+            .init_resource::<davelib::options::GameplaySettings>()
+            .add_message::<FireShot>()
+            .add_message::<GuardHit>()
+            .add_message::<PlaySfx>()
+            .add_systems(Update, (process_fire_shots, projectiles::tick_smoke_puffs).chain());
+
+        let smoke: [Handle<StandardMaterial>; 4] = {
+            let mut mats = app.world_mut().resource_mut::<Assets<StandardMaterial>>();
+            std::array::from_fn(|_| mats.add(StandardMaterial::default()))
+        };
+        app.insert_resource(projectiles::ProjectileAssets {
+            quad: default(),
+            fireball_0: default(),
+            fireball_1: default(),
+            syringe: default(),
+            rocket: default(),
+            rocket_smoke: default(),
+            rocket_impact: default(),
+            smoke: smoke.clone(),
+            blood: default(),
+        });
+
+        app.world_mut().write_message(FireShot {
+            weapon: WeaponSlot::Pistol,
+            origin: Vec3::new(1.0, 0.5, 1.0),
+            dir: Vec3::X,
+            max_dist: 64.0,
+        });
+        app.update();
+
+        let sounds: Vec<SfxKind> = app
+            .world()
+            .resource::<Messages<PlaySfx>>()
+            .iter_current_update_messages()
+            .map(|m| m.kind)
+            .collect();
+        assert_eq!(sounds, vec![SfxKind::BulletRicochet]);
+
+        // Just Off the Face of the Wall it Struck, on the First Shared Frame
+        let mut q = app.world_mut().query::<(&Transform, &MeshMaterial3d<StandardMaterial>)>();
+        let puffs: Vec<(Vec3, Handle<StandardMaterial>)> =
+            q.iter(app.world()).map(|(tf, mat)| (tf.translation, mat.0.clone())).collect();
+        assert_eq!(puffs.len(), 1);
+        assert!(puffs[0].0.distance(Vec3::new(3.46, 0.5, 1.0)) < 0.01, "{:?}", puffs[0].0);
+        assert_eq!(puffs[0].1, smoke[0]);
+
+        // Each Frame Swaps to the Next Shared Material, Then the Puff Is Gone
+        for _ in 0..2 {
+            app.update();
+        }
+        let mut q = app.world_mut().query::<&MeshMaterial3d<StandardMaterial>>();
+        assert_eq!(q.single(app.world()).unwrap().0, smoke[1]);
+
+        for _ in 0..9 {
+            app.update();
+        }
+        let mut q = app.world_mut().query::<&projectiles::SmokePuff>();
+        assert_eq!(q.iter(app.world()).count(), 0);
+    }
+
+    #[test]
+    fn kill_sprays_blood_away_from_the_shooter_that_expires() {
+        let (grid, _, _) = MapGrid::from_ascii(&["#####", "#...#", "#####"]);
+        let solid = SolidStatics::new(grid.width, grid.height);
+
+        let mut app = App::new();
+        app.insert_resource(grid)
+            .insert_resource(solid)
+            .init_resource::<davelib::level_score::LevelScore>()
+            .init_resource::<Assets<StandardMaterial>>()
+            .init_resource::<davelib::options::GameplaySettings>()
+            .init_resource::<davelib::options::VideoSettings>()
+            .insert_resource(projectiles::ProjectileAssets {
+                quad: default(),
+                fireball_0: default(),
+                fireball_1: default(),
+                syringe: default(),
+                rocket: default(),
+                rocket_smoke: default(),
+                rocket_impact: default(),
+                smoke: default(),
+                blood: default(),
+            })
+            .add_message::<FireShot>()
+            .add_message::<GuardHit>()
+            .add_message::<PlaySfx>()
+            .add_systems(
+                Update,
+                (process_fire_shots, spawn_death_blood, projectiles::tick_blood_drops).chain(),
+            );
+
+        app.world_mut().spawn((
+            EnemyKind::Guard,
+            OccupiesTile(IVec2::new(2, 1)),
+            GlobalTransform::from_xyz(2.0, 0.0, 1.0),
+            Health { cur: 1, max: 25 },
+        ));
+
+        app.world_mut().write_message(FireShot {
+            weapon: WeaponSlot::Knife,
+            origin: Vec3::new(1.0, 0.5, 1.0),
+            dir: Vec3::X,
+            max_dist: 1.5,
+        });
+        app.update();
+
+        // Six Drops, Each Already One Tic Along and Thrown Past the Guard
+        let mut q = app.world_mut().query::<(&projectiles::BloodDrop, &Transform)>();
+        let drops: Vec<(u8, Vec3)> = q.iter(app.world()).map(|(d, tf)| (d.tics, tf.translation)).collect();
+        assert_eq!(drops.len(), 6);
+        for (tics, pos) in drops {
+            assert_eq!(tics, 34);
+            assert!(pos.x > 2.0, "{pos:?}");
+        }
+
+        // Half a Second of Tics Later They Are All Gone
+        let count = |app: &mut App| {
+            let mut q = app.world_mut().query::<&projectiles::BloodDrop>();
+            q.iter(app.world()).count()
+        };
+        for _ in 0..33 {
+            app.update();
+        }
+        assert_eq!(count(&mut app), 6);
+        app.update();
+        assert_eq!(count(&mut app), 0);
     }
 }
@@ -37,6 +37,11 @@ pub struct ProjectileAssets {
 	pub rocket: [Handle<Image>; 8],
 	pub rocket_smoke: [Handle<Image>; 4],
 	pub rocket_impact: [Handle<Image>; 4],
+	/// One Material per Smoke Frame, Shared by Every Rocket Trail Puff and
+	/// Bullet Wall Puff, so a Puff Allocates No Materials
+	pub smoke: [Handle<StandardMaterial>; SMOKE_FRAMES],
+	/// Shared by Every Blood Drop, so a Kill Allocates No Materials
+	pub blood: Handle<StandardMaterial>,
 }
 
 #[derive(Component)]
@@ -65,11 +70,6 @@ pub struct SmokePuff {
 	pub tics: u8,
 }
 
-#[derive(Component)]
-pub struct SmokePuffView {
-	pub mat: Handle<StandardMaterial>,
-}
-
 const ROCKET_SMOKE_EMIT_TICS: u8 = 3;
 const SMOKE_FRAME_TICS: u8 = 3;
 const SMOKE_FRAMES: usize = 4;
@@ -89,6 +89,25 @@ pub struct RocketImpactView {
 const IMPACT_FRAME_TICS: u8 = 3;
 const IMPACT_FRAMES: usize = 4;
 
+// Death Blood Burst: a Few Flat Red Quads Thrown Along the Killing Shot That
+// Fall and Vanish. Lifetime in Tics (35 = Half a Second)
+#[derive(Component)]
+pub struct BloodDrop {
+	pub vel: Vec3,
+	pub tics: u8,
+}
+
+const BLOOD_DROPS: usize = 6;
+const BLOOD_TICS: u8 = 35;
+const BLOOD_SIZE: f32 = 0.07;
+const BLOOD_GRAVITY: f32 = 9.0;
+const BLOOD_FLOOR_Y: f32 = 0.02;
+
+// Bullet Wall Puff Reuses the Rocket Smoke Animation, Drawn Small
+const BULLET_PUFF_SCALE: f32 = 0.22;
+// Pull the Puff Off the Wall Face so it Never Z-Fights the Texture
+const BULLET_PUFF_WALL_OFFSET: f32 = 0.04;
+
 fn kind_speed(kind: ProjectileKind) -> f32 {
 	match kind {
 		ProjectileKind::Fireball => 1.6,
@@ -217,6 +236,91 @@ fn spawn_rocket_impact(
 	));
 }
 
+/// Brief Impact Puff Where a Player Hitscan Shot Struck a Wall
+/// Rides on SmokePuff so tick_smoke_puffs / update_smoke_puff_views Animate,
+/// Billboard and Despawn it Like the Rocket Trail
+pub fn spawn_bullet_puff(
+	commands: &mut Commands,
+	assets: &ProjectileAssets,
+	hit_pos: Vec3,
+	normal: Vec3,
+) {
+	let pos = hit_pos + normal * BULLET_PUFF_WALL_OFFSET;
+
+	commands.spawn((
+		SmokePuff { frame: 0, tics: SMOKE_FRAME_TICS },
+		Mesh3d(assets.quad.clone()),
+		MeshMaterial3d(assets.smoke[0].clone()),
+		Transform::from_translation(pos)
+			.with_scale(Vec3::new(BULLET_PUFF_SCALE, BULLET_PUFF_SCALE, 1.0)),
+	));
+}
+
+/// Burst of Blood Drops at a Dying Enemy, Sprayed Away From the Shooter
+pub fn spawn_blood_burst(
+	commands: &mut Commands,
+	assets: &ProjectileAssets,
+	pos: Vec3,
+	shot_dir: Vec3,
+) {
+	let mut rng = rand::rng();
+	let away = Vec3::new(shot_dir.x, 0.0, shot_dir.z).normalize_or_zero();
+
+	for _ in 0..BLOOD_DROPS {
+		let spread = Vec3::new(
+			rng.random_range(-0.8..0.8),
+			rng.random_range(0.6..1.6),
+			rng.random_range(-0.8..0.8),
+		);
+		let vel = away * rng.random_range(1.0..2.2) + spread;
+
+		commands.spawn((
+			BloodDrop { vel, tics: BLOOD_TICS },
+			Mesh3d(assets.quad.clone()),
+			MeshMaterial3d(assets.blood.clone()),
+			Transform::from_translation(pos)
+				.with_scale(Vec3::new(BLOOD_SIZE, BLOOD_SIZE, 1.0)),
+		));
+	}
+}
+
+pub fn tick_blood_drops(
+	mut commands: Commands,
+	mut q: Query<(Entity, &mut BloodDrop, &mut Transform)>,
+) {
+	let dt = davelib::tics::TIC;
+
+	for (e, mut drop, mut xform) in q.iter_mut() {
+		drop.tics = drop.tics.saturating_sub(1);
+		if drop.tics == 0 {
+			commands.entity(e).despawn();
+			continue;
+		}
+
+		// Drops That Reach the Floor Stay Put Until They Expire
+		if xform.translation.y <= BLOOD_FLOOR_Y {
+			continue;
+		}
+		drop.vel.y -= BLOOD_GRAVITY * dt;
+		xform.translation += drop.vel * dt;
+		xform.translation.y = xform.translation.y.max(BLOOD_FLOOR_Y);
+	}
+}
+
+pub fn update_blood_drop_views(
+	q_player: Query<&Transform, (With<Player>, Without<BloodDrop>)>,
+	mut q: Query<&mut Transform, (With<BloodDrop>, Without<Player>)>,
+) {
+	let Some(player_xform) = q_player.iter().next() else { return; };
+	let player_pos = player_xform.translation;
+
+	for mut xform in q.iter_mut() {
+		let to_player = player_pos - xform.translation;
+		let yaw = to_player.x.atan2(to_player.z);
+		xform.rotation = Quat::from_rotation_y(yaw);
+	}
+}
+
 pub fn tick_rocket_impacts(
 	mut commands: Commands,
 	assets: Option<Res<ProjectileAssets>>,
@@ -268,6 +372,7 @@ pub fn setup_projectile_assets(
 	mut commands: Commands,
 	asset_server: Res<AssetServer>,
 	mut meshes: ResMut<Assets<Mesh>>,
+	mut mats: ResMut<Assets<StandardMaterial>>,
 ) {
 	let fireball_0: Handle<Image> =
 		asset_server.load("enemies/ghost_hitler/fake_hitler_fireball_0.png");
@@ -292,6 +397,23 @@ pub fn setup_projectile_assets(
 
 	let quad = meshes.add(Rectangle::new(1.0, 1.0));
 
+	let smoke: [Handle<StandardMaterial>; SMOKE_FRAMES] = std::array::from_fn(|i| {
+		mats.add(StandardMaterial {
+			base_color_texture: Some(rocket_smoke[i].clone()),
+			alpha_mode: AlphaMode::Mask(0.5),
+			unlit: true,
+			cull_mode: None,
+			..default()
+		})
+	});
+
+	let blood = mats.add(StandardMaterial {
+		base_color: Color::srgb(0.55, 0.0, 0.0),
+		unlit: true,
+		cull_mode: None,
+		..default()
+	});
+
 	commands.insert_resource(ProjectileAssets {
 		quad,
 		fireball_0,
@@ -300,6 +422,8 @@ pub fn setup_projectile_assets(
 		rocket,
 		rocket_smoke,
 		rocket_impact,
+		smoke,
+		blood,
 	});
 }
 
@@ -424,12 +548,11 @@ fn tile_blocks_projectile(t: Tile) -> bool {
 pub fn tick_smoke_puffs(
 	mut commands: Commands,
 	assets: Option<Res<ProjectileAssets>>,
-	mut mats: ResMut<Assets<StandardMaterial>>,
-	mut q: Query<(Entity, &mut SmokePuff, &SmokePuffView)>,
+	mut q: Query<(Entity, &mut SmokePuff, &mut MeshMaterial3d<StandardMaterial>)>,
 ) {
 	let Some(assets) = assets else { return; };
 
-	for (e, mut puff, view) in q.iter_mut() {
+	for (e, mut puff, mut mat) in q.iter_mut() {
 		if puff.tics > 0 {
 			puff.tics -= 1;
 		}
@@ -445,12 +568,7 @@ pub fn tick_smoke_puffs(
 		}
 
 		puff.tics = SMOKE_FRAME_TICS;
-
-		let Some(mut mat) = mats.get_mut(&view.mat) else { continue; };
-		let tex = assets.rocket_smoke[puff.frame].clone();
-		if mat.base_color_texture.as_ref() != Some(&tex) {
-			mat.base_color_texture = Some(tex);
-		}
+		mat.0 = assets.smoke[puff.frame].clone();
 	}
 }
 
@@ -476,6 +594,7 @@ pub fn tick_projectiles(
 	grid: Option<Res<MapGrid>>,
 	solid: Option<Res<SolidStatics>>,
 	god: Option<Res<GodMode>>,
+	skill: Res<davelib::skill::SkillLevel>,
 	mut sfx: MessageWriter<PlaySfx>,
 	mut q_player: Query<(&Transform, &mut PlayerVitals), (With<Player>, Without<Projectile>)>,
 	mut q: Query<(Entity, &mut Transform, &Projectile, Option<&mut RocketSmokeEmitter>)>,
@@ -498,7 +617,7 @@ pub fn tick_projectiles(
 		let b = a + proj.dir * proj.speed * dt;
 
 		if !god && segment_hits_player_xz(a, b, player_pos, hit_r) {
-			let dmg = kind_damage(proj.kind);
+			let dmg = skill.scale_damage(kind_damage(proj.kind));
 			vitals.hp = (vitals.hp - dmg).max(0);
 			commands.entity(e).despawn();
 			continue;
@@ -541,19 +660,10 @@ pub fn tick_projectiles(
 			if em.tics == 0 {
 				em.tics = ROCKET_SMOKE_EMIT_TICS;
 
-				let mat = mats.add(StandardMaterial {
-					base_color_texture: Some(assets.rocket_smoke[0].clone()),
-					alpha_mode: AlphaMode::Mask(0.5),
-					unlit: true,
-					cull_mode: None,
-					..default()
-				});
-
 				commands.spawn((
 					SmokePuff { frame: 0, tics: SMOKE_FRAME_TICS },
-					SmokePuffView { mat: mat.clone() },
 					Mesh3d(assets.quad.clone()),
-					MeshMaterial3d(mat),
+					MeshMaterial3d(assets.smoke[0].clone()),
 					Transform::from_translation(a).with_scale(Vec3::new(0.55, 0.55, 1.0)),
 				));
 			}
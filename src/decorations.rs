@@ -21,6 +21,147 @@ pub struct FloorDecal;
 #[derive(Component, Copy, Clone)]
 pub struct BillboardTilt(pub f32);
 
+/// Floor and Ceiling Planes Upright Billboards Anchor Against
+const FLOOR_Y: f32 = 0.0;
+const CEILING_Y: f32 = 1.0;
+
+/// Which Edge of an Upright Billboard Quad Is Pinned in Place
+/// - 'Feet'   -> Bottom Edge on the Floor (Enemies, Standing Statics, Pickups)
+/// - 'Center' -> Quad Centered Between Floor and Ceiling
+/// - 'Top'    -> Top Edge on the Ceiling (Chandeliers, Lamps, Hanging Cages)
+///
+/// Placement Depends Only on the Quad Height, so a Taller or Shorter Sprite
+/// Still Stands on (or Hangs From) the Right Plane
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SpriteAnchor {
+    #[default]
+    Feet,
+    Center,
+    Top,
+}
+
+impl SpriteAnchor {
+    /// World Y for the Center of a Quad 'h' Tall
+    pub fn center_y(self, h: f32) -> f32 {
+        match self {
+            SpriteAnchor::Feet => FLOOR_Y + h * 0.5,
+            SpriteAnchor::Center => (FLOOR_Y + CEILING_Y) * 0.5,
+            SpriteAnchor::Top => CEILING_Y - h * 0.5,
+        }
+    }
+
+    /// Move a Quad's Center When Its Scale Changes From 'old_k' to 'new_k'
+    /// so the Anchored Edge Stays Put, Any Extra Lift in 'y' Is Preserved
+    pub fn rescaled_center_y(self, y: f32, base_h: f32, old_k: f32, new_k: f32) -> f32 {
+        let dh = base_h * (new_k - old_k) * 0.5;
+        match self {
+            SpriteAnchor::Feet => y + dh,
+            SpriteAnchor::Center => y,
+            SpriteAnchor::Top => y - dh,
+        }
+    }
+}
+
+pub const SPRITE_SCALE_SHRINK_KEY: KeyCode = KeyCode::F7;
+pub const SPRITE_SCALE_GROW_KEY: KeyCode = KeyCode::F8;
+pub const SPRITE_SCALE_RESET_KEY: KeyCode = KeyCode::F9;
+
+/// World Heights for Enemy and Pickup Billboards, Width Follows Each
+/// Sprite's Aspect. Defaults Match Wolf3D Proportions Against 1.0 Unit Walls
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct SpriteScales {
+    pub enemy_h: f32,
+    pub weapon_h: f32,
+    pub ammo_h: f32,
+    pub first_aid_h: f32,
+    pub dinner_h: f32,
+    pub dog_food_h: f32,
+    pub oneup_h: f32,
+    pub treasure_h: f32,
+    pub key_h: f32,
+}
+
+impl SpriteScales {
+    pub const CLASSIC: Self = Self {
+        enemy_h: 1.0,
+        weapon_h: 0.28,
+        ammo_h: 0.22,
+        first_aid_h: 0.18,
+        dinner_h: 0.18,
+        dog_food_h: 0.22,
+        oneup_h: 0.50,
+        treasure_h: 0.24,
+        key_h: 0.42,
+    };
+
+    // Live Tuning Step and Limits, Relative to CLASSIC
+    const NUDGE_STEP: f32 = 0.05;
+    const MIN_FACTOR: f32 = 0.5;
+    const MAX_FACTOR: f32 = 2.0;
+
+    /// Scale Factor Applied to Enemy Quads Built at the CLASSIC Height
+    pub fn enemy_factor(&self) -> f32 {
+        self.enemy_h / Self::CLASSIC.enemy_h
+    }
+
+    pub fn nudge_enemies(&mut self, steps: i32) {
+        let k = (self.enemy_factor() + steps as f32 * Self::NUDGE_STEP)
+            .clamp(Self::MIN_FACTOR, Self::MAX_FACTOR);
+        self.enemy_h = Self::CLASSIC.enemy_h * k;
+    }
+
+    /// Scales Every Pickup Height Together so Their Relative Sizes Hold
+    pub fn nudge_pickups(&mut self, steps: i32) {
+        let c = Self::CLASSIC;
+        let k = (self.ammo_h / c.ammo_h + steps as f32 * Self::NUDGE_STEP)
+            .clamp(Self::MIN_FACTOR, Self::MAX_FACTOR);
+
+        self.weapon_h = c.weapon_h * k;
+        self.ammo_h = c.ammo_h * k;
+        self.first_aid_h = c.first_aid_h * k;
+        self.dinner_h = c.dinner_h * k;
+        self.dog_food_h = c.dog_food_h * k;
+        self.oneup_h = c.oneup_h * k;
+        self.treasure_h = c.treasure_h * k;
+        self.key_h = c.key_h * k;
+    }
+}
+
+impl Default for SpriteScales {
+    fn default() -> Self {
+        Self::CLASSIC
+    }
+}
+
+/// Debug Tuning: F7 / F8 Shrink / Grow Enemies, Hold Shift for Pickups,
+/// F9 Restores the Classic Heights. Only Runs in Dev Builds
+pub fn nudge_sprite_scales(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut scales: ResMut<SpriteScales>,
+) {
+    if keys.just_pressed(SPRITE_SCALE_RESET_KEY) {
+        *scales = SpriteScales::CLASSIC;
+        info!("Sprite Scales: Classic");
+        return;
+    }
+
+    let steps = if keys.just_pressed(SPRITE_SCALE_GROW_KEY) {
+        1
+    } else if keys.just_pressed(SPRITE_SCALE_SHRINK_KEY) {
+        -1
+    } else {
+        return;
+    };
+
+    if keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]) {
+        scales.nudge_pickups(steps);
+    } else {
+        scales.nudge_enemies(steps);
+    }
+
+    info!("Sprite Scales: {:?}", *scales);
+}
+
 #[derive(Resource, Debug, Clone)]
 pub struct SolidStatics {
     width: usize,
@@ -69,8 +210,9 @@ pub struct Decoration {
     pub blocks: bool,
 }
 
+/// How a statinfo Static Behaves: Pure Dressing, Movement Blocker or Pickup
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum StatKind {
+pub enum StatKind {
     Dressing,
     Block,
     Pickup,
@@ -225,11 +367,26 @@ pub fn billboard_floor_decals(
     }
 }
 
+/// Statics That Hang From the Ceiling Rather Than Stand on the Floor
+fn anchor_for_plane1(code: u16) -> SpriteAnchor {
+    match stat_idx_from_plane1(code) {
+        // 4 Chandelier, 5 Hanged Man, 14 Ceiling Light,
+        // 17 Hanging Cage, 18 Skeleton in Cage
+        Some(4 | 5 | 14 | 17 | 18) => SpriteAnchor::Top,
+        _ => SpriteAnchor::Feet,
+    }
+}
+
 /// Determines if Plane1 Code Should be Rendered as Floor Decal
 fn is_floor_decal_plane1(code: u16) -> bool {
     matches!(code, 23 | 32 | 61 | 63)
 }
 
+/// statinfo Kind for a plane1 Code, None When the Code Is Not a Static
+pub fn stat_kind_for_plane1(code: u16) -> Option<StatKind> {
+    STAT_KIND.get(stat_idx_from_plane1(code)?).copied()
+}
+
 /// Spawn "Statics" (Decorations) From Plane1 Codes Using WL_ACT1.C statinfo[]
 /// Pickups / Treasure / Weapons Handled by Pickups Module
 pub fn spawn_decorations(
@@ -271,16 +428,9 @@ pub fn spawn_decorations(
 		for x in 0..grid.width {
 			let idx = (y * grid.width + x) as usize;
 			let code = plane1_res.0[idx];
-			if code < 23 {
+			let Some(kind) = stat_kind_for_plane1(code) else {
 				continue;
-			}
-
-			let si = (code - 23) as usize;
-			if si >= STAT_KIND.len() {
-				continue;
-			}
-
-			let kind = STAT_KIND[si];
+			};
 			if kind == StatKind::Pickup {
 				continue;
 			}
@@ -304,7 +454,7 @@ pub fn spawn_decorations(
 				(
                     quad_upright.clone(),
                     0.0,
-                    h * 0.5,
+                    anchor_for_plane1(code).center_y(h),
                     w,
                     h,
                     if blocks { 0.0 } else { 0.5 }
@@ -373,3 +523,49 @@ pub fn billboard_decorations(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn feet_anchor_puts_bottom_edge_on_floor() {
+        for h in [0.5, 0.95, 1.0, 1.6] {
+            let bottom = SpriteAnchor::Feet.center_y(h) - h * 0.5;
+            assert!((bottom - FLOOR_Y).abs() < 1e-6, "h = {h}");
+        }
+    }
+
+    #[test]
+    fn hanging_statics_touch_the_ceiling() {
+        let h = 0.95;
+        let chandelier = anchor_for_plane1(23 + 4);
+        assert_eq!(chandelier, SpriteAnchor::Top);
+        let top = chandelier.center_y(h) + h * 0.5;
+        assert!((top - CEILING_Y).abs() < 1e-6);
+
+        // Floor Lamp Stands on the Floor
+        assert_eq!(anchor_for_plane1(23 + 3), SpriteAnchor::Feet);
+    }
+
+    #[test]
+    fn rescaling_keeps_feet_on_the_floor() {
+        let base_h = SpriteScales::CLASSIC.enemy_h;
+        let mut scales = SpriteScales::default();
+        let mut k = 1.0;
+        let mut y = SpriteAnchor::Feet.center_y(base_h);
+
+        for steps in [3, -7, 2, -20, 40] {
+            scales.nudge_enemies(steps);
+            let new_k = scales.enemy_factor();
+            y = SpriteAnchor::Feet.rescaled_center_y(y, base_h, k, new_k);
+            k = new_k;
+
+            let bottom = y - base_h * k * 0.5;
+            assert!((bottom - FLOOR_Y).abs() < 1e-5, "k = {k}");
+        }
+
+        // Clamped to the Tuning Range
+        assert!((k - 2.0).abs() < 1e-5);
+    }
+}
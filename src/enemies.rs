@@ -15,8 +15,9 @@ use crate::audio::{
     SfxKind,
     ActiveEnemyVoiceSfx,
 };
+use crate::decorations::{SpriteAnchor, SpriteScales};
 use crate::episode_end::DeathCamBoss;
-use crate::player::Player;
+use crate::player::{game_running, Player};
 
 const GUARD_MAX_HP: i32 = 25;
 const MUTANT_MAX_HP: i32 = 45;
@@ -24,6 +25,27 @@ const SS_MAX_HP: i32 = 100;
 const OFFICER_MAX_HP: i32 = 50;
 const DOG_MAX_HP: i32 = 1;
 
+// Enemy Billboard Quad Size. Character Art Fills the Full 64x64 Frame, so the
+// Quad Is One Wall Tall and Anchored by its Feet to the Floor
+const ENEMY_SPRITE_W: f32 = 0.85;
+// Quads Are Built at the Classic Height, SpriteScales Tunes Them Live
+const ENEMY_SPRITE_H: f32 = SpriteScales::CLASSIC.enemy_h;
+
+// Corpse Sprite per Enemy, Also What Startup Asset Validation Checks For
+pub const GUARD_CORPSE_PATH: &str = "enemies/guard/guard_corpse.png";
+pub const MUTANT_CORPSE_PATH: &str = "enemies/mutant/mutant_corpse.png";
+pub const OFFICER_CORPSE_PATH: &str = "enemies/officer/officer_corpse.png";
+pub const SS_CORPSE_PATH: &str = "enemies/ss/ss_corpse.png";
+pub const DOG_CORPSE_PATH: &str = "enemies/dog/dog_corpse.png";
+pub const HANS_CORPSE_PATH: &str = "enemies/hans/hans_corpse.png";
+pub const GRETEL_CORPSE_PATH: &str = "enemies/gretel/gretel_corpse.png";
+pub const HITLER_CORPSE_PATH: &str = "enemies/hitler/hitler_corpse.png";
+pub const MECHA_HITLER_CORPSE_PATH: &str = "enemies/mecha_hitler/mecha_hitler_corpse.png";
+pub const GHOST_HITLER_CORPSE_PATH: &str = "enemies/ghost_hitler/fake_hitler_corpse.png";
+pub const SCHABBS_CORPSE_PATH: &str = "enemies/schabbs/schabbs_corpse.png";
+pub const OTTO_CORPSE_PATH: &str = "enemies/otto/otto_corpse.png";
+pub const GENERAL_CORPSE_PATH: &str = "enemies/general/general_corpse.png";
+
 pub(crate) const SS_SHOOT_SECS: f32 = 0.35;
 pub(crate) const OFFICER_SHOOT_SECS: f32 = 0.35;
 pub(crate) const DOG_BITE_SECS: f32 = 0.35;
@@ -90,7 +112,7 @@ pub(crate) fn boss_health(kind: EnemyKind, skill: &crate::skill::SkillLevel) ->
 #[derive(Component, Debug, Clone, Copy)]
 pub struct DeathCamReplaySlow(pub u8);
 
-#[derive(Component, Debug, Clone, Copy)]
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Dir8(pub u8);
 
 // Cached to Avoid Redundant Texture Swaps
@@ -120,6 +142,27 @@ pub enum EnemyKind {
     General,
 }
 
+impl EnemyKind {
+    /// Score for a Kill, From the Original KillActor
+    pub const fn points(self) -> i32 {
+        match self {
+            EnemyKind::Guard => 100,
+            EnemyKind::Dog => 200,
+            EnemyKind::Officer => 400,
+            EnemyKind::Ss => 500,
+            EnemyKind::Mutant => 700,
+            EnemyKind::GhostHitler => 2000,
+            EnemyKind::Hans
+            | EnemyKind::Gretel
+            | EnemyKind::Hitler
+            | EnemyKind::MechaHitler
+            | EnemyKind::Schabbs
+            | EnemyKind::Otto
+            | EnemyKind::General => 5000,
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug)]
 pub struct EnemyTuning {
     pub max_hp: i32,
@@ -322,6 +365,12 @@ pub struct GuardCorpse;
 #[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SpawnIndex(pub u32);
 
+// Map Tile an Enemy Originated On. No Two Enemies Share an Origin Tile, so This
+// Is a Stable Unique Identity Across Skill Levels and Spawn Order Changes, Used
+// by Saves to Mark "the Enemy From (x, z) Is Dead" and Restore It as Such
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SpawnId(pub IVec2);
+
 #[derive(Component)]
 pub struct MutantCorpse;
 
@@ -347,6 +396,11 @@ pub struct MutantPain {
     pub timer: Timer,
 }
 
+/// Guard Death Animation: Four Frames Held Six Tics Each, so the Body Hits the
+/// Floor 24 Tics (About a Third of a Second) After the Killing Shot
+pub const GUARD_DEATH_FRAMES: u8 = 4;
+pub const GUARD_DEATH_TICS_PER_FRAME: u8 = 6;
+
 #[derive(Component, Debug, Clone, Copy)]
 pub struct GuardDying {
     pub frame: u8, // 0..DEATH_FRAMES-1
@@ -359,9 +413,46 @@ pub struct MutantDying {
     pub tics: u8,
 }
 
+/// Guard Attack in Three Frames Like the Original: Raise, Flash, Lower
+/// The Shot Is Rolled When the Attack Starts but Only Delivered (EnemyFire and
+/// the Gunshot) on Entering the Flash Frame, so the Hit Lands With the Muzzle
+/// Flash. Pain or Death Removes the Component, Cancelling a Shot Not Yet Fired
 #[derive(Component, Debug)]
-pub struct GuardShoot {
+pub struct GuardAttack {
     pub timer: Timer,
+    pub frame: u8,
+    // Rolled Damage, None on a Miss (the Gunshot Still Sounds)
+    pub damage: Option<i32>,
+    pub fire_pending: bool,
+}
+
+pub const GUARD_ATTACK_FRAMES: u8 = 3;
+pub const GUARD_ATTACK_FLASH_FRAME: u8 = 1;
+
+impl GuardAttack {
+    pub fn new(secs: f32, damage: Option<i32>) -> Self {
+        Self {
+            timer: Timer::from_seconds(secs, TimerMode::Once),
+            frame: 0,
+            damage,
+            fire_pending: true,
+        }
+    }
+
+    /// Steps the Attack, True Exactly Once: the Step That Reaches the Flash Frame
+    pub fn advance(&mut self, dt: std::time::Duration) -> bool {
+        self.timer.tick(dt);
+
+        let dur = self.timer.duration().as_secs_f32().max(1e-6);
+        let t = self.timer.elapsed_secs() / dur;
+        self.frame = ((t * GUARD_ATTACK_FRAMES as f32) as u8).min(GUARD_ATTACK_FRAMES - 1);
+
+        if self.fire_pending && self.frame >= GUARD_ATTACK_FLASH_FRAME {
+            self.fire_pending = false;
+            return true;
+        }
+        false
+    }
 }
 
 #[derive(Component, Debug)]
@@ -424,7 +515,7 @@ impl FromWorld for GuardSprites {
             asset_server.load(format!("enemies/guard/guard_death_{}.png", i))
         });
 
-        let corpse: Handle<Image> = asset_server.load("enemies/guard/guard_corpse.png");
+        let corpse: Handle<Image> = asset_server.load(GUARD_CORPSE_PATH);
 
         // Shooting
         let shoot_front_aim: Handle<Image> =
@@ -467,7 +558,7 @@ impl FromWorld for MutantSprites {
             asset_server.load(format!("enemies/mutant/mutant_death_{}.png", i))
         });
 
-        let corpse: Handle<Image> = asset_server.load("enemies/mutant/mutant_corpse.png");
+        let corpse: Handle<Image> = asset_server.load(MUTANT_CORPSE_PATH);
 
         let shoot_front_aim: Handle<Image> = asset_server.load(
             "enemies/mutant/mutant_shoot_front_aim.png",
@@ -642,7 +733,7 @@ impl FromWorld for OfficerSprites {
             std::array::from_fn(|_| h.clone())
         });
 
-        let corpse_one: Handle<Image> = server.load("enemies/officer/officer_corpse.png");
+        let corpse_one: Handle<Image> = server.load(OFFICER_CORPSE_PATH);
         let corpse = std::array::from_fn(|_| corpse_one.clone());
 
         Self { idle, walk, shoot, pain, dying, corpse }
@@ -706,7 +797,7 @@ impl FromWorld for SsSprites {
             std::array::from_fn(|_| h.clone())
         });
 
-        let corpse_one: Handle<Image> = server.load("enemies/ss/ss_corpse.png");
+        let corpse_one: Handle<Image> = server.load(SS_CORPSE_PATH);
         let corpse = std::array::from_fn(|_| corpse_one.clone());
 
         Self { idle, walk, shoot, pain, dying, corpse }
@@ -739,7 +830,7 @@ impl FromWorld for DogSprites {
         });
 
         // Corpse: duplicated across dirs
-        let corpse_one: Handle<Image> = server.load("enemies/dog/dog_corpse.png");
+        let corpse_one: Handle<Image> = server.load(DOG_CORPSE_PATH);
         let corpse = std::array::from_fn(|_| corpse_one.clone());
 
         Self { idle, walk, bite, dying, corpse }
@@ -1035,7 +1126,7 @@ impl FromWorld for HansSprites {
             std::array::from_fn(|_| d3.clone()),
         ];
 
-        let corpse0: Handle<Image> = server.load("enemies/hans/hans_corpse.png");
+        let corpse0: Handle<Image> = server.load(HANS_CORPSE_PATH);
         let corpse = std::array::from_fn(|_| corpse0.clone());
 
         Self {
@@ -1072,7 +1163,7 @@ impl FromWorld for GretelSprites {
             std::array::from_fn(|_| d3.clone()),
         ];
 
-        let corpse0: Handle<Image> = server.load("enemies/gretel/gretel_corpse.png");
+        let corpse0: Handle<Image> = server.load(GRETEL_CORPSE_PATH);
         let corpse = std::array::from_fn(|_| corpse0.clone());
 
         Self {
@@ -1104,7 +1195,7 @@ impl FromWorld for HitlerSprites {
             std::array::from_fn(|_| h.clone())
         });
 
-        let corpse0: Handle<Image> = server.load("enemies/hitler/hitler_corpse.png");
+        let corpse0: Handle<Image> = server.load(HITLER_CORPSE_PATH);
         let corpse = std::array::from_fn(|_| corpse0.clone());
 
         Self { idle, walk, shoot, dying, corpse }
@@ -1135,7 +1226,7 @@ impl FromWorld for MechaHitlerSprites {
             std::array::from_fn(|_| d3.clone()),
         ];
 
-        let corpse0: Handle<Image> = server.load("enemies/mecha_hitler/mecha_hitler_corpse.png");
+        let corpse0: Handle<Image> = server.load(MECHA_HITLER_CORPSE_PATH);
         let corpse = std::array::from_fn(|_| corpse0.clone());
 
         Self { idle, walk, shoot, dying, corpse }
@@ -1170,8 +1261,7 @@ impl FromWorld for GhostHitlerSprites {
             server.load(format!("enemies/ghost_hitler/fake_hitler_death_{i}.png"))
         });
 
-        let corpse: Handle<Image> =
-            server.load("enemies/ghost_hitler/fake_hitler_corpse.png");
+        let corpse: Handle<Image> = server.load(GHOST_HITLER_CORPSE_PATH);
 
         Self {
             idle,
@@ -1222,7 +1312,7 @@ impl FromWorld for SchabbsSprites {
             std::array::from_fn(|_| d2.clone()),
         ];
 
-        let corpse_one: Handle<Image> = server.load("enemies/schabbs/schabbs_corpse.png");
+        let corpse_one: Handle<Image> = server.load(SCHABBS_CORPSE_PATH);
         let corpse: [Handle<Image>; 8] = std::array::from_fn(|_| corpse_one.clone());
 
         Self {
@@ -1268,7 +1358,7 @@ impl FromWorld for OttoSprites {
             std::array::from_fn(|_| d2.clone()),
         ];
 
-        let corpse_one: Handle<Image> = server.load("enemies/otto/otto_corpse.png");
+        let corpse_one: Handle<Image> = server.load(OTTO_CORPSE_PATH);
         let corpse: [Handle<Image>; 8] = std::array::from_fn(|_| corpse_one.clone());
 
         Self {
@@ -1320,7 +1410,7 @@ impl FromWorld for GeneralSprites {
             std::array::from_fn(|_| d2.clone()),
         ];
 
-        let corpse_one: Handle<Image> = server.load("enemies/general/general_corpse.png");
+        let corpse_one: Handle<Image> = server.load(GENERAL_CORPSE_PATH);
         let corpse: [Handle<Image>; 8] = std::array::from_fn(|_| corpse_one.clone());
 
         Self {
@@ -1632,11 +1722,14 @@ pub fn spawn_hans(
     skill: &crate::skill::SkillLevel,
 ) -> Entity {
     const TILE_SIZE: f32 = 1.0;
-    const WALL_H: f32 = 1.0;
 
-    let pos = Vec3::new(tile.x as f32 * TILE_SIZE, WALL_H * 0.5, tile.y as f32 * TILE_SIZE);
+    let pos = Vec3::new(
+        tile.x as f32 * TILE_SIZE,
+        SpriteAnchor::Feet.center_y(ENEMY_SPRITE_H),
+        tile.y as f32 * TILE_SIZE,
+    );
 
-    let quad = meshes.add(Mesh::from(Rectangle::new(0.85, 1.0)));
+    let quad = meshes.add(Mesh::from(Rectangle::new(ENEMY_SPRITE_W, ENEMY_SPRITE_H)));
     let mat = materials.add(StandardMaterial {
         base_color_texture: Some(sprites.idle[0].clone()),
         alpha_mode: AlphaMode::Mask(0.5),
@@ -1667,11 +1760,14 @@ pub fn spawn_gretel(
     skill: &crate::skill::SkillLevel,
 ) -> Entity {
     const TILE_SIZE: f32 = 1.0;
-    const WALL_H: f32 = 1.0;
 
-    let pos = Vec3::new(tile.x as f32 * TILE_SIZE, WALL_H * 0.5, tile.y as f32 * TILE_SIZE);
+    let pos = Vec3::new(
+        tile.x as f32 * TILE_SIZE,
+        SpriteAnchor::Feet.center_y(ENEMY_SPRITE_H),
+        tile.y as f32 * TILE_SIZE,
+    );
 
-    let quad = meshes.add(Mesh::from(Rectangle::new(0.85, 1.0)));
+    let quad = meshes.add(Mesh::from(Rectangle::new(ENEMY_SPRITE_W, ENEMY_SPRITE_H)));
     let mat = materials.add(StandardMaterial {
         base_color_texture: Some(sprites.idle[0].clone()),
         alpha_mode: AlphaMode::Mask(0.5),
@@ -1702,11 +1798,14 @@ pub fn spawn_hitler(
     skill: &crate::skill::SkillLevel,
 ) -> Entity {
     const TILE_SIZE: f32 = 1.0;
-    const WALL_H: f32 = 1.0;
 
-    let pos = Vec3::new(tile.x as f32 * TILE_SIZE, WALL_H * 0.5, tile.y as f32 * TILE_SIZE);
+    let pos = Vec3::new(
+        tile.x as f32 * TILE_SIZE,
+        SpriteAnchor::Feet.center_y(ENEMY_SPRITE_H),
+        tile.y as f32 * TILE_SIZE,
+    );
 
-    let quad = meshes.add(Mesh::from(Rectangle::new(0.85, 1.0)));
+    let quad = meshes.add(Mesh::from(Rectangle::new(ENEMY_SPRITE_W, ENEMY_SPRITE_H)));
     let mat = materials.add(StandardMaterial {
         base_color_texture: Some(sprites.idle[0].clone()),
         alpha_mode: AlphaMode::Mask(0.5),
@@ -1738,11 +1837,14 @@ pub fn spawn_mecha_hitler(
     skill: &crate::skill::SkillLevel,
 ) -> Entity {
     const TILE_SIZE: f32 = 1.0;
-    const WALL_H: f32 = 1.0;
 
-    let pos = Vec3::new(tile.x as f32 * TILE_SIZE, WALL_H * 0.5, tile.y as f32 * TILE_SIZE);
+    let pos = Vec3::new(
+        tile.x as f32 * TILE_SIZE,
+        SpriteAnchor::Feet.center_y(ENEMY_SPRITE_H),
+        tile.y as f32 * TILE_SIZE,
+    );
 
-    let quad = meshes.add(Mesh::from(Rectangle::new(0.85, 1.0)));
+    let quad = meshes.add(Mesh::from(Rectangle::new(ENEMY_SPRITE_W, ENEMY_SPRITE_H)));
     let mat = materials.add(StandardMaterial {
         base_color_texture: Some(sprites.idle[0].clone()),
         alpha_mode: AlphaMode::Mask(0.5),
@@ -1773,11 +1875,14 @@ pub fn spawn_ghost_hitler(
     skill: &crate::skill::SkillLevel,
 ) -> Entity {
     const TILE_SIZE: f32 = 1.0;
-    const WALL_H: f32 = 1.0;
 
-    let pos = Vec3::new(tile.x as f32 * TILE_SIZE, WALL_H * 0.5, tile.y as f32 * TILE_SIZE);
+    let pos = Vec3::new(
+        tile.x as f32 * TILE_SIZE,
+        SpriteAnchor::Feet.center_y(ENEMY_SPRITE_H),
+        tile.y as f32 * TILE_SIZE,
+    );
 
-    let quad = meshes.add(Mesh::from(Rectangle::new(0.85, 1.0)));
+    let quad = meshes.add(Mesh::from(Rectangle::new(ENEMY_SPRITE_W, ENEMY_SPRITE_H)));
     let mat = materials.add(StandardMaterial {
         base_color_texture: Some(sprites.idle[0].clone()),
         alpha_mode: AlphaMode::Mask(0.5),
@@ -1808,11 +1913,14 @@ pub fn spawn_schabbs(
     skill: &crate::skill::SkillLevel,
 ) -> Entity {
     const TILE_SIZE: f32 = 1.0;
-    const WALL_H: f32 = 1.0;
 
-    let pos = Vec3::new(tile.x as f32 * TILE_SIZE, WALL_H * 0.5, tile.y as f32 * TILE_SIZE);
+    let pos = Vec3::new(
+        tile.x as f32 * TILE_SIZE,
+        SpriteAnchor::Feet.center_y(ENEMY_SPRITE_H),
+        tile.y as f32 * TILE_SIZE,
+    );
 
-    let quad = meshes.add(Mesh::from(Rectangle::new(0.85, 1.0)));
+    let quad = meshes.add(Mesh::from(Rectangle::new(ENEMY_SPRITE_W, ENEMY_SPRITE_H)));
     let mat = materials.add(StandardMaterial {
         base_color_texture: Some(sprites.idle[0].clone()),
         alpha_mode: AlphaMode::Mask(0.5),
@@ -1844,11 +1952,14 @@ pub fn spawn_otto(
     skill: &crate::skill::SkillLevel,
 ) -> Entity {
     const TILE_SIZE: f32 = 1.0;
-    const WALL_H: f32 = 1.0;
 
-    let pos = Vec3::new(tile.x as f32 * TILE_SIZE, WALL_H * 0.5, tile.y as f32 * TILE_SIZE);
+    let pos = Vec3::new(
+        tile.x as f32 * TILE_SIZE,
+        SpriteAnchor::Feet.center_y(ENEMY_SPRITE_H),
+        tile.y as f32 * TILE_SIZE,
+    );
 
-    let quad = meshes.add(Mesh::from(Rectangle::new(0.85, 1.0)));
+    let quad = meshes.add(Mesh::from(Rectangle::new(ENEMY_SPRITE_W, ENEMY_SPRITE_H)));
     let mat = materials.add(StandardMaterial {
         base_color_texture: Some(sprites.idle[0].clone()),
         alpha_mode: AlphaMode::Mask(0.5),
@@ -1880,11 +1991,14 @@ pub fn spawn_general(
     skill: &crate::skill::SkillLevel,
 ) -> Entity {
     const TILE_SIZE: f32 = 1.0;
-    const WALL_H: f32 = 1.0;
 
-    let pos = Vec3::new(tile.x as f32 * TILE_SIZE, WALL_H * 0.5, tile.y as f32 * TILE_SIZE);
+    let pos = Vec3::new(
+        tile.x as f32 * TILE_SIZE,
+        SpriteAnchor::Feet.center_y(ENEMY_SPRITE_H),
+        tile.y as f32 * TILE_SIZE,
+    );
 
-    let quad = meshes.add(Mesh::from(Rectangle::new(0.85, 1.0)));
+    let quad = meshes.add(Mesh::from(Rectangle::new(ENEMY_SPRITE_W, ENEMY_SPRITE_H)));
     let mat = materials.add(StandardMaterial {
         base_color_texture: Some(sprites.idle[0].clone()),
         alpha_mode: AlphaMode::Mask(0.5),
@@ -2751,6 +2865,7 @@ fn tick_dog_bite(
             enemy_fire.write(crate::ai::EnemyFire {
                 kind: EnemyKind::Dog,
                 damage: dmg,
+                origin: dog_pos,
             });
         }
 
@@ -2985,15 +3100,40 @@ pub fn tick_mutant_pain(
     started.retain(|e, _| live.iter().any(|x| x == e));
 }
 
-fn tick_guard_shoot(
+fn tick_guard_attack(
     time: Res<Time>,
     mut commands: Commands,
-    mut q: Query<(Entity, &mut GuardShoot), With<Guard>>,
+    mut q: Query<
+        (Entity, &mut GuardAttack, &Transform, Has<GuardPain>, Has<GuardDying>, Has<Dead>),
+        With<Guard>,
+    >,
+    mut enemy_fire: MessageWriter<crate::ai::EnemyFire>,
+    mut sfx: MessageWriter<PlaySfx>,
 ) {
-    for (e, mut shoot) in q.iter_mut() {
-        shoot.timer.tick(time.delta());
-        if shoot.timer.is_finished() {
-            commands.entity(e).remove::<GuardShoot>();
+    for (e, mut attack, tf, pain, dying, dead) in q.iter_mut() {
+        // Interrupted Before the Flash: the Shot Never Happens
+        if pain || dying || dead {
+            commands.entity(e).remove::<GuardAttack>();
+            continue;
+        }
+
+        if attack.advance(time.delta()) {
+            sfx.write(PlaySfx {
+                kind: SfxKind::EnemyShoot(EnemyKind::Guard),
+                pos: tf.translation,
+            });
+
+            if let Some(damage) = attack.damage {
+                enemy_fire.write(crate::ai::EnemyFire {
+                    kind: EnemyKind::Guard,
+                    damage,
+                    origin: tf.translation,
+                });
+            }
+        }
+
+        if attack.timer.is_finished() {
+            commands.entity(e).remove::<GuardAttack>();
         }
     }
 }
@@ -3041,12 +3181,15 @@ pub fn spawn_guard(
     tile: IVec2,
 ) -> Entity {
     const TILE_SIZE: f32 = 1.0;
-    const WALL_H: f32 = 1.0;
 
-    let pos = Vec3::new(tile.x as f32 * TILE_SIZE, WALL_H * 0.5, tile.y as f32 * TILE_SIZE);
+    let pos = Vec3::new(
+        tile.x as f32 * TILE_SIZE,
+        SpriteAnchor::Feet.center_y(ENEMY_SPRITE_H),
+        tile.y as f32 * TILE_SIZE,
+    );
 
     // A Vertical Quad in XY Plane (Normal +Z), UVs "Upright"
-    let quad = meshes.add(Mesh::from(Rectangle::new(0.85, 1.0)));
+    let quad = meshes.add(Mesh::from(Rectangle::new(ENEMY_SPRITE_W, ENEMY_SPRITE_H)));
     let mat = materials.add(StandardMaterial {
         base_color_texture: Some(sprites.idle[0].clone()),
         alpha_mode: AlphaMode::Mask(0.5),
@@ -3086,12 +3229,15 @@ pub fn spawn_dead_guard(
     tile: IVec2,
 ) -> Entity {
     const TILE_SIZE: f32 = 1.0;
-    const WALL_H: f32 = 1.0;
 
-    let pos = Vec3::new(tile.x as f32 * TILE_SIZE, WALL_H * 0.5, tile.y as f32 * TILE_SIZE);
+    let pos = Vec3::new(
+        tile.x as f32 * TILE_SIZE,
+        SpriteAnchor::Feet.center_y(ENEMY_SPRITE_H),
+        tile.y as f32 * TILE_SIZE,
+    );
 
     // Same Quad and Material Flags as a Live Guard, Showing the Corpse Texture
-    let quad = meshes.add(Mesh::from(Rectangle::new(0.85, 1.0)));
+    let quad = meshes.add(Mesh::from(Rectangle::new(ENEMY_SPRITE_W, ENEMY_SPRITE_H)));
     let mat = materials.add(StandardMaterial {
         base_color_texture: Some(sprites.corpse.clone()),
         alpha_mode: AlphaMode::Mask(0.5),
@@ -3121,12 +3267,15 @@ pub fn spawn_mutant(
     tile: IVec2,
 ) -> Entity {
     const TILE_SIZE: f32 = 1.0;
-    const WALL_H: f32 = 1.0;
 
-    let pos = Vec3::new(tile.x as f32 * TILE_SIZE, WALL_H * 0.5, tile.y as f32 * TILE_SIZE);
+    let pos = Vec3::new(
+        tile.x as f32 * TILE_SIZE,
+        SpriteAnchor::Feet.center_y(ENEMY_SPRITE_H),
+        tile.y as f32 * TILE_SIZE,
+    );
 
     // A Vertical Quad in XY Plane (Normal +Z), UVs "Upright"
-    let quad = meshes.add(Mesh::from(Rectangle::new(0.85, 1.0)));
+    let quad = meshes.add(Mesh::from(Rectangle::new(ENEMY_SPRITE_W, ENEMY_SPRITE_H)));
     let mat = materials.add(StandardMaterial {
         base_color_texture: Some(sprites.idle[0].clone()),
         alpha_mode: AlphaMode::Mask(0.5),
@@ -3156,11 +3305,14 @@ pub fn spawn_ss(
     tile: IVec2,
 ) -> Entity {
     const TILE_SIZE: f32 = 1.0;
-    const WALL_H: f32 = 1.0;
 
-    let pos = Vec3::new(tile.x as f32 * TILE_SIZE, WALL_H * 0.5, tile.y as f32 * TILE_SIZE);
+    let pos = Vec3::new(
+        tile.x as f32 * TILE_SIZE,
+        SpriteAnchor::Feet.center_y(ENEMY_SPRITE_H),
+        tile.y as f32 * TILE_SIZE,
+    );
 
-    let quad = meshes.add(Mesh::from(Rectangle::new(0.85, 1.0)));
+    let quad = meshes.add(Mesh::from(Rectangle::new(ENEMY_SPRITE_W, ENEMY_SPRITE_H)));
     let mat = materials.add(StandardMaterial {
         base_color_texture: Some(sprites.idle[0].clone()),
         alpha_mode: AlphaMode::Mask(0.5),
@@ -3190,11 +3342,14 @@ pub fn spawn_officer(
     tile: IVec2,
 ) -> Entity {
     const TILE_SIZE: f32 = 1.0;
-    const WALL_H: f32 = 1.0;
 
-    let pos = Vec3::new(tile.x as f32 * TILE_SIZE, WALL_H * 0.5, tile.y as f32 * TILE_SIZE);
+    let pos = Vec3::new(
+        tile.x as f32 * TILE_SIZE,
+        SpriteAnchor::Feet.center_y(ENEMY_SPRITE_H),
+        tile.y as f32 * TILE_SIZE,
+    );
 
-    let quad = meshes.add(Mesh::from(Rectangle::new(0.85, 1.0)));
+    let quad = meshes.add(Mesh::from(Rectangle::new(ENEMY_SPRITE_W, ENEMY_SPRITE_H)));
     let mat = materials.add(StandardMaterial {
         base_color_texture: Some(sprites.idle[0].clone()),
         alpha_mode: AlphaMode::Mask(0.5),
@@ -3368,11 +3523,14 @@ pub fn spawn_dog(
     tile: IVec2,
 ) -> Entity {
     const TILE_SIZE: f32 = 1.0;
-    const WALL_H: f32 = 1.0;
 
-    let pos = Vec3::new(tile.x as f32 * TILE_SIZE, WALL_H * 0.5, tile.y as f32 * TILE_SIZE);
+    let pos = Vec3::new(
+        tile.x as f32 * TILE_SIZE,
+        SpriteAnchor::Feet.center_y(ENEMY_SPRITE_H),
+        tile.y as f32 * TILE_SIZE,
+    );
 
-    let quad = meshes.add(Mesh::from(Rectangle::new(0.85, 1.0)));
+    let quad = meshes.add(Mesh::from(Rectangle::new(ENEMY_SPRITE_W, ENEMY_SPRITE_H)));
     let mat = materials.add(StandardMaterial {
         base_color_texture: Some(sprites.idle[0].clone()),
         alpha_mode: AlphaMode::Mask(0.5),
@@ -3536,17 +3694,14 @@ pub fn tick_guard_dying(
     mut commands: Commands,
     mut q: Query<(Entity, &mut GuardDying), With<Guard>>,
 ) {
-    const DEATH_FRAMES: u8 = 4;
-    const TICS_PER_FRAME: u8 = 6;
-
     for (e, mut dying) in q.iter_mut() {
         dying.tics = dying.tics.saturating_add(1);
 
-        if dying.tics >= TICS_PER_FRAME {
+        if dying.tics >= GUARD_DEATH_TICS_PER_FRAME {
             dying.tics = 0;
             dying.frame = dying.frame.saturating_add(1);
 
-            if dying.frame >= DEATH_FRAMES {
+            if dying.frame >= GUARD_DEATH_FRAMES {
                 // End of Animation -> Permanent Corpse (and non-blocking)
                 commands.entity(e).remove::<GuardDying>();
                 commands.entity(e).insert(GuardCorpse);
@@ -3584,6 +3739,7 @@ pub fn tick_mutant_dying(
 
 pub fn apply_guard_corpses(
     sprites: Res<GuardSprites>,
+    scales: Res<SpriteScales>,
     mut materials: ResMut<Assets<StandardMaterial>>,
     mut q: Query<(
         &MeshMaterial3d<StandardMaterial>,
@@ -3613,7 +3769,7 @@ pub fn apply_guard_corpses(
             *v = Visibility::Visible;
         }
 
-        tf.translation.y = 0.5;
+        tf.translation.y = SpriteAnchor::Feet.center_y(scales.enemy_h);
     }
 }
 
@@ -3627,7 +3783,7 @@ pub fn update_guard_views(
             Option<&GuardDying>,
             Option<&GuardPain>,
             Option<&GuardWalk>,
-            Option<&GuardShoot>,
+            Option<&GuardAttack>,
             Option<&EnemyMove>,
             &GlobalTransform,
             &Dir8,
@@ -3668,13 +3824,10 @@ pub fn update_guard_views(
             sprites.dying[i].clone()
         } else if pain.is_some() {
             sprites.pain.clone()
-        } else if let Some(s) = shoot {
-            // GuardShoot Has Only Timer, Pick Aim vs Fire Based on Timer Progress
-            let dur = s.timer.duration().as_secs_f32().max(1e-6);
-            let t = s.timer.elapsed().as_secs_f32();
-            let fire_phase = t >= (dur * 0.5);
-
-            if fire_phase {
+        } else if let Some(a) = shoot {
+            // Attack Art Is Front-Only Like the Original, so Every View Angle
+            // Shows the Same Raise / Flash / Lower Frames
+            if a.frame == GUARD_ATTACK_FLASH_FRAME {
                 sprites.shoot_front_fire.clone()
             } else {
                 sprites.shoot_front_aim.clone()
@@ -3788,6 +3941,28 @@ fn update_mutant_views(
     }
 }
 
+/// Keeps Enemy Quads at the Tuned SpriteScales Height, Feet on the Floor
+pub fn apply_enemy_sprite_scales(
+    scales: Res<SpriteScales>,
+    mut q: Query<(Ref<EnemyKind>, &mut Transform)>,
+) {
+    let k = scales.enemy_factor();
+
+    for (kind, mut tf) in q.iter_mut() {
+        if !scales.is_changed() && !kind.is_added() {
+            continue;
+        }
+
+        let old_k = tf.scale.y;
+        if old_k == k {
+            continue;
+        }
+
+        tf.translation.y = SpriteAnchor::Feet.rescaled_center_y(tf.translation.y, ENEMY_SPRITE_H, old_k, k);
+        tf.scale = Vec3::splat(k);
+    }
+}
+
 pub struct EnemiesPlugin;
 
 impl Plugin for EnemiesPlugin {
@@ -3805,6 +3980,7 @@ impl Plugin for EnemiesPlugin {
             .init_resource::<SchabbsSprites>()
             .init_resource::<OttoSprites>()
             .init_resource::<GeneralSprites>()
+            .init_resource::<SpriteScales>()
             // Update Systems: Attach Walk Components
             .add_systems(Update, attach_guard_walk)
             .add_systems(Update, attach_mutant_walk)
@@ -3819,6 +3995,15 @@ impl Plugin for EnemiesPlugin {
             .add_systems(Update, attach_schabbs_walk)
             .add_systems(Update, attach_otto_walk)
             .add_systems(Update, attach_general_walk)
+            // Update Systems: Sprite Scale Tuning (Keys Are Dev Builds Only)
+            .add_systems(
+                Update,
+                (
+                    crate::decorations::nudge_sprite_scales.run_if(|| cfg!(debug_assertions)),
+                    apply_enemy_sprite_scales,
+                )
+                    .chain(),
+            )
             // Update Systems: Update Views
             .add_systems(Update, update_guard_views)
             .add_systems(Update, update_mutant_views)
@@ -3834,62 +4019,114 @@ impl Plugin for EnemiesPlugin {
             .add_systems(Update, update_otto_views)
             .add_systems(Update, update_general_views)
             // FixedUpdate Systems: Guards
-            .add_systems(FixedUpdate, tick_guard_walk)
-            .add_systems(FixedUpdate, tick_guard_pain)
-            .add_systems(FixedUpdate, tick_guard_shoot)
-            .add_systems(FixedUpdate, tick_guard_dying)
+            .add_systems(FixedUpdate, tick_guard_walk.run_if(game_running))
+            .add_systems(FixedUpdate, tick_guard_pain.run_if(game_running))
+            .add_systems(FixedUpdate, tick_guard_attack.run_if(game_running))
+            .add_systems(FixedUpdate, tick_guard_dying.run_if(game_running))
             // FixedUpdate Systems: Mutants
-            .add_systems(FixedUpdate, tick_mutant_walk)
-            .add_systems(FixedUpdate, tick_mutant_pain)
-            .add_systems(FixedUpdate, tick_mutant_shoot)
-            .add_systems(FixedUpdate, tick_mutant_dying)
+            .add_systems(FixedUpdate, tick_mutant_walk.run_if(game_running))
+            .add_systems(FixedUpdate, tick_mutant_pain.run_if(game_running))
+            .add_systems(FixedUpdate, tick_mutant_shoot.run_if(game_running))
+            .add_systems(FixedUpdate, tick_mutant_dying.run_if(game_running))
             // FixedUpdate Systems: SS
-            .add_systems(FixedUpdate, tick_ss_walk)
-            .add_systems(FixedUpdate, tick_ss_pain)
-            .add_systems(FixedUpdate, tick_ss_shoot)
-            .add_systems(FixedUpdate, tick_ss_dying)
+            .add_systems(FixedUpdate, tick_ss_walk.run_if(game_running))
+            .add_systems(FixedUpdate, tick_ss_pain.run_if(game_running))
+            .add_systems(FixedUpdate, tick_ss_shoot.run_if(game_running))
+            .add_systems(FixedUpdate, tick_ss_dying.run_if(game_running))
             // FixedUpdate Systems: Officers
-            .add_systems(FixedUpdate, tick_officer_walk)
-            .add_systems(FixedUpdate, tick_officer_pain)
-            .add_systems(FixedUpdate, tick_officer_shoot)
-            .add_systems(FixedUpdate, tick_officer_dying)
+            .add_systems(FixedUpdate, tick_officer_walk.run_if(game_running))
+            .add_systems(FixedUpdate, tick_officer_pain.run_if(game_running))
+            .add_systems(FixedUpdate, tick_officer_shoot.run_if(game_running))
+            .add_systems(FixedUpdate, tick_officer_dying.run_if(game_running))
             // FixedUpdate Systems: Dogs
-            .add_systems(FixedUpdate, tick_dog_walk)
-            .add_systems(FixedUpdate, tick_dog_pain)
-            .add_systems(FixedUpdate, tick_dog_bite_cooldown)
-            .add_systems(FixedUpdate, tick_dog_bite)
-            .add_systems(FixedUpdate, tick_dog_dying)
+            .add_systems(FixedUpdate, tick_dog_walk.run_if(game_running))
+            .add_systems(FixedUpdate, tick_dog_pain.run_if(game_running))
+            .add_systems(FixedUpdate, tick_dog_bite_cooldown.run_if(game_running))
+            .add_systems(FixedUpdate, tick_dog_bite.run_if(game_running))
+            .add_systems(FixedUpdate, tick_dog_dying.run_if(game_running))
             // FixedUpdate Systems: Hans
-            .add_systems(FixedUpdate, tick_hans_walk)
-            .add_systems(FixedUpdate, tick_hans_shoot)
-            .add_systems(FixedUpdate, tick_hans_dying)
+            .add_systems(FixedUpdate, tick_hans_walk.run_if(game_running))
+            .add_systems(FixedUpdate, tick_hans_shoot.run_if(game_running))
+            .add_systems(FixedUpdate, tick_hans_dying.run_if(game_running))
             // FixedUpdate Systems: Gretel
-            .add_systems(FixedUpdate, tick_gretel_walk)
-            .add_systems(FixedUpdate, tick_gretel_shoot)
-            .add_systems(FixedUpdate, tick_gretel_dying)
+            .add_systems(FixedUpdate, tick_gretel_walk.run_if(game_running))
+            .add_systems(FixedUpdate, tick_gretel_shoot.run_if(game_running))
+            .add_systems(FixedUpdate, tick_gretel_dying.run_if(game_running))
             // FixedUpdate Systems: Mecha Hitler
-            .add_systems(FixedUpdate, tick_mecha_hitler_walk)
-            .add_systems(FixedUpdate, tick_mecha_hitler_shoot)
-            .add_systems(FixedUpdate, tick_mecha_hitler_dying)
+            .add_systems(FixedUpdate, tick_mecha_hitler_walk.run_if(game_running))
+            .add_systems(FixedUpdate, tick_mecha_hitler_shoot.run_if(game_running))
+            .add_systems(FixedUpdate, tick_mecha_hitler_dying.run_if(game_running))
             // FixedUpdate Systems: Hitler
-            .add_systems(FixedUpdate, tick_hitler_walk)
-            .add_systems(FixedUpdate, tick_hitler_shoot)
-            .add_systems(FixedUpdate, tick_hitler_dying)
+            .add_systems(FixedUpdate, tick_hitler_walk.run_if(game_running))
+            .add_systems(FixedUpdate, tick_hitler_shoot.run_if(game_running))
+            .add_systems(FixedUpdate, tick_hitler_dying.run_if(game_running))
             // FixedUpdate Systems: Ghost Hitler
-            .add_systems(FixedUpdate, tick_ghost_hitler_walk)
-            .add_systems(FixedUpdate, tick_ghost_hitler_shoot)
-            .add_systems(FixedUpdate, tick_ghost_hitler_dying)
+            .add_systems(FixedUpdate, tick_ghost_hitler_walk.run_if(game_running))
+            .add_systems(FixedUpdate, tick_ghost_hitler_shoot.run_if(game_running))
+            .add_systems(FixedUpdate, tick_ghost_hitler_dying.run_if(game_running))
             // FixedUpdate Systems: Schabbs
-            .add_systems(FixedUpdate, tick_schabbs_walk)
-            .add_systems(FixedUpdate, tick_schabbs_throw)
-            .add_systems(FixedUpdate, tick_schabbs_dying)
+            .add_systems(FixedUpdate, tick_schabbs_walk.run_if(game_running))
+            .add_systems(FixedUpdate, tick_schabbs_throw.run_if(game_running))
+            .add_systems(FixedUpdate, tick_schabbs_dying.run_if(game_running))
             // FixedUpdate Systems: Otto
-            .add_systems(FixedUpdate, tick_otto_walk)
-            .add_systems(FixedUpdate, tick_otto_shoot)
-            .add_systems(FixedUpdate, tick_otto_dying)
+            .add_systems(FixedUpdate, tick_otto_walk.run_if(game_running))
+            .add_systems(FixedUpdate, tick_otto_shoot.run_if(game_running))
+            .add_systems(FixedUpdate, tick_otto_dying.run_if(game_running))
             // FixedUpdate Systems: General
-            .add_systems(FixedUpdate, tick_general_walk)
-            .add_systems(FixedUpdate, tick_general_shoot)
-            .add_systems(FixedUpdate, tick_general_dying);
+            .add_systems(FixedUpdate, tick_general_walk.run_if(game_running))
+            .add_systems(FixedUpdate, tick_general_shoot.run_if(game_running))
+            .add_systems(FixedUpdate, tick_general_dying.run_if(game_running));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn guard_attack_fires_once_on_the_flash_frame() {
+        let mut attack = GuardAttack::new(0.25, Some(12));
+        let step = Duration::from_secs_f32(1.0 / crate::tics::TIC_HZ);
+
+        let mut fired = 0;
+        while !attack.timer.is_finished() {
+            let before = attack.frame;
+            if attack.advance(step) {
+                fired += 1;
+                assert!(before < GUARD_ATTACK_FLASH_FRAME);
+                assert_eq!(attack.frame, GUARD_ATTACK_FLASH_FRAME);
+            }
+        }
+
+        assert_eq!(fired, 1);
+        assert_eq!(attack.frame, GUARD_ATTACK_FRAMES - 1);
+    }
+
+    #[test]
+    fn guard_death_advances_a_frame_every_six_tics_then_leaves_a_corpse() {
+        let mut app = App::new();
+        app.add_systems(FixedUpdate, tick_guard_dying);
+
+        let guard = app
+            .world_mut()
+            .spawn((Guard, GuardDying { frame: 0, tics: 0 }))
+            .id();
+
+        // One FixedUpdate Run Is One Tic
+        let tic = |app: &mut App| app.world_mut().run_schedule(FixedUpdate);
+
+        for frame in 0..GUARD_DEATH_FRAMES {
+            for _ in 0..GUARD_DEATH_TICS_PER_FRAME {
+                let dying = app.world().get::<GuardDying>(guard).copied();
+                assert_eq!(dying.map(|d| d.frame), Some(frame));
+                tic(&mut app);
+            }
+        }
+
+        let world = app.world();
+        assert!(world.get::<GuardDying>(guard).is_none());
+        assert!(world.get::<GuardCorpse>(guard).is_some());
+        assert!(world.get_entity(guard).is_ok());
     }
 }
@@ -6,7 +6,7 @@ use bevy::prelude::*;
 use bevy::window::PrimaryWindow;
 
 use davelib::audio::{MusicMode, MusicModeKind, PlaySfx, SfxKind};
-use davelib::level::{CurrentLevel, LevelId, WolfPlane1};
+use davelib::level::{CurrentLevel, ExitOutcome, ExitTrigger, LevelId, WolfPlane1, resolve_level_exit};
 use davelib::map::MapGrid;
 use davelib::player::{Player, PlayerControlLock};
 
@@ -300,9 +300,11 @@ fn start_death_cam(
 		return;
 	};
 
-	let on_floor_9 = current_level.0.floor_number() == 9;
+	// Hans and Gretel Drop a Key for the Elevator, Every Other Boss Kill Ends
+	// the Episode Only When the Level Exit Rules Say So
+	let ends_episode = resolve_level_exit(ExitTrigger::BossKilled, current_level.0) == ExitOutcome::EpisodeVictory;
 	let is_hans_or_gretel = matches!(current_level.0, LevelId::E1M9 | LevelId::E5M9);
-	if !on_floor_9 || is_hans_or_gretel {
+	if !ends_episode || is_hans_or_gretel {
 		return;
 	}
 
@@ -630,12 +632,7 @@ fn episode_end_finish_to_ui(
     lock.0 = true;
     music_mode.0 = MusicModeKind::Scores;
 
-    name_entry.active = false;
-    name_entry.name.clear();
-    name_entry.cursor_pos = 0;
-    name_entry.rank = 0;
-    name_entry.score = 0;
-    name_entry.episode = result.episode;
+    name_entry.reset_for_episode(result.episode);
 
     flow.phase = EpisodeEndPhase::Inactive;
 }
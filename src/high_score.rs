@@ -113,16 +113,33 @@ impl HighScores {
                 continue;
             };
 
-            let Ok(scores) = ron::from_str::<Self>(&contents) else {
-                continue;
+            // A Damaged File Is Skipped Rather Than Fatal, the Next Candidate
+            // or the Defaults Take Over and the Next Save Rewrites It
+            let scores = match ron::from_str::<Self>(&contents) {
+                Ok(scores) => scores,
+                Err(error) => {
+                    warn!("Ignoring unreadable high scores at {}: {error}", path.display());
+                    continue;
+                }
             };
 
-            return scores;
+            return scores.normalized();
         }
 
         Self::default()
     }
 
+    /// Table as the Scores Screen Expects It: Best First, at Most MAX_SCORES
+    /// Rows, Initials Capped at 3. Hand-Edited Files Can Break All Three
+    fn normalized(mut self) -> Self {
+        self.entries.sort_by(|a, b| b.score.cmp(&a.score));
+        self.entries.truncate(MAX_SCORES);
+        for e in &mut self.entries {
+            e.name = e.name.chars().filter(|c| !c.is_control()).take(3).collect();
+        }
+        self
+    }
+
     pub fn save(&self) {
         let Some(path) = Self::save_path() else {
             warn!("Unable to resolve the Davenstein high score path");
@@ -139,12 +156,22 @@ impl HighScores {
         }
     }
 
+    /// Empty the Table, Scores Screen Pads Missing Rows With "---" Placeholders
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
     pub fn qualifies(&self, score: i32) -> bool {
         self.entries.len() < MAX_SCORES || self.entries.last().is_some_and(|e| score > e.score)
     }
 
-    pub fn add(&mut self, name: String, score: i32, episode: u8) -> Option<usize> {
-        if !self.qualifies(score) {
+    /// A Run Entered With Any Cheat Never Makes the Table, Whatever its Score
+    pub fn qualifies_run(&self, score: i32, cheated: bool) -> bool {
+        !cheated && self.qualifies(score)
+    }
+
+    pub fn add(&mut self, name: String, score: i32, episode: u8, cheated: bool) -> Option<usize> {
+        if !self.qualifies_run(score, cheated) {
             return None;
         }
 
@@ -203,3 +230,50 @@ impl Default for NameEntryState {
         }
     }
 }
+
+impl NameEntryState {
+    /// Clear Any Half-Typed Entry Before an Episode's Results Screen Opens
+    pub fn reset_for_episode(&mut self, episode: u8) {
+        *self = Self { episode, ..Self::default() };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cleared_table_accepts_any_score() {
+        let mut scores = HighScores::default();
+        assert!(!scores.qualifies(0));
+
+        scores.clear();
+        assert!(scores.entries.is_empty());
+        assert!(scores.qualifies(0));
+        assert!(!scores.qualifies_run(0, true));
+
+        // A Cheated Run Is Turned Away Before Anything Is Written
+        assert_eq!(scores.add("ABC".into(), 100, 1, true), None);
+        assert!(scores.entries.is_empty());
+    }
+
+    #[test]
+    fn loaded_table_is_sorted_and_trimmed() {
+        let text = r#"(entries: [
+            (name: "LOW", score: 5, episode: 1),
+            (name: "TOPPER", score: 900, episode: 2),
+            (name: "A", score: 1, episode: 1),
+            (name: "B", score: 2, episode: 1),
+            (name: "C", score: 3, episode: 1),
+            (name: "D", score: 4, episode: 1),
+            (name: "E", score: 6, episode: 1),
+            (name: "F", score: 7, episode: 1),
+        ])"#;
+        let scores = ron::from_str::<HighScores>(text).unwrap().normalized();
+
+        assert_eq!(scores.entries.len(), MAX_SCORES);
+        assert_eq!(scores.entries[0].name, "TOP");
+        assert!(scores.entries.windows(2).all(|w| w[0].score >= w[1].score));
+        assert!(scores.entries.iter().all(|e| e.score > 1));
+    }
+}
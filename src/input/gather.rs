@@ -9,8 +9,9 @@ Resetting to Default First is What Keeps Unpressed Inputs From Going Stale
 
 Merge Contract Honored by Each Source contribute Function
 - Vectors move_wish and look_delta Accumulate Additively
-- Booleans run and fire and fire_pressed and use_pressed Combine by OR
+- Booleans run, fire, fire_pressed, use_pressed and use_held Combine by OR
 - weapon_select Keeps the First Source That Sets it, so Call Order is Priority
+- weapon_cycle Keeps the First Nonzero Step, Like weapon_select
 - move_wish Uses Keyboard Priority, so Later Sources Fill Only When Still Zero
 
 Keyboard and Mouse Runs First and Establishes the Base. Gamepad and Touch
@@ -18,7 +19,7 @@ Merge on Top of the Base in Later Milestones
 */
 
 use bevy::prelude::*;
-use bevy::input::mouse::AccumulatedMouseMotion;
+use bevy::input::mouse::{AccumulatedMouseMotion, AccumulatedMouseScroll};
 use bevy::window::{CursorOptions, PrimaryWindow};
 
 use crate::input::intent::PlayerIntent;
@@ -34,6 +35,7 @@ pub fn gather(
     keys: Res<ButtonInput<KeyCode>>,
     mouse_buttons: Res<ButtonInput<MouseButton>>,
     mouse_motion: Res<AccumulatedMouseMotion>,
+    mouse_scroll: Res<AccumulatedMouseScroll>,
     q_cursor: Query<&CursorOptions, With<PrimaryWindow>>,
     q_gamepads: Query<&Gamepad>,
     controls: Res<ControlSettings>,
@@ -49,6 +51,7 @@ pub fn gather(
         &keys,
         &mouse_buttons,
         &mouse_motion,
+        &mouse_scroll,
         &q_cursor,
         &controls,
     );
@@ -61,6 +64,11 @@ pub fn gather(
 
     // Touch Contributes Here in a Later Milestone
 
+    // Always Run Flips the Merged Run Input, so Holding Run Walks
+    if controls.always_run {
+        acc.run = !acc.run;
+    }
+
     *intent = acc;
 
     // Menu Navigation Uses the Same Reset-Then-Merge Pattern as PlayerIntent
@@ -71,3 +79,94 @@ pub fn gather(
     }
     *menu = nav;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::options::InputBinding;
+
+    fn gather_app() -> App {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<ButtonInput<MouseButton>>()
+            .init_resource::<AccumulatedMouseMotion>()
+            .init_resource::<AccumulatedMouseScroll>()
+            .init_resource::<ControlSettings>()
+            .init_resource::<PlayerIntent>()
+            .init_resource::<MenuNav>()
+            .add_systems(Update, gather);
+        app
+    }
+
+    #[test]
+    fn always_run_runs_by_default_and_holding_run_walks() {
+        let mut app = gather_app();
+        app.world_mut().resource_mut::<ControlSettings>().always_run = true;
+        let InputBinding::Key(run_key) = app.world().resource::<ControlSettings>().key_bindings.run else {
+            panic!("run defaults to a key");
+        };
+
+        app.update();
+        assert!(app.world().resource::<PlayerIntent>().run);
+
+        app.world_mut().resource_mut::<ButtonInput<KeyCode>>().press(run_key);
+        app.update();
+        assert!(!app.world().resource::<PlayerIntent>().run);
+    }
+
+    #[test]
+    fn bound_fire_key_drives_fire_intent_like_the_mouse() {
+        let mut app = gather_app();
+        let InputBinding::Key(fire_key) = app.world().resource::<ControlSettings>().key_bindings.fire else {
+            panic!("fire defaults to a key");
+        };
+
+        // First Frame of the Press: Held and Pressed, Like a Fresh Left Click
+        app.world_mut().resource_mut::<ButtonInput<KeyCode>>().press(fire_key);
+        app.update();
+        let intent = *app.world().resource::<PlayerIntent>();
+        assert!(intent.fire);
+        assert!(intent.fire_pressed);
+
+        // Still Held Next Frame: Full-Auto Keeps Firing, Single-Shot Gets No New Edge
+        app.world_mut().resource_mut::<ButtonInput<KeyCode>>().clear();
+        app.update();
+        let intent = *app.world().resource::<PlayerIntent>();
+        assert!(intent.fire);
+        assert!(!intent.fire_pressed);
+
+        // Released: Intent Resets so the Trigger Never Sticks
+        app.world_mut().resource_mut::<ButtonInput<KeyCode>>().release(fire_key);
+        app.update();
+        assert!(!app.world().resource::<PlayerIntent>().fire);
+    }
+
+    #[test]
+    fn q_and_e_step_weapons_unless_rebound() {
+        let mut app = gather_app();
+
+        app.world_mut().resource_mut::<ButtonInput<KeyCode>>().press(KeyCode::KeyE);
+        app.update();
+        assert_eq!(app.world().resource::<PlayerIntent>().weapon_cycle, 1);
+
+        // One Step per Press, Not per Frame Held
+        app.world_mut().resource_mut::<ButtonInput<KeyCode>>().clear();
+        app.update();
+        assert_eq!(app.world().resource::<PlayerIntent>().weapon_cycle, 0);
+
+        app.world_mut().resource_mut::<ButtonInput<KeyCode>>().press(KeyCode::KeyQ);
+        app.update();
+        assert_eq!(app.world().resource::<PlayerIntent>().weapon_cycle, -1);
+
+        // Bound to Strafe Left, Q Strafes and No Longer Cycles
+        app.world_mut().resource_mut::<ControlSettings>().key_bindings.strafe_left = KeyCode::KeyQ.into();
+        app.world_mut().resource_mut::<ButtonInput<KeyCode>>().release(KeyCode::KeyQ);
+        app.world_mut().resource_mut::<ButtonInput<KeyCode>>().clear();
+        app.world_mut().resource_mut::<ButtonInput<KeyCode>>().press(KeyCode::KeyQ);
+        app.update();
+        let intent = *app.world().resource::<PlayerIntent>();
+        assert_eq!(intent.weapon_cycle, 0);
+        assert_eq!(intent.move_wish.x, -1.0);
+    }
+}
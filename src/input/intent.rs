@@ -37,8 +37,15 @@ pub struct PlayerIntent {
     // Use or Open Door Pressed This Frame
     pub use_pressed: bool,
 
+    // Use Held, Only Doors Honor it (Hold Use Door Setting)
+    pub use_held: bool,
+
     // Weapon Slot Requested This Frame from 1 Through 4
     // Uses a Device-Neutral Index Rather Than the Binary's WeaponSlot Enum
     // This Keeps davelib Free of a Dependency on the Binary Crate
     pub weapon_select: Option<u8>,
+
+    // Step Through Owned Weapons This Frame, +1 Next / -1 Previous, 0 None
+    // An Absolute weapon_select the Same Frame Wins Over the Cycle
+    pub weapon_cycle: i8,
 }
@@ -18,11 +18,13 @@ pub mod cursor;
 pub mod sources;
 pub mod gather;
 pub mod menu;
+pub mod suppress;
 
 use bevy::prelude::*;
 
 pub use intent::PlayerIntent;
 pub use menu::MenuNav;
+pub use suppress::GameplayInputSuppress;
 
 // System Set Containing Per-Frame Intent Gathering
 // Order Consumers After This Set When They Must Read Fresh Intent in the Same Schedule
@@ -37,6 +39,8 @@ impl Plugin for InputPlugin {
         app
             .init_resource::<PlayerIntent>()
             .init_resource::<MenuNav>()
+            .init_resource::<GameplayInputSuppress>()
+            .add_systems(First, suppress::snapshot_control_lock)
             .add_systems(
                 Update,
                 gather::gather.in_set(InputGather),
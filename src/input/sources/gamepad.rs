@@ -68,12 +68,15 @@ pub fn contribute(
         // Run While the Left Stick is Clicked In
         acc.run |= gp.pressed(GamepadButton::LeftThumb);
 
-        // Fire on the South Face Button (A on Xbox), Held Plus a One Frame Edge
-        acc.fire |= gp.pressed(GamepadButton::South);
-        acc.fire_pressed |= gp.just_pressed(GamepadButton::South);
+        // Fire on the Right Trigger or the South Face Button (A on Xbox), Held
+        // Plus a One Frame Edge
+        acc.fire |= gp.pressed(GamepadButton::RightTrigger2) || gp.pressed(GamepadButton::South);
+        acc.fire_pressed |= gp.just_pressed(GamepadButton::RightTrigger2)
+            || gp.just_pressed(GamepadButton::South);
 
         // Use or Open Door on the West Face Button (X on Xbox), One Frame Edge
         acc.use_pressed |= gp.just_pressed(GamepadButton::West);
+        acc.use_held |= gp.pressed(GamepadButton::West);
 
         // Weapon Select on the D-Pad, Absolute 1..=4 Matching the Keyboard Slots
         // Up = 1 Knife, Right = 2 Pistol, Down = 3 MachineGun, Left = 4 Chaingun
@@ -90,6 +93,15 @@ pub fn contribute(
             None
         };
         acc.weapon_select = acc.weapon_select.or(weapon);
+
+        // Bumpers Step Through Owned Weapons, Right = Next, Left = Previous
+        if acc.weapon_cycle == 0 {
+            if gp.just_pressed(GamepadButton::RightTrigger) {
+                acc.weapon_cycle = 1;
+            } else if gp.just_pressed(GamepadButton::LeftTrigger) {
+                acc.weapon_cycle = -1;
+            }
+        }
     }
 }
 
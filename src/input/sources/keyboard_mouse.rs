@@ -6,12 +6,12 @@ a fresh PlayerIntent every frame
 */
 
 use bevy::prelude::*;
-use bevy::input::mouse::AccumulatedMouseMotion;
+use bevy::input::mouse::{AccumulatedMouseMotion, AccumulatedMouseScroll};
 use bevy::window::{CursorOptions, PrimaryWindow};
 
 use crate::input::intent::PlayerIntent;
 use crate::input::menu::MenuNav;
-use crate::options::ControlSettings;
+use crate::options::{ControlSettings, InputBinding};
 use crate::player::cursor_is_captured;
 
 // Base Sensitivity Applied on Top of ControlSettings.mouse_sensitivity
@@ -36,6 +36,7 @@ pub fn contribute(
     keys: &ButtonInput<KeyCode>,
     mouse_buttons: &ButtonInput<MouseButton>,
     mouse_motion: &AccumulatedMouseMotion,
+    mouse_scroll: &AccumulatedMouseScroll,
     q_cursor: &Query<&CursorOptions, With<PrimaryWindow>>,
     controls: &ControlSettings,
 ) {
@@ -43,20 +44,20 @@ pub fn contribute(
 
     // Movement in the Local Player Frame: X = Strafe, Y = Forward
     let mut wish = Vec2::ZERO;
-    if keys.pressed(kb.move_forward) || keys.pressed(KeyCode::ArrowUp) {
+    if kb.move_forward.pressed(keys, mouse_buttons) || keys.pressed(KeyCode::ArrowUp) {
         wish.y += 1.0;
     }
-    if keys.pressed(kb.move_backward) || keys.pressed(KeyCode::ArrowDown) {
+    if kb.move_backward.pressed(keys, mouse_buttons) || keys.pressed(KeyCode::ArrowDown) {
         wish.y -= 1.0;
     }
-    if keys.pressed(kb.strafe_right) {
+    if kb.strafe_right.pressed(keys, mouse_buttons) {
         wish.x += 1.0;
     }
-    if keys.pressed(kb.strafe_left) {
+    if kb.strafe_left.pressed(keys, mouse_buttons) {
         wish.x -= 1.0;
     }
 
-    let run = keys.pressed(kb.run) || keys.pressed(KeyCode::ShiftRight);
+    let run = kb.run.pressed(keys, mouse_buttons) || keys.pressed(KeyCode::ShiftRight);
 
     // Look Input
     let mut look = Vec2::ZERO;
@@ -83,33 +84,51 @@ pub fn contribute(
     // Precise for Aiming Without Making Full Turns Feel Sluggish
     let dt = time.delta_secs();
     let turn_speed = if run { KEY_TURN_SPEED_RUN } else { KEY_TURN_SPEED };
-    if keys.pressed(kb.turn_left) {
+    if kb.turn_left.pressed(keys, mouse_buttons) {
         look.x += turn_speed * dt;
     }
-    if keys.pressed(kb.turn_right) {
+    if kb.turn_right.pressed(keys, mouse_buttons) {
         look.x -= turn_speed * dt;
     }
 
     // Action Edges Populated Now and Consumed Later
-    // When the Fire Path Moves to Intent, Separate Left Click from Cursor Capture
-    // The Default Fire Binding is ControlLeft, Which Also Releases the Cursor
-    let fire = keys.pressed(kb.fire) || mouse_buttons.pressed(MouseButton::Left);
-    let fire_pressed =
-        keys.just_pressed(kb.fire) || mouse_buttons.just_pressed(MouseButton::Left);
-    let use_pressed = keys.just_pressed(kb.use_door);
-
-    let weapon_select = if keys.just_pressed(kb.weapon_1) {
+    // The Fire Binding and the Left Mouse Button Feed the Same Held / Pressed
+    // Pair, so Full-Auto and Single-Shot Behave Identically From Either. Left
+    // Click Stops Firing Once Another Action Is Bound to it. Cursor Capture
+    // Happens on Focus in grab_mouse, so No Click is Ever Swallowed Here
+    let left_fires = !kb.is_bound(InputBinding::Mouse(MouseButton::Left));
+    let fire = kb.fire.pressed(keys, mouse_buttons)
+        || (left_fires && mouse_buttons.pressed(MouseButton::Left));
+    let fire_pressed = kb.fire.just_pressed(keys, mouse_buttons)
+        || (left_fires && mouse_buttons.just_pressed(MouseButton::Left));
+    let use_pressed = kb.use_door.just_pressed(keys, mouse_buttons);
+    let use_held = kb.use_door.pressed(keys, mouse_buttons);
+
+    let weapon_select = if kb.weapon_1.just_pressed(keys, mouse_buttons) {
         Some(1)
-    } else if keys.just_pressed(kb.weapon_2) {
+    } else if kb.weapon_2.just_pressed(keys, mouse_buttons) {
         Some(2)
-    } else if keys.just_pressed(kb.weapon_3) {
+    } else if kb.weapon_3.just_pressed(keys, mouse_buttons) {
         Some(3)
-    } else if keys.just_pressed(kb.weapon_4) {
+    } else if kb.weapon_4.just_pressed(keys, mouse_buttons) {
         Some(4)
     } else {
         None
     };
 
+    // Wheel Up or E Steps to the Next Owned Weapon, Wheel Down or Q to the
+    // Previous. The Wheel Only Counts While the Cursor Is Captured, and Q / E
+    // Step Aside for Any Action the Player Has Bound to Them
+    let key_free = |k: KeyCode| !kb.is_bound(InputBinding::Key(k));
+    let mut weapon_cycle: i8 = 0;
+    if captured && mouse_scroll.delta.y != 0.0 {
+        weapon_cycle = if mouse_scroll.delta.y > 0.0 { 1 } else { -1 };
+    } else if key_free(KeyCode::KeyE) && keys.just_pressed(KeyCode::KeyE) {
+        weapon_cycle = 1;
+    } else if key_free(KeyCode::KeyQ) && keys.just_pressed(KeyCode::KeyQ) {
+        weapon_cycle = -1;
+    }
+
     // Merge This Frame Contribution into the Shared Accumulator
     // move_wish and look_delta Accumulate, Booleans Combine by OR
     // weapon_select Keeps the First Source That Sets it, so Keyboard Wins Here
@@ -119,7 +138,11 @@ pub fn contribute(
     acc.fire |= fire;
     acc.fire_pressed |= fire_pressed;
     acc.use_pressed |= use_pressed;
+    acc.use_held |= use_held;
     acc.weapon_select = acc.weapon_select.or(weapon_select);
+    if acc.weapon_cycle == 0 {
+        acc.weapon_cycle = weapon_cycle;
+    }
 }
 
 // Merge Keyboard Menu Navigation Into the Shared MenuNav Accumulator
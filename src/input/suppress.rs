@@ -0,0 +1,31 @@
+/*
+Davenstein - by David Petnick
+
+One-Frame Gameplay Input Suppression
+
+The Click or Key That Dismisses a Menu (New Game, Return to Game, Level Load)
+Is Still Present in PlayerIntent on the Frame the Menu Unlocks Controls. Any
+Fire or Use System That Runs Later in That Same Frame Would Otherwise See the
+Unlock and the Press Together and Act on It
+
+snapshot_control_lock Runs in First and Records Whether Controls Were Locked
+When the Frame Began. Consumers Treat a Suppressed Frame Like a Locked One, so
+the Transition Frame Never Fires or Uses, While a Held Trigger Starts Firing on
+the Very Next Frame Because the Flag Clears as Soon as a Frame Begins Unlocked
+*/
+
+use bevy::prelude::*;
+
+use crate::player::PlayerControlLock;
+
+// True on Every Frame That Began With Controls Locked, Including the Frame a
+// Menu Hands Control Back to Gameplay
+#[derive(Resource, Debug, Clone, Copy, Default)]
+pub struct GameplayInputSuppress(pub bool);
+
+pub fn snapshot_control_lock(
+    lock: Res<PlayerControlLock>,
+    mut suppress: ResMut<GameplayInputSuppress>,
+) {
+    suppress.0 = lock.0;
+}
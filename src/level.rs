@@ -4,6 +4,11 @@ Davenstein - by David Petnick
 
 use bevy::prelude::*;
 
+use crate::decorations::StatKind;
+use crate::map::MapGrid;
+use crate::pushwalls::PushwallMarkers;
+use crate::skill::SkillLevel;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum LevelId {
     // Episode 1
@@ -70,6 +75,23 @@ impl LevelId {
         }
     }
 
+    /// The Episode's Secret Floor (Always Floor 10), None Past the Six Episodes
+    pub const fn secret_level_of_episode(episode: u8) -> Option<Self> {
+        match episode {
+            1 => Some(LevelId::E1M10),
+            2 => Some(LevelId::E2M10),
+            3 => Some(LevelId::E3M10),
+            4 => Some(LevelId::E4M10),
+            5 => Some(LevelId::E5M10),
+            6 => Some(LevelId::E6M10),
+            _ => None,
+        }
+    }
+
+    pub const fn is_secret_floor(self) -> bool {
+        self.floor_number() == 10
+    }
+
     /// Episode Progression (Normal Exits, Not Secret)
     pub const fn next_normal(self) -> Self {
         match self {
@@ -148,6 +170,48 @@ impl LevelId {
     }
 }
 
+/// Ordered Elevator Route Through an Episode, Floor 1 to the Boss Floor
+/// The Secret Floor (M10) is Off the Main Route and Not Listed
+pub const fn levels_in_episode(episode: u8) -> [LevelId; 9] {
+    match episode {
+        2 => [
+            LevelId::E2M1, LevelId::E2M2, LevelId::E2M3, LevelId::E2M4, LevelId::E2M5,
+            LevelId::E2M6, LevelId::E2M7, LevelId::E2M8, LevelId::E2M9,
+        ],
+        3 => [
+            LevelId::E3M1, LevelId::E3M2, LevelId::E3M3, LevelId::E3M4, LevelId::E3M5,
+            LevelId::E3M6, LevelId::E3M7, LevelId::E3M8, LevelId::E3M9,
+        ],
+        4 => [
+            LevelId::E4M1, LevelId::E4M2, LevelId::E4M3, LevelId::E4M4, LevelId::E4M5,
+            LevelId::E4M6, LevelId::E4M7, LevelId::E4M8, LevelId::E4M9,
+        ],
+        5 => [
+            LevelId::E5M1, LevelId::E5M2, LevelId::E5M3, LevelId::E5M4, LevelId::E5M5,
+            LevelId::E5M6, LevelId::E5M7, LevelId::E5M8, LevelId::E5M9,
+        ],
+        6 => [
+            LevelId::E6M1, LevelId::E6M2, LevelId::E6M3, LevelId::E6M4, LevelId::E6M5,
+            LevelId::E6M6, LevelId::E6M7, LevelId::E6M8, LevelId::E6M9,
+        ],
+        _ => [
+            LevelId::E1M1, LevelId::E1M2, LevelId::E1M3, LevelId::E1M4, LevelId::E1M5,
+            LevelId::E1M6, LevelId::E1M7, LevelId::E1M8, LevelId::E1M9,
+        ],
+    }
+}
+
+/// Level a Normal Exit Leads To, or None When the Episode is Finished
+/// The Boss Floor (M9) is the Last Stop, so Leaving it Means Victory
+/// The Secret Floor Still Returns to its Episode's Main Route
+pub const fn next_level(current: LevelId) -> Option<LevelId> {
+    if current.floor_number() == 9 {
+        None
+    } else {
+        Some(current.next_normal())
+    }
+}
+
 pub const fn next_secret(from: LevelId) -> LevelId {
     match from {
         LevelId::E1M1 => LevelId::E1M10,
@@ -160,6 +224,39 @@ pub const fn next_secret(from: LevelId) -> LevelId {
     }
 }
 
+/// What Ended the Current Level
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExitTrigger {
+    Elevator,
+    SecretElevator,
+    BossKilled,
+}
+
+/// Where Play Goes After a Level Ends
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExitOutcome {
+    NextLevel(LevelId),
+    SecretLevel(LevelId),
+    EpisodeVictory,
+}
+
+/// Single Source of Truth for Level Progression
+/// - A Secret Elevator Leads to the Episode's Secret Floor, Falling Back to a
+///   Normal Exit on Floors Without One
+/// - A Normal Elevator or a Boss Kill Follows the Main Route, so Either One on
+///   the Boss Floor is Victory
+pub const fn resolve_level_exit(trigger: ExitTrigger, level: LevelId) -> ExitOutcome {
+    match trigger {
+        ExitTrigger::SecretElevator if next_secret(level).is_secret_floor() => {
+            ExitOutcome::SecretLevel(next_secret(level))
+        }
+        ExitTrigger::SecretElevator | ExitTrigger::Elevator | ExitTrigger::BossKilled => match next_level(level) {
+            Some(to) => ExitOutcome::NextLevel(to),
+            None => ExitOutcome::EpisodeVictory,
+        },
+    }
+}
+
 // Wolfenstein 3-D MS-DOS Ceiling Colors per Level (Episodes 1-6, Floors 1-10)
 // Each Entry is (r,g,b) in 8-bit RGB, Derived From Wolfenstein 3-D's vgaCeiling + GAMEPAL
 // Wolf3D WL6 ceiling palette indices (vgaCeiling) by episode and floor
@@ -188,6 +285,7 @@ const fn gamepal_rgb_u8(idx: u8) -> (u8, u8, u8) {
         // Index -> (r,g,b) in Wolfenstein 3-D GAMEPAL 6-bit, Scaled to 8-bit by *4
         0x00 => (0, 0, 0),
 
+        0x19 => (pal6_to_u8(28), pal6_to_u8(28), pal6_to_u8(28)),
         0x1d => (pal6_to_u8(14), pal6_to_u8(14), pal6_to_u8(14)),
         0x2d => (pal6_to_u8(22), 0, 0),
         0x4d => (pal6_to_u8(28), pal6_to_u8(27), 0),
@@ -206,19 +304,29 @@ const fn gamepal_rgb_u8(idx: u8) -> (u8, u8, u8) {
     }
 }
 
+/// Wolfenstein 3-D Clears Every Floor to the Same Gray (VGA Color 0x19), Only
+/// the Ceiling Changes per Level
+const VGA_FLOOR_PAL: u8 = 0x19;
+
+fn gamepal_color(idx: u8) -> Color {
+    let (r8, g8, b8) = gamepal_rgb_u8(idx);
+    Color::srgb(
+        r8 as f32 / 255.0,
+        g8 as f32 / 255.0,
+        b8 as f32 / 255.0,
+    )
+}
+
 impl LevelId {
+    pub fn floor_color(self) -> Color {
+        gamepal_color(VGA_FLOOR_PAL)
+    }
+
     pub fn ceiling_color(self) -> Color {
         let ep0 = self.episode().saturating_sub(1) as usize;
         let floor0 = (self.floor_number().clamp(1, 10) - 1) as usize;
 
-        let pal_idx = VGA_CEILING_PAL[ep0 * 10 + floor0];
-        let (r8, g8, b8) = gamepal_rgb_u8(pal_idx);
-
-        Color::srgb(
-            r8 as f32 / 255.0,
-            g8 as f32 / 255.0,
-            b8 as f32 / 255.0,
-        )
+        gamepal_color(VGA_CEILING_PAL[ep0 * 10 + floor0])
     }
 }
 
@@ -235,3 +343,572 @@ impl Default for CurrentLevel {
 /// Single Source of Truth for Decorations / Pickups Later
 #[derive(Resource, Debug, Clone, Default)]
 pub struct WolfPlane1(pub Vec<u16>);
+
+/// Every Wolfenstein 3-D Level Is a 64 x 64 Tile Grid
+pub const LEVEL_SIZE: usize = 64;
+
+/// Bundled plane0 / plane1 Text for a Level (One Decimal u16 per Tile)
+pub fn level_plane_text(level: LevelId) -> (&'static str, &'static str) {
+    match level {
+        // Episode 1
+        LevelId::E1M1 => (
+            include_str!("../assets/maps/episode1/e1m1_plane0_u16.txt"),
+            include_str!("../assets/maps/episode1/e1m1_plane1_u16.txt"),
+        ),
+        LevelId::E1M2 => (
+            include_str!("../assets/maps/episode1/e1m2_plane0_u16.txt"),
+            include_str!("../assets/maps/episode1/e1m2_plane1_u16.txt"),
+        ),
+        LevelId::E1M3 => (
+            include_str!("../assets/maps/episode1/e1m3_plane0_u16.txt"),
+            include_str!("../assets/maps/episode1/e1m3_plane1_u16.txt"),
+        ),
+        LevelId::E1M4 => (
+            include_str!("../assets/maps/episode1/e1m4_plane0_u16.txt"),
+            include_str!("../assets/maps/episode1/e1m4_plane1_u16.txt"),
+        ),
+        LevelId::E1M5 => (
+            include_str!("../assets/maps/episode1/e1m5_plane0_u16.txt"),
+            include_str!("../assets/maps/episode1/e1m5_plane1_u16.txt"),
+        ),
+        LevelId::E1M6 => (
+            include_str!("../assets/maps/episode1/e1m6_plane0_u16.txt"),
+            include_str!("../assets/maps/episode1/e1m6_plane1_u16.txt"),
+        ),
+        LevelId::E1M7 => (
+            include_str!("../assets/maps/episode1/e1m7_plane0_u16.txt"),
+            include_str!("../assets/maps/episode1/e1m7_plane1_u16.txt"),
+        ),
+        LevelId::E1M8 => (
+            include_str!("../assets/maps/episode1/e1m8_plane0_u16.txt"),
+            include_str!("../assets/maps/episode1/e1m8_plane1_u16.txt"),
+        ),
+        LevelId::E1M9 => (
+            include_str!("../assets/maps/episode1/e1m9_plane0_u16.txt"),
+            include_str!("../assets/maps/episode1/e1m9_plane1_u16.txt"),
+        ),
+        LevelId::E1M10 => (
+            include_str!("../assets/maps/episode1/e1m10_plane0_u16.txt"),
+            include_str!("../assets/maps/episode1/e1m10_plane1_u16.txt"),
+        ),
+
+        // Episode 2
+        LevelId::E2M1 => (
+            include_str!("../assets/maps/episode2/e2m1_plane0_u16.txt"),
+            include_str!("../assets/maps/episode2/e2m1_plane1_u16.txt"),
+        ),
+        LevelId::E2M2 => (
+            include_str!("../assets/maps/episode2/e2m2_plane0_u16.txt"),
+            include_str!("../assets/maps/episode2/e2m2_plane1_u16.txt"),
+        ),
+        LevelId::E2M3 => (
+            include_str!("../assets/maps/episode2/e2m3_plane0_u16.txt"),
+            include_str!("../assets/maps/episode2/e2m3_plane1_u16.txt"),
+        ),
+        LevelId::E2M4 => (
+            include_str!("../assets/maps/episode2/e2m4_plane0_u16.txt"),
+            include_str!("../assets/maps/episode2/e2m4_plane1_u16.txt"),
+        ),
+        LevelId::E2M5 => (
+            include_str!("../assets/maps/episode2/e2m5_plane0_u16.txt"),
+            include_str!("../assets/maps/episode2/e2m5_plane1_u16.txt"),
+        ),
+        LevelId::E2M6 => (
+            include_str!("../assets/maps/episode2/e2m6_plane0_u16.txt"),
+            include_str!("../assets/maps/episode2/e2m6_plane1_u16.txt"),
+        ),
+        LevelId::E2M7 => (
+            include_str!("../assets/maps/episode2/e2m7_plane0_u16.txt"),
+            include_str!("../assets/maps/episode2/e2m7_plane1_u16.txt"),
+        ),
+        LevelId::E2M8 => (
+            include_str!("../assets/maps/episode2/e2m8_plane0_u16.txt"),
+            include_str!("../assets/maps/episode2/e2m8_plane1_u16.txt"),
+        ),
+        LevelId::E2M9 => (
+            include_str!("../assets/maps/episode2/e2m9_plane0_u16.txt"),
+            include_str!("../assets/maps/episode2/e2m9_plane1_u16.txt"),
+        ),
+        LevelId::E2M10 => (
+            include_str!("../assets/maps/episode2/e2m10_plane0_u16.txt"),
+            include_str!("../assets/maps/episode2/e2m10_plane1_u16.txt"),
+        ),
+
+        // Episode 3
+        LevelId::E3M1 => (
+            include_str!("../assets/maps/episode3/e3m1_plane0_u16.txt"),
+            include_str!("../assets/maps/episode3/e3m1_plane1_u16.txt"),
+        ),
+        LevelId::E3M2 => (
+            include_str!("../assets/maps/episode3/e3m2_plane0_u16.txt"),
+            include_str!("../assets/maps/episode3/e3m2_plane1_u16.txt"),
+        ),
+        LevelId::E3M3 => (
+            include_str!("../assets/maps/episode3/e3m3_plane0_u16.txt"),
+            include_str!("../assets/maps/episode3/e3m3_plane1_u16.txt"),
+        ),
+        LevelId::E3M4 => (
+            include_str!("../assets/maps/episode3/e3m4_plane0_u16.txt"),
+            include_str!("../assets/maps/episode3/e3m4_plane1_u16.txt"),
+        ),
+        LevelId::E3M5 => (
+            include_str!("../assets/maps/episode3/e3m5_plane0_u16.txt"),
+            include_str!("../assets/maps/episode3/e3m5_plane1_u16.txt"),
+        ),
+        LevelId::E3M6 => (
+            include_str!("../assets/maps/episode3/e3m6_plane0_u16.txt"),
+            include_str!("../assets/maps/episode3/e3m6_plane1_u16.txt"),
+        ),
+        LevelId::E3M7 => (
+            include_str!("../assets/maps/episode3/e3m7_plane0_u16.txt"),
+            include_str!("../assets/maps/episode3/e3m7_plane1_u16.txt"),
+        ),
+        LevelId::E3M8 => (
+            include_str!("../assets/maps/episode3/e3m8_plane0_u16.txt"),
+            include_str!("../assets/maps/episode3/e3m8_plane1_u16.txt"),
+        ),
+        LevelId::E3M9 => (
+            include_str!("../assets/maps/episode3/e3m9_plane0_u16.txt"),
+            include_str!("../assets/maps/episode3/e3m9_plane1_u16.txt"),
+        ),
+        LevelId::E3M10 => (
+            include_str!("../assets/maps/episode3/e3m10_plane0_u16.txt"),
+            include_str!("../assets/maps/episode3/e3m10_plane1_u16.txt"),
+        ),
+
+        // Episode 4
+        LevelId::E4M1 => (
+            include_str!("../assets/maps/episode4/e4m1_plane0_u16.txt"),
+            include_str!("../assets/maps/episode4/e4m1_plane1_u16.txt"),
+        ),
+        LevelId::E4M2 => (
+            include_str!("../assets/maps/episode4/e4m2_plane0_u16.txt"),
+            include_str!("../assets/maps/episode4/e4m2_plane1_u16.txt"),
+        ),
+        LevelId::E4M3 => (
+            include_str!("../assets/maps/episode4/e4m3_plane0_u16.txt"),
+            include_str!("../assets/maps/episode4/e4m3_plane1_u16.txt"),
+        ),
+        LevelId::E4M4 => (
+            include_str!("../assets/maps/episode4/e4m4_plane0_u16.txt"),
+            include_str!("../assets/maps/episode4/e4m4_plane1_u16.txt"),
+        ),
+        LevelId::E4M5 => (
+            include_str!("../assets/maps/episode4/e4m5_plane0_u16.txt"),
+            include_str!("../assets/maps/episode4/e4m5_plane1_u16.txt"),
+        ),
+        LevelId::E4M6 => (
+            include_str!("../assets/maps/episode4/e4m6_plane0_u16.txt"),
+            include_str!("../assets/maps/episode4/e4m6_plane1_u16.txt"),
+        ),
+        LevelId::E4M7 => (
+            include_str!("../assets/maps/episode4/e4m7_plane0_u16.txt"),
+            include_str!("../assets/maps/episode4/e4m7_plane1_u16.txt"),
+        ),
+        LevelId::E4M8 => (
+            include_str!("../assets/maps/episode4/e4m8_plane0_u16.txt"),
+            include_str!("../assets/maps/episode4/e4m8_plane1_u16.txt"),
+        ),
+        LevelId::E4M9 => (
+            include_str!("../assets/maps/episode4/e4m9_plane0_u16.txt"),
+            include_str!("../assets/maps/episode4/e4m9_plane1_u16.txt"),
+        ),
+        LevelId::E4M10 => (
+            include_str!("../assets/maps/episode4/e4m10_plane0_u16.txt"),
+            include_str!("../assets/maps/episode4/e4m10_plane1_u16.txt"),
+        ),
+
+        // Episode 5
+        LevelId::E5M1 => (
+            include_str!("../assets/maps/episode5/e5m1_plane0_u16.txt"),
+            include_str!("../assets/maps/episode5/e5m1_plane1_u16.txt"),
+        ),
+        LevelId::E5M2 => (
+            include_str!("../assets/maps/episode5/e5m2_plane0_u16.txt"),
+            include_str!("../assets/maps/episode5/e5m2_plane1_u16.txt"),
+        ),
+        LevelId::E5M3 => (
+            include_str!("../assets/maps/episode5/e5m3_plane0_u16.txt"),
+            include_str!("../assets/maps/episode5/e5m3_plane1_u16.txt"),
+        ),
+        LevelId::E5M4 => (
+            include_str!("../assets/maps/episode5/e5m4_plane0_u16.txt"),
+            include_str!("../assets/maps/episode5/e5m4_plane1_u16.txt"),
+        ),
+        LevelId::E5M5 => (
+            include_str!("../assets/maps/episode5/e5m5_plane0_u16.txt"),
+            include_str!("../assets/maps/episode5/e5m5_plane1_u16.txt"),
+        ),
+        LevelId::E5M6 => (
+            include_str!("../assets/maps/episode5/e5m6_plane0_u16.txt"),
+            include_str!("../assets/maps/episode5/e5m6_plane1_u16.txt"),
+        ),
+        LevelId::E5M7 => (
+            include_str!("../assets/maps/episode5/e5m7_plane0_u16.txt"),
+            include_str!("../assets/maps/episode5/e5m7_plane1_u16.txt"),
+        ),
+        LevelId::E5M8 => (
+            include_str!("../assets/maps/episode5/e5m8_plane0_u16.txt"),
+            include_str!("../assets/maps/episode5/e5m8_plane1_u16.txt"),
+        ),
+        LevelId::E5M9 => (
+            include_str!("../assets/maps/episode5/e5m9_plane0_u16.txt"),
+            include_str!("../assets/maps/episode5/e5m9_plane1_u16.txt"),
+        ),
+
+        LevelId::E5M10 => (
+            include_str!("../assets/maps/episode5/e5m10_plane0_u16.txt"),
+            include_str!("../assets/maps/episode5/e5m10_plane1_u16.txt"),
+        ),
+
+        // Episode 6
+        LevelId::E6M1 => (
+            include_str!("../assets/maps/episode6/e6m1_plane0_u16.txt"),
+            include_str!("../assets/maps/episode6/e6m1_plane1_u16.txt"),
+        ),
+        LevelId::E6M2 => (
+            include_str!("../assets/maps/episode6/e6m2_plane0_u16.txt"),
+            include_str!("../assets/maps/episode6/e6m2_plane1_u16.txt"),
+        ),
+        LevelId::E6M3 => (
+            include_str!("../assets/maps/episode6/e6m3_plane0_u16.txt"),
+            include_str!("../assets/maps/episode6/e6m3_plane1_u16.txt"),
+        ),
+        LevelId::E6M4 => (
+            include_str!("../assets/maps/episode6/e6m4_plane0_u16.txt"),
+            include_str!("../assets/maps/episode6/e6m4_plane1_u16.txt"),
+        ),
+        LevelId::E6M5 => (
+            include_str!("../assets/maps/episode6/e6m5_plane0_u16.txt"),
+            include_str!("../assets/maps/episode6/e6m5_plane1_u16.txt"),
+        ),
+        LevelId::E6M6 => (
+            include_str!("../assets/maps/episode6/e6m6_plane0_u16.txt"),
+            include_str!("../assets/maps/episode6/e6m6_plane1_u16.txt"),
+        ),
+        LevelId::E6M7 => (
+            include_str!("../assets/maps/episode6/e6m7_plane0_u16.txt"),
+            include_str!("../assets/maps/episode6/e6m7_plane1_u16.txt"),
+        ),
+        LevelId::E6M8 => (
+            include_str!("../assets/maps/episode6/e6m8_plane0_u16.txt"),
+            include_str!("../assets/maps/episode6/e6m8_plane1_u16.txt"),
+        ),
+        LevelId::E6M9 => (
+            include_str!("../assets/maps/episode6/e6m9_plane0_u16.txt"),
+            include_str!("../assets/maps/episode6/e6m9_plane1_u16.txt"),
+        ),
+        LevelId::E6M10 => (
+            include_str!("../assets/maps/episode6/e6m10_plane0_u16.txt"),
+            include_str!("../assets/maps/episode6/e6m10_plane1_u16.txt"),
+        ),
+    }
+}
+
+/// Parse plane0 / plane1 Text Into Raw Code Grids
+pub fn parse_level_planes(plane0_text: &str, plane1_text: &str) -> (Vec<u16>, Vec<u16>) {
+    (
+        MapGrid::parse_u16_grid(plane0_text, LEVEL_SIZE, LEVEL_SIZE),
+        MapGrid::parse_u16_grid(plane1_text, LEVEL_SIZE, LEVEL_SIZE),
+    )
+}
+
+/// Enemy Spawn Tiles per Kind, Already Filtered to the Selected Skill
+#[derive(Debug, Clone, Default)]
+pub struct EnemySpawns {
+    pub guards: Vec<IVec2>,
+    pub mutants: Vec<IVec2>,
+    pub ss: Vec<IVec2>,
+    pub officers: Vec<IVec2>,
+    pub dogs: Vec<IVec2>,
+    pub hans: Vec<IVec2>,
+    pub gretel: Vec<IVec2>,
+    pub mecha_hitler: Vec<IVec2>,
+    pub ghost_hitler: Vec<IVec2>,
+    pub schabbs: Vec<IVec2>,
+    pub otto: Vec<IVec2>,
+    pub general: Vec<IVec2>,
+}
+
+impl EnemySpawns {
+    /// Intermission Kill Total. Mecha Hitler Counts Twice, Once for the Armor
+    /// and Once for Hitler Himself When He Steps Out
+    // FIXME: Is this right? Should bosses be counted in this way?
+    pub fn kills_total(&self) -> usize {
+        self.guards.len()
+            + self.mutants.len()
+            + self.ss.len()
+            + self.officers.len()
+            + self.dogs.len()
+            + self.hans.len()
+            + self.gretel.len()
+            + self.mecha_hitler.len() * 2
+            + self.ghost_hitler.len()
+            + self.schabbs.len()
+            + self.otto.len()
+            + self.general.len()
+    }
+}
+
+/// Everything a Level Defines, as Plain Data With No ECS Dependency, so Map
+/// Viewers and Validators Can Reuse the Same Loader as world::setup
+#[derive(Debug, Clone)]
+pub struct LevelData {
+    pub grid: MapGrid,
+    pub plane1: Vec<u16>,
+    pub spawn: IVec2,
+    pub spawn_yaw: f32,
+    pub enemies: EnemySpawns,
+    /// Dead Guard Set Dressing (plane1 Code 124)
+    pub dead_guards: Vec<IVec2>,
+    pub pushwalls: PushwallMarkers,
+    /// statinfo Statics the Player Collects, With Their plane1 Code
+    pub pickups: Vec<(IVec2, u16)>,
+    /// statinfo Statics That Only Dress or Block, With Their plane1 Code
+    pub decorations: Vec<(IVec2, u16)>,
+    pub secrets_total: usize,
+    pub treasure_total: usize,
+}
+
+// Wolfenstein 3-D Enemy Codes Repeat in Three Bands of Eight (Base, Medium, Hard)
+// Easier Skills Spawn Only the Lower Bands
+fn in_skill_bands(code: u16, bands: [u16; 3], skill_off: u16) -> bool {
+    let active = match skill_off {
+        36 => 2,
+        72 => 3,
+        _ => 1,
+    };
+    bands[..active].iter().any(|&lo| (lo..=lo + 7).contains(&code))
+}
+
+/// Build the Grid and Extract Spawns, Statics and Totals From Raw Planes
+pub fn load_level_data(plane0: &[u16], plane1: &[u16], skill: SkillLevel) -> LevelData {
+    let (grid, spawn, guards, mutants, ss, officers, dogs, hans, gretel, mecha_hitler, ghost_hitler, schabbs, otto, general) =
+        MapGrid::from_wolf_planes(LEVEL_SIZE, LEVEL_SIZE, plane0, plane1);
+    // A Map Without a Player Start Still Loads, but Would Drop the Player Into
+    // the Corner Wall, so Say so Rather Than Leave it a Mystery
+    if spawn.is_none() {
+        warn!("Level has no player start (plane1 19..=22), spawning at (1, 1)");
+    }
+    let (spawn, spawn_yaw) = spawn.unwrap_or((IVec2::new(1, 1), 0.0));
+
+    let code_at = |t: IVec2| plane1[t.y as usize * LEVEL_SIZE + t.x as usize];
+    let skill_off = skill.spawn_offset();
+    let banded = |tiles: Vec<IVec2>, bands: [u16; 3]| -> Vec<IVec2> {
+        tiles
+            .into_iter()
+            .filter(|&t| in_skill_bands(code_at(t), bands, skill_off))
+            .collect()
+    };
+
+    // Can I Play Daddy Leaves Every Other Guard Out, Deterministic in Map Order
+    let guards = banded(guards, [108, 144, 180])
+        .into_iter()
+        .step_by(skill.guard_stride())
+        .collect();
+
+    // Bosses Are Not Difficulty-Banded, Spawn Always If Present
+    let enemies = EnemySpawns {
+        guards,
+        mutants: banded(mutants, [216, 234, 252]),
+        ss: banded(ss, [126, 162, 198]),
+        officers: banded(officers, [116, 152, 188]),
+        dogs: banded(dogs, [134, 170, 206]),
+        hans,
+        gretel,
+        mecha_hitler,
+        ghost_hitler,
+        schabbs,
+        otto,
+        general,
+    };
+
+    let tile_of = |i: usize| IVec2::new((i % LEVEL_SIZE) as i32, (i / LEVEL_SIZE) as i32);
+
+    let mut dead_guards = Vec::new();
+    let mut pickups = Vec::new();
+    let mut decorations = Vec::new();
+    for (i, &code) in plane1.iter().enumerate() {
+        if code == 124 {
+            dead_guards.push(tile_of(i));
+            continue;
+        }
+        match crate::decorations::stat_kind_for_plane1(code) {
+            Some(StatKind::Pickup) => pickups.push((tile_of(i), code)),
+            Some(_) => decorations.push((tile_of(i), code)),
+            None => {}
+        }
+    }
+
+    LevelData {
+        grid,
+        plane1: plane1.to_vec(),
+        spawn,
+        spawn_yaw,
+        enemies,
+        dead_guards,
+        pushwalls: PushwallMarkers::from_wolf_plane1(LEVEL_SIZE, LEVEL_SIZE, plane1),
+        pickups,
+        decorations,
+        secrets_total: plane1.iter().filter(|&&c| c == 98).count(),
+        treasure_total: plane1.iter().filter(|&&c| matches!(c, 52..=55)).count(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn next_level_walks_each_episode_then_ends_in_victory() {
+        for ep in 1..=6u8 {
+            let route = levels_in_episode(ep);
+            let mut level = LevelId::first_level_of_episode(ep);
+            let mut visited = vec![level];
+
+            while let Some(next) = next_level(level) {
+                assert_eq!(next.episode(), ep);
+                visited.push(next);
+                level = next;
+                assert!(visited.len() <= route.len(), "episode {ep} never reached its boss floor");
+            }
+
+            assert_eq!(visited.as_slice(), route.as_slice());
+            assert_eq!(level.floor_number(), 9);
+        }
+    }
+
+    #[test]
+    fn secret_floor_rejoins_main_route() {
+        // Each Secret Floor Drops the Player on the Floor After the Secret Exit
+        let returns = [
+            (LevelId::E1M10, LevelId::E1M2),
+            (LevelId::E2M10, LevelId::E2M2),
+            (LevelId::E3M10, LevelId::E3M8),
+            (LevelId::E4M10, LevelId::E4M4),
+            (LevelId::E5M10, LevelId::E5M6),
+            (LevelId::E6M10, LevelId::E6M4),
+        ];
+
+        for (ep, (secret, back)) in (1..=6u8).zip(returns) {
+            assert_eq!(LevelId::secret_level_of_episode(ep), Some(secret));
+            assert_eq!(next_level(secret), Some(back), "{secret:?}");
+            assert_eq!(resolve_level_exit(ExitTrigger::Elevator, secret), ExitOutcome::NextLevel(back));
+        }
+    }
+
+    #[test]
+    fn every_episode_names_its_secret_floor() {
+        for ep in 1..=6u8 {
+            let secret = LevelId::secret_level_of_episode(ep).expect("six episodes, six secret floors");
+            assert_eq!(secret.episode(), ep);
+            assert!(secret.is_secret_floor());
+        }
+        assert_eq!(LevelId::secret_level_of_episode(7), None);
+        assert!(!LevelId::E1M9.is_secret_floor());
+    }
+
+    #[test]
+    fn level_exit_outcomes_for_each_episode() {
+        let secret_exits = [
+            (LevelId::E1M1, LevelId::E1M10),
+            (LevelId::E2M1, LevelId::E2M10),
+            (LevelId::E3M7, LevelId::E3M10),
+            (LevelId::E4M3, LevelId::E4M10),
+            (LevelId::E5M5, LevelId::E5M10),
+            (LevelId::E6M3, LevelId::E6M10),
+        ];
+
+        for (ep, (secret_from, secret_to)) in (1..=6u8).zip(secret_exits) {
+            let route = levels_in_episode(ep);
+
+            // Normal Exit Walks the Main Route
+            assert_eq!(
+                resolve_level_exit(ExitTrigger::Elevator, route[0]),
+                ExitOutcome::NextLevel(route[1]),
+            );
+
+            // Secret Exit Reaches the Secret Floor
+            assert_eq!(
+                resolve_level_exit(ExitTrigger::SecretElevator, secret_from),
+                ExitOutcome::SecretLevel(secret_to),
+            );
+
+            // A Secret Switch Elsewhere Behaves Like a Normal Exit
+            assert_eq!(
+                resolve_level_exit(ExitTrigger::SecretElevator, route[7]),
+                ExitOutcome::NextLevel(route[8]),
+            );
+
+            // A Boss Kill Before the Last Floor Does Not End the Episode
+            assert_eq!(resolve_level_exit(ExitTrigger::BossKilled, route[7]), ExitOutcome::NextLevel(route[8]));
+
+            // Last Floor: Boss Kill or Its Elevator Ends the Episode
+            assert_eq!(resolve_level_exit(ExitTrigger::BossKilled, route[8]), ExitOutcome::EpisodeVictory);
+            assert_eq!(resolve_level_exit(ExitTrigger::Elevator, route[8]), ExitOutcome::EpisodeVictory);
+        }
+    }
+
+    #[test]
+    fn every_level_loads_with_the_player_on_open_floor() {
+        for ep in 1..=6u8 {
+            let route = levels_in_episode(ep);
+            let secret = route.iter().map(|l| next_secret(*l)).find(|l| l.floor_number() == 10).unwrap();
+
+            for level in route.into_iter().chain([secret]) {
+                let (t0, t1) = level_plane_text(level);
+                let (plane0, plane1) = parse_level_planes(t0, t1);
+                let data = load_level_data(&plane0, &plane1, SkillLevel(2));
+
+                assert_ne!(data.spawn, IVec2::new(1, 1), "{level:?} has no player start");
+                assert_eq!(
+                    data.grid.tile(data.spawn.x as usize, data.spawn.y as usize),
+                    crate::map::Tile::Empty,
+                    "{level:?} starts the player inside a wall or door",
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn floor_is_always_gray_and_ceiling_follows_the_level() {
+        let gray = |v: u8| Color::srgb(v as f32 / 255.0, v as f32 / 255.0, v as f32 / 255.0);
+
+        assert_eq!(LevelId::E1M1.floor_color(), gray(112));
+        assert_eq!(LevelId::E4M10.floor_color(), gray(112));
+        assert_eq!(LevelId::E1M1.ceiling_color(), gray(56));
+
+        // E1M10's Ceiling Is Purple, E2M1 Brown
+        assert_eq!(LevelId::E1M10.ceiling_color(), Color::srgb(64.0 / 255.0, 0.0, 64.0 / 255.0));
+        assert_eq!(LevelId::E2M1.ceiling_color(), Color::srgb(88.0 / 255.0, 84.0 / 255.0, 0.0));
+    }
+
+    #[test]
+    fn library_loader_extracts_e1m1_without_ecs() {
+        let (t0, t1) = level_plane_text(LevelId::E1M1);
+        let (plane0, plane1) = parse_level_planes(t0, t1);
+
+        let easy = load_level_data(&plane0, &plane1, SkillLevel(1));
+        assert_eq!((easy.grid.width, easy.grid.height), (LEVEL_SIZE, LEVEL_SIZE));
+        assert_eq!(easy.spawn, IVec2::new(29, 57));
+        assert_eq!(easy.enemies.guards.len(), 10);
+        assert_eq!(easy.enemies.dogs.len(), 1);
+        assert_eq!(easy.enemies.kills_total(), 11);
+        assert_eq!(easy.dead_guards.len(), 1);
+        assert_eq!(easy.pickups.len(), 48);
+        assert_eq!(easy.decorations.len(), 73);
+        assert_eq!((easy.secrets_total, easy.treasure_total), (5, 22));
+
+        // Can I Play Daddy Keeps Every Other Base Guard, Nothing Else Changes
+        let daddy = load_level_data(&plane0, &plane1, SkillLevel(0));
+        assert_eq!(daddy.enemies.guards.len(), 5);
+        assert!(daddy.enemies.guards.iter().all(|g| easy.enemies.guards.contains(g)));
+        assert_eq!(daddy.enemies.dogs.len(), 1);
+        assert_eq!(daddy.enemies.kills_total(), 6);
+
+        // Hard Adds the Medium and Hard Bands on Top of the Base Spawns
+        let hard = load_level_data(&plane0, &plane1, SkillLevel(3));
+        assert_eq!(hard.enemies.guards.len(), 32);
+        assert_eq!(hard.enemies.dogs.len(), 5);
+    }
+}
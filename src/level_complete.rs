@@ -9,31 +9,32 @@ use davelib::map::{MapGrid, Tile};
 use davelib::player::{Player, PlayerControlLock};
 use davelib::world::RebuildWalls;
 use davelib::input::PlayerIntent;
+use davelib::level::{ExitOutcome, ExitTrigger, resolve_level_exit};
 
 /// Wall IDs for the Elevator Switch Textures 
 // (Wolfenstein Wall IDs, NOT Atlas Chunk Indices)
 const ELEV_SWITCH_DOWN_WALL_ID: u16 = 21;
 const ELEV_SWITCH_UP_WALL_ID: u16 = 22;
 
-/// Latched "Win" State, Driven by Elevator Switch
+/// Latched "Win" State, Set Once the Elevator Switch Exit Delay Runs Out
+/// This is the Between-Levels Intermission: While Set, the Mission Success
+/// Overlay Tallies the Finished Floor's Kill / Secret / Treasure Ratios, Time,
+/// Par and Bonus, or Only the Flat Bonus on a Secret Floor. Input While the
+/// Tally Runs Skips to Its End; Input After That Loads the Next Floor Behind
+/// the GET PSYCHED Screen (Shortened or Hidden per the Psyched Screen Option).
+/// Leaving the Last Floor of the Episode's Route Goes to EpisodeVictory Instead
 #[derive(Resource, Debug, Clone, Default)]
 pub struct LevelComplete(pub bool);
 
 /// Plane0 code under the player that marks a secret elevator exit
 const ALT_ELEVATOR_FLOOR_CODE: u16 = 107;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum LevelExitKind {
-    Normal,
-    Secret,
-}
-
 #[derive(Resource, Debug, Clone, Copy)]
-pub(crate) struct PendingLevelExit(pub LevelExitKind);
+pub(crate) struct PendingLevelExit(pub ExitTrigger);
 
 impl Default for PendingLevelExit {
     fn default() -> Self {
-        Self(LevelExitKind::Normal)
+        Self(ExitTrigger::Elevator)
     }
 }
 
@@ -41,6 +42,17 @@ impl Default for PendingLevelExit {
 #[derive(Component)]
 pub struct MissionSuccessOverlay;
 
+/// Intermission Contents for an Ordinary Floor: Time, Par and Ratios
+#[derive(Component)]
+pub struct MissionNormalPage;
+
+/// Intermission Contents for a Secret Floor: a Flat SECRET_FLOOR_BONUS
+#[derive(Component)]
+pub struct MissionSecretPage;
+
+/// Original LevelCompleted Awards This in Place of the Usual Tally
+const SECRET_FLOOR_BONUS: i32 = 15_000;
+
 #[derive(Component, Clone, Copy)]
 pub enum MissionStatKind {
     Title,
@@ -84,6 +96,8 @@ pub struct MissionSuccessTally {
     pub shown_bonus: i32,
     pub target_bonus: i32,
     pub bonus_applied: bool,
+    // Kill, Secret and Treasure Categories That Earn the 100% Bonus
+    pub perfect: [bool; 3],
 
     pub tick: Timer,
 }
@@ -117,9 +131,10 @@ impl Default for MissionSuccessTally {
             shown_bonus: 0,
             target_bonus: 0,
             bonus_applied: false,
+            perfect: [false; 3],
 
             // Count Rate Matches Original Wolf3D WL_INTER.C at One Percent Per 70Hz Step
-            tick: Timer::from_seconds(1.0 / 70.0, TimerMode::Repeating),
+            tick: Timer::from_seconds(davelib::tics::tics(1), TimerMode::Repeating),
         }
     }
 }
@@ -173,8 +188,49 @@ pub fn tick_elevator_exit_delay(
     }
 }
 
+/// Unflipped Elevator Switch the Player Is Facing, and Which Exit it Takes.
+/// The Switch Is the Wall One Tile Ahead in the Dominant Facing Direction (Same
+/// 4 Way Rule as Doors); Standing on plane0 Code 107 Makes it the Secret Exit
+pub fn elevator_switch_ahead(grid: &MapGrid, player_tile: IVec2, forward: Vec3) -> Option<(IVec2, ExitTrigger)> {
+    let in_bounds = |t: IVec2| t.x >= 0 && t.y >= 0 && t.x < grid.width as i32 && t.y < grid.height as i32;
+    if !in_bounds(player_tile) {
+        return None;
+    }
+
+    let fwd = Vec2::new(forward.x, forward.z);
+    if fwd.length_squared() < 1e-6 {
+        return None;
+    }
+    let step = if fwd.x.abs() > fwd.y.abs() {
+        IVec2::new(fwd.x.signum() as i32, 0)
+    } else {
+        IVec2::new(0, fwd.y.signum() as i32)
+    };
+
+    let target = player_tile + step;
+    if !in_bounds(target) {
+        return None;
+    }
+    let (tx, tz) = (target.x as usize, target.y as usize);
+
+    // Only Down Switch Can be Activated
+    if grid.tile(tx, tz) != Tile::Wall || grid.plane0_code(tx, tz) != ELEV_SWITCH_DOWN_WALL_ID {
+        return None;
+    }
+
+    let under_player = grid.plane0_code(player_tile.x as usize, player_tile.y as usize);
+    let trigger = if under_player == ALT_ELEVATOR_FLOOR_CODE {
+        ExitTrigger::SecretElevator
+    } else {
+        ExitTrigger::Elevator
+    };
+
+    Some((target, trigger))
+}
+
 pub fn use_elevator_exit(
     intent: Res<PlayerIntent>,
+    suppress: Res<davelib::input::GameplayInputSuppress>,
     mut lock: ResMut<PlayerControlLock>,
     win: ResMut<LevelComplete>,
     mut grid: ResMut<MapGrid>,
@@ -186,8 +242,8 @@ pub fn use_elevator_exit(
     mut pending_exit: ResMut<PendingLevelExit>,
 ) {
     // If Gameplay Already Locked, or Win Screen Already up, or
-    // We're Already Delaying, Do Nothing
-    if lock.0 || win.0 || exit_delay.active {
+    // We're Already Delaying, or a Menu Just Handed Control Back, Do Nothing
+    if lock.0 || suppress.0 || win.0 || exit_delay.active {
         return;
     }
     // Elevator Exit Now Reads the Device-Neutral use_pressed Edge so Gamepad
@@ -198,64 +254,19 @@ pub fn use_elevator_exit(
 
     let Some(player_tf) = q_player.iter().next() else { return; };
 
-    fn world_to_tile(p: Vec2) -> IVec2 {
-        IVec2::new((p.x + 0.5).floor() as i32, (p.y + 0.5).floor() as i32)
-    }
-
-    let player_tile = world_to_tile(Vec2::new(player_tf.translation.x, player_tf.translation.z));
-    if player_tile.x < 0
-        || player_tile.y < 0
-        || player_tile.x >= grid.width as i32
-        || player_tile.y >= grid.height as i32
-    {
-        return;
-    }
-
-    // 4 Way Facing (Same as Doors)
-    let mut fwd = player_tf.rotation * Vec3::NEG_Z;
-    fwd.y = 0.0;
-    if fwd.length_squared() < 1e-6 {
+    let player_tile = IVec2::new(
+        (player_tf.translation.x + 0.5).floor() as i32,
+        (player_tf.translation.z + 0.5).floor() as i32,
+    );
+    let Some((target, trigger)) = elevator_switch_ahead(&grid, player_tile, player_tf.rotation * Vec3::NEG_Z) else {
         return;
-    }
-    let fwd = fwd.normalize();
-
-    let (dx, dz) = if fwd.x.abs() > fwd.z.abs() {
-        (fwd.x.signum() as i32, 0)
-    } else {
-        (0, fwd.z.signum() as i32)
     };
 
-    let target = IVec2::new(player_tile.x + dx, player_tile.y + dz);
-    if target.x < 0
-        || target.y < 0
-        || target.x >= grid.width as i32
-        || target.y >= grid.height as i32
-    {
-        return;
-    }
-
-    let (tx, tz) = (target.x as usize, target.y as usize);
-    if grid.tile(tx, tz) != Tile::Wall {
-        return;
-    }
-
-    // Only Down Switch Can be Activated
-    let wall_id = grid.plane0_code(tx, tz);
-    if wall_id != ELEV_SWITCH_DOWN_WALL_ID {
-        return;
-    }
-
-    // Latch Whether This is Secret Exit Based on Tile Under Player
-    let (px, pz) = (player_tile.x as usize, player_tile.y as usize);
-    let under_player = grid.plane0_code(px, pz);
-    pending_exit.0 = if under_player == ALT_ELEVATOR_FLOOR_CODE {
-        LevelExitKind::Secret
-    } else {
-        LevelExitKind::Normal
-    };
+    // Latch Whether This is the Secret Exit for the Intermission to Resolve
+    pending_exit.0 = trigger;
 
     // Flip Switch Texture
-    grid.set_plane0_code(tx, tz, ELEV_SWITCH_UP_WALL_ID);
+    grid.set_plane0_code(target.x as usize, target.y as usize, ELEV_SWITCH_UP_WALL_ID);
 
     // Rebuild Wall Faces so Flipped Wall ID is Visible
     rebuild.write(RebuildWalls { skip: None });
@@ -279,57 +290,47 @@ pub fn use_elevator_exit(
     // NOTE: DO NOT set win.0 Here Anymore
 }
 
-fn par_seconds_ep1(floor: i32) -> Option<u32> {
-    // Wolf3D Episode 1 PAR Times (Floors 1..=8)
-    // Boss / Secret Floors Have No Par
-    // 1:30, 2:00, 2:00, 3:30, 3:00, 3:00, 2:30, 2:30  (E1)
-    match floor {
-        1 => Some(1 * 60 + 30),
-        2 => Some(2 * 60 + 0),
-        3 => Some(2 * 60 + 0),
-        4 => Some(3 * 60 + 30),
-        5 => Some(3 * 60 + 0),
-        6 => Some(3 * 60 + 0),
-        7 => Some(2 * 60 + 30),
-        8 => Some(2 * 60 + 30),
-        _ => None, // 9/10 (Boss / Secret) => no PAR
-    }
+/// The Floor Clock Runs From the Moment Get Psyched Clears and Hands Control
+/// Back, Until the Elevator Locks Control and LevelComplete Latches. Pausing
+/// Locks Control Too, so Time in Menus Never Counts Against Par
+pub fn level_clock_running(lock: Res<PlayerControlLock>, win: Res<LevelComplete>) -> bool {
+    !lock.0 && !win.0
 }
 
 fn mm_ss_from_seconds(total: u32) -> (u32, u32) {
     (total / 60, total % 60)
 }
 
-const PAR_AMOUNT: i32 = 500;
 const PERCENT100AMT: i32 = 10_000;
 
-fn compute_target_bonus(score: &davelib::level_score::LevelScore, floor: i32) -> i32 {
-    let time_secs = score.time_secs.max(0.0).floor() as i32;
+/// Kill, Secret and Treasure Categories Worth PERCENT100AMT: Exactly 100% of
+/// Something the Floor Has. A Category the Floor Has None of Shows 100% but
+/// Earns Nothing, Like the Original
+fn perfect_categories(score: &davelib::level_score::LevelScore) -> [bool; 3] {
+    [
+        score.kills_total > 0 && score.kills_pct() == 100,
+        score.secrets_total > 0 && score.secrets_pct() == 100,
+        score.treasure_total > 0 && score.treasure_pct() == 100,
+    ]
+}
 
+fn compute_target_bonus(score: &davelib::level_score::LevelScore, level: davelib::level::LevelId) -> i32 {
     // Time Under Par Bonus
-    let mut bonus = 0;
-    if let Some(par) = par_seconds_ep1(floor) {
-        let under = (par as i32 - time_secs).max(0);
-        bonus += under * PAR_AMOUNT;
-    }
+    let mut bonus = davelib::level_score::LevelSummary::new(level, score).time_bonus();
 
-    // +10,000 for Each Category Exactly 100%
-    if score.kills_pct() == 100 {
-        bonus += PERCENT100AMT;
-    }
-    if score.secrets_pct() == 100 {
-        bonus += PERCENT100AMT;
-    }
-    if score.treasure_pct() == 100 {
-        bonus += PERCENT100AMT;
-    }
+    // +10,000 for Each Perfect Category
+    let perfect = perfect_categories(score).iter().filter(|&&p| p).count() as i32;
+    bonus += perfect * PERCENT100AMT;
 
     bonus.max(0)
 }
 
 pub fn sync_mission_success_overlay_visibility(
     win: Res<LevelComplete>,
+    current_level: Res<davelib::level::CurrentLevel>,
     mut q: Query<&mut Visibility, With<MissionSuccessOverlay>>,
+    mut q_normal: Query<&mut Visibility, (With<MissionNormalPage>, Without<MissionSuccessOverlay>, Without<MissionSecretPage>)>,
+    mut q_secret: Query<&mut Visibility, (With<MissionSecretPage>, Without<MissionSuccessOverlay>, Without<MissionNormalPage>)>,
 ) {
     let Some(mut vis) = q.iter_mut().next() else { return; };
 
@@ -338,6 +339,15 @@ pub fn sync_mission_success_overlay_visibility(
     } else {
         Visibility::Hidden
     };
+
+    let secret = current_level.0.is_secret_floor();
+    let page = |shown: bool| if shown { Visibility::Inherited } else { Visibility::Hidden };
+    for mut v in q_normal.iter_mut() {
+        v.set_if_neq(page(!secret));
+    }
+    for mut v in q_secret.iter_mut() {
+        v.set_if_neq(page(secret));
+    }
 }
 
 pub fn sync_mission_success_stats_text(
@@ -404,7 +414,7 @@ pub fn sync_mission_success_stats_text(
                 score.secrets_pct(),
                 score.treasure_pct(),
                 score.time_mm_ss(),
-                compute_target_bonus(&score, floor),
+                compute_target_bonus(&score, current_level.0),
             )
         }
     } else {
@@ -413,7 +423,7 @@ pub fn sync_mission_success_stats_text(
             score.secrets_pct(),
             score.treasure_pct(),
             score.time_mm_ss(),
-            compute_target_bonus(&score, floor),
+            compute_target_bonus(&score, current_level.0),
         )
     };
 
@@ -424,7 +434,7 @@ pub fn sync_mission_success_stats_text(
             MissionStatKind::KillRatio => format!("{kill_pct}%"),
             MissionStatKind::SecretRatio => format!("{secret_pct}%"),
             MissionStatKind::TreasureRatio => format!("{treasure_pct}%"),
-            MissionStatKind::Par => match par_seconds_ep1(floor) {
+            MissionStatKind::Par => match davelib::level_score::par_secs(current_level.0) {
                 Some(par_sec) => {
                     let (pm, ps) = mm_ss_from_seconds(par_sec);
                     format!("{}:{:02}", pm, ps)
@@ -460,17 +470,26 @@ pub fn sync_mission_success_stats_text(
     }
 }
 
+/// Resources the Intermission Touches When the Episode's Final Exit is Taken
+#[derive(bevy::ecs::system::SystemParam)]
+pub struct EpisodeVictoryParams<'w> {
+    win: ResMut<'w, LevelComplete>,
+    step: ResMut<'w, crate::ui::SplashStep>,
+    lock: ResMut<'w, PlayerControlLock>,
+    name_entry: ResMut<'w, davelib::high_score::NameEntryState>,
+}
+
 pub fn mission_success_input(
     keys: Res<ButtonInput<KeyCode>>,
     intent: Res<PlayerIntent>,
-    win: Res<LevelComplete>,
     mut tally: ResMut<MissionSuccessTally>,
     mut advance: ResMut<crate::ui::sync::AdvanceLevelRequested>,
     mut current_level: ResMut<davelib::level::CurrentLevel>,
     mut music_mode: ResMut<davelib::audio::MusicMode>,
     mut pending_exit: ResMut<PendingLevelExit>,
+    mut victory: EpisodeVictoryParams,
 ) {
-    if !win.0 || advance.0 {
+    if !victory.win.0 || advance.0 {
         return;
     }
 
@@ -502,12 +521,23 @@ pub fn mission_success_input(
     }
 
     let from = current_level.0;
-    let to = match pending_exit.0 {
-        LevelExitKind::Secret => davelib::level::next_secret(from),
-        LevelExitKind::Normal => from.next_normal(),
+    let outcome = resolve_level_exit(pending_exit.0, from);
+
+    pending_exit.0 = ExitTrigger::Elevator;
+
+    // Leaving the Last Floor of the Route Ends the Episode. Hand Off to the Same
+    // Victory Screen the Boss Death Cam and BJ Cutscene Finish Into, Which Tallies
+    // the Episode's Recorded Per-Level Stats
+    let (ExitOutcome::NextLevel(to) | ExitOutcome::SecretLevel(to)) = outcome else {
+        victory.win.0 = false;
+        *victory.step = crate::ui::SplashStep::EpisodeVictory;
+        victory.lock.0 = true;
+        music_mode.0 = davelib::audio::MusicModeKind::Scores;
+
+        victory.name_entry.reset_for_episode(from.episode());
+        return;
     };
 
-    pending_exit.0 = LevelExitKind::Normal;
     music_mode.0 = davelib::audio::MusicModeKind::Gameplay;
     current_level.0 = to;
     advance.0 = true;
@@ -588,17 +618,21 @@ pub fn start_mission_success_tally_on_win(
 
     tally.pause_steps = 0;
 
-    let floor = current_level.0.floor_number();
-    tally.target_bonus = compute_target_bonus(&score, floor);
+    tally.target_bonus = compute_target_bonus(&score, current_level.0);
+    tally.perfect = perfect_categories(&score);
 
-    let mut base_bonus = tally.target_bonus;
-    if tally.target_kill == 100 {
-        base_bonus = base_bonus.saturating_sub(PERCENT100AMT);
-    }
-    if tally.target_secret == 100 {
-        base_bonus = base_bonus.saturating_sub(PERCENT100AMT);
+    // Nothing to Count Up on a Secret Floor, Just the Flat Bonus
+    if current_level.0.is_secret_floor() {
+        tally.phase = MissionSuccessPhase::Done;
+        tally.target_bonus = SECRET_FLOOR_BONUS;
+        tally.shown_bonus = SECRET_FLOOR_BONUS;
+        tally.tick.reset();
+        return;
     }
-    if tally.target_treasure == 100 {
+
+    // Each Perfect Category Adds its Chunk as its Stinger Plays
+    let mut base_bonus = tally.target_bonus;
+    for _ in tally.perfect.iter().filter(|&&p| p) {
         base_bonus = base_bonus.saturating_sub(PERCENT100AMT);
     }
     tally.shown_bonus = base_bonus.max(0);
@@ -607,7 +641,7 @@ pub fn start_mission_success_tally_on_win(
 }
 
 pub fn tick_mission_success_tally(
-    time: Res<Time>,
+    time: Res<Time<Real>>,
     win: Res<LevelComplete>,
     mut tally: ResMut<MissionSuccessTally>,
     mut sfx: MessageWriter<PlaySfx>,
@@ -707,6 +741,7 @@ pub fn tick_mission_success_tally(
     }
 
     let schedule_end = |ratio: i32,
+                        perfect: bool,
                         next: MissionSuccessPhase,
                         pause_steps: &mut i32,
                         pending_sound: &mut Option<SfxKind>,
@@ -718,8 +753,9 @@ pub fn tick_mission_success_tally(
         // Reset Any Previously Queued Bonus Delta
         *pending_bonus = 0;
 
-        // Choose Completion Sound Based on Final Ratio
-        let (sound, pre_steps, post_steps) = if ratio == 100 {
+        // Choose Completion Sound Based on Final Ratio. An Empty Category
+        // Reads 100% but Is not Perfect, so it Just Confirms
+        let (sound, pre_steps, post_steps) = if perfect {
             // Queue +10,000 to be Applied Right After the 100% Stinger Plays
             *pending_bonus = PERCENT100AMT;
             (SfxKind::IntermissionPercent100, pre_stinger_steps, post_percent100_steps)
@@ -802,6 +838,7 @@ pub fn tick_mission_success_tally(
                 } else {
                     schedule_end(
                         tally.target_kill,
+                        tally.perfect[0],
                         MissionSuccessPhase::Secret,
                         pause_steps,
                         pending_sound,
@@ -825,6 +862,7 @@ pub fn tick_mission_success_tally(
                 } else {
                     schedule_end(
                         tally.target_secret,
+                        tally.perfect[1],
                         MissionSuccessPhase::Treasure,
                         pause_steps,
                         pending_sound,
@@ -852,6 +890,7 @@ pub fn tick_mission_success_tally(
                 } else {
                     schedule_end(
                         tally.target_treasure,
+                        tally.perfect[2],
                         MissionSuccessPhase::Done,
                         pause_steps,
                         pending_sound,
@@ -871,3 +910,164 @@ pub fn tick_mission_success_tally(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn elevator() -> MapGrid {
+        let (mut grid, _, _) = MapGrid::from_ascii(&[
+            "#####",
+            "#...#",
+            "#####",
+        ]);
+        grid.set_plane0_code(4, 1, ELEV_SWITCH_DOWN_WALL_ID);
+        grid
+    }
+
+    #[test]
+    fn under_par_bonus_reaches_the_mission_tally() {
+        use davelib::level::LevelId;
+
+        // E5M2 Par Is 1:30, 60s Is 30s Under: Three Full 10s Steps
+        let quick = davelib::level_score::LevelScore { time_secs: 60.0, ..default() };
+        let slow = davelib::level_score::LevelScore { time_secs: 600.0, ..default() };
+        let base = compute_target_bonus(&slow, LevelId::E5M2);
+        assert_eq!(compute_target_bonus(&quick, LevelId::E5M2) - base, 300);
+        assert_eq!(compute_target_bonus(&slow, LevelId::E5M9), base);
+    }
+
+    #[test]
+    fn level_clock_runs_from_get_psyched_until_level_complete() {
+        use std::time::Duration;
+
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<davelib::level_score::LevelScore>()
+            .insert_resource(PlayerControlLock(true))
+            .init_resource::<LevelComplete>()
+            .add_systems(Update, davelib::level_score::tick_level_time.run_if(level_clock_running));
+
+        let step = |app: &mut App| {
+            app.world_mut().resource_mut::<Time>().advance_by(Duration::from_secs(1));
+            app.update();
+            app.world().resource::<davelib::level_score::LevelScore>().time_secs
+        };
+
+        // Get Psyched Holds the Lock, Nothing Counts Yet
+        assert_eq!(step(&mut app), 0.0);
+
+        // Control Handed Back: the Clock Starts
+        app.world_mut().resource_mut::<PlayerControlLock>().0 = false;
+        assert_eq!(step(&mut app), 1.0);
+        assert_eq!(step(&mut app), 2.0);
+
+        // LevelComplete Stops it, Even Before the Lock Catches up
+        app.world_mut().resource_mut::<LevelComplete>().0 = true;
+        assert_eq!(step(&mut app), 2.0);
+    }
+
+    #[test]
+    fn facing_the_down_switch_picks_normal_or_secret_exit() {
+        let mut grid = elevator();
+        let at = IVec2::new(3, 1);
+
+        assert_eq!(elevator_switch_ahead(&grid, at, Vec3::X), Some((IVec2::new(4, 1), ExitTrigger::Elevator)));
+        assert_eq!(elevator_switch_ahead(&grid, at, Vec3::NEG_X), None);
+        assert_eq!(elevator_switch_ahead(&grid, at, Vec3::Z), None);
+
+        grid.set_plane0_code(3, 1, ALT_ELEVATOR_FLOOR_CODE);
+        assert_eq!(elevator_switch_ahead(&grid, at, Vec3::X), Some((IVec2::new(4, 1), ExitTrigger::SecretElevator)));
+
+        // A Flipped Switch Cannot be Used Twice
+        grid.set_plane0_code(4, 1, ELEV_SWITCH_UP_WALL_ID);
+        assert_eq!(elevator_switch_ahead(&grid, at, Vec3::X), None);
+    }
+
+    #[derive(Resource, Default)]
+    struct Stingers(usize);
+
+    fn count_stingers(mut sfx: MessageReader<PlaySfx>, mut stingers: ResMut<Stingers>) {
+        stingers.0 += sfx.read().filter(|m| m.kind == SfxKind::IntermissionPercent100).count();
+    }
+
+    /// Runs a Win on `level` Through the Tally: the Tally Right After it Starts,
+    /// the Finished Tally and How Many 100% Stingers Played
+    fn run_tally(
+        level: davelib::level::LevelId,
+        score: davelib::level_score::LevelScore,
+    ) -> (MissionSuccessTally, MissionSuccessTally, usize) {
+        let mut app = App::new();
+        app.add_message::<PlaySfx>()
+            .insert_resource(LevelComplete(true))
+            .insert_resource(score)
+            .insert_resource(davelib::level::CurrentLevel(level))
+            .init_resource::<MissionSuccessTally>()
+            .init_resource::<davelib::level_score::EpisodeStats>()
+            .init_resource::<Time<Real>>()
+            .init_resource::<Stingers>()
+            .add_systems(
+                Update,
+                (start_mission_success_tally_on_win, tick_mission_success_tally, count_stingers).chain(),
+            );
+
+        app.update();
+        let started = app.world().resource::<MissionSuccessTally>().clone();
+
+        for _ in 0..2000 {
+            app.world_mut()
+                .resource_mut::<Time<Real>>()
+                .update_with_duration(std::time::Duration::from_millis(50));
+            app.update();
+            if !app.world().resource::<MissionSuccessTally>().active {
+                break;
+            }
+        }
+
+        let done = app.world().resource::<MissionSuccessTally>().clone();
+        (started, done, app.world().resource::<Stingers>().0)
+    }
+
+    #[test]
+    fn empty_categories_read_100_percent_without_a_perfect_bonus() {
+        // Every Guard Killed, but no Secrets or Treasure on the Floor and Well
+        // Over Par, so the Only Bonus Is the One Perfect Category
+        let score = davelib::level_score::LevelScore {
+            kills_found: 4,
+            kills_total: 4,
+            time_secs: 600.0,
+            ..default()
+        };
+        let (started, done, stingers) = run_tally(davelib::level::LevelId::E1M1, score);
+
+        assert_eq!(started.target_bonus, PERCENT100AMT);
+        assert_eq!(started.shown_bonus, 0);
+        assert_eq!((done.target_secret, done.target_treasure), (100, 100));
+        assert_eq!(done.phase, MissionSuccessPhase::Done);
+        assert_eq!(done.shown_bonus, PERCENT100AMT);
+        assert_eq!(stingers, 1);
+    }
+
+    #[test]
+    fn secret_floor_awards_only_the_flat_bonus() {
+        // A Perfect Run Still Gets No Ratio Count-Up or 100% Stingers
+        let score = davelib::level_score::LevelScore {
+            kills_found: 4,
+            kills_total: 4,
+            secrets_found: 1,
+            secrets_total: 1,
+            treasure_found: 2,
+            treasure_total: 2,
+            time_secs: 10.0,
+            ..default()
+        };
+        let level = davelib::level::LevelId::secret_level_of_episode(1).unwrap();
+        let (started, done, stingers) = run_tally(level, score);
+
+        assert_eq!(started.phase, MissionSuccessPhase::Done);
+        assert_eq!(started.shown_bonus, SECRET_FLOOR_BONUS);
+        assert!(!done.active);
+        assert_eq!(done.shown_bonus, SECRET_FLOOR_BONUS);
+        assert_eq!(stingers, 0);
+    }
+}
@@ -53,13 +53,21 @@ impl LevelScore {
         self.time_secs = 0.0;
     }
 
+    /// Carry the Clock of the Attempt the Player Died on Into the Rebuilt
+    /// Floor. Found Counts Start Over: the Rebuild Respawns Every Enemy, Item
+    /// and Pushwall, so Keeping Them Would Count the Same Finds Twice
+    pub fn keep_clock_from(&mut self, prev: &LevelScore) {
+        self.time_secs = prev.time_secs;
+    }
+
+    /// Whole Percent, Rounded Down. A Floor With None of Something Has
+    /// Nothing Left to Find, so it Reads as 100%
     #[inline]
     fn ratio_percent(found: i32, total: i32) -> i32 {
         if total <= 0 {
-            return 0;
+            return 100;
         }
-        let pct = (found as f32) / (total as f32) * 100.0;
-        pct.round().clamp(0.0, 100.0) as i32
+        (found.max(0) * 100 / total).clamp(0, 100)
     }
 
     pub fn kills_pct(&self) -> i32 {
@@ -78,7 +86,57 @@ impl LevelScore {
     }
 }
 
-/// Tick Only While Gameplay is Running (We Already Gate FixedUpdate with PlayerControlLock)
+/// Wolf3D PAR Times in Seconds (parTimes in WL_INTER.C), Floors 1..=8 of
+/// Each Episode. Boss and Secret Floors Have No Par
+const PAR_SECONDS: [[u32; 8]; 6] = [
+    [90, 120, 120, 210, 180, 180, 150, 150],
+    [90, 210, 180, 120, 240, 360, 60, 180],
+    [90, 90, 150, 150, 210, 150, 120, 360],
+    [120, 120, 90, 60, 270, 210, 120, 270],
+    [150, 90, 150, 150, 240, 180, 270, 210],
+    [390, 240, 270, 360, 300, 330, 330, 510],
+];
+
+/// Each Full PAR_BONUS_STEP_SECS Under Par Is Worth PAR_BONUS_PER_STEP Points
+pub const PAR_BONUS_STEP_SECS: i32 = 10;
+pub const PAR_BONUS_PER_STEP: i32 = 100;
+
+pub fn par_secs(level: LevelId) -> Option<u32> {
+    let episode = PAR_SECONDS.get((level.episode() as usize).checked_sub(1)?)?;
+    episode.get((level.floor_number() as usize).checked_sub(1)?).copied()
+}
+
+/// One Finished Floor as the Intermission Shows it: Ratios, Time and Par
+#[derive(Clone, Copy, Debug)]
+pub struct LevelSummary {
+    pub par_secs: Option<u32>,
+    pub time_secs: f32,
+    pub kill_pct: i32,
+    pub secret_pct: i32,
+    pub treasure_pct: i32,
+}
+
+impl LevelSummary {
+    pub fn new(level: LevelId, score: &LevelScore) -> Self {
+        Self {
+            par_secs: par_secs(level),
+            time_secs: score.time_secs,
+            kill_pct: score.kills_pct(),
+            secret_pct: score.secrets_pct(),
+            treasure_pct: score.treasure_pct(),
+        }
+    }
+
+    /// Points for Beating Par, Counted in Whole 10 Second Steps. Over Par or
+    /// a Floor Without Par Earns Nothing
+    pub fn time_bonus(&self) -> i32 {
+        let Some(par) = self.par_secs else { return 0; };
+        let under = par as i32 - self.time_secs.max(0.0).floor() as i32;
+        (under.max(0) / PAR_BONUS_STEP_SECS) * PAR_BONUS_PER_STEP
+    }
+}
+
+/// Tick Only While the Floor Clock Runs (Gated in main.rs Between Get Psyched and LevelComplete)
 pub fn tick_level_time(time: Res<Time>, mut score: ResMut<LevelScore>) {
     score.time_secs += time.delta_secs();
 }
@@ -169,3 +227,78 @@ impl EpisodeStats {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn percentages_round_down_and_empty_categories_read_full() {
+        let mut score = LevelScore::default();
+        score.reset_for_level(3, 0, 7);
+        score.kills_found = 2;
+        score.treasure_found = 6;
+
+        // 2/3 and 6/7 Floor to 66 and 85, No Secrets on the Floor Is 100%
+        assert_eq!(score.kills_pct(), 66);
+        assert_eq!(score.secrets_pct(), 100);
+        assert_eq!(score.treasure_pct(), 85);
+    }
+
+    #[test]
+    fn respawn_keeps_the_clock_but_counts_finds_afresh() {
+        let mut before = LevelScore::default();
+        before.reset_for_level(10, 2, 5);
+        before.kills_found = 12;
+        before.treasure_found = 3;
+        before.time_secs = 95.0;
+
+        let mut rebuilt = LevelScore::default();
+        rebuilt.reset_for_level(10, 2, 5);
+        rebuilt.keep_clock_from(&before);
+
+        // Everything Is Back on the Floor to be Found Again
+        assert_eq!((rebuilt.kills_found, rebuilt.secrets_found, rebuilt.treasure_found), (0, 0, 0));
+        assert_eq!(rebuilt.time_secs, 95.0);
+    }
+
+    #[test]
+    fn every_episode_has_par_times_except_boss_and_secret_floors() {
+        assert_eq!(par_secs(LevelId::E1M1), Some(90));
+        assert_eq!(par_secs(LevelId::E2M6), Some(360));
+        assert_eq!(par_secs(LevelId::E6M8), Some(510));
+        assert_eq!(par_secs(LevelId::E3M9), None);
+        assert_eq!(par_secs(LevelId::E4M10), None);
+    }
+
+    #[test]
+    fn time_bonus_pays_100_per_full_10_seconds_under_par() {
+        // E1M1 Par Is 1:30
+        let summary = |time_secs: f32, level: LevelId| {
+            let score = LevelScore { time_secs, ..Default::default() };
+            LevelSummary::new(level, &score).time_bonus()
+        };
+
+        assert_eq!(summary(60.0, LevelId::E1M1), 300);
+        assert_eq!(summary(61.5, LevelId::E1M1), 200);
+        assert_eq!(summary(81.0, LevelId::E1M1), 0);
+        assert_eq!(summary(200.0, LevelId::E1M1), 0);
+        assert_eq!(summary(0.0, LevelId::E1M9), 0);
+    }
+
+    #[test]
+    fn fully_cleared_floor_records_a_perfect_row() {
+        let mut score = LevelScore::default();
+        score.reset_for_level(12, 2, 9);
+        score.kills_found = 12;
+        score.secrets_found = 2;
+        score.treasure_found = 9;
+
+        let mut stats = EpisodeStats::default();
+        stats.record_level(LevelId::E1M3, &score);
+
+        let row = stats.levels[3];
+        assert!(row.has);
+        assert_eq!((row.kill_pct, row.secret_pct, row.treasure_pct), (100, 100, 100));
+    }
+}
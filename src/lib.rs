@@ -17,8 +17,11 @@ pub mod level;
 pub mod level_score;
 pub mod map;
 pub mod options;
+pub mod pathfind;
 pub mod perf_overlay;
 pub mod player;
 pub mod pushwalls;
 pub mod skill;
+pub mod tics;
+pub mod time_scale;
 pub mod world;
@@ -32,7 +32,7 @@ IMPORTANT: Level rebuild scheduling + Bevy ordering pitfalls (READ BEFORE TOUCHI
    - Fix strategy (the correct pattern for this codebase):
      - Register the rebuild pipeline systems (despawn -> setup -> decorations -> pickups) exactly ONCE per schedule
      - Gate that pipeline with a single run_if predicate that detects ANY rebuild request:
-         level_rebuild_requested = RestartRequested || NewGameRequested || AdvanceLevelRequested
+         level_rebuild_requested = RestartRequested || NewGameRequested || AdvanceLevelRequested || RestartLevelRequested
      - Keep the per-request “finish” systems (restart_finish / new_game_finish / advance_level_finish) separate and gated individually
      - This avoids duplicate SystemTypeSet instances and preserves deterministic ordering with `.after(...)`
 
@@ -46,6 +46,8 @@ IMPORTANT: Level rebuild scheduling + Bevy ordering pitfalls (READ BEFORE TOUCHI
 - Instead: one rebuild pipeline gated by level_rebuild_requested(), plus per-request finish systems gated individually
 */
 
+mod asset_check;
+mod checkpoint;
 mod combat;
 mod episode_end;
 mod level_complete;
@@ -84,13 +86,11 @@ use davelib::enemies::EnemiesPlugin;
 use davelib::player::{
     apply_look,
     level_pitch_without_mouselook,
-    door_animate,
-    door_auto_close,
     init_player_render_interp,
     player_interp_capture_after_tic,
     player_interp_restore_before_tic,
     apply_player_render_interp,
-    player_move,
+    game_running,
     toggle_god_mode,
     use_doors,
     PlayerSettings,
@@ -107,6 +107,7 @@ use davelib::pushwalls::{
 use davelib::world::{
     setup,
     rebuild_wall_faces_on_request,
+    sync_floor_style,
     RebuildWalls,
 };
 
@@ -124,13 +125,19 @@ fn world_ready(
 	map.is_some() && plane1.is_some()
 }
 
+/// Debug Keys (Stress Map, Sprite Scale Tuning) Only Exist in Debug Builds
+fn dev_build() -> bool {
+	cfg!(debug_assertions)
+}
+
 fn level_rebuild_requested(
     r: Res<ui::sync::RestartRequested>,
     n: Res<ui::sync::NewGameRequested>,
     a: Res<ui::sync::AdvanceLevelRequested>,
+    rl: Res<ui::sync::RestartLevelRequested>,
     l: Res<save::LoadGameRequested>,
 ) -> bool {
-    r.0 || n.0 || a.0 || l.0.is_some()
+    r.0 || n.0 || a.0 || rl.0 || l.0.is_some()
 }
 
 /// Force CPU Light Clustering on the Software Render Path Only
@@ -188,6 +195,7 @@ fn main() {
 	} else {
 		".".to_string()
 	};
+	let asset_root = std::path::PathBuf::from(&asset_file_path);
 	let high_scores = davelib::high_score::HighScores::load();
 
 	let default_plugins = DefaultPlugins
@@ -200,6 +208,7 @@ fn main() {
 		.set(ImagePlugin::default_nearest())
 		.set(WindowPlugin {
 			primary_window: Some(Window {
+				title: format!("Davenstein v{}", env!("CARGO_PKG_VERSION")),
 				present_mode: PresentMode::Fifo,
 				..default()
 			}),
@@ -231,12 +240,16 @@ fn main() {
 	App::new()
 		.add_plugins(pak_assets::PakAssetsPlugin)
 		.add_plugins(default_plugins)
+		.add_plugins(asset_check::AssetCheckPlugin { root: asset_root })
 		.add_plugins(davelib::options::OptionsPlugin)
 		.add_plugins(davelib::input::InputPlugin)
 		.add_plugins(davelib::perf_overlay::PerfOverlayPlugin)
+		.add_plugins(davelib::time_scale::TimeScalePlugin)
 		.add_plugins(ui::UiPlugin)
 		.add_plugins(save::SavePlugin)
+		.add_plugins(checkpoint::CheckpointPlugin)
 		.add_plugins(settings::SettingsPlugin)
+		.add_plugins(davelib::player::PlayerPlugin)
 		.add_plugins(EnemiesPlugin)
 		.add_plugins(EnemyAiPlugin)
 		.add_plugins(combat::CombatPlugin)
@@ -245,17 +258,24 @@ fn main() {
 		// FixedUpdate Equal Exactly One AI Tic (tics == 1), so the Ported
 		// Formulas Behave as They Did at Full Speed and the Manual Sub-Tick
 		// Accumulators (AiTicker, Pushwall Clock) Are no Longer Needed
-		.insert_resource(Time::<Fixed>::from_hz(70.0))
+		.insert_resource(Time::<Fixed>::from_hz(davelib::tics::TIC_HZ as f64))
 		.insert_resource(high_scores)
 		.init_resource::<PlayerSettings>()
 		.init_resource::<PlayerControlLock>()
+		.init_resource::<davelib::player::GamePaused>()
 		.init_resource::<PlayerDeathLatch>()
 		.init_resource::<davelib::player::GodMode>()
+		.init_resource::<davelib::player::CheatState>()
+		.init_resource::<davelib::player::HealthRegen>()
+		.init_resource::<davelib::player::PlayerMotion>()
 		.init_resource::<davelib::skill::SkillLevel>()
 		.init_resource::<ui::sync::DeathDelay>()
 		.init_resource::<ui::sync::RestartRequested>()
 		.init_resource::<ui::sync::NewGameRequested>()
 		.init_resource::<ui::sync::AdvanceLevelRequested>()
+		.init_resource::<ui::sync::RestartLevelRequested>()
+		.init_resource::<restart::LevelEntryState>()
+		.init_resource::<restart::StressMapSwap>()
 		.init_resource::<PushwallOcc>()
 		.init_resource::<PushwallState>()
 		.init_resource::<davelib::pushwalls::CompletedPushwalls>()
@@ -271,13 +291,23 @@ fn main() {
 		.init_resource::<davelib::high_score::NameEntryState>()
 		.add_message::<PlaySfx>()
 		.add_message::<RebuildWalls>()
+		.add_message::<davelib::pushwalls::SecretFound>()
+		.add_message::<davelib::player::DoorLocked>()
 		.add_systems(Startup, setup_audio)
 		.add_systems(Startup, start_music.after(setup_audio))
 		.add_systems(Startup, spawn_menu_ui_camera)
 		.add_systems(Startup, disable_gpu_clustering)
 		.add_systems(
 			Update,
-			toggle_god_mode.run_if(|lock: Res<PlayerControlLock>, win: Res<level_complete::LevelComplete>| !lock.0 && !win.0),
+			(toggle_god_mode, davelib::player::toggle_noclip, pickups::give_all_weapons_cheat)
+				.run_if(|lock: Res<PlayerControlLock>, win: Res<level_complete::LevelComplete>| !lock.0 && !win.0),
+		)
+		.add_systems(Update, pickups::flash_on_cheat_entered)
+		.add_systems(
+			Update,
+			restart::toggle_stress_map
+				.run_if(dev_build)
+				.run_if(|lock: Res<PlayerControlLock>, win: Res<level_complete::LevelComplete>| !lock.0 && !win.0),
 		)
 		.add_systems(
 			Update,
@@ -301,6 +331,7 @@ fn main() {
 		.add_systems(Update, level_complete::mission_success_input)
 		.add_systems(Update, level_complete::apply_mission_success_bonus_to_player_score_once)
 		.add_systems(Update, pickups::billboard_pickups.run_if(world_ready))
+		.add_systems(Update, pickups::apply_pickup_sprite_scales.after(davelib::decorations::nudge_sprite_scales))
 		.add_systems(Update, billboard_decorations.run_if(world_ready))
 		.add_systems(Update, use_pushwalls.run_if(world_ready).after(davelib::input::InputGather))
 		.add_systems(Update, use_doors.run_if(world_ready).after(davelib::input::InputGather))
@@ -310,9 +341,17 @@ fn main() {
 		.add_systems(PostUpdate, tick_hard_stop_sfx)
 		.add_systems(PostUpdate, davelib::audio::sync_boot_music)
 		.add_systems(PostUpdate, davelib::audio::sync_level_music)
+		.add_systems(Update, davelib::audio::update_combat_music.run_if(world_ready))
+		.add_systems(
+			PostUpdate,
+			(davelib::audio::sync_combat_music, davelib::audio::tick_music_fades)
+				.chain()
+				.after(davelib::audio::sync_level_music),
+		)
 		.add_systems(
 			PostUpdate,
 			(
+				restart::drop_stress_map_on_level_change,
 				restart::restart_despawn_level,
 				setup,
 				ApplyDeferred,
@@ -340,6 +379,12 @@ fn main() {
 				.after(pickups::spawn_pickups)
 				.run_if(|r: Res<ui::sync::AdvanceLevelRequested>| r.0),
 		)
+		.add_systems(
+			PostUpdate,
+			restart::restart_level_finish
+				.after(pickups::spawn_pickups)
+				.run_if(|r: Res<ui::sync::RestartLevelRequested>| r.0),
+		)
 		.add_systems(
 		    PostUpdate,
 		    restart::load_game_finish
@@ -347,6 +392,7 @@ fn main() {
 		        .after(restart::restart_finish)
 		        .after(restart::new_game_finish)
 		        .after(restart::advance_level_finish)
+		        .after(restart::restart_level_finish)
 		        .run_if(|r: Res<save::LoadGameRequested>| r.0.is_some()),
 		)
 		.add_systems(
@@ -359,13 +405,12 @@ fn main() {
 			FixedUpdate,
 			davelib::level_score::tick_level_time
 				.run_if(world_ready)
-				.run_if(|lock: Res<PlayerControlLock>| !lock.0),
+				.run_if(level_complete::level_clock_running)
+				.run_if(game_running),
 		)
-		.add_systems(FixedUpdate, tick_pushwalls.run_if(world_ready).run_if(|lock: Res<PlayerControlLock>| !lock.0))
+		.add_systems(FixedUpdate, tick_pushwalls.run_if(world_ready).run_if(|lock: Res<PlayerControlLock>| !lock.0).run_if(game_running))
 		.add_systems(FixedUpdate, rebuild_wall_faces_on_request.run_if(world_ready).run_if(|lock: Res<PlayerControlLock>| !lock.0))
-		.add_systems(FixedUpdate, door_auto_close.run_if(world_ready).run_if(|lock: Res<PlayerControlLock>| !lock.0))
-		.add_systems(FixedUpdate, door_animate.run_if(world_ready).run_if(|lock: Res<PlayerControlLock>| !lock.0))
-		.add_systems(FixedUpdate, player_move.run_if(world_ready).run_if(|lock: Res<PlayerControlLock>| !lock.0))
+		.add_systems(Update, sync_floor_style.run_if(world_ready))
 		// Camera Render Interpolation: Seed the Snapshots When the Player Spawns,
 		// Bracket the Fixed Tic to Record Tic-Aligned Positions, and Every Frame
 		// Draw the Camera Interpolated Between the Two Most Recent Tics. This Keeps
@@ -373,13 +418,14 @@ fn main() {
 		.add_systems(PreUpdate, init_player_render_interp.run_if(world_ready))
 		.add_systems(FixedFirst, player_interp_restore_before_tic.run_if(world_ready))
 		.add_systems(FixedLast, player_interp_capture_after_tic.run_if(world_ready))
+		.add_systems(FixedLast, davelib::player::update_player_motion.after(player_interp_capture_after_tic).run_if(world_ready))
 		.add_systems(Update, apply_player_render_interp.run_if(world_ready))
-		.add_systems(FixedUpdate, pickups::drop_guard_ammo.run_if(world_ready).run_if(|lock: Res<PlayerControlLock>| !lock.0))
-		.add_systems(FixedUpdate, pickups::drop_mutant_ammo.run_if(world_ready).run_if(|lock: Res<PlayerControlLock>| !lock.0))
-		.add_systems(FixedUpdate, pickups::drop_ss_loot.run_if(world_ready).run_if(|lock: Res<PlayerControlLock>| !lock.0))
-		.add_systems(FixedUpdate, pickups::drop_officer_ammo.run_if(world_ready).run_if(|lock: Res<PlayerControlLock>| !lock.0))
-		.add_systems(FixedUpdate, pickups::drop_hans_key.run_if(world_ready).run_if(|lock: Res<PlayerControlLock>| !lock.0))
-		.add_systems(FixedUpdate, pickups::drop_gretel_key.run_if(world_ready).run_if(|lock: Res<PlayerControlLock>| !lock.0))
-		.add_systems(FixedUpdate, pickups::collect_pickups.run_if(world_ready).run_if(|lock: Res<PlayerControlLock>| !lock.0))
+		.add_systems(FixedUpdate, pickups::drop_guard_ammo.run_if(world_ready).run_if(|lock: Res<PlayerControlLock>| !lock.0).run_if(game_running))
+		.add_systems(FixedUpdate, pickups::drop_mutant_ammo.run_if(world_ready).run_if(|lock: Res<PlayerControlLock>| !lock.0).run_if(game_running))
+		.add_systems(FixedUpdate, pickups::drop_ss_loot.run_if(world_ready).run_if(|lock: Res<PlayerControlLock>| !lock.0).run_if(game_running))
+		.add_systems(FixedUpdate, pickups::drop_officer_ammo.run_if(world_ready).run_if(|lock: Res<PlayerControlLock>| !lock.0).run_if(game_running))
+		.add_systems(FixedUpdate, pickups::drop_hans_key.run_if(world_ready).run_if(|lock: Res<PlayerControlLock>| !lock.0).run_if(game_running))
+		.add_systems(FixedUpdate, pickups::drop_gretel_key.run_if(world_ready).run_if(|lock: Res<PlayerControlLock>| !lock.0).run_if(game_running))
+		.add_systems(FixedUpdate, pickups::collect_pickups.run_if(world_ready).run_if(|lock: Res<PlayerControlLock>| !lock.0).run_if(game_running))
 		.run();
 }
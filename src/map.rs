@@ -21,6 +21,24 @@ pub struct DoorAnim {
     pub progress: f32,    // 0.0 = Closed, 1.0 = Open
     pub closed_pos: Vec3, // World Space Position When Fully Closed
     pub slide_axis: Vec3, // World Space Unit Direction to Slide Into Wall
+    pub pocket: bool,     // A Wall Lies Along slide_axis to Swallow the Panel
+}
+
+impl DoorAnim {
+    /// Door Root Translation and Scale at the Current Progress. With a Wall
+    /// Pocket the Panel Slides a Full Tile and the Wall Hides What Has Gone In.
+    /// Without One (Door Between Open Areas) it Shrinks Toward its Leading Edge
+    /// Instead, so Nothing Ever Pokes Out Across Open Floor
+    pub fn pose(&self) -> (Vec3, Vec3) {
+        if self.pocket {
+            return (self.closed_pos + self.slide_axis * self.progress, Vec3::ONE);
+        }
+
+        let remaining = (1.0 - self.progress).max(0.001);
+        let translation = self.closed_pos + self.slide_axis * (self.progress * 0.5);
+        let scale = Vec3::ONE - self.slide_axis.abs() * (1.0 - remaining);
+        (translation, scale)
+    }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -62,6 +80,21 @@ impl MapGrid {
         self.plane0[self.idx(x, z)]
     }
 
+    /// The Key a Door Wants: Some(true) for Gold (92/93), Some(false) for
+    /// Silver (94/95), None for a Plain Door or Anything Else
+    pub fn door_key(&self, x: usize, z: usize) -> Option<bool> {
+        match self.plane0_code(x, z) {
+            92 | 93 => Some(true),
+            94 | 95 => Some(false),
+            _ => None,
+        }
+    }
+
+    /// Gold and Silver Doors Open Only for a Player With the Key
+    pub fn is_locked_door(&self, x: usize, z: usize) -> bool {
+        self.door_key(x, z).is_some()
+    }
+
     pub fn set_plane0_code(&mut self, x: usize, z: usize, code: u16) {
         let i = self.idx(x, z);
         self.plane0[i] = code;
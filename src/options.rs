@@ -37,17 +37,22 @@ impl Plugin for OptionsPlugin {
 				apply_video_settings_startup,
 				apply_sound_settings_startup,
 			).chain())
+			.add_systems(Update, set_window_icon)
 			// Startup: Create the Persistent World Canvas Before Any Level
 			// Rebuild ('setup' Runs in PostUpdate, so This Always Precedes It)
 			.add_systems(Startup, create_world_canvas)
 			// Update: Deal With Changes
 			.add_systems(Update, (
 				apply_video_settings_on_change,
+				apply_camera_fov,
 				apply_view_size_on_change,
 				resize_world_canvas,
 				apply_sound_settings_on_change,
 				apply_control_settings_on_change,
 			))
+			// Frame Cap Sleeps at the End of the Frame, After Everything Else Ran
+			.init_resource::<FrameLimiter>()
+			.add_systems(Last, limit_frame_rate)
 			// Debug Hotkeys (Gate Behind DEV Flag Later)
 			.add_systems(Update, debug_toggle_vsync);
 	}
@@ -175,6 +180,154 @@ impl RenderScale {
 	}
 }
 
+/// Aiming Reticle Drawn in the Middle of the 3D View. Off Matches the Original
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CrosshairStyle {
+	#[default]
+	Off,
+	Dot,
+	Cross,
+}
+
+impl CrosshairStyle {
+	/// Cycle Forward Through Styles (Wraps Around)
+	pub fn next(self) -> Self {
+		match self {
+			CrosshairStyle::Off   => CrosshairStyle::Dot,
+			CrosshairStyle::Dot   => CrosshairStyle::Cross,
+			CrosshairStyle::Cross => CrosshairStyle::Off,
+		}
+	}
+
+	/// Cycle Backward Through Styles (Wraps Around)
+	pub fn prev(self) -> Self {
+		match self {
+			CrosshairStyle::Off   => CrosshairStyle::Cross,
+			CrosshairStyle::Dot   => CrosshairStyle::Off,
+			CrosshairStyle::Cross => CrosshairStyle::Dot,
+		}
+	}
+
+	/// Human Readable Label for the Menu
+	pub fn label(self) -> &'static str {
+		match self {
+			CrosshairStyle::Off   => "Off",
+			CrosshairStyle::Dot   => "Dot",
+			CrosshairStyle::Cross => "Cross",
+		}
+	}
+}
+
+/// Maximum Frame Rate When VSync Is Off
+/// Caps How Often the Main Loop Runs so an Uncapped Present Mode Doesn't Spin
+/// the GPU Flat Out (Heat / Coil Whine). Only Render Rate Is Capped: Gameplay
+/// Runs in FixedUpdate at 70 Hz, Which Catches up With Several Steps per Frame
+/// Below That Rate, so the Simulation Speed Never Changes. Ignored With VSync On
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FpsCap {
+	/// No Cap (Classic Behavior)
+	#[default]
+	Off,
+	Fps30,
+	Fps60,
+	Fps120,
+	Fps144,
+	Fps240,
+}
+
+impl FpsCap {
+	/// Cycle Forward Through Caps (Wraps Around)
+	pub fn next(self) -> Self {
+		match self {
+			FpsCap::Off    => FpsCap::Fps30,
+			FpsCap::Fps30  => FpsCap::Fps60,
+			FpsCap::Fps60  => FpsCap::Fps120,
+			FpsCap::Fps120 => FpsCap::Fps144,
+			FpsCap::Fps144 => FpsCap::Fps240,
+			FpsCap::Fps240 => FpsCap::Off,
+		}
+	}
+
+	/// Cycle Backward Through Caps (Wraps Around)
+	pub fn prev(self) -> Self {
+		match self {
+			FpsCap::Off    => FpsCap::Fps240,
+			FpsCap::Fps30  => FpsCap::Off,
+			FpsCap::Fps60  => FpsCap::Fps30,
+			FpsCap::Fps120 => FpsCap::Fps60,
+			FpsCap::Fps144 => FpsCap::Fps120,
+			FpsCap::Fps240 => FpsCap::Fps144,
+		}
+	}
+
+	/// Frames per Second, None When Uncapped
+	pub fn fps(self) -> Option<u32> {
+		match self {
+			FpsCap::Off    => None,
+			FpsCap::Fps30  => Some(30),
+			FpsCap::Fps60  => Some(60),
+			FpsCap::Fps120 => Some(120),
+			FpsCap::Fps144 => Some(144),
+			FpsCap::Fps240 => Some(240),
+		}
+	}
+
+	/// Human Readable Label for the Menu
+	pub fn label(self) -> String {
+		match self.fps() {
+			Some(fps) => fps.to_string(),
+			None => "Off".to_string(),
+		}
+	}
+}
+
+/// How the FOV Setting Maps Onto the Camera's Vertical 'PerspectiveProjection.fov'
+/// - 'Vertical'   -> Setting Is the Vertical FOV, Used As-Is (Classic Behavior)
+/// - 'Horizontal' -> Setting Is the Horizontal FOV; Vertical Is Derived From the
+///                   3-D View's Aspect Ratio so Wide Windows Keep the Chosen
+///                   Horizontal Span Instead of Feeling Narrow
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FovMode {
+	#[default]
+	Vertical,
+	Horizontal,
+}
+
+impl FovMode {
+	/// Toggle Between the Two Modes
+	pub fn toggled(self) -> Self {
+		match self {
+			FovMode::Vertical => FovMode::Horizontal,
+			FovMode::Horizontal => FovMode::Vertical,
+		}
+	}
+
+	/// Human Readable Label for the Menu
+	pub fn label(self) -> &'static str {
+		match self {
+			FovMode::Vertical => "Vertical",
+			FovMode::Horizontal => "Horizontal",
+		}
+	}
+}
+
+/// Aspect Ratios Outside This Range Are Clamped Before Deriving Vertical FOV
+/// Very Tall or Ultra-Wide Windows Would Otherwise Squash the View to a Sliver
+/// or Blow it up Past Anything the Renderer Draws Without Heavy Distortion
+const FOV_MIN_ASPECT: f32 = 0.75;
+const FOV_MAX_ASPECT: f32 = 32.0 / 9.0;
+
+/// Vertical FOV (Radians) That Yields 'hfov' (Radians) Horizontally at 'aspect'
+/// (Width / Height). Aspect Is Clamped to Avoid Extreme Distortion
+pub fn vertical_fov_from_horizontal(hfov: f32, aspect: f32) -> f32 {
+	let aspect = if aspect.is_finite() {
+		aspect.clamp(FOV_MIN_ASPECT, FOV_MAX_ASPECT)
+	} else {
+		4.0 / 3.0
+	};
+	2.0 * ((hfov * 0.5).tan() / aspect).atan()
+}
+
 /// Which MSAA Preset User has Chosen
 /// Bevy 0.18 Treats 'MSAA' as a *Camera Component*, so Apply System
 /// Will Insert / Mutate it on any Camera Entity Tagged
@@ -189,6 +342,8 @@ pub enum MsaaSetting {
 #[derive(Resource, Clone, Copy, PartialEq)]
 pub struct VideoSettings {
 	pub vsync: bool,
+	/// Frame Rate Cap Applied Only While 'vsync' Is Off (See 'FpsCap')
+	pub fps_cap: FpsCap,
 	pub display_mode: DisplayMode,
 	/// Desired Resolution
 	/// - 'Windowed'             -> Used Directly as the Window's Logical Size
@@ -198,23 +353,39 @@ pub struct VideoSettings {
 	/// - 'BorderlessFullscreen' -> Ignored (Borderless Always Matches the
 	///                             Desktop Resolution by Definition)
 	pub resolution: (u32, u32),
-	/// Vertical FOV in *Degrees*. Clamped to 60..=120
+	/// FOV in *Degrees*. Clamped to 40..=120
+	/// Vertical or Horizontal Depending on 'fov_mode'
 	/// Camera Setup Should Read This via 'Res<VideoSettings>'
 	pub fov: f32,
+	/// Whether 'fov' Is the Vertical or Horizontal FOV (See 'FovMode')
+	pub fov_mode: FovMode,
 	/// Classic Wolfenstein 3D "View Size" (How Much Screen the 3-D
-	/// Viewport Occupies vs HUD Border) Range 4..=20
+	/// Viewport Occupies vs HUD Border) Range 5..=20
 	/// HUD / Viewport Layout Reads This
 	pub view_size: u8,
 	pub msaa: MsaaSetting,
 	/// Internal Render Scale for the 3-D View (See 'RenderScale')
 	/// Defaults to 'Native' so Behavior Is Unchanged Until the User Opts In
 	pub render_scale: RenderScale,
+	/// Letterbox the Game to the Largest Whole Multiple of 320x200 That Fits
+	/// so Every Game Pixel Is an Exact NxN Block (See 'integer_scale_region')
+	/// Off Fills the Whole Window
+	pub integer_scale: bool,
+	/// Textured Floor, or Off for the Original's Flat Gray (LevelId::floor_color)
+	pub textured_floor: bool,
+	/// Reticle in the Middle of the 3D View (See 'CrosshairStyle')
+	pub crosshair: CrosshairStyle,
+	/// Blood Burst When an Enemy Is Killed. On by Default
+	pub gore: bool,
+	/// Brief Camera Shake on Taking Damage and Nearby Explosions
+	pub screen_shake: bool,
 }
 
 impl Default for VideoSettings {
 	fn default() -> Self {
 		Self {
 			vsync: true,
+			fps_cap: FpsCap::default(),
 			#[cfg(not(feature = "software_render"))]
 			display_mode: DisplayMode::default(),
 			// Software (CPU) Rendering Defaults to Exclusive Fullscreen at a Low Mode
@@ -233,9 +404,15 @@ impl Default for VideoSettings {
 			#[cfg(feature = "software_render")]
 			resolution: (320, 240),
 			fov: 40.0,
+			fov_mode: FovMode::default(),
 			view_size: 20,
 			msaa: MsaaSetting::Off,
 			render_scale: RenderScale::default(),
+			integer_scale: false,
+			textured_floor: true,
+			crosshair: CrosshairStyle::default(),
+			gore: true,
+			screen_shake: true,
 		}
 	}
 }
@@ -302,6 +479,29 @@ pub fn world_canvas_size(win_w: u32, win_h: u32, scale: RenderScale) -> UVec2 {
 	)
 }
 
+/// Classic Base Resolution Integer Scale Snaps To
+pub const INTEGER_SCALE_BASE: UVec2 = UVec2::new(320, 200);
+
+/// Largest Whole Multiple of the 320x200 Base That Fits the Window, in Physical
+/// Pixels. A Window Smaller Than 1x Just Uses the Window Itself
+pub fn integer_scale_region(win_w: u32, win_h: u32) -> UVec2 {
+	let n = (win_w / INTEGER_SCALE_BASE.x).min(win_h / INTEGER_SCALE_BASE.y);
+	if n == 0 {
+		return UVec2::new(win_w.max(1), win_h.max(1));
+	}
+	INTEGER_SCALE_BASE * n
+}
+
+/// Physical Region of the Window the Game Draws Into: the Whole Window, or the
+/// Centered Integer-Scale Box When That Mode Is On
+pub fn game_region(win_w: u32, win_h: u32, integer_scale: bool) -> UVec2 {
+	if integer_scale {
+		integer_scale_region(win_w, win_h)
+	} else {
+		UVec2::new(win_w.max(1), win_h.max(1))
+	}
+}
+
 /// List of Available Resolutions for Windowed Mode
 /// Populated at Startup from Monitor Query, Falls Back to
 /// Common 16:9 Presets if Query Yields Nothing
@@ -362,42 +562,78 @@ impl ResolutionList {
 }
 
 //  CONTROL SETTINGS (Controls Screen)
-/// Rebindable Key Map for Modern WASD + Mouselook
+/// One Physical Input an Action Can Be Bound to, a Keyboard Key or a Mouse Button
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputBinding {
+	Key(KeyCode),
+	Mouse(MouseButton),
+}
+
+impl InputBinding {
+	pub fn pressed(self, keys: &ButtonInput<KeyCode>, mouse: &ButtonInput<MouseButton>) -> bool {
+		match self {
+			Self::Key(k) => keys.pressed(k),
+			Self::Mouse(b) => mouse.pressed(b),
+		}
+	}
+
+	pub fn just_pressed(self, keys: &ButtonInput<KeyCode>, mouse: &ButtonInput<MouseButton>) -> bool {
+		match self {
+			Self::Key(k) => keys.just_pressed(k),
+			Self::Mouse(b) => mouse.just_pressed(b),
+		}
+	}
+}
+
+impl From<KeyCode> for InputBinding {
+	fn from(k: KeyCode) -> Self {
+		Self::Key(k)
+	}
+}
+
+impl From<MouseButton> for InputBinding {
+	fn from(b: MouseButton) -> Self {
+		Self::Mouse(b)
+	}
+}
+
+/// Rebindable Input Map for Modern WASD + Mouselook. Any Action Takes Either a
+/// Key or a Mouse Button
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct KeyBindings {
-	pub move_forward:  KeyCode,
-	pub move_backward: KeyCode,
-	pub strafe_left:   KeyCode,
-	pub strafe_right:  KeyCode,
+	pub move_forward:  InputBinding,
+	pub move_backward: InputBinding,
+	pub strafe_left:   InputBinding,
+	pub strafe_right:  InputBinding,
 	/// Keyboard yaw. Used when mouselook is off, and available alongside the
 	/// mouse when it is on, so the game is playable without a mouse.
-	pub turn_left:     KeyCode,
-	pub turn_right:    KeyCode,
-	pub fire:          KeyCode,
-	pub use_door:      KeyCode,
-	pub run:           KeyCode,
-	pub weapon_1:      KeyCode,
-	pub weapon_2:      KeyCode,
-	pub weapon_3:      KeyCode,
-	pub weapon_4:      KeyCode,
+	pub turn_left:     InputBinding,
+	pub turn_right:    InputBinding,
+	pub fire:          InputBinding,
+	pub use_door:      InputBinding,
+	pub run:           InputBinding,
+	pub weapon_1:      InputBinding,
+	pub weapon_2:      InputBinding,
+	pub weapon_3:      InputBinding,
+	pub weapon_4:      InputBinding,
 }
 
 impl Default for KeyBindings {
 	fn default() -> Self {
 		Self {
-			move_forward:  KeyCode::KeyW,
-			move_backward: KeyCode::KeyS,
-			strafe_left:   KeyCode::KeyA,
-			strafe_right:  KeyCode::KeyD,
-			turn_left:     KeyCode::ArrowLeft,
-			turn_right:    KeyCode::ArrowRight,
-			fire:          KeyCode::ControlLeft,
-			use_door:      KeyCode::Space,
-			run:           KeyCode::ShiftLeft,
-			weapon_1:      KeyCode::Digit1,
-			weapon_2:      KeyCode::Digit2,
-			weapon_3:      KeyCode::Digit3,
-			weapon_4:      KeyCode::Digit4,
+			move_forward:  KeyCode::KeyW.into(),
+			move_backward: KeyCode::KeyS.into(),
+			strafe_left:   KeyCode::KeyA.into(),
+			strafe_right:  KeyCode::KeyD.into(),
+			turn_left:     KeyCode::ArrowLeft.into(),
+			turn_right:    KeyCode::ArrowRight.into(),
+			fire:          KeyCode::ControlLeft.into(),
+			use_door:      KeyCode::Space.into(),
+			run:           KeyCode::ShiftLeft.into(),
+			weapon_1:      KeyCode::Digit1.into(),
+			weapon_2:      KeyCode::Digit2.into(),
+			weapon_3:      KeyCode::Digit3.into(),
+			weapon_4:      KeyCode::Digit4.into(),
 		}
 	}
 }
@@ -426,8 +662,8 @@ impl KeyBindings {
 		}
 	}
 
-	/// The Key Currently Bound to the Action at a Given Index
-	pub fn key_at(&self, i: usize) -> KeyCode {
+	/// The Input Currently Bound to the Action at a Given Index
+	pub fn binding_at(&self, i: usize) -> InputBinding {
 		match i {
 			0  => self.move_forward,
 			1  => self.move_backward,
@@ -446,30 +682,35 @@ impl KeyBindings {
 		}
 	}
 
-	/// Bind the Action at a Given Index to a New Key
-	pub fn set_at(&mut self, i: usize, key: KeyCode) {
+	/// Bind the Action at a Given Index to a New Key or Button
+	pub fn set_at(&mut self, i: usize, binding: InputBinding) {
 		match i {
-			0  => self.move_forward  = key,
-			1  => self.move_backward = key,
-			2  => self.strafe_left   = key,
-			3  => self.strafe_right  = key,
-			4  => self.turn_left     = key,
-			5  => self.turn_right    = key,
-			6  => self.fire          = key,
-			7  => self.use_door      = key,
-			8  => self.run           = key,
-			9  => self.weapon_1      = key,
-			10 => self.weapon_2      = key,
-			11 => self.weapon_3      = key,
-			12 => self.weapon_4      = key,
+			0  => self.move_forward  = binding,
+			1  => self.move_backward = binding,
+			2  => self.strafe_left   = binding,
+			3  => self.strafe_right  = binding,
+			4  => self.turn_left     = binding,
+			5  => self.turn_right    = binding,
+			6  => self.fire          = binding,
+			7  => self.use_door      = binding,
+			8  => self.run           = binding,
+			9  => self.weapon_1      = binding,
+			10 => self.weapon_2      = binding,
+			11 => self.weapon_3      = binding,
+			12 => self.weapon_4      = binding,
 			_  => {}
 		}
 	}
 
-	/// Index of an Action Already Bound to key, Excluding except, if Any
-	/// Used to Reject a Conflicting Rebind so No Two Actions Share a Key
-	pub fn conflict(&self, key: KeyCode, except: usize) -> Option<usize> {
-		(0..Self::COUNT).find(|&i| i != except && self.key_at(i) == key)
+	/// Index of an Action Already Bound to binding, Excluding except, if Any
+	/// Used to Reject a Conflicting Rebind so No Two Actions Share an Input
+	pub fn conflict(&self, binding: InputBinding, except: usize) -> Option<usize> {
+		(0..Self::COUNT).find(|&i| i != except && self.binding_at(i) == binding)
+	}
+
+	/// Whether Any Action Is Bound to This Input
+	pub fn is_bound(&self, binding: InputBinding) -> bool {
+		(0..Self::COUNT).any(|i| self.binding_at(i) == binding)
 	}
 }
 
@@ -497,6 +738,9 @@ pub struct ControlSettings {
 	/// Applied to 'GamepadSettings.default_axis_settings' on Every
 	/// Connected Gamepad Entity
 	pub gamepad_deadzone: f32,
+	/// When True, Movement Runs by Default and Holding Run Walks Instead
+	/// Default: false
+	pub always_run: bool,
 	pub key_bindings: KeyBindings,
 }
 
@@ -509,14 +753,154 @@ impl Default for ControlSettings {
 			gamepad_enabled: true,
 			gamepad_sensitivity: 1.0,
 			gamepad_deadzone: 0.1,
+			always_run: false,
 			key_bindings: KeyBindings::default(),
 		}
 	}
 }
 
 //  GAMEPLAY SETTINGS (Gameplay Screen)
+/// GET PSYCHED Screen Shown While a Level Loads. Every Mode Still Locks
+/// Controls Until the Level Is Ready, Off Just Never Draws the Screen
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PsychedScreen {
+	#[default]
+	Classic,
+	Short,
+	Instant,
+	Off,
+}
+
+impl PsychedScreen {
+	/// Cycle Forward Through Modes (Wraps Around)
+	pub fn next(self) -> Self {
+		match self {
+			PsychedScreen::Classic => PsychedScreen::Short,
+			PsychedScreen::Short   => PsychedScreen::Instant,
+			PsychedScreen::Instant => PsychedScreen::Off,
+			PsychedScreen::Off     => PsychedScreen::Classic,
+		}
+	}
+
+	/// Human Readable Label for the Menu
+	pub fn label(self) -> &'static str {
+		match self {
+			PsychedScreen::Classic => "Classic",
+			PsychedScreen::Short   => "Short",
+			PsychedScreen::Instant => "Instant",
+			PsychedScreen::Off     => "Off",
+		}
+	}
+
+	/// How Long the Loading Bar Runs
+	pub fn duration_secs(self) -> f32 {
+		match self {
+			PsychedScreen::Classic => 2.5,
+			PsychedScreen::Short   => 0.8,
+			PsychedScreen::Instant => 0.1,
+			PsychedScreen::Off     => 0.0,
+		}
+	}
+
+	pub fn shows_screen(self) -> bool {
+		self != PsychedScreen::Off
+	}
+}
+
+/// Strength of the Viewmodel Kick on Each Shot. Off Keeps the Original's
+/// Perfectly Still Weapon Sprite
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ViewmodelRecoil {
+	#[default]
+	Off,
+	Subtle,
+	Normal,
+	Strong,
+}
+
+impl ViewmodelRecoil {
+	/// Cycle Forward Through Strengths (Wraps Around)
+	pub fn next(self) -> Self {
+		match self {
+			ViewmodelRecoil::Off    => ViewmodelRecoil::Subtle,
+			ViewmodelRecoil::Subtle => ViewmodelRecoil::Normal,
+			ViewmodelRecoil::Normal => ViewmodelRecoil::Strong,
+			ViewmodelRecoil::Strong => ViewmodelRecoil::Off,
+		}
+	}
+
+	/// Human Readable Label for the Menu
+	pub fn label(self) -> &'static str {
+		match self {
+			ViewmodelRecoil::Off    => "Off",
+			ViewmodelRecoil::Subtle => "Subtle",
+			ViewmodelRecoil::Normal => "Normal",
+			ViewmodelRecoil::Strong => "Strong",
+		}
+	}
+
+	/// Multiplier on Each Weapon's Base Kick
+	pub fn scale(self) -> f32 {
+		match self {
+			ViewmodelRecoil::Off    => 0.0,
+			ViewmodelRecoil::Subtle => 0.5,
+			ViewmodelRecoil::Normal => 1.0,
+			ViewmodelRecoil::Strong => 1.6,
+		}
+	}
+}
+
+/// How Forgiving Player Hitscan Is Against Enemy Hitboxes
+/// Classic Keeps the Original Padded Cylinders That Stand in for Wolf3D's
+/// Column Based Auto Aim, Off Shrinks Them Toward the Visible Sprite and
+/// Generous Widens Them Further
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AutoAim {
+	Off,
+	#[default]
+	Classic,
+	Generous,
+}
+
+impl AutoAim {
+	/// Cycle Forward Through Strengths (Wraps Around)
+	pub fn next(self) -> Self {
+		match self {
+			AutoAim::Off      => AutoAim::Classic,
+			AutoAim::Classic  => AutoAim::Generous,
+			AutoAim::Generous => AutoAim::Off,
+		}
+	}
+
+	/// Human Readable Label for the Menu
+	pub fn label(self) -> &'static str {
+		match self {
+			AutoAim::Off      => "Off",
+			AutoAim::Classic  => "Classic",
+			AutoAim::Generous => "Generous",
+		}
+	}
+
+	/// Scale Applied to Hitbox Radius + Half Height
+	/// Off Still Leaves a Centered Shot on a Guard Comfortably Inside the Cylinder
+	pub fn hitbox_scale(self) -> f32 {
+		match self {
+			AutoAim::Off      => 0.70,
+			AutoAim::Classic  => 1.0,
+			AutoAim::Generous => 1.35,
+		}
+	}
+
+	/// Effective (radius, half_h) for a Base Hitbox
+	pub fn apply(self, radius: f32, half_h: f32) -> (f32, f32) {
+		let k = self.hitbox_scale();
+		(radius * k, half_h * k)
+	}
+}
+
 /// Opt-In Fidelity Tweaks That Deviate From the Original Game
-/// Everything Defaults to Classic Wolfenstein 3-D Behavior
+/// Everything Defaults to Classic Wolfenstein 3-D Behavior Except Two Modern
+/// Touches That Start On: screen_fade and secret_notice
 #[derive(Resource, Clone, Copy, PartialEq)]
 pub struct GameplaySettings {
 	/// When False (Default) Pushwalls Behave Like the Original / One Shot,
@@ -524,12 +908,70 @@ pub struct GameplaySettings {
 	/// When True the Marker Travels With the Wall so It Can Be Pushed Again,
 	/// Including Back, Which Prevents Getting Stuck by Shoving One the Wrong Way
 	pub reversible_pushwalls: bool,
+	/// Loadout Used by the Pause Menu's Restart Level. True (Default) Replays the
+	/// Level From a Pistol Start, False Restores the Inventory Carried Into the Level
+	/// Score Reverts to the Level Entry Value Either Way
+	pub restart_level_pistol_start: bool,
+	/// When False (Default) the Damage Flash Tints the 3D View Behind the Weapon
+	/// Sprite, When True It Is Drawn Over the Weapon Too Like the Original
+	pub damage_flash_over_weapon: bool,
+	/// When True a Red Bar at the Edge of the View Points Toward Whoever Just
+	/// Hit the Player. Off (Default) Leaves Only the Classic Full-Screen Flash
+	pub damage_direction_indicator: bool,
+	/// When True Startup Goes Straight to the Main Menu, Skipping the Two Title
+	/// Screens. The '--skip-splash' Command Line Flag Does the Same for One Launch
+	pub skip_splash: bool,
+	/// When True (Default) Major Screen Changes (Menus, Get Psyched, Gameplay,
+	/// Intermission) Cut to Black and Fade In, a Smoothed Take on the Original's
+	/// Palette Fades. False Switches Screens Instantly
+	pub screen_fade: bool,
+	/// GET PSYCHED Length, or Off to Skip the Screen (Classic by Default)
+	pub psyched_screen: PsychedScreen,
+	/// When True Holding Use Keeps Opening Doors the Player Walks Up To and
+	/// Props the Faced Door Open. False (Default) Is the Classic One Press per
+	/// Door. Pushwalls, Switches and Elevators Always Need a Fresh Press
+	pub hold_use_doors: bool,
+	/// When True (Default) Show a Brief "A SECRET!" Banner and Chime When a
+	/// Pushwall Secret Is First Found. Off Keeps the Classic Silent Discovery
+	pub secret_notice: bool,
+	/// Kick the Weapon Sprite Down on Each Shot (See 'ViewmodelRecoil')
+	pub viewmodel_recoil: ViewmodelRecoil,
+	/// When True Death Returns the Player to the Last Checkpoint (First Pass
+	/// Through Each Door) Instead of Restarting the Level. False (Default) Is
+	/// the Classic Full Restart
+	pub checkpoint_respawn: bool,
+	/// When True Shots Follow the Full Camera Pitch. False (Default) Flattens
+	/// the Fire Direction to the Horizontal Plane Like the Original's 2-D Combat
+	pub true_3d_aim: bool,
+	/// Slow Health Recovery After a Quiet Spell, Never Past the Top of the
+	/// Current 25-Point Band. Off (Default) Keeps the Classic No-Regen Game
+	pub health_regen: bool,
+	/// When True (Default) a Gun That Runs Dry Mid-Fire Drops to the Knife and
+	/// Comes Back Once Ammo Is Picked Up, Like the Original. False Keeps the
+	/// Empty Gun Drawn
+	pub empty_weapon_switch: bool,
+	/// Hitscan Forgiveness for the Player's Guns and Knife (See 'AutoAim')
+	pub auto_aim: AutoAim,
 }
 
 impl Default for GameplaySettings {
 	fn default() -> Self {
 		Self {
 			reversible_pushwalls: false,
+			restart_level_pistol_start: true,
+			damage_flash_over_weapon: false,
+			damage_direction_indicator: false,
+			skip_splash: false,
+			screen_fade: true,
+			psyched_screen: PsychedScreen::Classic,
+			hold_use_doors: false,
+			secret_notice: true,
+			viewmodel_recoil: ViewmodelRecoil::Off,
+			checkpoint_respawn: false,
+			true_3d_aim: false,
+			health_regen: false,
+			empty_weapon_switch: true,
+			auto_aim: AutoAim::Classic,
 		}
 	}
 }
@@ -545,6 +987,21 @@ pub struct MusicTrack;
 #[derive(Component)]
 pub struct SfxSound;
 
+/// Marker Component: Put This on Menu / UI Sound Entities
+/// so the UI Bus Volume Reaches Them via 'AudioSink'
+#[derive(Component)]
+pub struct UiSound;
+
+/// Logical Mix Bus a Sound Plays Through
+/// Each Bus Has its Own Volume Scalar in 'SoundSettings'
+/// and Master Scales All of Them via 'GlobalVolume'
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AudioBus {
+	Music,
+	Sfx,
+	Ui,
+}
+
 #[derive(Resource, Clone, Copy, PartialEq)]
 pub struct SoundSettings {
 	/// Overall Volume Multiplier (Written to 'GlobalVolume')
@@ -559,6 +1016,10 @@ pub struct SoundSettings {
 	/// Range: 0.0 ..= 1.0
 	/// Default: 1.0
 	pub sfx_volume: f32,
+	/// Volume Scalar for Menu / UI Sinks
+	/// Range: 0.0 ..= 1.0
+	/// Default: 1.0
+	pub ui_volume: f32,
 	/// When False, Music Sinks Paused
 	pub music_enabled: bool,
 	/// When False, SFX Spawning Systems
@@ -572,6 +1033,7 @@ impl Default for SoundSettings {
 			master_volume: 1.0,
 			music_volume: 1.0,
 			sfx_volume: 1.0,
+			ui_volume: 1.0,
 			music_enabled: true,
 			sfx_enabled: true,
 		}
@@ -661,7 +1123,8 @@ fn create_world_canvas(
 		))
 		.unwrap_or((1280, 720));
 
-	let size = world_canvas_size(win_w, win_h, settings.render_scale);
+	let region = game_region(win_w, win_h, settings.integer_scale);
+	let size = world_canvas_size(region.x, region.y, settings.render_scale);
 
 	// Single Srgb Target, No Separate View Format. The Pi's V3D GPU Lacks the
 	// VIEW_FORMATS Downlevel Flag, so an Srgb View Over a Unorm Texture Cannot Be
@@ -695,7 +1158,8 @@ fn resize_world_canvas(
 
 	let win_w = window.resolution.physical_width().max(1);
 	let win_h = window.resolution.physical_height().max(1);
-	let want = world_canvas_size(win_w, win_h, settings.render_scale);
+	let region = game_region(win_w, win_h, settings.integer_scale);
+	let want = world_canvas_size(region.x, region.y, settings.render_scale);
 
 	// Resize the Backing Canvas Image Only When the Target Size Truly Changes.
 	// On the Pi's V3D Vulkan Driver, Resizing a Render-Target Image in Place While
@@ -750,10 +1214,16 @@ fn resize_world_canvas(
 		// Left, so the Previous Image and Its Depth Are Released Automatically
 	}
 
-	let logical = Vec2::new(
-		window.resolution.width().max(1.0),
-		window.resolution.height().max(1.0),
-	);
+	// Integer Scale Shrinks the Sprite to the Snapped Box, the Present Camera
+	// Keeps it Centered and its Black Clear Shows Around it as the Letterbox
+	let logical = if settings.integer_scale {
+		region.as_vec2() / window.resolution.scale_factor().max(f32::EPSILON)
+	} else {
+		Vec2::new(
+			window.resolution.width().max(1.0),
+			window.resolution.height().max(1.0),
+		)
+	};
 	for mut sprite in q_sprite.iter_mut() {
 		if sprite.custom_size != Some(logical) {
 			sprite.custom_size = Some(logical);
@@ -761,6 +1231,44 @@ fn resize_world_canvas(
 	}
 }
 
+impl VideoSettings {
+	/// Target Frame Duration for the Limiter, None When VSync Already Paces
+	/// Frames or No Cap Is Set
+	pub fn frame_cap_duration(&self) -> Option<std::time::Duration> {
+		if self.vsync {
+			return None;
+		}
+		let fps = self.fps_cap.fps()?;
+		Some(std::time::Duration::from_secs_f64(1.0 / fps as f64))
+	}
+}
+
+/// Deadline for the Next Frame, Carried Between Frames so Sleep Overshoot
+/// Doesn't Accumulate Into a Lower Rate Than Requested
+#[derive(Resource, Default)]
+pub struct FrameLimiter {
+	next_frame: Option<std::time::Instant>,
+}
+
+/// Sleep-Based Frame Cap. Runs in 'Last' so the Whole Frame's Work Counts
+/// Toward the Budget, Then Sleeps out the Remainder
+fn limit_frame_rate(settings: Res<VideoSettings>, mut limiter: ResMut<FrameLimiter>) {
+	let Some(frame) = settings.frame_cap_duration() else {
+		limiter.next_frame = None;
+		return;
+	};
+
+	let now = std::time::Instant::now();
+	let deadline = limiter.next_frame.unwrap_or(now);
+	if deadline > now {
+		std::thread::sleep(deadline - now);
+	}
+
+	// A Frame That Ran Long Restarts the Schedule From Now Instead of
+	// Bursting Several Unpaced Frames to Catch Up
+	limiter.next_frame = Some(deadline.max(now) + frame);
+}
+
 fn desired_present_mode(s: &VideoSettings) -> PresentMode {
 	if s.vsync {
 		// Explicit 'Fifo' Rather Than 'AutoVsync'. 'AutoVsync' Is a Best-Effort
@@ -843,7 +1351,76 @@ fn desired_msaa(s: &VideoSettings) -> Msaa {
 	}
 }
 
-/// Run Once at Startup to Make Sure Window Matches Defaults
+/// The Saved Windowed Resolution if the Largest Monitor Mode Can Hold it,
+/// Else the Default Size. A Setting Carried Over From a Bigger Display Would
+/// Otherwise Open a Window Partly off Screen. Only the Window Gets the Fallback,
+/// the Saved Setting Is Kept for When the Bigger Display Is Back
+fn windowed_resolution_that_fits(saved: (u32, u32), largest: Option<(u32, u32)>) -> (u32, u32) {
+	let fits = saved.0 > 0
+		&& saved.1 > 0
+		&& largest.is_none_or(|(w, h)| saved.0 <= w && saved.1 <= h);
+	if fits { saved } else { VideoSettings::default().resolution }
+}
+
+/// Largest Video Mode Any Connected Monitor Reports, in Physical Pixels
+fn largest_monitor_mode(q_monitors: &Query<&Monitor>) -> Option<(u32, u32)> {
+	q_monitors
+		.iter()
+		.flat_map(|m| m.video_modes.iter())
+		.map(|mode| (mode.physical_size.x, mode.physical_size.y))
+		.max_by_key(|&(w, h)| w as u64 * h as u64)
+}
+
+/// Icon Baked Into the Binary so the Window Shows it Without Any Installed
+/// Desktop Files. Windows Also Embeds the .ico for Explorer via build.rs
+const WINDOW_ICON_PNG: &[u8] = include_bytes!("../packaging/linux/davenstein.png");
+
+/// Decode the Embedded Icon to Straight RGBA8 Plus its Size
+fn window_icon_rgba() -> Option<(Vec<u8>, u32, u32)> {
+	let image = Image::from_buffer(
+		WINDOW_ICON_PNG,
+		bevy::image::ImageType::Extension("png"),
+		bevy::image::CompressedImageFormats::NONE,
+		true,
+		ImageSampler::Default,
+		bevy::asset::RenderAssetUsages::MAIN_WORLD,
+	)
+	.ok()?;
+	let rgba = image.try_into_dynamic().ok()?.to_rgba8();
+	let (w, h) = rgba.dimensions();
+	Some((rgba.into_raw(), w, h))
+}
+
+/// Hand the Embedded Icon to the OS Window. Winit Windows Live on the Main
+/// Thread and Only Exist Once the Event Loop Resumes, so This Checks Each Frame
+/// Until the Primary Window Is There, Then Stops
+fn set_window_icon(
+	_main_thread: bevy::ecs::system::NonSendMarker,
+	q_window: Query<Entity, With<PrimaryWindow>>,
+	mut done: Local<bool>,
+) {
+	if *done {
+		return;
+	}
+	let Ok(entity) = q_window.single() else {
+		return;
+	};
+
+	bevy::winit::WINIT_WINDOWS.with_borrow(|windows| {
+		let Some(window) = windows.get_window(entity) else {
+			return;
+		};
+		*done = true;
+
+		let icon = window_icon_rgba().and_then(|(rgba, w, h)| winit::window::Icon::from_rgba(rgba, w, h).ok());
+		match icon {
+			Some(icon) => window.set_window_icon(Some(icon)),
+			None => warn!("Embedded Window Icon Could Not Be Decoded"),
+		}
+	});
+}
+
+/// Run Once at Startup so the First Frame Opens in Last Session's Mode and Size
 fn apply_video_settings_startup(
 	settings: Res<VideoSettings>,
 	q_monitors: Query<&Monitor>,
@@ -851,10 +1428,18 @@ fn apply_video_settings_startup(
 	// Restricted to the 3-D World Camera. MSAA Only Makes Sense (and Is Only Ever
 	// Wanted) for the World Pass; the 2-D Present and Menu Cameras Must Stay at One
 	// Sample so the Nearest-Neighbor Upscale Stays Crisp and the None-Clear Menu
-	// Camera's Depth Never Mismatches the Single-Sample Window Surface. FOV Is a
-	// Perspective-Only Concept and Likewise Belongs to the 3-D Camera
-	mut q_camera: Query<(&mut Msaa, &mut Projection), With<Camera3d>>,
+	// Camera's Depth Never Mismatches the Single-Sample Window Surface. FOV Is
+	// Applied Separately by 'apply_camera_fov'
+	mut q_camera: Query<&mut Msaa, With<Camera3d>>,
 ) {
+	let resolution = windowed_resolution_that_fits(settings.resolution, largest_monitor_mode(&q_monitors));
+	if settings.display_mode == DisplayMode::Windowed && resolution != settings.resolution {
+		warn!(
+			"Saved Resolution {}x{} Is Not Available, Opening at {}x{}",
+			settings.resolution.0, settings.resolution.1, resolution.0, resolution.1
+		);
+	}
+
 	if let Some(mut window) = q_window.iter_mut().next() {
 		window.present_mode = desired_present_mode(&settings);
 		window.mode = desired_window_mode(&settings, &q_monitors);
@@ -865,18 +1450,14 @@ fn apply_video_settings_startup(
 			// the Display Scale Factor - on a 2x-DPI Display That Makes the Window
 			// Twice the Intended Size, Desyncing the Depth and Color Attachment
 			// Sizes and Crashing the Renderer (Only on Non-1x DPI, i.e. Windows)
-			let (w, h) = settings.resolution;
+			let (w, h) = resolution;
 			window.resolution.set_physical_resolution(w, h);
 		}
 	}
 
 	let msaa = desired_msaa(&settings);
-	let want_fov = settings.fov_radians();
-	for (mut cam_msaa, mut projection) in q_camera.iter_mut() {
+	for mut cam_msaa in q_camera.iter_mut() {
 		*cam_msaa = msaa;
-		if let Projection::Perspective(ref mut persp) = *projection {
-			persp.fov = want_fov;
-		}
 	}
 }
 
@@ -887,10 +1468,10 @@ fn apply_video_settings_on_change(
 	settings: Res<VideoSettings>,
 	q_monitors: Query<&Monitor>,
 	mut q_window: Query<&mut Window, With<PrimaryWindow>>,
-	// See 'apply_video_settings_startup': MSAA/FOV Target the 3-D World Camera
+	// See 'apply_video_settings_startup': MSAA Targets the 3-D World Camera
 	// Only, so Toggling MSAA On From the Menu Can Never Force the None-Clear 2-D
 	// Menu Camera to a Multisampled Depth That the Window Surface Cannot Match
-	mut q_camera: Query<(&mut Msaa, &mut Projection), With<Camera3d>>,
+	mut q_camera: Query<&mut Msaa, With<Camera3d>>,
 	// Remembers the Last 'WindowMode' We *Requested*, so We Can Detect a
 	// Change Even When Only the Fullscreen 'VideoMode' Differs (Both Variants
 	// Are 'WindowMode::Fullscreen'). Tracking Our Own Request Instead of
@@ -925,7 +1506,8 @@ fn apply_video_settings_on_change(
 			// Setting via '.set()' Applies the Display Scale Factor Twice and, on a
 			// 2x-DPI Display, Sizes the Window to Twice the Requested Pixels -
 			// Desyncing Depth vs Color and Crashing the Renderer on the Switch
-			let (w, h) = settings.resolution;
+			// The Same Fit Check as Startup Keeps This First Pass From Undoing it
+			let (w, h) = windowed_resolution_that_fits(settings.resolution, largest_monitor_mode(&q_monitors));
 			let (cur_w, cur_h) = (
 				window.resolution.physical_width(),
 				window.resolution.physical_height(),
@@ -937,15 +1519,31 @@ fn apply_video_settings_on_change(
 	}
 
 	let msaa = desired_msaa(&settings);
-	let want_fov = settings.fov_radians();
-	for (mut cam_msaa, mut projection) in q_camera.iter_mut() {
+	for mut cam_msaa in q_camera.iter_mut() {
 		if *cam_msaa != msaa {
 			*cam_msaa = msaa;
 		}
+	}
+}
+
+/// Keep the 3-D Camera's Vertical FOV in Sync With 'VideoSettings'
+/// Runs Every Frame Rather Than on Settings Change Because Horizontal Mode
+/// Depends on the View's Aspect Ratio, Which Bevy Updates on Window Resize,
+/// Render Scale, and View Size Changes. Also Covers Cameras Respawned by a
+/// Level Rebuild. Only Writes When the Value Differs, so Projection Change
+/// Detection Stays Quiet
+fn apply_camera_fov(
+	settings: Res<VideoSettings>,
+	mut q_camera: Query<&mut Projection, With<Camera3d>>,
+) {
+	for mut projection in q_camera.iter_mut() {
+		let Projection::Perspective(persp) = &*projection else { continue; };
+		let want_fov = settings.vertical_fov_radians(persp.aspect_ratio);
+		if (persp.fov - want_fov).abs() <= 0.001 {
+			continue;
+		}
 		if let Projection::Perspective(ref mut persp) = *projection {
-			if (persp.fov - want_fov).abs() > 0.001 {
-				persp.fov = want_fov;
-			}
+			persp.fov = want_fov;
 		}
 	}
 }
@@ -1039,10 +1637,8 @@ fn apply_view_size_on_change(
 		return;
 	}
 
-	// Inset Fraction: at view_size 5 Inset ~47%, at 19 Inset ~3%
-	// Linear Mapping: Fraction = (20 - view_size) / 32
-	// This Gives a Subtle Border at 19 and Large Border at 4
-	let inset_frac = (20.0 - vs) / 32.0;
+	// Inset Fraction per Side: at view_size 5 Inset 37.5%, at 19 Inset 2.5%
+	let inset_frac = settings.view_inset_frac();
 
 	let inset_x = (cv_w as f32 * inset_frac).round() as u32;
 	let inset_y = (view_h as f32 * inset_frac).round() as u32;
@@ -1076,13 +1672,15 @@ fn apply_sound_settings_startup(
 ///  'master_volume'  -> Written to 'GlobalVolume'
 ///  'music_volume'   -> Written to Every 'AudioSink' Tagged 'MusicTrack'
 ///  'sfx_volume'     -> Written to Every 'AudioSink' Tagged 'SfxSound'
+///  'ui_volume'      -> Written to Every 'AudioSink' Tagged 'UiSound'
 ///  'music_enabled'  -> Pause / Unpause Music Sinks
 ///  'sfx_enabled'    -> (Checked at *Play Time* by SFX Systems)
 fn apply_sound_settings_on_change(
 	settings: Res<SoundSettings>,
 	mut global_vol: ResMut<GlobalVolume>,
 	mut q_music: Query<&mut AudioSink, (With<MusicTrack>, Without<SfxSound>)>,
-	mut q_sfx:   Query<&mut AudioSink, (With<SfxSound>, Without<MusicTrack>)>,
+	mut q_sfx:   Query<&mut AudioSink, (With<SfxSound>, Without<MusicTrack>, Without<UiSound>)>,
+	mut q_ui:    Query<&mut AudioSink, (With<UiSound>, Without<MusicTrack>, Without<SfxSound>)>,
 ) {
 	if !settings.is_changed() {
 		return;
@@ -1106,6 +1704,11 @@ fn apply_sound_settings_on_change(
 	for mut sink in q_sfx.iter_mut() {
 		sink.set_volume(Volume::Linear(settings.sfx_volume));
 	}
+
+	// UI Sinks (Menu Sounds Still Playing)
+	for mut sink in q_ui.iter_mut() {
+		sink.set_volume(Volume::Linear(settings.ui_volume));
+	}
 }
 
 //  CONTROLS: Apply Systems
@@ -1135,6 +1738,26 @@ fn apply_control_settings_on_change(
 //  Public Helpers for Player Controller
 #[allow(dead_code)]
 impl ControlSettings {
+	/// Range Both Look Sensitivities Are Held to, in the Menu and on Load
+	pub const SENSITIVITY_MIN: f32 = 0.1;
+	pub const SENSITIVITY_MAX: f32 = 10.0;
+
+	/// Sensitivity After `steps` Menu Nudges of 0.1. Snapped to Tenths so a Long
+	/// Hold Never Accumulates Float Drift Into the Saved Value
+	pub fn nudge_sensitivity(value: f32, steps: i32) -> f32 {
+		let tenths = (value * 10.0).round() as i32 + steps;
+		(tenths as f32 / 10.0).clamp(Self::SENSITIVITY_MIN, Self::SENSITIVITY_MAX)
+	}
+
+	/// Loaded Sensitivity Held to Range, a Non-Number Falls Back to 1.0
+	pub fn sanitize_sensitivity(value: f32) -> f32 {
+		if value.is_finite() {
+			value.clamp(Self::SENSITIVITY_MIN, Self::SENSITIVITY_MAX)
+		} else {
+			1.0
+		}
+	}
+
 	/// Returns Sensitivity Scaled, Invert Aware Look Delta
 	/// From Raw 'MouseMotion' Input. Feed Result Straight
 	/// into Camera Yaw / Pitch
@@ -1173,12 +1796,28 @@ impl VideoSettings {
 		self.fov.clamp(40.0, 120.0).to_radians()
 	}
 
+	/// Vertical FOV in *Radians* for a 3-D View of the Given Aspect Ratio
+	/// Vertical Mode Ignores the Aspect; Horizontal Mode Derives it
+	pub fn vertical_fov_radians(&self, aspect: f32) -> f32 {
+		match self.fov_mode {
+			FovMode::Vertical => self.fov_radians(),
+			FovMode::Horizontal => vertical_fov_from_horizontal(self.fov_radians(), aspect),
+		}
+	}
+
 	/// Nudge FOV by `delta` Degrees, Clamped to 40..=120
 	pub fn nudge_fov(&mut self, delta: f32) {
 		self.fov = (self.fov + delta).clamp(40.0, 120.0);
 	}
 
-	/// Nudge View Size by `delta`, Clamped to 4..=20
+	/// Share of the Play Area's Width and Height Given to the Border on Each
+	/// Side of the 3-D View. The Original's View Is view_size * 16 Pixels of
+	/// 320 Wide, so 20 Fills the Area and 5 Leaves a Quarter-Size Window
+	pub fn view_inset_frac(&self) -> f32 {
+		(20.0 - self.view_size.clamp(5, 20) as f32) / 40.0
+	}
+
+	/// Nudge View Size by `delta`, Clamped to 5..=20
 	pub fn nudge_view_size(&mut self, delta: i8) {
 		let new_val = (self.view_size as i16 + delta as i16).clamp(5, 20) as u8;
 		self.view_size = new_val;
@@ -1214,4 +1853,164 @@ impl SoundSettings {
 	pub fn effective_music_volume(&self) -> f32 {
 		self.music_volume
 	}
+
+	/// Effective Linear Volume to set on *NEW* Menu / UI 'PlaybackSettings'
+	pub fn effective_ui_volume(&self) -> f32 {
+		self.ui_volume
+	}
+
+	/// Volume Scalar for Whichever Bus a Sound Routes Through
+	pub fn bus_volume(&self, bus: AudioBus) -> f32 {
+		match bus {
+			AudioBus::Music => self.effective_music_volume(),
+			AudioBus::Sfx => self.effective_sfx_volume(),
+			AudioBus::Ui => self.effective_ui_volume(),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn sensitivity_nudges_land_on_tenths_and_stay_in_range() {
+		let mut v = 1.0;
+		for _ in 0..37 {
+			v = ControlSettings::nudge_sensitivity(v, 1);
+		}
+		assert_eq!(v, 4.7);
+		assert_eq!(ControlSettings::nudge_sensitivity(0.2, -5), ControlSettings::SENSITIVITY_MIN);
+		assert_eq!(ControlSettings::nudge_sensitivity(9.9, 40), ControlSettings::SENSITIVITY_MAX);
+
+		assert_eq!(ControlSettings::sanitize_sensitivity(250.0), ControlSettings::SENSITIVITY_MAX);
+		assert_eq!(ControlSettings::sanitize_sensitivity(f32::NAN), 1.0);
+	}
+
+	fn deg(rad: f32) -> f32 {
+		rad.to_degrees()
+	}
+
+	#[test]
+	fn integer_scale_snaps_to_whole_multiples_of_320x200() {
+		for (win, n) in [((1920, 1080), 5), ((1024, 768), 3), ((2560, 1440), 7), ((640, 400), 2)] {
+			let region = integer_scale_region(win.0, win.1);
+			assert_eq!(region, INTEGER_SCALE_BASE * n, "window {win:?}");
+			assert!(region.x <= win.0 && region.y <= win.1);
+		}
+
+		// Below 1x There Is No Multiple, the Window Is Used as Is
+		assert_eq!(integer_scale_region(300, 180), UVec2::new(300, 180));
+
+		// Off Fills the Window Again
+		assert_eq!(game_region(1920, 1080, false), UVec2::new(1920, 1080));
+	}
+
+	#[test]
+	fn horizontal_fov_converts_to_expected_vertical() {
+		// 90 Degrees Across at 16:9 -> 2 * atan(9 / 16)
+		let v = vertical_fov_from_horizontal(90f32.to_radians(), 16.0 / 9.0);
+		assert!((deg(v) - 58.7155).abs() < 0.01);
+
+		// 90 Degrees Across at 4:3 -> 2 * atan(3 / 4)
+		let v = vertical_fov_from_horizontal(90f32.to_radians(), 4.0 / 3.0);
+		assert!((deg(v) - 73.7398).abs() < 0.01);
+	}
+
+	#[test]
+	fn extreme_aspect_is_clamped() {
+		let hfov = 90f32.to_radians();
+		let ultra = vertical_fov_from_horizontal(hfov, 10.0);
+		let cap = vertical_fov_from_horizontal(hfov, 32.0 / 9.0);
+		assert!((ultra - cap).abs() < 1e-6);
+
+		let tall = vertical_fov_from_horizontal(hfov, 0.1);
+		let floor = vertical_fov_from_horizontal(hfov, 0.75);
+		assert!((tall - floor).abs() < 1e-6);
+	}
+
+	#[test]
+	fn smallest_view_is_a_quarter_of_the_play_area() {
+		let mut video = VideoSettings::default();
+		assert_eq!(video.view_inset_frac(), 0.0);
+
+		video.view_size = 5;
+		assert_eq!(1.0 - 2.0 * video.view_inset_frac(), 0.25);
+
+		// Out of Range Values Clamp Like the Menu
+		video.view_size = 1;
+		assert_eq!(1.0 - 2.0 * video.view_inset_frac(), 0.25);
+	}
+
+	#[test]
+	fn vertical_mode_ignores_aspect() {
+		let video = VideoSettings { fov: 60.0, fov_mode: FovMode::Vertical, ..Default::default() };
+		assert_eq!(video.vertical_fov_radians(3.0), 60f32.to_radians());
+	}
+	#[test]
+	fn fps_cap_is_ignored_with_vsync_on() {
+		let mut video = VideoSettings { fps_cap: FpsCap::Fps60, vsync: true, ..Default::default() };
+		assert_eq!(video.frame_cap_duration(), None);
+
+		video.vsync = false;
+		let frame = video.frame_cap_duration().expect("cap applies with vsync off");
+		assert!((frame.as_secs_f64() - 1.0 / 60.0).abs() < 1e-9);
+
+		video.fps_cap = FpsCap::Off;
+		assert_eq!(video.frame_cap_duration(), None);
+	}
+
+	#[test]
+	fn saved_window_size_falls_back_when_the_monitor_cannot_hold_it() {
+		let default = VideoSettings::default().resolution;
+		assert_eq!(windowed_resolution_that_fits((1280, 720), Some((1920, 1080))), (1280, 720));
+		assert_eq!(windowed_resolution_that_fits((2560, 1440), Some((1920, 1080))), default);
+		assert_eq!(windowed_resolution_that_fits((0, 720), None), default);
+
+		// No Monitor Modes Reported Keeps Whatever Was Saved
+		assert_eq!(windowed_resolution_that_fits((3840, 2160), None), (3840, 2160));
+	}
+
+	#[test]
+	fn startup_shrinks_the_window_but_keeps_the_saved_size() {
+		let saved = VideoSettings {
+			display_mode: DisplayMode::Windowed,
+			resolution: (2560, 1440),
+			..default()
+		};
+
+		let mut app = App::new();
+		app.insert_resource(saved)
+			.add_systems(Update, apply_video_settings_startup);
+		app.world_mut().spawn(Monitor {
+			name: None,
+			physical_height: 1080,
+			physical_width: 1920,
+			physical_position: IVec2::ZERO,
+			refresh_rate_millihertz: Some(60_000),
+			scale_factor: 1.0,
+			video_modes: vec![VideoMode {
+				physical_size: UVec2::new(1920, 1080),
+				bit_depth: 32,
+				refresh_rate_millihertz: 60_000,
+			}],
+		});
+		let window = app.world_mut().spawn((Window::default(), PrimaryWindow)).id();
+		app.update();
+
+		let window = app.world().get::<Window>(window).unwrap();
+		assert_eq!(
+			(window.resolution.physical_width(), window.resolution.physical_height()),
+			VideoSettings::default().resolution,
+		);
+		assert_eq!(window.mode, WindowMode::Windowed);
+		assert_eq!(app.world().resource::<VideoSettings>().resolution, saved.resolution);
+	}
+
+	#[test]
+	fn embedded_window_icon_decodes_to_square_rgba() {
+		let (rgba, w, h) = window_icon_rgba().expect("embedded icon decodes");
+		assert_eq!((w, h), (256, 256));
+		assert_eq!(rgba.len(), (w * h * 4) as usize);
+	}
 }
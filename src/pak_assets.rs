@@ -106,6 +106,9 @@ impl Plugin for PakAssetsPlugin {
 			}
 		};
 
+		// Expose Package Index so Startup Asset Validation Checks the Package
+		app.insert_resource(PakFileIndex(inner.clone()));
+
 		// Replace Bevy Default Asset Source With DVPK-Backed Asset Reader
 		app.register_asset_source(
 			AssetSourceId::Default,
@@ -114,6 +117,17 @@ impl Plugin for PakAssetsPlugin {
 	}
 }
 
+// Present Only When Assets are Served From a DVPK Package
+#[derive(Resource, Clone)]
+pub struct PakFileIndex(Arc<PakInner>);
+
+impl PakFileIndex {
+	// True When Package Index Holds the Given Relative Asset Path
+	pub fn contains(&self, path: &str) -> bool {
+		self.0.files.contains_key(norm_path(Path::new(path)).as_str())
+	}
+}
+
 // Resolves Explicit Package Path Before Falling Back to Executable Directory
 fn resolve_pak_path() -> Option<PathBuf> {
 	if let Some(p) = std::env::var_os("DAVENSTEIN_PAK_PATH") {
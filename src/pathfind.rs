@@ -0,0 +1,181 @@
+/*
+Davenstein - by David Petnick
+
+Grid Pathfinding
+
+Breadth-First Search Over the Tile Grid With Four-Way Steps. Walls, Closed
+Doors and Blocking Statics Are Impassable, so a Path Only Ever Crosses Open
+Floor and Doors Already Standing Open. Every Step Costs the Same, so the First
+Route BFS Reaches Is a Shortest One
+*/
+
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+
+use crate::decorations::SolidStatics;
+use crate::map::{MapGrid, Tile};
+
+const DIRS: [IVec2; 4] = [
+    IVec2::new(1, 0),
+    IVec2::new(-1, 0),
+    IVec2::new(0, 1),
+    IVec2::new(0, -1),
+];
+
+fn in_bounds(grid: &MapGrid, t: IVec2) -> bool {
+    t.x >= 0 && t.y >= 0 && (t.x as usize) < grid.width && (t.y as usize) < grid.height
+}
+
+/// Whether an Actor Can Stand on `t` Without Opening Anything
+pub fn is_walkable(grid: &MapGrid, solid: &SolidStatics, t: IVec2) -> bool {
+    in_bounds(grid, t)
+        && matches!(grid.tile(t.x as usize, t.y as usize), Tile::Empty | Tile::DoorOpen)
+        && !solid.is_solid(t.x, t.y)
+}
+
+/// Shortest Four-Way Route From `start` to `goal`: the Tiles to Step Onto in
+/// Order, Ending on the Goal and Excluding the Start (Empty When Already There).
+/// A Blocked Goal Is Approached Instead, the Route Ending on an Open Tile Beside
+/// it. None When Nothing Connects the Two
+pub fn find_path(grid: &MapGrid, solid: &SolidStatics, start: IVec2, goal: IVec2) -> Option<Vec<IVec2>> {
+    if !in_bounds(grid, start) || !in_bounds(grid, goal) {
+        return None;
+    }
+
+    let goal_open = is_walkable(grid, solid, goal);
+    let arrived = |t: IVec2| {
+        if goal_open {
+            t == goal
+        } else {
+            (t - goal).abs().element_sum() == 1
+        }
+    };
+    if arrived(start) {
+        return Some(Vec::new());
+    }
+
+    let w = grid.width as i32;
+    let idx = |t: IVec2| (t.y * w + t.x) as usize;
+
+    // Tile Each Visited Tile Was Reached From, the Start Points at Itself
+    let mut came_from: Vec<Option<IVec2>> = vec![None; grid.width * grid.height];
+    came_from[idx(start)] = Some(start);
+
+    let mut queue = VecDeque::from([start]);
+    while let Some(cur) = queue.pop_front() {
+        for step in DIRS {
+            let next = cur + step;
+            if !is_walkable(grid, solid, next) || came_from[idx(next)].is_some() {
+                continue;
+            }
+            came_from[idx(next)] = Some(cur);
+
+            if arrived(next) {
+                let mut path = vec![next];
+                let mut at = cur;
+                while at != start {
+                    path.push(at);
+                    at = came_from[idx(at)]?;
+                }
+                path.reverse();
+                return Some(path);
+            }
+            queue.push_back(next);
+        }
+    }
+
+    None
+}
+
+/// A Route Cached on a Chasing Actor. Searching Is Cheap but not Free, so the
+/// Route Is Only Rebuilt Every REPATH_STEPS or Once the Actor Strays off it
+#[derive(Component, Debug, Clone, Default)]
+pub struct ChasePath {
+    tiles: Vec<IVec2>,
+    steps: u32,
+}
+
+impl ChasePath {
+    /// Steps Taken Along a Route Before it Is Searched Again, so it Bends
+    /// Toward a Player Who Has Moved Since. Counted in Tile Decisions, Not Tics
+    pub const REPATH_STEPS: u32 = 4;
+
+    /// The Tile to Step Onto Next From `from` on the Way to `goal`. Called Each
+    /// Time the Actor Stands on a Tile and Picks its Next One, Never Mid-Step
+    /// None When the Goal Is Unreachable or Already Reached
+    pub fn next_step(&mut self, grid: &MapGrid, solid: &SolidStatics, from: IVec2, goal: IVec2) -> Option<IVec2> {
+        self.steps = self.steps.saturating_add(1);
+        if self.tiles.first() == Some(&from) {
+            self.tiles.remove(0);
+        }
+
+        let on_route = self.tiles.first().is_some_and(|&t| (t - from).abs().element_sum() == 1);
+        if !on_route || self.steps >= Self::REPATH_STEPS {
+            self.tiles = find_path(grid, solid, from, goal).unwrap_or_default();
+            self.steps = 0;
+        }
+
+        self.tiles.first().copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn map(lines: &[&str]) -> (MapGrid, SolidStatics) {
+        let (grid, _, _) = MapGrid::from_ascii(lines);
+        let solid = SolidStatics::new(grid.width, grid.height);
+        (grid, solid)
+    }
+
+    #[test]
+    fn path_goes_around_walls_statics_and_closed_doors() {
+        let (grid, mut solid) = map(&[
+            "#######",
+            "#..D..#",
+            "#.###.#",
+            "#.....#",
+            "#######",
+        ]);
+
+        // The Door Between the Top Rooms Is Shut, so the Route Loops Below
+        let path = find_path(&grid, &solid, IVec2::new(1, 1), IVec2::new(5, 1)).unwrap();
+        assert_eq!(path.len(), 8);
+        assert_eq!(path.last(), Some(&IVec2::new(5, 1)));
+        assert!(path.windows(2).all(|p| (p[1] - p[0]).abs().element_sum() == 1));
+
+        // A Decoration Across the Lower Corridor Cuts the Only Way Round
+        solid.set_solid(3, 3, true);
+        assert_eq!(find_path(&grid, &solid, IVec2::new(1, 1), IVec2::new(5, 1)), None);
+
+        assert_eq!(find_path(&grid, &solid, IVec2::new(1, 1), IVec2::new(1, 1)), Some(Vec::new()));
+    }
+
+    #[test]
+    fn blocked_goal_is_approached_from_an_open_neighbor() {
+        let (grid, solid) = map(&["#####", "#..D#", "#####"]);
+
+        let path = find_path(&grid, &solid, IVec2::new(1, 1), IVec2::new(3, 1)).unwrap();
+        assert_eq!(path, vec![IVec2::new(2, 1)]);
+
+        // A Wall With no Open Neighbor at All Has no Way in
+        assert_eq!(find_path(&grid, &solid, IVec2::new(1, 1), IVec2::new(0, 0)), None);
+    }
+
+    #[test]
+    fn chase_path_follows_its_route_and_rebuilds_when_strayed() {
+        let (grid, solid) = map(&["######", "#....#", "#....#", "######"]);
+        let goal = IVec2::new(4, 1);
+        let mut route = ChasePath::default();
+
+        let first = route.next_step(&grid, &solid, IVec2::new(1, 1), goal).unwrap();
+        let second = route.next_step(&grid, &solid, first, goal).unwrap();
+        assert_eq!((second - first).abs().element_sum(), 1);
+
+        // Pushed Somewhere Else the Actor Gets a Fresh Route From There
+        let elsewhere = IVec2::new(4, 2);
+        assert_eq!(route.next_step(&grid, &solid, elsewhere, goal), Some(goal));
+    }
+}
@@ -10,12 +10,20 @@ use bevy::diagnostic::{
 use bevy::prelude::*;
 
 pub const PERF_OVERLAY_TOGGLE_KEY: KeyCode = KeyCode::F3;
+pub const STRESS_MAP_TOGGLE_KEY: KeyCode = KeyCode::F10;
+
+/// When True world::setup Builds the Bundled Stress Map (One Large Open Room
+/// Packed With Guards and Pickups) Instead of the Current Level, Giving a Fixed
+/// Heavy Scene for Before / After Numbers on the Perf Overlay
+#[derive(Resource, Debug, Clone, Copy, Default)]
+pub struct StressMap(pub bool);
 
 pub struct PerfOverlayPlugin;
 
 impl Plugin for PerfOverlayPlugin {
 	fn build(&self, app: &mut App) {
 		app.init_resource::<PerfOverlayState>()
+			.init_resource::<StressMap>()
 			.add_plugins((
 				FrameTimeDiagnosticsPlugin::default(),
 				EntityCountDiagnosticsPlugin::default(),
@@ -155,7 +163,7 @@ fn toggle_perf_overlay(
 }
 
 fn update_perf_overlay_text(
-	time: Res<Time>,
+	time: Res<Time<Real>>,
 	mut state: ResMut<PerfOverlayState>,
 	diagnostics: Res<DiagnosticsStore>,
 	mut spans: ParamSet<(
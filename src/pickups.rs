@@ -8,6 +8,7 @@ use std::f32::consts::FRAC_PI_2;
 use crate::combat::WeaponSlot;
 use crate::ui::HudState;
 use davelib::audio::{PlaySfx, SfxKind};
+use davelib::decorations::{SpriteAnchor, SpriteScales};
 use davelib::enemies::{
     GuardCorpse,
     MutantCorpse,
@@ -16,7 +17,8 @@ use davelib::enemies::{
     HansCorpse,
     GretelCorpse,
 };
-use davelib::player::Player;
+use davelib::map::{MapGrid, Tile};
+use davelib::player::{cheat_chord, CheatState, Player};
 
 // Ammo Pickup Amounts
 #[allow(dead_code)]
@@ -26,17 +28,20 @@ const OFFICER_DROP_AMMO_ROUNDS: i32 = 4;
 const MUTANT_DROP_AMMO_ROUNDS: i32 = 4;
 
 const AMMO_MAX: i32 = 99;
+// Original Leaves Ammo on the Floor When Full, Set True to Always Take It
+const AMMO_PICKUP_AT_CAP: bool = false;
 
 // Visual Size, Height in World Units
 // Width Derived From Sprite Aspect
-const PICKUP_H: f32 = 0.28;
-const AMMO_H: f32 = 0.22;
-const HEALTH_FIRST_AID_H: f32 = 0.18;
-const HEALTH_DINNER_H: f32 = 0.18;
-const HEALTH_DOGFOOD_H: f32 = AMMO_H;
-const ONEUP_H: f32 = 0.50;
-const TREASURE_H: f32 = 0.24;
-const KEY_H: f32 = 0.42;
+// Quads Are Built at the Classic Heights, SpriteScales Tunes Them Live
+const PICKUP_H: f32 = SpriteScales::CLASSIC.weapon_h;
+const AMMO_H: f32 = SpriteScales::CLASSIC.ammo_h;
+const HEALTH_FIRST_AID_H: f32 = SpriteScales::CLASSIC.first_aid_h;
+const HEALTH_DINNER_H: f32 = SpriteScales::CLASSIC.dinner_h;
+const HEALTH_DOGFOOD_H: f32 = SpriteScales::CLASSIC.dog_food_h;
+const ONEUP_H: f32 = SpriteScales::CLASSIC.oneup_h;
+const TREASURE_H: f32 = SpriteScales::CLASSIC.treasure_h;
+const KEY_H: f32 = SpriteScales::CLASSIC.key_h;
 
 const HEALTH_FIRST_AID_W_SCALE: f32 = 3.6;
 const HEALTH_DINNER_W_SCALE: f32 = 4.0;
@@ -174,6 +179,35 @@ fn key_size() -> (f32, f32) {
     (KEY_H, KEY_H)
 }
 
+fn pickup_size(kind: PickupKind) -> (f32, f32) {
+    match kind {
+        PickupKind::Weapon(slot) => weapon_pickup_size(slot),
+        PickupKind::Ammo { .. } => ammo_size(),
+        PickupKind::Treasure(t) => treasure_size(t),
+        PickupKind::Health(hk) => health_pickup_size(hk),
+        PickupKind::ExtraLife => oneup_size(),
+        PickupKind::Key(_) => key_size(),
+    }
+}
+
+fn pickup_height(kind: PickupKind, scales: &SpriteScales) -> f32 {
+    match kind {
+        PickupKind::Weapon(_) => scales.weapon_h,
+        PickupKind::Ammo { .. } => scales.ammo_h,
+        PickupKind::Treasure(_) => scales.treasure_h,
+        PickupKind::Health(HealthKind::FirstAid) => scales.first_aid_h,
+        PickupKind::Health(HealthKind::Dinner) => scales.dinner_h,
+        PickupKind::Health(HealthKind::DogFood) => scales.dog_food_h,
+        PickupKind::ExtraLife => scales.oneup_h,
+        PickupKind::Key(_) => scales.key_h,
+    }
+}
+
+/// Scale Factor for a Pickup Quad Built at Its Classic Size
+fn pickup_scale_factor(kind: PickupKind, scales: &SpriteScales) -> f32 {
+    pickup_height(kind, scales) / pickup_height(kind, &SpriteScales::CLASSIC)
+}
+
 fn weapon_pickup_texture(w: WeaponSlot) -> &'static str {
     match w {
         WeaponSlot::Chaingun => "textures/pickups/chaingun.png",
@@ -218,6 +252,53 @@ fn world_to_tile_xz(pos_xz: Vec2) -> IVec2 {
     IVec2::new((pos_xz.x + 0.5).floor() as i32, (pos_xz.y + 0.5).floor() as i32)
 }
 
+/// Tile a Corpse's Loot Lands On: the Tile the Actor Died On When That Is Open
+/// Floor, Otherwise the Nearest Open Neighbor (Ring by Ring) so a Drop Never
+/// Ends Up Inside a Wall or Door Where it Could Not Be Collected
+fn corpse_drop_tile(grid: &MapGrid, corpse_pos: Vec3) -> IVec2 {
+    const SEARCH_RADIUS: i32 = 2;
+
+    let tile = world_to_tile_xz(Vec2::new(corpse_pos.x, corpse_pos.z));
+    let open = |t: IVec2| {
+        t.x >= 0
+            && t.y >= 0
+            && (t.x as usize) < grid.width
+            && (t.y as usize) < grid.height
+            && grid.tile(t.x as usize, t.y as usize) == Tile::Empty
+    };
+
+    if open(tile) {
+        return tile;
+    }
+
+    for r in 1..=SEARCH_RADIUS {
+        let mut best: Option<IVec2> = None;
+        for dz in -r..=r {
+            for dx in -r..=r {
+                if dx.abs().max(dz.abs()) != r {
+                    continue;
+                }
+                let t = tile + IVec2::new(dx, dz);
+                if !open(t) {
+                    continue;
+                }
+                let d = Vec2::new(t.x as f32 - corpse_pos.x, t.y as f32 - corpse_pos.z).length_squared();
+                let closer = best.is_none_or(|b| {
+                    d < Vec2::new(b.x as f32 - corpse_pos.x, b.y as f32 - corpse_pos.z).length_squared()
+                });
+                if closer {
+                    best = Some(t);
+                }
+            }
+        }
+        if let Some(t) = best {
+            return t;
+        }
+    }
+
+    tile
+}
+
 fn pickup_base_rot() -> Quat {
     Quat::from_rotation_x(FRAC_PI_2)
 }
@@ -276,31 +357,14 @@ fn spawn_pickup_entity_cached(
     const DEPTH_BIAS: f32 = -250.0;
     const DROP_Y_LIFT: f32 = 0.01;
 
-    let (w, h, tex_path) = match kind {
-        PickupKind::Weapon(slot) => {
-            let (w, h) = weapon_pickup_size(slot);
-            (w, h, weapon_pickup_texture(slot))
-        }
-        PickupKind::Ammo { .. } => {
-            let (w, h) = ammo_size();
-            (w, h, ammo_texture())
-        }
-        PickupKind::Treasure(t) => {
-            let (w, h) = treasure_size(t);
-            (w, h, treasure_texture(t))
-        }
-        PickupKind::Health(hk) => {
-            let (w, h) = health_pickup_size(hk);
-            (w, h, health_texture(hk))
-        }
-        PickupKind::ExtraLife => {
-            let (w, h) = oneup_size();
-            (w, h, oneup_texture())
-        }
-        PickupKind::Key(k) => {
-            let (w, h) = key_size();
-            (w, h, key_texture(k))
-        }
+    let (w, h) = pickup_size(kind);
+    let tex_path = match kind {
+        PickupKind::Weapon(slot) => weapon_pickup_texture(slot),
+        PickupKind::Ammo { .. } => ammo_texture(),
+        PickupKind::Treasure(t) => treasure_texture(t),
+        PickupKind::Health(hk) => health_texture(hk),
+        PickupKind::ExtraLife => oneup_texture(),
+        PickupKind::Key(k) => key_texture(k),
     };
 
     // Reuse One Mesh Per Size and One Material Per Texture Across the Whole Map Load
@@ -326,7 +390,7 @@ fn spawn_pickup_entity_cached(
         })
         .clone();
 
-    let y = (h * 0.5) + if dropped { DROP_Y_LIFT } else { 0.0 };
+    let y = SpriteAnchor::Feet.center_y(h) + if dropped { DROP_Y_LIFT } else { 0.0 };
 
     let mut entity = commands.spawn((
         Name::new(name),
@@ -426,6 +490,7 @@ pub fn drop_guard_ammo(
     asset_server: Res<AssetServer>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
+    grid: Res<MapGrid>,
     q_corpses: Query<(Entity, &GlobalTransform), (With<GuardCorpse>, Without<DroppedLoot>)>,
 ) {
     // Depth Tweak: with AlphaMode::Mask this Will Actually Affect Depth Testing
@@ -440,7 +505,7 @@ pub fn drop_guard_ammo(
 
         // Drop at the Corpse Tile
         let p = gt.translation();
-        let tile = world_to_tile_xz(Vec2::new(p.x, p.z));
+        let tile = corpse_drop_tile(&grid, p);
 
         let rounds = GUARD_DROP_AMMO_ROUNDS;
 
@@ -464,7 +529,7 @@ pub fn drop_guard_ammo(
             ..default()
         });
 
-        let y = (h * 0.5) + DROP_Y_LIFT;
+        let y = SpriteAnchor::Feet.center_y(h) + DROP_Y_LIFT;
 
         commands.spawn((
             Name::new("Pickup_Drop_Ammo"),
@@ -487,6 +552,7 @@ pub fn drop_mutant_ammo(
     asset_server: Res<AssetServer>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
+    grid: Res<MapGrid>,
     q_corpses: Query<(Entity, &GlobalTransform), (With<MutantCorpse>, Without<DroppedLoot>)>,
 ) {
     // Depth Tweak: with AlphaMode::Mask this Will Actually Affect Depth Testing
@@ -501,7 +567,7 @@ pub fn drop_mutant_ammo(
 
         // Drop at the Corpse Tile
         let p = gt.translation();
-        let tile = world_to_tile_xz(Vec2::new(p.x, p.z));
+        let tile = corpse_drop_tile(&grid, p);
 
         let rounds = MUTANT_DROP_AMMO_ROUNDS;
 
@@ -525,7 +591,7 @@ pub fn drop_mutant_ammo(
             ..default()
         });
 
-        let y = (h * 0.5) + DROP_Y_LIFT;
+        let y = SpriteAnchor::Feet.center_y(h) + DROP_Y_LIFT;
 
         commands.spawn((
             Name::new("Pickup_Drop_Ammo_Mutant"),
@@ -543,11 +609,22 @@ pub fn drop_mutant_ammo(
     }
 }
 
+/// SS Drop a Machine Gun Until the Player Has One or Better (Wolf3D Checks
+/// bestweapon, so Holding the Chaingun Counts), and a Full Clip After That
+fn ss_drop_kind(hud: &HudState) -> PickupKind {
+    if hud.best_owned() as u8 >= WeaponSlot::MachineGun as u8 {
+        PickupKind::Ammo { rounds: MAP_AMMO_ROUNDS }
+    } else {
+        PickupKind::Weapon(WeaponSlot::MachineGun)
+    }
+}
+
 pub fn drop_ss_loot(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
+    grid: Res<MapGrid>,
     hud: Res<HudState>,
     q_corpses: Query<(Entity, &GlobalTransform), (With<SsCorpse>, Without<DroppedLoot>)>,
 ) {
@@ -555,12 +632,7 @@ pub fn drop_ss_loot(
     // Tiny Lift to Avoid Z Fighting With Floor
     const DROP_Y_LIFT: f32 = 0.01;
 
-    let has_machinegun = hud.owns(WeaponSlot::MachineGun);
-    let kind = if has_machinegun {
-        PickupKind::Ammo { rounds: MAP_AMMO_ROUNDS }
-    } else {
-        PickupKind::Weapon(WeaponSlot::MachineGun)
-    };
+    let kind = ss_drop_kind(&hud);
 
     let (w, h, tex_path) = match kind {
         PickupKind::Ammo { .. } => {
@@ -581,7 +653,7 @@ pub fn drop_ss_loot(
 
         // Drop at the Corpse Tile
         let p = gt.translation();
-        let tile = world_to_tile_xz(Vec2::new(p.x, p.z));
+        let tile = corpse_drop_tile(&grid, p);
 
         let quad = meshes.add(Plane3d::default().mesh().size(w, h));
         let tex: Handle<Image> = asset_server.load(tex_path);
@@ -595,7 +667,7 @@ pub fn drop_ss_loot(
             ..default()
         });
 
-        let y = (h * 0.5) + DROP_Y_LIFT;
+        let y = SpriteAnchor::Feet.center_y(h) + DROP_Y_LIFT;
 
         commands.spawn((
             Name::new("Pickup_Drop_SS"),
@@ -615,6 +687,7 @@ pub fn drop_officer_ammo(
     asset_server: Res<AssetServer>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
+    grid: Res<MapGrid>,
     q_corpses: Query<(Entity, &GlobalTransform), (With<OfficerCorpse>, Without<DroppedLoot>)>,
 ) {
     // Depth Tweak: with AlphaMode::Mask this Will Actually Affect Depth Testing
@@ -629,7 +702,7 @@ pub fn drop_officer_ammo(
 
         // Drop at the Corpse Tile
         let p = gt.translation();
-        let tile = world_to_tile_xz(Vec2::new(p.x, p.z));
+        let tile = corpse_drop_tile(&grid, p);
 
         let rounds = OFFICER_DROP_AMMO_ROUNDS;
 
@@ -653,7 +726,7 @@ pub fn drop_officer_ammo(
             ..default()
         });
 
-        let y = (h * 0.5) + DROP_Y_LIFT;
+        let y = SpriteAnchor::Feet.center_y(h) + DROP_Y_LIFT;
 
         commands.spawn((
             Name::new("Pickup_Drop_Ammo_Officer"),
@@ -676,6 +749,7 @@ pub fn drop_hans_key(
     asset_server: Res<AssetServer>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
+    grid: Res<MapGrid>,
     q_corpses: Query<(Entity, &GlobalTransform), (With<HansCorpse>, Without<DroppedLoot>)>,
 ) {
     const DROP_DEPTH_BIAS: f32 = -250.0;
@@ -693,7 +767,7 @@ pub fn drop_hans_key(
 
         // Drop at the Corpse Tile
         let p = gt.translation();
-        let tile = world_to_tile_xz(Vec2::new(p.x, p.z));
+        let tile = corpse_drop_tile(&grid, p);
 
         let quad = meshes.add(Plane3d::default().mesh().size(w, h));
         let tex: Handle<Image> = asset_server.load(tex_path);
@@ -707,7 +781,7 @@ pub fn drop_hans_key(
             ..default()
         });
 
-        let y = (h * 0.5) + DROP_Y_LIFT;
+        let y = SpriteAnchor::Feet.center_y(h) + DROP_Y_LIFT;
 
         commands.spawn((
             Name::new("Pickup_Drop_Hans_Key"),
@@ -727,6 +801,7 @@ pub fn drop_gretel_key(
     asset_server: Res<AssetServer>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
+    grid: Res<MapGrid>,
     q_corpses: Query<(Entity, &GlobalTransform), (With<GretelCorpse>, Without<DroppedLoot>)>,
 ) {
     // Depth Tweak: with AlphaMode::Mask this will actually affect depth testing
@@ -745,7 +820,7 @@ pub fn drop_gretel_key(
 
         // Drop at the Corpse Tile
         let p = gt.translation();
-        let tile = world_to_tile_xz(Vec2::new(p.x, p.z));
+        let tile = corpse_drop_tile(&grid, p);
 
         let quad = meshes.add(Plane3d::default().mesh().size(w, h));
         let tex: Handle<Image> = asset_server.load(tex_path);
@@ -759,7 +834,7 @@ pub fn drop_gretel_key(
             ..default()
         });
 
-        let y = (h * 0.5) + DROP_Y_LIFT;
+        let y = SpriteAnchor::Feet.center_y(h) + DROP_Y_LIFT;
 
         commands.spawn((
             Name::new("Pickup_Drop_Gretel_Key"),
@@ -774,6 +849,29 @@ pub fn drop_gretel_key(
     }
 }
 
+/// Keeps Pickup Quads at the Tuned SpriteScales Heights, Bottom Edge (and
+/// Any Drop Lift) Unchanged
+pub fn apply_pickup_sprite_scales(
+    scales: Res<SpriteScales>,
+    mut q: Query<(Ref<Pickup>, &mut Transform)>,
+) {
+    for (p, mut tf) in q.iter_mut() {
+        if !scales.is_changed() && !p.is_added() {
+            continue;
+        }
+
+        let k = pickup_scale_factor(p.kind, &scales);
+        let old_k = tf.scale.x;
+        if old_k == k {
+            continue;
+        }
+
+        let (_, base_h) = pickup_size(p.kind);
+        tf.translation.y = SpriteAnchor::Feet.rescaled_center_y(tf.translation.y, base_h, old_k, k);
+        tf.scale = Vec3::splat(k);
+    }
+}
+
 pub fn billboard_pickups(
     q_player: Query<&Transform, (With<Player>, Without<Pickup>)>,
     mut q_pickups: Query<(&Pickup, &mut Transform), (With<Pickup>, Without<Player>)>,
@@ -828,6 +926,20 @@ pub fn billboard_pickups(
     }
 }
 
+/// Consume-or-Leave Policy for Every Pickup, Checked Before Collecting
+/// - Weapons Always Collect (Granting Ammo if There's Room)
+/// - Ammo Collects Unless the Player Is at the Cap (See AMMO_PICKUP_AT_CAP)
+/// - Health Collects Only Below Max
+/// - Treasure, Keys and Extra Lives Always Collect
+pub fn can_pickup(kind: PickupKind, hud: &HudState, vitals: &davelib::player::PlayerVitals) -> bool {
+    match kind {
+        PickupKind::Weapon(_) => true,
+        PickupKind::Ammo { .. } => AMMO_PICKUP_AT_CAP || hud.ammo < AMMO_MAX,
+        PickupKind::Health(_) => vitals.hp < vitals.hp_max,
+        PickupKind::Treasure(_) | PickupKind::Key(_) | PickupKind::ExtraLife => true,
+    }
+}
+
 pub fn collect_pickups(
     time: Res<Time>,
     mut key_sfx_block_secs: Local<f32>,
@@ -873,7 +985,16 @@ pub fn collect_pickups(
         return;
     };
 
+    // Several Items on One Tile Each Get Their Own Sound, but Two of the Same
+    // Kind in One Frame Play Once Rather Than Stacking
+    let mut emitted: Vec<SfxKind> = Vec::new();
+
     let mut emit_pickup_sfx = |kind: SfxKind| {
+        if emitted.contains(&kind) {
+            return;
+        }
+        emitted.push(kind);
+
         let msg = PlaySfx {
             kind,
             pos: player_tf.translation,
@@ -910,7 +1031,9 @@ pub fn collect_pickups(
             continue;
         }
 
-        let mut consumed = true;
+        if !can_pickup(p.kind, &hud, &vitals) {
+            continue;
+        }
 
         match p.kind {
             PickupKind::Weapon(w) => {
@@ -920,30 +1043,23 @@ pub fn collect_pickups(
                     _ => None,
                 };
 
-                let already_owns = hud.owns(w);
+                let gain = WEAPON_PICKUP_BULLETS.min(AMMO_MAX - hud.ammo).max(0);
+                hud.ammo += gain;
 
-                if already_owns {
-                    if hud.ammo >= AMMO_MAX {
-                        consumed = false;
-                    } else {
-                        let gain = WEAPON_PICKUP_BULLETS.min(AMMO_MAX - hud.ammo);
-                        hud.ammo += gain;
-
-                        // When Already Own Weapon, Just Give Ammo
-                        emit_pickup_sfx(SfxKind::PickupAmmo);
-                    }
+                if hud.owns(w) {
+                    // When Already Own Weapon, Just Give Ammo
+                    emit_pickup_sfx(SfxKind::PickupAmmo);
                 } else {
                     if let Some(kind) = pickup_sfx {
                         emit_pickup_sfx(kind);
                     }
 
-                    hud.grant(w);
-                    hud.selected = w;
-
-                    if hud.ammo < AMMO_MAX {
-                        let gain = WEAPON_PICKUP_BULLETS.min(AMMO_MAX - hud.ammo);
-                        hud.ammo += gain;
+                    // A Lesser Gun Than One Already Held Joins the Inventory
+                    // Without Pulling the Better One Out of the Player's Hands
+                    if hud.is_upgrade(w) {
+                        hud.selected = w;
                     }
+                    hud.grant(w);
 
                     if w == WeaponSlot::Chaingun {
                         face_ov.active = true;
@@ -953,14 +1069,10 @@ pub fn collect_pickups(
             }
 
             PickupKind::Ammo { rounds } => {
-                if hud.ammo >= AMMO_MAX {
-                    consumed = false;
-                } else {
-                    let gain = rounds.min(AMMO_MAX - hud.ammo);
-                    hud.ammo += gain;
+                let gain = rounds.min(AMMO_MAX - hud.ammo).max(0);
+                hud.ammo += gain;
 
-                    emit_pickup_sfx(SfxKind::PickupAmmo);
-                }
+                emit_pickup_sfx(SfxKind::PickupAmmo);
             }
 
             PickupKind::Treasure(t) => {
@@ -979,24 +1091,20 @@ pub fn collect_pickups(
             }
 
             PickupKind::Health(hk) => {
-                if vitals.hp >= vitals.hp_max {
-                    consumed = false;
-                } else {
-                    let gain = hk.heal().min(vitals.hp_max - vitals.hp);
-                    vitals.hp += gain;
+                let gain = hk.heal().min(vitals.hp_max - vitals.hp);
+                vitals.hp += gain;
 
-                    let kind = match hk {
-                        HealthKind::FirstAid => SfxKind::PickupHealthFirstAid,
-                        HealthKind::Dinner => SfxKind::PickupHealthDinner,
-                        HealthKind::DogFood => SfxKind::PickupHealthDogFood,
-                    };
+                let kind = match hk {
+                    HealthKind::FirstAid => SfxKind::PickupHealthFirstAid,
+                    HealthKind::Dinner => SfxKind::PickupHealthDinner,
+                    HealthKind::DogFood => SfxKind::PickupHealthDogFood,
+                };
 
-                    emit_pickup_sfx(kind);
-                }
+                emit_pickup_sfx(kind);
             }
 
             PickupKind::ExtraLife => {
-                hud.lives += 1;
+                hud.give_extra_life();
                 vitals.hp = vitals.hp_max;
                 hud.ammo = (hud.ammo + 25).min(AMMO_MAX);
 
@@ -1004,65 +1112,206 @@ pub fn collect_pickups(
             }
 
             PickupKind::Key(k) => {
+                match k {
+                    KeyKind::Gold => hud.key_gold = true,
+                    KeyKind::Silver => hud.key_silver = true,
+                }
+
                 match q_pkeys.get_mut(player_e) {
-                    Ok(mut pk) => {
-                        let already = match k {
-                            KeyKind::Gold => pk.gold,
-                            KeyKind::Silver => pk.silver,
-                        };
-
-                        if already {
-                            consumed = false;
-                        } else {
-                            match k {
-                                KeyKind::Gold => {
-                                    pk.gold = true;
-                                    hud.key_gold = true;
-                                }
-                                KeyKind::Silver => {
-                                    pk.silver = true;
-                                    hud.key_silver = true;
-                                }
-                            }
-
-                            emit_pickup_sfx(SfxKind::PickupKey);
-                        }
-                    }
+                    Ok(mut pk) => match k {
+                        KeyKind::Gold => pk.gold = true,
+                        KeyKind::Silver => pk.silver = true,
+                    },
                     Err(_) => {
-                        let already = match k {
-                            KeyKind::Gold => hud.key_gold,
-                            KeyKind::Silver => hud.key_silver,
-                        };
-
-                        if already {
-                            consumed = false;
-                        } else {
-                            match k {
-                                KeyKind::Gold => hud.key_gold = true,
-                                KeyKind::Silver => hud.key_silver = true,
-                            }
-
-                            commands.entity(player_e).insert(match k {
-                                KeyKind::Gold => davelib::player::PlayerKeys {
-                                    gold: true,
-                                    silver: false,
-                                },
-                                KeyKind::Silver => davelib::player::PlayerKeys {
-                                    gold: false,
-                                    silver: true,
-                                },
-                            });
-
-                            emit_pickup_sfx(SfxKind::PickupKey);
-                        }
+                        commands.entity(player_e).insert(davelib::player::PlayerKeys {
+                            gold: hud.key_gold,
+                            silver: hud.key_silver,
+                        });
                     }
                 }
+
+                emit_pickup_sfx(SfxKind::PickupKey);
             }
         }
 
-        if consumed {
-            pickup_flash.trigger(Srgba::new(1.0, 62.0 / 64.0, 0.0, 1.0));
-            commands.entity(e).try_despawn();
+        pickup_flash.trigger(Srgba::new(1.0, 62.0 / 64.0, 0.0, 1.0));
+        commands.entity(e).try_despawn();
+    }
+}
+
+/// G+U+N Cheat: Every Weapon, Switched to the Best, and Full Ammo
+pub fn give_all_weapons_cheat(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut cheats: ResMut<CheatState>,
+    mut hud: ResMut<HudState>,
+) {
+    if !cheat_chord(&keys, [KeyCode::KeyG, KeyCode::KeyU, KeyCode::KeyN]) {
+        return;
+    }
+
+    hud.grant_all();
+    hud.selected = hud.best_owned();
+    hud.ammo = AMMO_MAX;
+    cheats.note_entered();
+}
+
+/// Flashes the Screen Like a Pickup Each Time a Cheat Is Entered. A New Game
+/// Zeroes the Count, Which Never Flashes
+pub fn flash_on_cheat_entered(
+    cheats: Res<CheatState>,
+    mut seen: Local<u32>,
+    mut pickup_flash: ResMut<crate::ui::PickupFlash>,
+) {
+    if cheats.entered > *seen {
+        pickup_flash.trigger(Srgba::new(1.0, 62.0 / 64.0, 0.0, 1.0));
+    }
+    *seen = cheats.entered;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn corpse_drop_snaps_to_death_tile_or_nearest_open_floor() {
+        let (grid, _, _) = MapGrid::from_ascii(&[
+            "#######",
+            "#.....#",
+            "#..D..#",
+            "#######",
+        ]);
+
+        // Died Mid-Step Just Off a Valid Tile: Drop on That Tile's Center
+        let tile = corpse_drop_tile(&grid, Vec3::new(2.3, 0.0, 1.2));
+        assert_eq!(tile, IVec2::new(2, 1));
+
+        // Nudged Into the Door Tile: Moves to the Nearest Open Floor Instead
+        let tile = corpse_drop_tile(&grid, Vec3::new(3.0, 0.0, 2.1));
+        assert_eq!(grid.tile(tile.x as usize, tile.y as usize), Tile::Empty);
+        assert_eq!((tile - IVec2::new(3, 2)).abs().max_element(), 1);
+
+        // Nudged Into a Wall: Same Rule
+        let tile = corpse_drop_tile(&grid, Vec3::new(5.0, 0.0, 0.4));
+        assert_eq!(tile, IVec2::new(5, 1));
+    }
+
+    #[test]
+    fn treasure_pickup_plays_its_jingle_once_per_frame() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<HudState>()
+            .init_resource::<crate::ui::HudFaceOverride>()
+            .init_resource::<crate::ui::PickupFlash>()
+            .init_resource::<davelib::level_score::LevelScore>()
+            .add_message::<PlaySfx>()
+            .add_systems(Update, collect_pickups);
+
+        app.world_mut().spawn((
+            Player,
+            davelib::player::PlayerVitals::default(),
+            Transform::from_xyz(3.0, 0.5, 2.0),
+        ));
+
+        // Two Crosses and a Chest Stacked on the Player's Tile
+        let tile = IVec2::new(3, 2);
+        for kind in [TreasureKind::Cross, TreasureKind::Cross, TreasureKind::Chest] {
+            app.world_mut().spawn(Pickup { tile, kind: PickupKind::Treasure(kind) });
+        }
+
+        app.update();
+
+        let msgs = app.world().resource::<Messages<PlaySfx>>();
+        let kinds: Vec<SfxKind> = msgs.iter_current_update_messages().map(|m| m.kind).collect();
+        assert_eq!(kinds.len(), 2);
+        assert!(kinds.contains(&SfxKind::PickupTreasureCross));
+        assert!(kinds.contains(&SfxKind::PickupTreasureChest));
+
+        let hud = app.world().resource::<HudState>();
+        assert_eq!(hud.score, 100 + 100 + 1000);
+    }
+
+    #[test]
+    fn overflowing_pickups_clamp_and_wasted_ones_stay_put() {
+        use davelib::player::PlayerVitals;
+
+        let run = |hp: i32, ammo: i32, kind: PickupKind| {
+            let mut app = App::new();
+            app.init_resource::<Time>()
+                .insert_resource(HudState { ammo, ..default() })
+                .init_resource::<crate::ui::HudFaceOverride>()
+                .init_resource::<crate::ui::PickupFlash>()
+                .init_resource::<davelib::level_score::LevelScore>()
+                .add_message::<PlaySfx>()
+                .add_systems(Update, collect_pickups);
+
+            app.world_mut().spawn((
+                Player,
+                PlayerVitals { hp, hp_max: 100 },
+                Transform::from_xyz(3.0, 0.5, 2.0),
+            ));
+            app.world_mut().spawn(Pickup { tile: IVec2::new(3, 2), kind });
+            app.update();
+
+            let left = app.world_mut().query::<&Pickup>().iter(app.world()).count();
+            let hp = app.world_mut().query::<&PlayerVitals>().single(app.world()).unwrap().hp;
+            (hp, app.world().resource::<HudState>().clone(), left)
+        };
+
+        // First Aid at 90 Heals Only up to the Cap and Is Used Up
+        let (hp, _, left) = run(90, 8, PickupKind::Health(HealthKind::FirstAid));
+        assert_eq!((hp, left), (100, 0));
+
+        // At Full Health it Would Be Fully Wasted, so it Stays on the Floor
+        let (hp, _, left) = run(100, 8, PickupKind::Health(HealthKind::FirstAid));
+        assert_eq!((hp, left), (100, 1));
+
+        // A Clip Near the Cap Tops Off Without Overflowing
+        let (_, hud, left) = run(100, AMMO_MAX - 2, PickupKind::Ammo { rounds: MAP_AMMO_ROUNDS });
+        assert_eq!((hud.ammo, left), (AMMO_MAX, 0));
+
+        // Treasure Ignores Both Caps
+        let (_, hud, left) = run(100, AMMO_MAX, PickupKind::Treasure(TreasureKind::Chalice));
+        assert_eq!((hud.score, left), (TreasureKind::Chalice.points(), 0));
+    }
+
+    #[test]
+    fn ss_drop_a_machine_gun_only_to_players_without_a_better_gun() {
+        let mut hud = HudState::default();
+        assert!(matches!(ss_drop_kind(&hud), PickupKind::Weapon(WeaponSlot::MachineGun)));
+
+        hud.grant(WeaponSlot::Chaingun);
+        assert!(matches!(ss_drop_kind(&hud), PickupKind::Ammo { rounds: MAP_AMMO_ROUNDS }));
+    }
+
+    #[test]
+    fn pickup_policy_at_boundary_states() {
+        use davelib::player::PlayerVitals;
+
+        let hud_with_ammo = |ammo: i32| HudState { ammo, ..default() };
+        let vitals = |hp: i32| PlayerVitals { hp, hp_max: 100 };
+
+        // (Kind, Ammo, HP, Expected)
+        let cases = [
+            (PickupKind::Weapon(WeaponSlot::MachineGun), AMMO_MAX, 100, true),
+            (PickupKind::Weapon(WeaponSlot::Chaingun), 0, 1, true),
+            (PickupKind::Ammo { rounds: MAP_AMMO_ROUNDS }, AMMO_MAX - 1, 100, true),
+            (PickupKind::Ammo { rounds: MAP_AMMO_ROUNDS }, AMMO_MAX, 100, AMMO_PICKUP_AT_CAP),
+            (PickupKind::Health(HealthKind::DogFood), 8, 99, true),
+            (PickupKind::Health(HealthKind::DogFood), 8, 100, false),
+            (PickupKind::Health(HealthKind::FirstAid), 8, 100, false),
+            (PickupKind::Health(HealthKind::Dinner), 8, 1, true),
+            (PickupKind::Treasure(TreasureKind::Crown), AMMO_MAX, 100, true),
+            (PickupKind::Key(KeyKind::Gold), AMMO_MAX, 100, true),
+            (PickupKind::Key(KeyKind::Silver), 0, 1, true),
+            (PickupKind::ExtraLife, AMMO_MAX, 100, true),
+        ];
+
+        for (kind, ammo, hp, expected) in cases {
+            assert_eq!(
+                can_pickup(kind, &hud_with_ammo(ammo), &vitals(hp)),
+                expected,
+                "{kind:?} at ammo {ammo}, hp {hp}",
+            );
         }
     }
 }
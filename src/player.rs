@@ -19,8 +19,14 @@ use crate::map::{
 };
 
 #[derive(Component)]
+#[require(PlayerVelocity)]
 pub struct Player;
 
+/// Ground Velocity in Tiles per Second (x = World X, y = World Z). player_move
+/// Eases it Toward the Wished Velocity so Starts and Stops Ramp Instead of Snap
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct PlayerVelocity(pub Vec2);
+
 /// Render-Interpolation Snapshots for the Player Camera
 ///
 /// 'player_move' Advances the Player at the Fixed 70 Hz Tic Rate (the Authentic
@@ -40,6 +46,45 @@ pub struct PlayerRenderInterp {
     pub curr: Vec3,
 }
 
+/// Per-Tic Displacement Below This (Tiles) Counts as Standing Still, so Float
+/// Drift in a Stationary Tic Never Reads as a Step
+pub const PLAYER_MOTION_DEADZONE: f32 = 0.001;
+
+/// Single Authoritative Read of How the Player Moved Over the Last Tic, Taken
+/// From the Render Interpolation Snapshots. Anything That Moved the Player in
+/// the Tic Counts (Walking, or Being Shoved by a Door or Pushwall). The Automap's
+/// Visited Tiles Read it, and Anything Else Reacting to Movement Should Too, so
+/// They All Share One Threshold Instead of Their Own
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq)]
+pub struct PlayerMotion {
+    /// XZ Displacement Over the Tic, Zero Inside the Deadzone
+    pub delta: Vec3,
+    /// Tiles per Second
+    pub speed: f32,
+}
+
+impl PlayerMotion {
+    pub fn from_tic(prev: Vec3, curr: Vec3) -> Self {
+        let delta = Vec3::new(curr.x - prev.x, 0.0, curr.z - prev.z);
+        if delta.length() < PLAYER_MOTION_DEADZONE {
+            return Self::default();
+        }
+        Self { delta, speed: delta.length() * crate::tics::TIC_HZ }
+    }
+
+    pub fn is_moving(&self) -> bool {
+        self.delta != Vec3::ZERO
+    }
+}
+
+/// Sent on a Fresh Use Press Against a Locked Door the Player Has No Key For
+#[derive(Message, Debug, Clone, Copy)]
+pub struct DoorLocked {
+    pub tile: IVec2,
+    /// Key the Door Wants, Gold When True, Silver Otherwise
+    pub gold: bool,
+}
+
 #[derive(Component, Default, Clone, Copy)]
 pub struct PlayerKeys {
     pub gold: bool,
@@ -61,12 +106,18 @@ impl LookAngles {
 #[derive(Resource)]
 pub struct PlayerSettings {
 	speed: f32,
+	/// Run Speed as a Multiple of Walk Speed
+	pub run_factor: f32,
+	/// Seconds to Reach Full Speed From a Standstill, and to Stop Again
+	pub accel_secs: f32,
 }
 
 impl Default for PlayerSettings {
 	fn default() -> Self {
 		Self {
 			speed: 3.5,
+			run_factor: 1.6,
+			accel_secs: 0.1,
 		}
 	}
 }
@@ -88,6 +139,17 @@ impl Default for PlayerVitals {
 #[derive(Default, Resource)]
 pub struct PlayerControlLock(pub bool);
 
+/// When True, a Menu Is Up Over the Level and the World Stands Still: Doors,
+/// Enemies and Projectiles Hold Their Exact State Until Play Resumes. Unlike
+/// PlayerControlLock, Death and Level Exits Never Set This
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Resource)]
+pub struct GamePaused(pub bool);
+
+/// Run Condition for Systems That Advance the World
+pub fn game_running(paused: Res<GamePaused>) -> bool {
+    !paused.0
+}
+
 /// Prevents Decrementing Lives Every Frame While HP == 0
 /// False = Alive (or not yet processed), True = Death Handled
 #[derive(Default, Resource)]
@@ -96,20 +158,106 @@ pub struct PlayerDeathLatch(pub bool);
 #[derive(Default, Debug, Clone, Copy, Resource)]
 pub struct GodMode(pub bool);
 
+/// Debug Cheats Other Than God Mode, Plus Whether Any Cheat Was Entered This
+/// Run. `used` Sticks Until New Game so a Cheated Score Never Reaches the High
+/// Score Table, and `entered` Counts Entries for the Confirmation Flash
+#[derive(Default, Debug, Clone, Copy, Resource)]
+pub struct CheatState {
+    pub noclip: bool,
+    pub used: bool,
+    pub entered: u32,
+}
+
+impl CheatState {
+    pub fn note_entered(&mut self) {
+        self.used = true;
+        self.entered = self.entered.wrapping_add(1);
+    }
+}
+
+/// All Three Keys Held, One of Them Pressed This Frame, so Holding the Chord
+/// Fires Once
+pub fn cheat_chord(keys: &ButtonInput<KeyCode>, chord: [KeyCode; 3]) -> bool {
+    keys.all_pressed(chord) && keys.any_just_pressed(chord)
+}
+
+/// M+I+L Toggles God Mode
 pub fn toggle_god_mode(
     keys: Res<ButtonInput<KeyCode>>,
     mut god: ResMut<GodMode>,
+    mut cheats: ResMut<CheatState>,
 ) {
-    let mil_pressed = keys.pressed(KeyCode::KeyM)
-        && keys.pressed(KeyCode::KeyI)
-        && keys.pressed(KeyCode::KeyL);
-    let mil_just_completed = keys.just_pressed(KeyCode::KeyM)
-        || keys.just_pressed(KeyCode::KeyI)
-        || keys.just_pressed(KeyCode::KeyL);
-
-    if mil_pressed && mil_just_completed {
+    if cheat_chord(&keys, [KeyCode::KeyM, KeyCode::KeyI, KeyCode::KeyL]) {
         god.0 = !god.0;
+        cheats.note_entered();
+    }
+}
+
+/// N+C+L Toggles Noclip: Walls, Doors, Statics and Enemies Stop Blocking
+/// Movement, Though the Player Still Never Leaves the Map
+pub fn toggle_noclip(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut cheats: ResMut<CheatState>,
+) {
+    if cheat_chord(&keys, [KeyCode::KeyN, KeyCode::KeyC, KeyCode::KeyL]) {
+        cheats.noclip = !cheats.noclip;
+        cheats.note_entered();
+    }
+}
+
+/// Regen Refills HP to the Top of its Current Band of This Many Points
+pub const HEALTH_REGEN_BAND: i32 = 25;
+/// Quiet Tics After the Last Hit Before Regen Starts (4 Seconds)
+pub const HEALTH_REGEN_GRACE_TICS: u32 = 280;
+/// Tics per Point Regained Once Regen Runs (2 HP a Second)
+pub const HEALTH_REGEN_INTERVAL_TICS: u32 = 35;
+
+/// Tracks the Quiet Spell Since the Player Last Lost HP
+#[derive(Resource, Debug, Default)]
+pub struct HealthRegen {
+    last_hp: Option<i32>,
+    quiet_tics: u32,
+}
+
+/// Highest HP Regen Reaches From hp: the Top of its 25-Point Band, Capped at
+/// hp_max. 37 Comes Back to 50, While 50 Is Already a Band Top and Stays
+pub fn regen_ceiling(hp: i32, hp_max: i32) -> i32 {
+    if hp <= 0 {
+        return hp;
+    }
+    let band_top = (hp + HEALTH_REGEN_BAND - 1) / HEALTH_REGEN_BAND * HEALTH_REGEN_BAND;
+    band_top.min(hp_max).max(hp)
+}
+
+/// Optional Health Regeneration, One Step per Tic (FixedUpdate). Any Drop in HP
+/// Counts as a Hit Whatever Dealt it and Restarts the Grace Period. God Mode and
+/// the Dead Player Never Regen, and the Control Lock That Menus and Death Set
+/// Keeps the Whole System From Running While Paused
+pub fn regenerate_health(
+    gameplay: Res<crate::options::GameplaySettings>,
+    god: Res<GodMode>,
+    mut regen: ResMut<HealthRegen>,
+    mut q_vitals: Query<&mut PlayerVitals, With<Player>>,
+) {
+    let Some(mut vitals) = q_vitals.iter_mut().next() else { return; };
+
+    if regen.last_hp.is_some_and(|last| vitals.hp < last) {
+        regen.quiet_tics = 0;
+    } else {
+        regen.quiet_tics = regen.quiet_tics.saturating_add(1);
     }
+
+    let quiet = regen.quiet_tics;
+    if gameplay.health_regen
+        && !god.0
+        && quiet > HEALTH_REGEN_GRACE_TICS
+        && (quiet - HEALTH_REGEN_GRACE_TICS) % HEALTH_REGEN_INTERVAL_TICS == 0
+        && vitals.hp < regen_ceiling(vitals.hp, vitals.hp_max)
+    {
+        vitals.hp += 1;
+    }
+
+    regen.last_hp = Some(vitals.hp);
 }
 
 pub fn cursor_is_captured(grab_mode: CursorGrabMode) -> bool {
@@ -192,9 +340,10 @@ pub fn player_move(
     grid: Res<MapGrid>,
     solid: Res<crate::decorations::SolidStatics>,
     q_enemies: Query<&OccupiesTile, Without<Dead>>,
-    mut q_player: Query<&mut Transform, With<Player>>,
+    mut q_player: Query<(&mut Transform, &mut PlayerVelocity), With<Player>>,
     settings: Res<PlayerSettings>,
     push_occ: Res<crate::pushwalls::PushwallOcc>,
+    cheats: Res<CheatState>,
 ) {
     if lock.0 {
         return;
@@ -202,9 +351,8 @@ pub fn player_move(
 
     // Tile Units (Tile = 1.0)
     const PLAYER_RADIUS: f32 = 0.20;
-    const RUN_MULTIPLIER: f32 = 1.6;
 
-    let Ok(mut transform) = q_player.single_mut() else {
+    let Ok((mut transform, mut velocity)) = q_player.single_mut() else {
         return;
     };
 
@@ -225,18 +373,22 @@ pub fn player_move(
     // x = strafe (+right), y = forward (+forward).
     let wish = (right * intent.move_wish.x + forward * intent.move_wish.y)
         .normalize_or_zero();
-    if wish == Vec3::ZERO {
+
+    let run_speed = settings.speed * settings.run_factor;
+    let speed = if intent.run { run_speed } else { settings.speed };
+
+    // Ease Toward the Wished Velocity, Never Past it, so Letting go Stops the
+    // Player Within accel_secs Too. The Rate Is Set by Run Speed so Walking and
+    // Running Both Settle in the Same Time
+    let dt = time.delta_secs();
+    let target = Vec2::new(wish.x, wish.z) * speed;
+    let rate = run_speed / settings.accel_secs.max(f32::EPSILON);
+    velocity.0 = velocity.0.move_towards(target, rate * dt);
+    if velocity.0 == Vec2::ZERO {
         return;
     }
 
-    let running = intent.run;
-    let speed = if running {
-        settings.speed * RUN_MULTIPLIER
-    } else {
-        settings.speed
-    };
-
-    let step = wish * speed * time.delta_secs();
+    let step = Vec3::new(velocity.0.x, 0.0, velocity.0.y) * dt;
 
     // Collision Helpers
     // IMPORTANT: Tiles Centered on Integer Coords
@@ -313,20 +465,48 @@ pub fn player_move(
     // Apply Movement With Sliding (X then Z)
     let mut pos = Vec2::new(transform.translation.x, transform.translation.z);
 
+    if cheats.noclip {
+        pos += Vec2::new(step.x, step.z);
+        let pos = clamp_to_map_bounds(pos, grid.width, grid.height, PLAYER_RADIUS);
+        transform.translation.x = pos.x;
+        transform.translation.z = pos.y;
+        return;
+    }
+
+    // A Blocked Axis Also Loses its Velocity, so Sliding Along a Wall Keeps Only
+    // the Free Component and Nothing Builds up Against the Wall
     let try_x = Vec2::new(pos.x + step.x, pos.y);
     if !collides(&grid, &solid, &push_occ, &occupied, try_x, PLAYER_RADIUS) {
         pos.x = try_x.x;
+    } else {
+        velocity.0.x = 0.0;
     }
 
     let try_z = Vec2::new(pos.x, pos.y + step.z);
     if !collides(&grid, &solid, &push_occ, &occupied, try_z, PLAYER_RADIUS) {
         pos.y = try_z.y;
+    } else {
+        velocity.0.y = 0.0;
     }
 
+    // Safety Net Independent of Wall Collision: Never Leave the Floor Plane
+    let pos = clamp_to_map_bounds(pos, grid.width, grid.height, PLAYER_RADIUS);
+
     transform.translation.x = pos.x;
     transform.translation.z = pos.y;
 }
 
+/// Clamp an XZ Position to the Map Rectangle, Inset by the Player Radius
+/// Tiles Are Centered on Integer Coords, so the Map Spans -0.5 ..= size - 0.5
+/// on Each Axis, the Same Extent as the Floor Plane. Wall Collision Already
+/// Treats Out-of-Bounds as Solid, so an Open Edge Tile Is Still Reachable Up to
+/// the Same Radius Margin Collision Would Allow
+fn clamp_to_map_bounds(pos: Vec2, width: usize, height: usize, radius: f32) -> Vec2 {
+    let min = Vec2::splat(-0.5 + radius);
+    let max = Vec2::new(width as f32 - 0.5 - radius, height as f32 - 0.5 - radius);
+    pos.clamp(min, max.max(min))
+}
+
 /// Attach 'PlayerRenderInterp' to a Newly Spawned Player, Seeded to Its Current
 /// Position so the First Frames Interpolate From the Right Place Rather Than the
 /// Origin. Runs Before the Fixed Loop so the Snapshot Systems See the Component
@@ -367,6 +547,21 @@ pub fn player_interp_capture_after_tic(
     }
 }
 
+/// Runs in 'FixedLast', After the Snapshot Capture. Publishes This Tic's Motion
+pub fn update_player_motion(
+    q: Query<&PlayerRenderInterp, With<Player>>,
+    mut motion: ResMut<PlayerMotion>,
+) {
+    let next = q
+        .iter()
+        .next()
+        .map(|interp| PlayerMotion::from_tic(interp.prev, interp.curr))
+        .unwrap_or_default();
+    if *motion != next {
+        *motion = next;
+    }
+}
+
 /// Runs Every Rendered Frame, After the Fixed Loop and Before Transform
 /// Propagation. Places the Camera at the Position Interpolated Between the Two
 /// Most Recent Tic Snapshots by the Fraction of the Current Fixed Step Elapsed
@@ -385,23 +580,30 @@ pub fn apply_player_render_interp(
 
 pub fn use_doors(
     intent: Res<PlayerIntent>,
+    gameplay: Res<crate::options::GameplaySettings>,
     lock: Res<PlayerControlLock>,
+    suppress: Res<crate::input::GameplayInputSuppress>,
     grid: ResMut<MapGrid>,
     q_player: Query<&Transform, With<Player>>,
     q_keys: Query<&PlayerKeys, With<Player>>,
     mut q_doors: Query<(&DoorTile, &mut DoorState, &mut Visibility)>,
     mut sfx: MessageWriter<PlaySfx>,
+    mut locked_out: MessageWriter<DoorLocked>,
 ) {
-    if lock.0 {
+    // Suppressed Frames Keep a Menu-Dismissing Use Press From Opening a Door
+    if lock.0 || suppress.0 {
         return;
     }
 
     const TILE_SIZE: f32 = 1.0;
-    const DOOR_OPEN_SECS: f32 = 4.5;
+    const DOOR_OPEN_SECS: f32 = crate::tics::tics(crate::tics::DOOR_OPEN_TICS);
 
     // Door Use Now Comes From PlayerIntent, Which Reads the Rebindable use_door
-    // Key, so Rebinding Use Affects Doors and Pushwalls Alike
-    if !intent.use_pressed {
+    // Key, so Rebinding Use Affects Doors and Pushwalls Alike. With Hold Use
+    // Doors On, Holding the Key Keeps Working Each Frame, but Only Here; Pushwalls
+    // and the Elevator Still Read the use_pressed Edge
+    let held = gameplay.hold_use_doors && intent.use_held && !intent.use_pressed;
+    if !intent.use_pressed && !held {
         return;
     }
 
@@ -444,14 +646,14 @@ pub fn use_doors(
 
     // Locked Doors Encoded in plane0 Codes,
     // But Share Same Tile State
-    let plane0 = grid.plane0_code(tx, tz);
-    let needs_gold = matches!(plane0, 92 | 93);
-    let needs_silver = matches!(plane0, 94 | 95);
-    let locked = needs_gold || needs_silver;
+    let needs_key = grid.door_key(tx, tz);
 
     let pk = q_keys.iter().next().copied().unwrap_or_default();
-    let has_gold = pk.gold;
-    let has_silver = pk.silver;
+    let key_missing = match needs_key {
+        Some(true) => !pk.gold,
+        Some(false) => !pk.silver,
+        None => false,
+    };
 
     if !matches!(cur, Tile::DoorClosed | Tile::DoorOpen) {
         return;
@@ -469,11 +671,19 @@ pub fn use_doors(
         match cur {
             Tile::DoorOpen => {
                 // Wolfenstein 3D Behavior: Using an Already Open Door Does Nothing
+                // Holding Use Props it Instead, Restarting the Hold-Open Timer
                 *vis = Visibility::Hidden;
+                if held {
+                    state.open_timer = DOOR_OPEN_SECS;
+                }
             }
             Tile::DoorClosed => {
-                if locked && ((needs_gold && !has_gold) || (needs_silver && !has_silver)) {
-                    sfx_kind = Some(SfxKind::NoWay);
+                if key_missing {
+                    // Only a Fresh Press Complains, Holding Into a Locked Door Stays Quiet
+                    if !held {
+                        sfx_kind = Some(SfxKind::NoWay);
+                        locked_out.write(DoorLocked { tile: target, gold: needs_key == Some(true) });
+                    }
                     break;
                 }
 
@@ -515,8 +725,8 @@ pub fn door_animate(
         &mut Visibility,
     )>,
 ) {
-    const TILE_SIZE: f32 = 1.0;
-    const SLIDE_SPEED: f32 = 2.0;
+    // Tiles per Second: One Full Slide Every DOOR_SLIDE_TICS
+    const SLIDE_SPEED: f32 = 1.0 / crate::tics::tics(crate::tics::DOOR_SLIDE_TICS);
 
     for (door, state, mut anim, mut tf, mut vis) in q_doors.iter_mut() {
         let tx = door.0.x;
@@ -543,7 +753,7 @@ pub fn door_animate(
             anim.progress = (anim.progress - step).max(0.0);
         }
 
-        tf.translation = anim.closed_pos + anim.slide_axis * (anim.progress * TILE_SIZE);
+        (tf.translation, tf.scale) = anim.pose();
 
         // Only When Fully Open Tile Becomes Passable / Able to be Shot Through
         if want_open && anim.progress >= 0.999 {
@@ -676,3 +886,379 @@ pub fn apply_enemy_fire_to_player(
         vitals.hp = (vitals.hp - e.damage).max(0);
     }
 }
+
+fn world_ready(
+    map: Option<Res<MapGrid>>,
+    plane1: Option<Res<crate::level::WolfPlane1>>,
+) -> bool {
+    map.is_some() && plane1.is_some()
+}
+
+fn controls_unlocked(lock: Res<PlayerControlLock>) -> bool {
+    !lock.0
+}
+
+/// Per-Tic Player and Door Simulation. Held by the Control Lock (Death, Level
+/// End, Menus) and Frozen Outright by GamePaused so Doors Keep Their Exact
+/// Progress Under the Pause Menu
+pub struct PlayerPlugin;
+
+impl Plugin for PlayerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            FixedUpdate,
+            (door_auto_close, door_animate, player_move, regenerate_health)
+                .run_if(world_ready)
+                .run_if(controls_unlocked)
+                .run_if(game_running),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn movement_stops_at_map_edge() {
+        let r = 0.20;
+        let mut pos = Vec2::new(60.0, 32.0);
+        for _ in 0..100 {
+            pos = clamp_to_map_bounds(pos + Vec2::new(0.25, 0.0), 64, 64, r);
+        }
+        assert!((pos.x - (63.5 - r)).abs() < 1e-5);
+        assert_eq!(pos.y, 32.0);
+
+        let corner = clamp_to_map_bounds(Vec2::new(-10.0, -10.0), 64, 64, r);
+        assert_eq!(corner, Vec2::splat(-0.5 + r));
+    }
+
+    fn doors_opened_walking_a_corridor(hold_use_doors: bool) -> [bool; 2] {
+        let (grid, _, _) = MapGrid::from_ascii(&[
+            "#######",
+            "#.D.D.#",
+            "#######",
+        ]);
+
+        let mut app = App::new();
+        app.add_message::<PlaySfx>()
+            .add_message::<DoorLocked>()
+            .insert_resource(grid)
+            .insert_resource(crate::options::GameplaySettings {
+                hold_use_doors,
+                ..default()
+            })
+            .init_resource::<PlayerControlLock>()
+            .init_resource::<crate::input::GameplayInputSuppress>()
+            .init_resource::<PlayerIntent>()
+            .add_systems(Update, use_doors);
+
+        // Facing +X Down the Corridor
+        let facing_east = Quat::from_rotation_y(-std::f32::consts::FRAC_PI_2);
+        let player = app
+            .world_mut()
+            .spawn((Player, Transform::from_xyz(1.0, 0.5, 1.0).with_rotation(facing_east)))
+            .id();
+        let doors = [2, 4].map(|x| {
+            app.world_mut()
+                .spawn((
+                    DoorTile(IVec2::new(x, 1)),
+                    DoorState { open_timer: 0.0, want_open: false },
+                    Visibility::Visible,
+                ))
+                .id()
+        });
+
+        // Press Use at the First Door, Then Keep Holding While Stepping Past it
+        app.insert_resource(PlayerIntent { use_pressed: true, use_held: true, ..default() });
+        app.update();
+
+        app.world_mut().get_mut::<Transform>(player).unwrap().translation.x = 3.0;
+        app.insert_resource(PlayerIntent { use_held: true, ..default() });
+        app.update();
+
+        doors.map(|d| app.world().get::<DoorState>(d).unwrap().want_open)
+    }
+
+    #[test]
+    fn held_use_opens_each_door_in_a_corridor() {
+        assert_eq!(doors_opened_walking_a_corridor(true), [true, true]);
+
+        // Classic Press per Door Leaves the Second One Shut
+        assert_eq!(doors_opened_walking_a_corridor(false), [true, false]);
+    }
+
+    #[test]
+    fn use_bound_to_right_mouse_opens_door_on_right_click() {
+        use crate::options::{ControlSettings, InputBinding};
+        use bevy::input::mouse::{AccumulatedMouseMotion, AccumulatedMouseScroll};
+
+        let (grid, _, _) = MapGrid::from_ascii(&[
+            "#####",
+            "#.D.#",
+            "#####",
+        ]);
+
+        let mut controls = ControlSettings::default();
+        controls.key_bindings.use_door = InputBinding::Mouse(MouseButton::Right);
+
+        let mut app = App::new();
+        app.add_message::<PlaySfx>()
+            .add_message::<DoorLocked>()
+            .insert_resource(grid)
+            .insert_resource(controls)
+            .init_resource::<crate::options::GameplaySettings>()
+            .init_resource::<PlayerControlLock>()
+            .init_resource::<crate::input::GameplayInputSuppress>()
+            .init_resource::<PlayerIntent>()
+            .init_resource::<crate::input::MenuNav>()
+            .init_resource::<Time>()
+            .init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<ButtonInput<MouseButton>>()
+            .init_resource::<AccumulatedMouseMotion>()
+            .init_resource::<AccumulatedMouseScroll>()
+            .add_systems(Update, (crate::input::gather::gather, use_doors).chain());
+
+        let facing_east = Quat::from_rotation_y(-std::f32::consts::FRAC_PI_2);
+        app.world_mut()
+            .spawn((Player, Transform::from_xyz(1.0, 0.5, 1.0).with_rotation(facing_east)));
+        let door = app
+            .world_mut()
+            .spawn((
+                DoorTile(IVec2::new(2, 1)),
+                DoorState { open_timer: 0.0, want_open: false },
+                Visibility::Visible,
+            ))
+            .id();
+
+        // The Old Use Key No Longer Opens it
+        app.world_mut().resource_mut::<ButtonInput<KeyCode>>().press(KeyCode::Space);
+        app.update();
+        assert!(!app.world().get::<DoorState>(door).unwrap().want_open);
+
+        app.world_mut().resource_mut::<ButtonInput<MouseButton>>().press(MouseButton::Right);
+        app.update();
+        assert!(app.world().get::<DoorState>(door).unwrap().want_open);
+    }
+
+    #[test]
+    fn motion_deadzone_ignores_drift_but_not_steps() {
+        let at = Vec3::new(10.0, 0.5, 4.0);
+
+        // Float Drift While Standing Still
+        let drift = PlayerMotion::from_tic(at, at + Vec3::new(1e-5, 0.0, -2e-5));
+        assert!(!drift.is_moving());
+        assert_eq!(drift.speed, 0.0);
+
+        // A Walking Tic, and a Door or Pushwall Shove, Both Register
+        let walk = PlayerMotion::from_tic(at, at + Vec3::new(0.0, 0.0, 0.05));
+        assert!(walk.is_moving());
+        assert!((walk.speed - 0.05 * crate::tics::TIC_HZ).abs() < 1e-3);
+
+        let shove = PlayerMotion::from_tic(at, at + Vec3::new(0.01, 0.0, 0.0));
+        assert!(shove.is_moving());
+
+        // Height Changes Never Count as Moving Across the Floor
+        assert!(!PlayerMotion::from_tic(at, at + Vec3::Y).is_moving());
+    }
+
+    #[test]
+    fn health_regen_refills_to_the_band_top_then_stops() {
+        let mut app = App::new();
+        app.insert_resource(crate::options::GameplaySettings {
+            health_regen: true,
+            ..default()
+        })
+        .init_resource::<GodMode>()
+        .init_resource::<HealthRegen>()
+        .add_systems(Update, regenerate_health);
+
+        let player = app
+            .world_mut()
+            .spawn((Player, PlayerVitals { hp: 37, hp_max: 100 }))
+            .id();
+        let hp = |app: &App| app.world().get::<PlayerVitals>(player).unwrap().hp;
+
+        // One Update Stands in for One Tic. Nothing Comes Back During the Grace
+        for _ in 0..HEALTH_REGEN_GRACE_TICS {
+            app.update();
+        }
+        assert_eq!(hp(&app), 37);
+
+        // A Hit Restarts the Grace
+        app.world_mut().get_mut::<PlayerVitals>(player).unwrap().hp = 30;
+        for _ in 0..HEALTH_REGEN_GRACE_TICS {
+            app.update();
+        }
+        assert_eq!(hp(&app), 30);
+
+        // Past the Grace it Climbs to 50 and Holds There
+        for _ in 0..(HEALTH_REGEN_INTERVAL_TICS * 40) {
+            app.update();
+        }
+        assert_eq!(hp(&app), 50);
+
+        assert_eq!(regen_ceiling(96, 100), 100);
+        assert_eq!(regen_ceiling(50, 100), 50);
+    }
+
+    /// Where the Player Ends up After Walking East Along a Corridor for a Second,
+    /// With a Blocking Decoration (if Any) at x = 3
+    fn walk_east_past_a_decoration(blocking: bool, noclip: bool) -> f32 {
+        let (grid, _, _) = MapGrid::from_ascii(&["#######", "#.....#", "#######"]);
+        let mut solid = crate::decorations::SolidStatics::new(grid.width, grid.height);
+        solid.set_solid(3, 1, blocking);
+        let mut fixed = Time::<Fixed>::default();
+        fixed.advance_by(std::time::Duration::from_secs_f32(crate::tics::TIC));
+
+        let mut app = App::new();
+        app.insert_resource(grid)
+            .insert_resource(solid)
+            .insert_resource(fixed)
+            .insert_resource(PlayerIntent { move_wish: Vec2::Y, ..default() })
+            .init_resource::<PlayerControlLock>()
+            .init_resource::<PlayerSettings>()
+            .init_resource::<crate::pushwalls::PushwallOcc>()
+            .insert_resource(CheatState { noclip, ..default() })
+            .add_systems(Update, player_move);
+
+        let facing_east = Quat::from_rotation_y(-std::f32::consts::FRAC_PI_2);
+        let player = app
+            .world_mut()
+            .spawn((Player, Transform::from_xyz(1.0, 0.5, 1.0).with_rotation(facing_east)))
+            .id();
+
+        for _ in 0..crate::tics::TIC_HZ as u32 {
+            app.update();
+        }
+        app.world().get::<Transform>(player).unwrap().translation.x
+    }
+
+    /// Where the Player Ends up After `tics` of the Given Intent, Starting at
+    /// Rest on `start` and Facing -Z (so move_wish.y = 1 Heads Toward -Z)
+    fn move_for(lines: &[&str], start: Vec2, intent: PlayerIntent, tics: u32) -> Vec2 {
+        let (grid, _, _) = MapGrid::from_ascii(lines);
+        let solid = crate::decorations::SolidStatics::new(grid.width, grid.height);
+        let mut fixed = Time::<Fixed>::default();
+        fixed.advance_by(std::time::Duration::from_secs_f32(crate::tics::TIC));
+
+        let mut app = App::new();
+        app.insert_resource(grid)
+            .insert_resource(solid)
+            .insert_resource(fixed)
+            .insert_resource(intent)
+            .init_resource::<PlayerControlLock>()
+            .init_resource::<PlayerSettings>()
+            .init_resource::<crate::pushwalls::PushwallOcc>()
+            .init_resource::<CheatState>()
+            .add_systems(Update, player_move);
+
+        let player = app
+            .world_mut()
+            .spawn((Player, Transform::from_xyz(start.x, 0.5, start.y)))
+            .id();
+        for _ in 0..tics {
+            app.update();
+        }
+        let at = app.world().get::<Transform>(player).unwrap().translation;
+        Vec2::new(at.x, at.z)
+    }
+
+    #[test]
+    fn running_ramps_up_to_a_faster_top_speed() {
+        let corridor = ["################", "#..............#", "################"];
+        let start = Vec2::new(1.0, 1.0);
+        let east = |run| PlayerIntent { move_wish: Vec2::X, run, ..default() };
+        let settings = PlayerSettings::default();
+
+        // The First Tic Covers Less Than a Full-Speed Tic: Speed Ramps up
+        let first = move_for(&corridor, start, east(false), 1).x - start.x;
+        assert!(first > 0.0 && first < settings.speed * crate::tics::TIC);
+
+        // Once up to Speed, Running Covers run_factor Times the Ground
+        let walk = move_for(&corridor, start, east(false), 35).x - start.x;
+        let run = move_for(&corridor, start, east(true), 35).x - start.x;
+        assert!(run > walk * (settings.run_factor - 0.1));
+        assert!(run < walk * settings.run_factor);
+    }
+
+    #[test]
+    fn running_into_a_diagonal_corner_does_not_clip_through() {
+        // The Only Way on Is Through the Point Where Two Walls Meet
+        let map = ["####", "#.##", "##.#", "####"];
+        let toward_corner = PlayerIntent { move_wish: Vec2::new(1.0, -1.0), run: true, ..default() };
+
+        let at = move_for(&map, Vec2::new(1.0, 1.0), toward_corner, 140);
+        let limit = 1.5 - 0.20;
+        assert!(at.x <= limit + 1e-4 && at.y <= limit + 1e-4, "{at:?}");
+        assert!(at.x > limit - 0.05 && at.y > limit - 0.05, "{at:?}");
+    }
+
+    #[test]
+    fn blocking_decorations_stop_the_player_like_walls() {
+        // Stopped at the Radius Short of the Decoration's Tile Edge
+        assert!(walk_east_past_a_decoration(true, false) < 2.5);
+
+        // Without One the Same Walk Carries on Past Its Tile
+        assert!(walk_east_past_a_decoration(false, false) > 3.5);
+
+        // Noclip Walks Straight Through it
+        assert!(walk_east_past_a_decoration(true, true) > 3.5);
+    }
+
+    #[test]
+    fn paused_door_holds_its_exact_progress_and_resumes() {
+        let (grid, _, _) = MapGrid::from_ascii(&["###", ".D.", "###"]);
+        let mut fixed = Time::<Fixed>::default();
+        fixed.advance_by(std::time::Duration::from_secs_f32(crate::tics::TIC));
+
+        let mut app = App::new();
+        app.add_plugins(PlayerPlugin)
+            .insert_resource(grid)
+            .insert_resource(crate::decorations::SolidStatics::new(3, 3))
+            .insert_resource(crate::level::WolfPlane1(Vec::new()))
+            .insert_resource(fixed)
+            .init_resource::<GamePaused>()
+            .init_resource::<PlayerControlLock>()
+            .init_resource::<PlayerIntent>()
+            .init_resource::<PlayerSettings>()
+            .init_resource::<CheatState>()
+            .init_resource::<GodMode>()
+            .init_resource::<HealthRegen>()
+            .init_resource::<crate::options::GameplaySettings>()
+            .init_resource::<crate::pushwalls::PushwallOcc>()
+            .add_message::<PlaySfx>();
+        let tic = |app: &mut App| app.world_mut().run_schedule(FixedUpdate);
+
+        let door = app
+            .world_mut()
+            .spawn((
+                DoorTile(IVec2::new(1, 1)),
+                DoorState { open_timer: 0.0, want_open: true },
+                DoorAnim { progress: 0.3, closed_pos: Vec3::ZERO, slide_axis: Vec3::X, pocket: true },
+                Transform::default(),
+                Visibility::Visible,
+            ))
+            .id();
+        let progress = |app: &App| app.world().get::<DoorAnim>(door).unwrap().progress;
+
+        app.world_mut().resource_mut::<GamePaused>().0 = true;
+        for _ in 0..20 {
+            tic(&mut app);
+        }
+        assert_eq!(progress(&app), 0.3);
+
+        // One Tic After Unpausing Moves it Exactly One Step From Where it Stopped
+        app.world_mut().resource_mut::<GamePaused>().0 = false;
+        tic(&mut app);
+        let step = crate::tics::TIC / crate::tics::tics(crate::tics::DOOR_SLIDE_TICS);
+        assert!((progress(&app) - (0.3 + step)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn open_edge_tile_is_still_reachable() {
+        // Center of Edge Tile 0 and Tile 63 Are Inside the Clamp
+        let p = Vec2::new(0.0, 63.0);
+        assert_eq!(clamp_to_map_bounds(p, 64, 64, 0.20), p);
+    }
+}
@@ -14,8 +14,7 @@ use crate::options::GameplaySettings;
 use crate::player::{Player, PlayerControlLock};
 use crate::world::{RebuildWalls, WallRenderCache};
 
-const WOLF_TIC_HZ: f32 = 70.0;
-const WOLF_TIC_SECS: f32 = 1.0 / WOLF_TIC_HZ;
+const WOLF_TIC_SECS: f32 = crate::tics::TIC;
 
 // 128 Tics Per Tile for Pushwalls (Stops at 256 for 2 Tiles)
 const PUSHWALL_TICS_PER_TILE: u32 = 128;
@@ -180,6 +179,12 @@ impl PushwallOcc {
 #[derive(Component)]
 pub struct PushwallVisual;
 
+/// Sent Once per Secret, the First Time a Pushwall is Credited
+#[derive(Message, Debug, Clone, Copy)]
+pub struct SecretFound {
+    pub tile: IVec2,
+}
+
 #[derive(Resource, Default)]
 pub struct PushwallClock {
     accum: f32,
@@ -387,6 +392,7 @@ fn spawn_pushwall_visual(
 pub fn use_pushwalls(
     intent: Res<PlayerIntent>,
     lock: Res<PlayerControlLock>,
+    suppress: Res<crate::input::GameplayInputSuppress>,
     gameplay: Res<GameplaySettings>,
     grid: Option<Res<MapGrid>>,
     solid: Option<Res<SolidStatics>>,
@@ -398,6 +404,7 @@ pub fn use_pushwalls(
     mut pw_occ: ResMut<PushwallOcc>,
     mut sfx: MessageWriter<PlaySfx>,
     mut rebuild: MessageWriter<RebuildWalls>,
+    mut secret_found: MessageWriter<SecretFound>,
     mut level_score: ResMut<crate::level_score::LevelScore>,
     mut commands: Commands,
 ) {
@@ -405,8 +412,9 @@ pub fn use_pushwalls(
         return;
     };
 
-     // Prevents Use of Pushwalls While Dead / Game Over
-    if lock.0 {
+     // Prevents Use of Pushwalls While Dead / Game Over or on the Frame a Menu
+    // Hands Control Back
+    if lock.0 || suppress.0 {
         return;
     }
 
@@ -498,6 +506,7 @@ pub fn use_pushwalls(
     if !markers.is_credited(front.x, front.y) {
         markers.set_credited(front.x, front.y);
         level_score.secrets_found += 1;
+        secret_found.write(SecretFound { tile: front });
     }
 
     // Consume the Marker at This Tile. Reversible Mode Re-Marks the Destination
@@ -686,3 +695,36 @@ pub fn tick_pushwalls(
         tf.translation = pos;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn markers_track_pushes_and_keep_credit_through_a_save() {
+        // 4x2 Plane1 With Markers at (1, 0) and (2, 1)
+        let plane1 = [0, PUSHWALL_MARKER_CODE, 0, 0, 0, 0, PUSHWALL_MARKER_CODE, 0];
+        let mut markers = PushwallMarkers::from_wolf_plane1(4, 2, &plane1);
+        assert_eq!(markers.marked_tiles(), vec![IVec2::new(1, 0), IVec2::new(2, 1)]);
+        assert!(!markers.is_marked(-1, 0) && !markers.is_marked(4, 0));
+
+        // Pushing (1, 0) East in Reversible Mode: Credit and Marker Move With it
+        markers.set_credited(1, 0);
+        markers.consume(1, 0);
+        markers.mark(3, 0);
+        markers.set_credited(3, 0);
+        assert!(!markers.is_marked(1, 0));
+
+        let mut loaded = PushwallMarkers::from_wolf_plane1(4, 2, &plane1);
+        loaded.restore_state(&markers.marked_tiles(), &markers.credited_tiles());
+        assert_eq!(loaded.marked_tiles(), vec![IVec2::new(3, 0), IVec2::new(2, 1)]);
+        assert!(loaded.is_credited(3, 0) && !loaded.is_credited(2, 1));
+    }
+
+    #[test]
+    fn push_direction_snaps_to_the_dominant_axis() {
+        assert_eq!(cardinal_from_fwd(Vec3::new(0.9, 0.0, 0.4)), IVec2::new(1, 0));
+        assert_eq!(cardinal_from_fwd(Vec3::new(-0.2, 0.0, -0.8)), IVec2::new(0, -1));
+        assert_eq!(cardinal_from_fwd(Vec3::new(-0.7, 0.0, 0.1)), IVec2::new(-1, 0));
+    }
+}
@@ -35,6 +35,8 @@ use crate::{
             AdvanceLevelRequested,
             DeathDelay,
             NewGameRequested,
+            RespawnLevelScore,
+            RestartLevelRequested,
             RestartRequested,
         },
         DeathOverlay,
@@ -51,6 +53,42 @@ use crate::{
 // (3) be Gated by WorldReady Run Condition
 // Missing-resource Panics Treated as Regressions. Add Gating Before Adding New Res Dependencies
 
+/// HUD Loadout the Player Carried Into the Current Level. Captured by Every Path
+/// That Enters a Level so Restart Level Can Revert Score / Inventory to it
+#[derive(Resource, Debug, Clone, Default)]
+pub struct LevelEntryState {
+    pub hud: HudState,
+}
+
+/// Loadout for a Restart Level. Score Always Reverts to the Level Entry Value and
+/// Lives Stay as They Are Now (Deaths During the Abandoned Attempt Still Count)
+/// Pistol Start Drops to the New Game Loadout (With the Skill's Starting Ammo),
+/// Otherwise the Entry Inventory Returns
+/// Keys Never Carry, the Rebuilt Level Starts With Every Lock Closed
+pub fn level_restart_hud(
+    entry: &HudState,
+    current: &HudState,
+    pistol_start: bool,
+    skill: davelib::skill::SkillLevel,
+) -> HudState {
+    let mut hud = if pistol_start {
+        HudState {
+            ammo: skill.starting_ammo(),
+            ..HudState::default()
+        }
+    } else {
+        entry.clone()
+    };
+
+    hud.score = entry.score;
+    hud.rebase_extra_life();
+    hud.lives = current.lives;
+    hud.key_gold = false;
+    hud.key_silver = false;
+
+    hud
+}
+
 // Despawn What Should NOT Persist Across Life Restart
 // Leave UI / Resources Alone, Rebuild Entire 3D World + Actors
 pub fn restart_despawn_level(
@@ -101,14 +139,27 @@ pub fn restart_finish(
     mut pw_state: ResMut<PushwallState>,
     mut pw_occ: ResMut<PushwallOcc>,
     mut pw_clock: ResMut<PushwallClock>,
+    mut entry: ResMut<LevelEntryState>,
+    mut level_score: ResMut<davelib::level_score::LevelScore>,
+    mut respawn_score: ResMut<RespawnLevelScore>,
+    skill: Res<davelib::skill::SkillLevel>,
 ) {
+    // The Clock of the Attempt Survives the Rebuild, the Found Counts Do Not
+    if let Some(prev) = respawn_score.0.take() {
+        level_score.keep_clock_from(&prev);
+    }
+
     // Keep Lives + Score, Reset Everything Else
     let lives = hud.lives;
     let score = hud.score;
+    let next_extra = hud.next_extra;
 
     *hud = HudState::default();
+    hud.ammo = skill.starting_ammo();
     hud.lives = lives;
     hud.score = score;
+    hud.next_extra = next_extra;
+    entry.hud = hud.clone();
 
     // Clear Death / Restart Nookkeeping + Win State
     *death = Default::default();
@@ -137,12 +188,22 @@ pub fn new_game_finish(
     mut pw_state: ResMut<PushwallState>,
     mut pw_occ: ResMut<PushwallOcc>,
     mut pw_clock: ResMut<PushwallClock>,
+    mut entry: ResMut<LevelEntryState>,
+    mut god: ResMut<davelib::player::GodMode>,
+    mut cheats: ResMut<davelib::player::CheatState>,
+    skill: Res<davelib::skill::SkillLevel>,
 ) {
     if !new_game.0 {
         return;
     }
 
+    // Cheats Belong to One Run, a New Game Starts Clean and Eligible for Scores
+    god.0 = false;
+    *cheats = davelib::player::CheatState::default();
+
     *hud = HudState::default();
+    hud.ammo = skill.starting_ammo();
+    entry.hud = hud.clone();
     *episode_stats = davelib::level_score::EpisodeStats::default();
 
     *death = Default::default();
@@ -170,11 +231,13 @@ pub fn advance_level_finish(
     mut pw_clock: ResMut<davelib::pushwalls::PushwallClock>,
     mut q_vitals: Query<&mut davelib::player::PlayerVitals, With<davelib::player::Player>>,
     mut q_keys: Query<&mut davelib::player::PlayerKeys, With<davelib::player::Player>>,
+    mut entry: ResMut<LevelEntryState>,
 ) {
     // Preserve Run Stats (Ammo / Score / Lives / Weapons) by NOT Resetting
     // HudState but Keys Do Not Carry Across Levels
     hud.key_gold = false;
     hud.key_silver = false;
+    entry.hud = hud.clone();
 
     if let Some(mut pkeys) = q_keys.iter_mut().next() {
         pkeys.gold = false;
@@ -202,6 +265,105 @@ pub fn advance_level_finish(
     advance.0 = false;
 }
 
+/// Set by the Stress Map Toggle so restart_level_finish Rebuilds Without Treating
+/// the Swap as a Retry. Under Pistol Start a Retry Strips the Loadout
+#[derive(Resource, Debug, Default)]
+pub struct StressMapSwap(pub bool);
+
+/// What a Restart Level Reverts to: the Request Itself, the Entry Loadout and
+/// the Settings That Shape the Restored Loadout
+#[derive(SystemParam)]
+pub struct RestartLevelParams<'w> {
+    restart_level: ResMut<'w, RestartLevelRequested>,
+    swap: ResMut<'w, StressMapSwap>,
+    gameplay: Res<'w, davelib::options::GameplaySettings>,
+    skill: Res<'w, davelib::skill::SkillLevel>,
+    entry: Res<'w, LevelEntryState>,
+}
+
+pub fn restart_level_finish(
+    mut req: RestartLevelParams,
+    mut lock: ResMut<PlayerControlLock>,
+    mut latch: ResMut<PlayerDeathLatch>,
+    mut death: ResMut<DeathDelay>,
+    mut hud: ResMut<HudState>,
+    mut win: ResMut<LevelComplete>,
+    mut tally: ResMut<MissionSuccessTally>,
+    mut elevator_delay: ResMut<ElevatorExitDelay>,
+    mut pending_exit: ResMut<PendingLevelExit>,
+    mut pw_state: ResMut<PushwallState>,
+    mut pw_occ: ResMut<PushwallOcc>,
+    mut pw_clock: ResMut<PushwallClock>,
+    mut q_vitals: Query<&mut PlayerVitals, With<Player>>,
+) {
+    // LevelScore (Kills / Secrets / Treasure / Time) Was Already Reset by
+    // world::setup During the Rebuild, Only the Run Loadout Needs Restoring
+    // A Stress Map Swap Carries the Loadout Over Untouched
+    if !req.swap.0 {
+        let restored = level_restart_hud(
+            &req.entry.hud,
+            &hud,
+            req.gameplay.restart_level_pistol_start,
+            *req.skill,
+        );
+        *hud = restored;
+    }
+    req.swap.0 = false;
+
+    // setup() Spawns PlayerVitals::default(), Match it to the Restored HUD
+    if let Some(mut vitals) = q_vitals.iter_mut().next() {
+        vitals.hp = hud.hp.clamp(1, vitals.hp_max);
+        hud.hp = vitals.hp;
+    }
+
+    *death = Default::default();
+    *tally = MissionSuccessTally::default();
+    *elevator_delay = ElevatorExitDelay::default();
+    *pending_exit = PendingLevelExit::default();
+    latch.0 = false;
+    lock.0 = false;
+    win.0 = false;
+
+    pw_state.active = None;
+    pw_occ.clear();
+    pw_clock.reset();
+
+    // Consume Request
+    req.restart_level.0 = false;
+}
+
+/// Debug Key: Swap the Current Level for the Bundled Stress Map (or Back) by
+/// Rebuilding Through the Restart Level Path, Flagged so the Loadout Is Kept
+pub fn toggle_stress_map(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut stress: ResMut<davelib::perf_overlay::StressMap>,
+    mut restart_level: ResMut<RestartLevelRequested>,
+    mut swap: ResMut<StressMapSwap>,
+) {
+    if !keys.just_pressed(davelib::perf_overlay::STRESS_MAP_TOGGLE_KEY) || restart_level.0 {
+        return;
+    }
+
+    stress.0 = !stress.0;
+    restart_level.0 = true;
+    swap.0 = true;
+    info!("Stress Map: {}", if stress.0 { "On" } else { "Off" });
+}
+
+/// The Stress Map Stands in for One Floor Only. Runs Ahead of the Rebuild so a
+/// New Game, Level Advance or Load Builds the Real Level Instead
+pub fn drop_stress_map_on_level_change(
+    new_game: Res<NewGameRequested>,
+    advance: Res<AdvanceLevelRequested>,
+    load: Res<crate::save::LoadGameRequested>,
+    mut stress: ResMut<davelib::perf_overlay::StressMap>,
+) {
+    if stress.0 && (new_game.0 || advance.0 || load.0.is_some()) {
+        stress.0 = false;
+        info!("Stress Map: Off");
+    }
+}
+
 #[derive(SystemParam)]
 pub struct LoadRequestParams<'w> {
     load: ResMut<'w, crate::save::LoadGameRequested>,
@@ -213,6 +375,9 @@ pub struct LoadRequestParams<'w> {
     restart: ResMut<'w, RestartRequested>,
     new_game: ResMut<'w, NewGameRequested>,
     advance: ResMut<'w, AdvanceLevelRequested>,
+    restart_level: ResMut<'w, RestartLevelRequested>,
+    god: ResMut<'w, davelib::player::GodMode>,
+    cheats: ResMut<'w, davelib::player::CheatState>,
 }
 
 #[derive(SystemParam)]
@@ -232,6 +397,7 @@ pub struct LoadRuntimeParams<'w> {
     pw_state: ResMut<'w, PushwallState>,
     pw_occ: ResMut<'w, PushwallOcc>,
     pw_clock: ResMut<'w, PushwallClock>,
+    entry: ResMut<'w, LevelEntryState>,
 }
 
 #[derive(SystemParam)]
@@ -259,6 +425,14 @@ pub fn load_game_finish(
     };
 
     crate::save::capture::apply_run_state(&mut *state.hud, &game.run_state);
+
+    // Cheats Belong to the Run Being Loaded, Not the One Being Left. Toggles Are
+    // Switched off and Only the Saved Mark Carries Over
+    req.god.0 = false;
+    *req.cheats = davelib::player::CheatState {
+        used: game.run_state.cheated,
+        ..default()
+    };
     crate::save::capture::apply_level_score(&mut *state.level_score, &game.level_score);
     crate::save::capture::apply_episode_stats(&mut *state.episode_stats, &game.episode_stats);
 
@@ -345,6 +519,7 @@ pub fn load_game_finish(
         req.restart.0 = false;
         req.new_game.0 = false;
         req.advance.0 = false;
+        req.restart_level.0 = false;
 
         return;
     };
@@ -361,6 +536,10 @@ pub fn load_game_finish(
 
     state.hud.hp = vitals.hp;
 
+    // A Mid-Level Save Has No Record of its Entry Loadout, so Restart Level
+    // After a Load Returns to the Loaded State
+    state.entry.hud = state.hud.clone();
+
     *state.death = DeathDelay::default();
     *state.death_overlay = DeathOverlay::default();
     *state.tally = MissionSuccessTally::default();
@@ -379,4 +558,129 @@ pub fn load_game_finish(
     req.restart.0 = false;
     req.new_game.0 = false;
     req.advance.0 = false;
+    req.restart_level.0 = false;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::combat::WeaponSlot;
+    use davelib::skill::SkillLevel;
+
+    fn entry_hud() -> HudState {
+        let mut hud = HudState::default();
+        hud.score = 4_000;
+        hud.ammo = 40;
+        hud.grant(WeaponSlot::MachineGun);
+        hud.selected = WeaponSlot::MachineGun;
+        hud
+    }
+
+    fn current_hud() -> HudState {
+        let mut hud = entry_hud();
+        hud.score = 9_500;
+        hud.lives = 2;
+        hud.hp = 31;
+        hud.key_gold = true;
+        hud.grant(WeaponSlot::Chaingun);
+        hud
+    }
+
+    #[test]
+    fn pistol_start_reverts_score_and_drops_inventory() {
+        let hud = level_restart_hud(&entry_hud(), &current_hud(), true, SkillLevel(1));
+
+        assert_eq!(hud.score, 4_000);
+        assert_eq!(hud.lives, 2);
+        assert_eq!(hud.hp, 100);
+        assert_eq!(hud.ammo, HudState::default().ammo);
+        assert_eq!(hud.selected, WeaponSlot::Pistol);
+        assert!(!hud.owns(WeaponSlot::MachineGun));
+        assert!(!hud.key_gold);
+
+        // Can I Play Daddy Restarts With its Own Starting Ammo
+        let daddy = level_restart_hud(&entry_hud(), &current_hud(), true, SkillLevel(0));
+        assert_eq!(daddy.ammo, 16);
+        assert_eq!(daddy.selected, WeaponSlot::Pistol);
+    }
+
+    #[test]
+    fn keep_items_restores_entry_inventory() {
+        let hud = level_restart_hud(&entry_hud(), &current_hud(), false, SkillLevel(0));
+
+        assert_eq!(hud.score, 4_000);
+        assert_eq!(hud.lives, 2);
+        assert_eq!(hud.ammo, 40);
+        assert_eq!(hud.selected, WeaponSlot::MachineGun);
+        assert!(hud.owns(WeaponSlot::MachineGun));
+        assert!(hud.owns(WeaponSlot::MachineGun));
+        assert!(!hud.owns(WeaponSlot::Chaingun));
+        assert!(!hud.key_gold);
+    }
+
+    #[test]
+    fn stress_map_survives_a_retry_but_not_a_level_change() {
+        let mut app = App::new();
+        app.insert_resource(davelib::perf_overlay::StressMap(true))
+            .init_resource::<NewGameRequested>()
+            .init_resource::<AdvanceLevelRequested>()
+            .init_resource::<crate::save::LoadGameRequested>()
+            .add_systems(Update, drop_stress_map_on_level_change);
+
+        app.update();
+        assert!(app.world().resource::<davelib::perf_overlay::StressMap>().0);
+
+        app.world_mut().resource_mut::<AdvanceLevelRequested>().0 = true;
+        app.update();
+        assert!(!app.world().resource::<davelib::perf_overlay::StressMap>().0);
+    }
+
+    #[test]
+    fn restart_level_reverts_the_loadout_and_clears_the_abandoned_attempt() {
+        let mut app = App::new();
+        app.insert_resource(RestartLevelRequested(true))
+            .init_resource::<StressMapSwap>()
+            .insert_resource(davelib::options::GameplaySettings {
+                restart_level_pistol_start: false,
+                ..Default::default()
+            })
+            .init_resource::<SkillLevel>()
+            .insert_resource(LevelEntryState { hud: entry_hud() })
+            .insert_resource(PlayerControlLock(true))
+            .insert_resource(PlayerDeathLatch(true))
+            .init_resource::<DeathDelay>()
+            .insert_resource(current_hud())
+            .insert_resource(LevelComplete(true))
+            .init_resource::<MissionSuccessTally>()
+            .init_resource::<ElevatorExitDelay>()
+            .init_resource::<PendingLevelExit>()
+            .init_resource::<PushwallState>()
+            .init_resource::<PushwallOcc>()
+            .init_resource::<PushwallClock>()
+            .add_systems(Update, restart_level_finish);
+
+        // Died Mid-Attempt With a Pushwall Still Sliding
+        app.world_mut().resource_mut::<DeathDelay>().active = true;
+        app.world_mut().resource_mut::<ElevatorExitDelay>().active = true;
+        app.world_mut().resource_mut::<PushwallOcc>().a = Some(IVec2::new(3, 3));
+        let player = app.world_mut().spawn((Player, PlayerVitals::default())).id();
+
+        app.update();
+
+        let world = app.world();
+        let hud = world.resource::<HudState>();
+        assert_eq!((hud.score, hud.lives, hud.ammo), (4_000, 2, 40));
+        assert!(hud.owns(WeaponSlot::MachineGun));
+        assert!(!hud.owns(WeaponSlot::Chaingun));
+        assert!(!hud.key_gold);
+        assert_eq!(world.get::<PlayerVitals>(player).unwrap().hp, hud.hp);
+
+        assert!(!world.resource::<RestartLevelRequested>().0);
+        assert!(!world.resource::<PlayerControlLock>().0);
+        assert!(!world.resource::<PlayerDeathLatch>().0);
+        assert!(!world.resource::<LevelComplete>().0);
+        assert!(!world.resource::<DeathDelay>().active);
+        assert!(!world.resource::<ElevatorExitDelay>().active);
+        assert!(world.resource::<PushwallOcc>().a.is_none());
+    }
 }
@@ -11,7 +11,7 @@ This Module is the Bridge Between Live ECS State and the Serializable Model
 use bevy::prelude::*;
 
 use crate::save::model::*;
-use davelib::enemies::{EnemyKind, SpawnIndex};
+use davelib::enemies::{EnemyKind, SpawnId, SpawnIndex};
 use davelib::level::{CurrentLevel, LevelId};
 use davelib::level_score::{EpisodeStats, LevelScore};
 use davelib::player::{
@@ -103,19 +103,47 @@ pub fn ai_state_from_u8(v: u8) -> davelib::ai::EnemyAiState {
 }
 
 /// Build the Dead-Enemy List From the Dead Enemies Currently in the World
-/// Each is Identified by Its Kind and Stable Per-Kind Spawn Index, Which is All
-/// Restore Needs to Put It Back as a Corpse on Load
+/// Each is Identified by Its Origin Tile, Plus Kind and Per-Kind Spawn Index as
+/// a Fallback, Which is All Restore Needs to Put It Back as a Corpse on Load
 pub fn capture_dead_enemies(
-    dead: &[(EnemyKind, SpawnIndex)],
+    dead: &[(EnemyKind, SpawnIndex, Option<SpawnId>)],
 ) -> Vec<DeadEnemy> {
     dead.iter()
-        .map(|(kind, idx)| DeadEnemy {
+        .map(|(kind, idx, id)| DeadEnemy {
             kind: enemy_kind_to_u8(*kind),
             index: idx.0,
+            origin: id.map(|id| [id.0.x, id.0.y]),
         })
         .collect()
 }
 
+/// Identity Key a Saved Enemy Is Matched Back By on Load
+/// Origin Tile When the Save Carries One, Else the Legacy (kind, index) Pair
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EnemyKey {
+    Origin(IVec2),
+    Index(u8, u32),
+}
+
+impl EnemyKey {
+    /// Key for a Saved Record
+    pub fn saved(kind: u8, index: u32, origin: Option<[i32; 2]>) -> Self {
+        match origin {
+            Some([x, z]) => EnemyKey::Origin(IVec2::new(x, z)),
+            None => EnemyKey::Index(kind, index),
+        }
+    }
+
+    /// Both Keys a Rebuilt Enemy Can Answer to. Origin First so a Save With
+    /// Origins Never Falls Through to the Index Match
+    pub fn live(kind: EnemyKind, idx: SpawnIndex, id: Option<SpawnId>) -> [Option<Self>; 2] {
+        [
+            id.map(|id| EnemyKey::Origin(id.0)),
+            Some(EnemyKey::Index(enemy_kind_to_u8(kind), idx.0)),
+        ]
+    }
+}
+
 /// Build a Bucket-1 SaveGame From Current Live State. Pure Reads, No Mutation
 /// Call This From a Save Trigger System That Has These Params Available
 pub fn capture_save_game(
@@ -126,6 +154,7 @@ pub fn capture_save_game(
     current_level: &CurrentLevel,
     level_score: &LevelScore,
     skill: u8,
+    cheated: bool,
     episode_stats: &EpisodeStats,
     dead_enemies: Vec<DeadEnemy>,
     present_pickups: Vec<[i32; 2]>,
@@ -156,6 +185,7 @@ pub fn capture_save_game(
         // Reads It From Res<SkillLevel> and Passes It In so capture Stays a Pure
         // Translation With No Resource Access of Its Own
         skill,
+        cheated,
     };
 
     let player = PlayerSnapshot {
@@ -227,11 +257,18 @@ pub fn apply_run_state(hud: &mut HudState, rs: &RunState) {
     hud.hp = rs.hp;
     hud.ammo = rs.ammo;
     hud.score = rs.score;
+    hud.rebase_extra_life();
     hud.lives = rs.lives;
     hud.key_gold = rs.key_gold;
     hud.key_silver = rs.key_silver;
     hud.owned_mask = rs.owned_mask;
+    hud.grant(crate::combat::WeaponSlot::Knife);
     hud.selected = weapon_from_u8(rs.selected_weapon);
+
+    // A Hand-Edited or Damaged Save Can Name a Weapon it Does Not Own
+    if !hud.owns(hud.selected) {
+        hud.selected = hud.best_owned();
+    }
 }
 
 /// Stamp Saved Player Position/Facing/Vitals Onto the Freshly Spawned Player
@@ -305,3 +342,64 @@ fn weapon_from_u8(v: u8) -> crate::combat::WeaponSlot {
         _ => Pistol, // Safe Fallback
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dead_enemy_round_trips_by_origin_tile() {
+        let origin = IVec2::new(12, 40);
+        let dead = capture_dead_enemies(&[(EnemyKind::Guard, SpawnIndex(3), Some(SpawnId(origin)))]);
+        let saved: std::collections::HashSet<EnemyKey> = dead
+            .iter()
+            .map(|d| EnemyKey::saved(d.kind, d.index, d.origin))
+            .collect();
+
+        // Same Origin Matches Even if Spawn Order Shifted the Index
+        let moved = EnemyKey::live(EnemyKind::Guard, SpawnIndex(7), Some(SpawnId(origin)));
+        assert!(moved.iter().flatten().any(|k| saved.contains(k)));
+
+        // Same Index but a Different Origin Is a Different Enemy
+        let other = EnemyKey::live(EnemyKind::Guard, SpawnIndex(3), Some(SpawnId(IVec2::new(1, 1))));
+        assert!(!other.iter().flatten().any(|k| saved.contains(k)));
+    }
+
+    #[test]
+    fn legacy_save_without_origin_matches_by_index() {
+        let saved = EnemyKey::saved(enemy_kind_to_u8(EnemyKind::Ss), 2, None);
+        let live = EnemyKey::live(EnemyKind::Ss, SpawnIndex(2), Some(SpawnId(IVec2::new(5, 5))));
+        assert!(live.iter().flatten().any(|k| *k == saved));
+    }
+
+    #[test]
+    fn cheated_mark_survives_a_save_and_older_saves_read_clean() {
+        let game = capture_save_game(
+            "E1M1".to_string(),
+            &HudState::default(),
+            &Transform::default(),
+            &PlayerVitals::default(),
+            &CurrentLevel(LevelId::E1M1),
+            &LevelScore::default(),
+            0,
+            true,
+            &EpisodeStats::default(),
+            Vec::new(),
+            Vec::new(),
+            Vec::new(),
+            Vec::new(),
+            Vec::new(),
+            Vec::new(),
+            true,
+            Vec::new(),
+            true,
+            Vec::new(),
+        );
+        let text = ron::to_string(&game.run_state).unwrap();
+        assert!(ron::from_str::<RunState>(&text).unwrap().cheated);
+
+        let legacy = "(hp: 100, ammo: 8, score: 0, lives: 3, key_gold: false, key_silver: false, \
+            selected_weapon: 1, owned_mask: 3)";
+        assert!(!ron::from_str::<RunState>(legacy).unwrap().cheated);
+    }
+}
@@ -16,6 +16,7 @@ pub mod storage;
 pub mod capture;
 
 use bevy::prelude::*;
+use bevy::ecs::system::SystemParam;
 
 use davelib::level::CurrentLevel;
 use davelib::level_score::LevelScore;
@@ -40,7 +41,7 @@ pub struct PendingDeadRestore(pub Vec<model::DeadEnemy>);
 
 /// Living-Enemy State From a Just-Loaded Save. Like PendingDeadRestore This
 /// Waits for the Rebuilt Enemies to Spawn, Then apply_pending_enemy_restore
-/// Matches Each by (kind, index) and Overwrites Its Health, Position, and Alert
+/// Matches Each by Origin Tile and Overwrites Its Health, Position, and Alert
 #[derive(Resource, Default)]
 pub struct PendingEnemyRestore(pub Vec<model::EnemySnapshot>);
 
@@ -110,147 +111,190 @@ impl Plugin for SavePlugin {
     }
 }
 
-/// When SaveGameRequested Holds a Slot, Capture Current State and Write It
-fn handle_save_requests(
-    mut req: ResMut<SaveGameRequested>,
-    hud: Res<HudState>,
-    current_level: Res<CurrentLevel>,
-    level_score: Res<LevelScore>,
-    skill: Res<davelib::skill::SkillLevel>,
-    episode_stats: Res<davelib::level_score::EpisodeStats>,
-    q_player: Query<(&Transform, &PlayerVitals), With<Player>>,
+/// Live World State a Snapshot Reads. Shared by Slot Saves and the In-Memory
+/// Death Checkpoints, so Both Capture Exactly the Same Things
+#[derive(SystemParam)]
+pub struct SnapshotParams<'w, 's> {
+    hud: Res<'w, HudState>,
+    current_level: Res<'w, CurrentLevel>,
+    level_score: Res<'w, LevelScore>,
+    skill: Res<'w, davelib::skill::SkillLevel>,
+    cheats: Res<'w, davelib::player::CheatState>,
+    episode_stats: Res<'w, davelib::level_score::EpisodeStats>,
+    q_player: Query<'w, 's, (&'static Transform, &'static PlayerVitals), With<Player>>,
     q_dead: Query<
-        (&davelib::enemies::EnemyKind, &davelib::enemies::SpawnIndex),
+        'w,
+        's,
+        (
+            &'static davelib::enemies::EnemyKind,
+            &'static davelib::enemies::SpawnIndex,
+            Option<&'static davelib::enemies::SpawnId>,
+        ),
         With<davelib::actors::Dead>,
     >,
     // Living Enemies (Not Yet Dead). A Dying Enemy Has hp <= 0 but Is Not Dead
     // Yet, so It Is Captured Here and Restored as a Corpse via the hp Check
     q_alive: Query<
+        'w,
+        's,
         (
-            &davelib::enemies::EnemyKind,
-            &davelib::enemies::SpawnIndex,
-            &davelib::actors::Health,
-            &Transform,
-            &davelib::actors::OccupiesTile,
-            &davelib::ai::EnemyAi,
+            &'static davelib::enemies::EnemyKind,
+            &'static davelib::enemies::SpawnIndex,
+            Option<&'static davelib::enemies::SpawnId>,
+            &'static davelib::actors::Health,
+            &'static Transform,
+            &'static davelib::actors::OccupiesTile,
+            &'static davelib::ai::EnemyAi,
         ),
         Without<davelib::actors::Dead>,
     >,
-    q_pickups: Query<(&crate::pickups::Pickup, Option<&crate::pickups::DroppedPickup>)>,
-    q_doors: Query<(&davelib::map::DoorTile, &davelib::map::DoorState)>,
-    completed_pushwalls: Res<davelib::pushwalls::CompletedPushwalls>,
+    q_pickups: Query<'w, 's, (&'static crate::pickups::Pickup, Option<&'static crate::pickups::DroppedPickup>)>,
+    q_doors: Query<'w, 's, (&'static davelib::map::DoorTile, &'static davelib::map::DoorState)>,
+    completed_pushwalls: Res<'w, davelib::pushwalls::CompletedPushwalls>,
     // Optional Because PushwallMarkers Is Inserted by setup and Does Not Exist
     // Before the First Level Loads (Ex: Saving From a Menu). A Strict Res Would
     // Fail System-Param Validation and Panic in That Window
-    markers: Option<Res<davelib::pushwalls::PushwallMarkers>>,
-) {
-    let Some(slot) = req.0 else { return; };
+    markers: Option<Res<'w, davelib::pushwalls::PushwallMarkers>>,
+}
 
-    let Ok((player_tf, vitals)) = q_player.single() else {
-        // No Player Yet (Ex: In a Menu) - Clear Request and Do Nothing
-        req.0 = None;
-        return;
-    };
+impl SnapshotParams<'_, '_> {
+    /// Auto-Name From Current Level For Now (Ex: "E1M3")
+    /// Slice B Replaces This With a Player-Typed Name via Save Name-Entry Screen
+    pub fn level_name(&self) -> String {
+        let lr = capture::level_to_ref(self.current_level.0);
+        format!("E{}M{}", lr.episode, lr.floor)
+    }
 
-    // Auto-Name From Current Level For Now (Ex: "E1M3")
-    // Slice B Replaces This With a Player-Typed Name via Save Name-Entry Screen
-    let lr = capture::level_to_ref(current_level.0);
-    let name = format!("E{}M{}", lr.episode, lr.floor);
-
-    // Collect Dead Enemies by Kind + Stable Spawn Index
-    // Load Restores Them as Corpses Instead of Respawning Them Alive
-    let dead: Vec<(davelib::enemies::EnemyKind, davelib::enemies::SpawnIndex)> =
-        q_dead.iter().map(|(k, i)| (*k, *i)).collect();
-    let dead_enemies = capture::capture_dead_enemies(&dead);
-
-    // Every Living Enemy With Its Health, Position, and Alert State. Load Matches
-    // Each One by (kind, index), so the Restored Skill Must Line Up With the Save.
-    // #1 Ensures That, Keeping the Spawn Set and Its Indices Aligned
-    let enemies: Vec<model::EnemySnapshot> = q_alive
-        .iter()
-        .map(|(kind, idx, hp, tf, occ, ai)| model::EnemySnapshot {
-            kind: capture::enemy_kind_to_u8(*kind),
-            index: idx.0,
-            hp_cur: hp.cur,
-            pos: [tf.translation.x, tf.translation.y, tf.translation.z],
-            tile: [occ.0.x, occ.0.y],
-            ai_state: capture::ai_state_to_u8(ai.state),
-            last_step: [ai.last_step.x, ai.last_step.y],
-        })
-        .collect();
+    /// Capture the Live World Into a SaveGame. None When There Is No Player
+    /// Yet (Ex: In a Menu)
+    pub fn capture(&self, name: String) -> Option<model::SaveGame> {
+        let Ok((player_tf, vitals)) = self.q_player.single() else {
+            return None;
+        };
 
-    // Tiles That Still Hold a Pickup Are the Un-Collected Ones (Collecting
-    // Despawns the Entity), so on Load We Despawn Any Pickup Not in This Set
-    let present_pickups: Vec<[i32; 2]> =
-        q_pickups.iter().map(|(p, _)| [p.tile.x, p.tile.y]).collect();
+        // Collect Dead Enemies by Origin Tile (Plus Kind + Spawn Index Fallback)
+        // Load Restores Them as Corpses Instead of Respawning Them Alive
+        let dead: Vec<(
+            davelib::enemies::EnemyKind,
+            davelib::enemies::SpawnIndex,
+            Option<davelib::enemies::SpawnId>,
+        )> = self.q_dead.iter().map(|(k, i, id)| (*k, *i, id.copied())).collect();
+        let dead_enemies = capture::capture_dead_enemies(&dead);
+
+        // Every Living Enemy With Its Health, Position, and Alert State. Load Matches
+        // Each One by Origin Tile, Falling Back to (kind, index) for Enemies Without
+        // a SpawnId, so the Restored Skill Must Line Up With the Save.
+        // #1 Ensures That, Keeping the Spawn Set and Its Indices Aligned
+        let enemies: Vec<model::EnemySnapshot> = self
+            .q_alive
+            .iter()
+            .map(|(kind, idx, id, hp, tf, occ, ai)| model::EnemySnapshot {
+                kind: capture::enemy_kind_to_u8(*kind),
+                index: idx.0,
+                origin: id.map(|id| [id.0.x, id.0.y]),
+                hp_cur: hp.cur,
+                pos: [tf.translation.x, tf.translation.y, tf.translation.z],
+                tile: [occ.0.x, occ.0.y],
+                ai_state: capture::ai_state_to_u8(ai.state),
+                last_step: [ai.last_step.x, ai.last_step.y],
+            })
+            .collect();
 
-    // Every Live Pickup With Its Kind, Map-Placed and Enemy-Dropped Alike. The
-    // Load Re-Spawns This List Verbatim, Which Is the Only Way an Enemy-Dropped
-    // Item (a Boss Key) Survives, Since the Map Rebuild Cannot Recreate It
-    let pickups_full: Vec<model::PickupSnapshot> = q_pickups
-        .iter()
-        .map(|(p, dropped)| model::PickupSnapshot {
-            tile: [p.tile.x, p.tile.y],
-            kind: p.kind,
-            dropped: dropped.is_some(),
-        })
-        .collect();
+        // Tiles That Still Hold a Pickup Are the Un-Collected Ones (Collecting
+        // Despawns the Entity), so on Load We Despawn Any Pickup Not in This Set
+        let present_pickups: Vec<[i32; 2]> =
+            self.q_pickups.iter().map(|(p, _)| [p.tile.x, p.tile.y]).collect();
 
-    // Doors Whose want_open Is True Were Open (or Opening) at Save Time
-    // Load Re-Opens These, Letting the Normal Door Tick / Auto-Close Take Over
-    let open_doors: Vec<[i32; 2]> = q_doors
-        .iter()
-        .filter(|(_, state)| state.want_open)
-        .map(|(door, _)| [door.0.x, door.0.y])
-        .collect();
+        // Every Live Pickup With Its Kind, Map-Placed and Enemy-Dropped Alike. The
+        // Load Re-Spawns This List Verbatim, Which Is the Only Way an Enemy-Dropped
+        // Item (a Boss Key) Survives, Since the Map Rebuild Cannot Recreate It
+        let pickups_full: Vec<model::PickupSnapshot> = self
+            .q_pickups
+            .iter()
+            .map(|(p, dropped)| model::PickupSnapshot {
+                tile: [p.tile.x, p.tile.y],
+                kind: p.kind,
+                dropped: dropped.is_some(),
+            })
+            .collect();
 
-    // Completed Pushwalls, Converted From the Engine Record to the Save Model
-    let pushwalls: Vec<model::PushwallRec> = completed_pushwalls
-        .items
-        .iter()
-        .map(|c| model::PushwallRec {
-            dest: [c.dest.x, c.dest.y],
-            dir: [c.dir.x, c.dir.y],
-            wall_id: c.wall_id,
-            tiles_moved: c.tiles_moved,
-        })
-        .collect();
+        // Doors Whose want_open Is True Were Open (or Opening) at Save Time
+        // Load Re-Opens These, Letting the Normal Door Tick / Auto-Close Take Over
+        let open_doors: Vec<[i32; 2]> = self
+            .q_doors
+            .iter()
+            .filter(|(_, state)| state.want_open)
+            .map(|(door, _)| [door.0.x, door.0.y])
+            .collect();
 
-    // Persist the Live Marker and Credit Grids so a Load Restores Them Exactly
-    // Rather Than Rederiving From the Completed Records. A Missing PushwallMarkers
-    // Resource Means There Is No Pushwall State to Save (No Level Loaded Yet)
-    let (marked_tiles, credited_tiles, pushwall_state_saved) = match &markers {
-        Some(m) => (
-            m.marked_tiles().iter().map(|t| [t.x, t.y]).collect(),
-            m.credited_tiles().iter().map(|t| [t.x, t.y]).collect(),
+        // Completed Pushwalls, Converted From the Engine Record to the Save Model
+        let pushwalls: Vec<model::PushwallRec> = self
+            .completed_pushwalls
+            .items
+            .iter()
+            .map(|c| model::PushwallRec {
+                dest: [c.dest.x, c.dest.y],
+                dir: [c.dir.x, c.dir.y],
+                wall_id: c.wall_id,
+                tiles_moved: c.tiles_moved,
+            })
+            .collect();
+
+        // Persist the Live Marker and Credit Grids so a Load Restores Them Exactly
+        // Rather Than Rederiving From the Completed Records. A Missing PushwallMarkers
+        // Resource Means There Is No Pushwall State to Save (No Level Loaded Yet)
+        let (marked_tiles, credited_tiles, pushwall_state_saved) = match &self.markers {
+            Some(m) => (
+                m.marked_tiles().iter().map(|t| [t.x, t.y]).collect(),
+                m.credited_tiles().iter().map(|t| [t.x, t.y]).collect(),
+                true,
+            ),
+            None => (Vec::new(), Vec::new(), false),
+        };
+
+        Some(capture::capture_save_game(
+            name,
+            &self.hud,
+            player_tf,
+            vitals,
+            &self.current_level,
+            &self.level_score,
+            self.skill.0,
+            self.cheats.used,
+            &self.episode_stats,
+            dead_enemies,
+            present_pickups,
+            open_doors,
+            pushwalls,
+            marked_tiles,
+            credited_tiles,
+            pushwall_state_saved,
+            pickups_full,
             true,
-        ),
-        None => (Vec::new(), Vec::new(), false),
-    };
+            enemies,
+        ))
+    }
+}
 
-    let game = capture::capture_save_game(
-        name,
-        &hud,
-        player_tf,
-        vitals,
-        &current_level,
-        &level_score,
-        skill.0,
-        &episode_stats,
-        dead_enemies,
-        present_pickups,
-        open_doors,
-        pushwalls,
-        marked_tiles,
-        credited_tiles,
-        pushwall_state_saved,
-        pickups_full,
-        true,
-        enemies,
-    );
+/// When SaveGameRequested Holds a Slot, Capture Current State and Write It
+fn handle_save_requests(
+    mut req: ResMut<SaveGameRequested>,
+    mut session: ResMut<crate::settings::LastSession>,
+    snapshot: SnapshotParams,
+) {
+    let Some(slot) = req.0 else { return; };
+
+    // No Player Yet (Ex: In a Menu) - Clear Request and Do Nothing
+    let Some(game) = snapshot.capture(snapshot.level_name()) else {
+        req.0 = None;
+        return;
+    };
 
     match storage::write_slot(slot, &game) {
-        Ok(()) => info!("Saved Game to Slot {slot}"),
+        Ok(()) => {
+            info!("Saved Game to Slot {slot}");
+            session.slot = Some(slot);
+        }
         Err(e) => error!("Save to Slot {slot} Failed: {e:?}"),
     }
 
@@ -268,6 +312,7 @@ fn apply_pending_dead_restore(
         Entity,
         &davelib::enemies::EnemyKind,
         &davelib::enemies::SpawnIndex,
+        Option<&davelib::enemies::SpawnId>,
     )>,
 ) {
     if pending.0.is_empty() {
@@ -280,13 +325,17 @@ fn apply_pending_dead_restore(
         return;
     }
 
-    // Build a Fast Lookup of Which (kind_u8, index) Pairs Should be Dead
-    let dead_set: std::collections::HashSet<(u8, u32)> =
-        pending.0.iter().map(|d| (d.kind, d.index)).collect();
+    // Build a Fast Lookup of Which Enemies Should be Dead, Keyed by Origin Tile
+    // (or (kind_u8, index) for Saves Written Before Origins Were Stored)
+    let dead_set: std::collections::HashSet<capture::EnemyKey> = pending
+        .0
+        .iter()
+        .map(|d| capture::EnemyKey::saved(d.kind, d.index, d.origin))
+        .collect();
 
-    for (e, kind, idx) in q_enemies.iter() {
-        let key = (capture::enemy_kind_to_u8(*kind), idx.0);
-        if dead_set.contains(&key) {
+    for (e, kind, idx, id) in q_enemies.iter() {
+        let keys = capture::EnemyKey::live(*kind, *idx, id.copied());
+        if keys.iter().flatten().any(|k| dead_set.contains(k)) {
             make_corpse(&mut commands, e, *kind);
         }
     }
@@ -296,7 +345,7 @@ fn apply_pending_dead_restore(
 }
 
 /// Restore Living-Enemy State After the Rebuild. Matches Each Rebuilt Enemy to
-/// Its Saved Twin by (kind, index) and Overwrites Health, Position, Occupied
+/// Its Saved Twin by Origin Tile (or (kind, index)) and Overwrites Health, Position, Occupied
 /// Tile, and Alert State. A Twin Saved With hp <= 0 (Dying) Becomes a Corpse
 fn apply_pending_enemy_restore(
     mut commands: Commands,
@@ -306,6 +355,7 @@ fn apply_pending_enemy_restore(
             Entity,
             &davelib::enemies::EnemyKind,
             &davelib::enemies::SpawnIndex,
+            Option<&davelib::enemies::SpawnId>,
             &mut davelib::actors::Health,
             &mut Transform,
             &mut davelib::actors::OccupiesTile,
@@ -324,17 +374,18 @@ fn apply_pending_enemy_restore(
         return;
     }
 
-    // Index the Saved Enemies by (kind_u8, index) for a Fast Match. Clones Are
-    // Owned so the Pending List Can Clear at the End Without a Borrow Snag
-    let by_id: std::collections::HashMap<(u8, u32), model::EnemySnapshot> = pending
+    // Index the Saved Enemies by Origin Tile (or (kind_u8, index) for Older
+    // Saves) for a Fast Match. Clones Are Owned so the Pending List Can Clear at
+    // the End Without a Borrow Snag
+    let by_id: std::collections::HashMap<capture::EnemyKey, model::EnemySnapshot> = pending
         .0
         .iter()
-        .map(|e| ((e.kind, e.index), e.clone()))
+        .map(|e| (capture::EnemyKey::saved(e.kind, e.index, e.origin), e.clone()))
         .collect();
 
-    for (entity, kind, idx, mut hp, mut tf, mut occ, mut ai) in q_enemies.iter_mut() {
-        let key = (capture::enemy_kind_to_u8(*kind), idx.0);
-        let Some(snap) = by_id.get(&key) else {
+    for (entity, kind, idx, id, mut hp, mut tf, mut occ, mut ai) in q_enemies.iter_mut() {
+        let keys = capture::EnemyKey::live(*kind, *idx, id.copied());
+        let Some(snap) = keys.iter().flatten().find_map(|k| by_id.get(k)) else {
             continue;
         };
 
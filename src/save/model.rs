@@ -78,6 +78,12 @@ pub struct RunState {
     /// serde(default) Keeps Older Saves Loadable at Skill 0 (Easy)
     #[serde(default)]
     pub skill: u8,
+
+    /// Whether Any Cheat Was Entered This Run. Persisted so Saving and Loading
+    /// Cannot Launder a Cheated Run Back Onto the High Score Table
+    /// serde(default) Reads Older Saves as Clean
+    #[serde(default)]
+    pub cheated: bool,
 }
 
 /// One Level's Contribution to the Episode Tally, Mirrored From EpisodeLevelStats
@@ -111,7 +117,7 @@ pub struct PickupSnapshot {
 }
 
 /// A Single Living Enemy at Save Time, Matched Back to Its Rebuilt Twin by
-/// (kind, index). An hp_cur of 0 or Less Means the Enemy Was Dying and Comes
+/// origin (or (kind, index) in Older Saves). An hp_cur of 0 or Less Means the Enemy Was Dying and Comes
 /// Back as a Corpse, so a Boss Caught Mid Death Animation Stays Dead. Health,
 /// Position, and ai_state Restore the Mid-Fight Scene. Transient AI (the Move
 /// Target and Burst Fire) Is Not Stored and Re-Derives Next Tick, so the Save
@@ -120,6 +126,10 @@ pub struct PickupSnapshot {
 pub struct EnemySnapshot {
     pub kind: u8,
     pub index: u32,
+    /// Origin Map Tile (SpawnId). Preferred Over (kind, index) When Present;
+    /// Older Saves Without It Fall Back to the Index Match
+    #[serde(default)]
+    pub origin: Option<[i32; 2]>,
     pub hp_cur: i32,
     pub pos: [f32; 3],
     pub tile: [i32; 2],
@@ -240,11 +250,14 @@ pub struct PushwallRec {
 }
 
 /// Dead Enemy Identity For Corpse Restore
-/// Kind + Per-Kind Spawn Order Is Enough to Restore on Load
+/// origin (the Enemy's Spawn Tile) Is the Primary Key; Kind + Per-Kind Spawn
+/// Order Remains as the Fallback for Saves Written Before origin Existed
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct DeadEnemy {
     pub kind: u8,
     pub index: u32,
+    #[serde(default)]
+    pub origin: Option<[i32; 2]>,
 }
 
 impl SaveGame {
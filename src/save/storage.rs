@@ -9,7 +9,7 @@ Save Model, Capture, and Menu Code Stay Identical
 Native Implementation Stores Saves Under Platform Data Dir as Slot Files
 */
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use crate::save::model::{
     SaveGame,
@@ -85,8 +85,38 @@ pub fn write_slot(slot: u32, game: &SaveGame) -> Result<(), SaveError> {
     let ron_str = ron::ser::to_string_pretty(game, ron::ser::PrettyConfig::default())
         .map_err(|e| SaveError::Serialize(e.to_string()))?;
 
-    std::fs::write(slot_path(slot)?, ron_str.as_bytes())?;
-    Ok(())
+    replace_file(&slot_path(slot)?, ron_str.as_bytes())
+}
+
+/// Write Beside `path` and Rename Over it, so a Crash or Full Disk Mid-Save
+/// Leaves the Previous Save Intact Instead of a Truncated File. rename Replaces
+/// an Existing File on Every Platform, so the Old Save Stays on Disk Until the
+/// New One Takes its Place. A Failed Write Cleans up its Temporary File
+fn replace_file(path: &Path, bytes: &[u8]) -> Result<(), SaveError> {
+    let tmp = path.with_extension("ron.tmp");
+    let written = std::fs::write(&tmp, bytes).and_then(|()| std::fs::rename(&tmp, path));
+    if written.is_err() {
+        let _ = std::fs::remove_file(&tmp);
+    }
+    Ok(written?)
+}
+
+/// Read and Version-Check One Save File
+fn read_file(path: &Path) -> Result<SaveGame, SaveError> {
+    let bytes = std::fs::read(path)?;
+    let text = String::from_utf8_lossy(&bytes);
+
+    let game: SaveGame =
+        ron::from_str(&text).map_err(|e| SaveError::Deserialize(e.to_string()))?;
+
+    if game.version != SAVE_FORMAT_VERSION {
+        return Err(SaveError::VersionMismatch {
+            found: game.version,
+            expected: SAVE_FORMAT_VERSION,
+        });
+    }
+
+    Ok(game)
 }
 
 /// Read and Deserialize a SaveGame From the Given Slot
@@ -97,20 +127,7 @@ pub fn read_slot(slot: u32) -> Result<Option<SaveGame>, SaveError> {
             continue;
         }
 
-        let bytes = std::fs::read(&path)?;
-        let text = String::from_utf8_lossy(&bytes);
-
-        let game: SaveGame =
-            ron::from_str(&text).map_err(|e| SaveError::Deserialize(e.to_string()))?;
-
-        if game.version != SAVE_FORMAT_VERSION {
-            return Err(SaveError::VersionMismatch {
-                found: game.version,
-                expected: SAVE_FORMAT_VERSION,
-            });
-        }
-
-        return Ok(Some(game));
+        return read_file(&path).map(Some);
     }
 
     Ok(None)
@@ -125,6 +142,12 @@ pub fn slot_occupied(slot: u32) -> bool {
     }
 }
 
+/// Whether a Slot Holds a Save This Build Can Actually Load. False for Empty
+/// Slots and for Corrupt or Version-Mismatched Files
+pub fn slot_loadable(slot: u32) -> bool {
+    matches!(read_slot(slot), Ok(Some(_)))
+}
+
 /// Lightweight Per-Slot Summary For the Load / Save Slot List UI
 /// None = Empty Slot
 /// Some = Stored Save Name and Level Metadata
@@ -152,3 +175,73 @@ pub fn read_all_slot_meta() -> Vec<Option<SlotMeta>> {
         })
         .collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::prelude::Transform;
+    use davelib::level::{CurrentLevel, LevelId};
+    use davelib::level_score::{EpisodeStats, LevelScore};
+    use davelib::player::PlayerVitals;
+
+    fn game_with_score(score: i32) -> SaveGame {
+        let mut hud = crate::ui::HudState::default();
+        hud.score = score;
+        crate::save::capture::capture_save_game(
+            "E1M2".to_string(),
+            &hud,
+            &Transform::default(),
+            &PlayerVitals::default(),
+            &CurrentLevel(LevelId::E1M2),
+            &LevelScore::default(),
+            2,
+            false,
+            &EpisodeStats::default(),
+            Vec::new(),
+            Vec::new(),
+            Vec::new(),
+            Vec::new(),
+            Vec::new(),
+            Vec::new(),
+            true,
+            Vec::new(),
+            true,
+            Vec::new(),
+        )
+    }
+
+    #[test]
+    fn save_file_round_trips_and_overwrites_in_place() {
+        let dir = std::env::temp_dir()
+            .join(format!("davenstein_save_storage_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("savegam0.ron");
+
+        let write = |score| {
+            let text = ron::ser::to_string_pretty(&game_with_score(score), ron::ser::PrettyConfig::default())
+                .unwrap();
+            replace_file(&path, text.as_bytes()).unwrap();
+        };
+
+        write(1_200);
+        write(3_400);
+        let game = read_file(&path).unwrap();
+        let tmp_left = path.with_extension("ron.tmp").exists();
+
+        // A Rename That Cannot Land (a Directory Sits Where the Slot Goes)
+        // Leaves no Temporary File Behind
+        let blocked = dir.join("savegam1.ron");
+        std::fs::create_dir_all(blocked.join("in_the_way")).unwrap();
+        let failed = replace_file(&blocked, b"()");
+        let failed_tmp_left = blocked.with_extension("ron.tmp").exists();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(game.run_state.score, 3_400);
+        assert_eq!(game.run_state.skill, 2);
+        assert_eq!(game.name, "E1M2");
+        assert!(!tmp_left);
+        assert!(failed.is_err());
+        assert!(!failed_tmp_left);
+    }
+}
@@ -12,7 +12,9 @@ the Filesystem Seam. This Module Wires Them Into the App:
 - A Debounced 'Update' Save That Writes Whenever Any Option Resource Changes,
   Coalescing a Burst of Menu Edits Into a Single Disk Write.
 
-Key Bindings Are Not Persisted Yet (See model.rs); Everything Else Round-Trips
+Every Option Round-Trips, Key and Mouse Bindings Included (See model.rs for How
+They Are Named), Along With the LastSession Menu Memory (Continue Slot, Last
+Episode and Skill)
 */
 
 pub mod model;
@@ -22,13 +24,22 @@ use bevy::prelude::*;
 
 use davelib::options::{ControlSettings, GameplaySettings, SoundSettings, VideoSettings};
 
-use model::SettingsFile;
+use model::{SessionDto, SettingsFile};
+
+/// Remembered Across Runs: the Save Slot Last Written or Loaded, Which the Main
+/// Menu's Continue Reopens, and the Episode / Skill Rows Last Picked for a New Game
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LastSession {
+    pub slot: Option<u32>,
+    pub episode: usize,
+    pub skill: usize,
+}
 
 pub struct SettingsPlugin;
 
 impl Plugin for SettingsPlugin {
     fn build(&self, app: &mut App) {
-        app
+        app.init_resource::<LastSession>()
             // PreStartup Runs Before Startup, so the Loaded Values Are in Place
             // Before OptionsPlugin's 'apply_*_startup' Chain Reads Them
             .add_systems(PreStartup, load_settings)
@@ -43,10 +54,12 @@ fn load_settings(
     mut control: ResMut<ControlSettings>,
     mut sound: ResMut<SoundSettings>,
     mut gameplay: ResMut<GameplaySettings>,
+    mut session: ResMut<LastSession>,
 ) {
     match store::load() {
         Ok(Some(file)) => {
             file.apply(&mut video, &mut control, &mut sound, &mut gameplay);
+            file.session.apply(&mut session);
             info!("Loaded player settings from settings.ron");
         }
         Ok(None) => {
@@ -69,6 +82,7 @@ fn save_settings_on_change(
     control: Res<ControlSettings>,
     sound: Res<SoundSettings>,
     gameplay: Res<GameplaySettings>,
+    session: Res<LastSession>,
     mut dirty: Local<bool>,
     mut debounce: Local<Option<Timer>>,
 ) {
@@ -76,6 +90,7 @@ fn save_settings_on_change(
         || control.is_changed()
         || sound.is_changed()
         || gameplay.is_changed()
+        || session.is_changed()
     {
         *dirty = true;
         *debounce = Some(Timer::from_seconds(0.75, TimerMode::Once));
@@ -91,7 +106,8 @@ fn save_settings_on_change(
         }
     }
 
-    let file = SettingsFile::from_resources(&video, &control, &sound, &gameplay);
+    let mut file = SettingsFile::from_resources(&video, &control, &sound, &gameplay);
+    file.session = SessionDto::from_session(&session);
     if let Err(e) = store::save(&file) {
         warn!("Could not write settings.ron ({e})");
     }
@@ -15,24 +15,36 @@ Two Robustness Choices Worth Noting:
   Enum Variants Can Never Corrupt an Existing Config (Same Reasoning as the
   Explicit u8 Maps in save/capture.rs).
 
-Key Bindings Are Intentionally Not Persisted Yet. They Hold bevy 'KeyCode' Values,
-Which Need Either bevy's Optional 'serialize' Feature or an Explicit
-KeyCode <-> String Map; That Lands in a Follow-Up Once the Approach Is Chosen.
-Until Then Bindings Stay at Their Defaults on Load and Everything Else Persists.
+Key Bindings Go Through the Same Kind of Explicit Map: Each Action Is Saved Under
+a Stable Name With its Key or Mouse Button as a Stable Name Too (KEY_NAMES,
+"mouse_left" ..). An Unknown Name Keeps That Action's Default, and Where Two
+Loaded Actions Would Share an Input Only the Clashing Action Falls Back.
 */
 
+use bevy::prelude::{KeyCode, MouseButton};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 use davelib::options::{
+    AutoAim,
     ControlSettings,
+    CrosshairStyle,
+    InputBinding,
+    KeyBindings,
     DisplayMode,
+    FovMode,
+    FpsCap,
     GameplaySettings,
     MsaaSetting,
+    PsychedScreen,
     RenderScale,
     SoundSettings,
     VideoSettings,
+    ViewmodelRecoil,
 };
 
+use crate::settings::LastSession;
+
 /// Bump When the On-Disk Settings Format Changes Incompatibly. A File With a
 /// Different Version Is Ignored on Load (the Player Keeps Live Defaults)
 pub const SETTINGS_FORMAT_VERSION: u32 = 1;
@@ -48,21 +60,32 @@ pub struct SettingsFile {
     pub control: ControlDto,
     pub sound: SoundDto,
     pub gameplay: GameplayDto,
+    pub session: SessionDto,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(default)]
 pub struct VideoDto {
     pub vsync: Option<bool>,
+    /// "off" | "30" | "60" | "120" | "144" | "240"
+    pub fps_cap: Option<String>,
     /// "windowed" | "borderless" | "exclusive"
     pub display_mode: Option<String>,
     pub resolution: Option<[u32; 2]>,
     pub fov: Option<f32>,
+    /// "vertical" | "horizontal"
+    pub fov_mode: Option<String>,
     pub view_size: Option<u8>,
     /// "off" | "x4"
     pub msaa: Option<String>,
     /// "native" | "75" | "50" | "33"
     pub render_scale: Option<String>,
+    pub integer_scale: Option<bool>,
+    pub textured_floor: Option<bool>,
+    /// "off" | "dot" | "cross"
+    pub crosshair: Option<String>,
+    pub gore: Option<bool>,
+    pub screen_shake: Option<bool>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -74,7 +97,9 @@ pub struct ControlDto {
     pub gamepad_enabled: Option<bool>,
     pub gamepad_sensitivity: Option<f32>,
     pub gamepad_deadzone: Option<f32>,
-    // Key Bindings Deliberately Omitted for Now (See Module Header)
+    pub always_run: Option<bool>,
+    /// Action Name -> Input Name, See KEY_BINDING_ACTIONS and binding_to_str
+    pub key_bindings: Option<BTreeMap<String, String>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -83,6 +108,7 @@ pub struct SoundDto {
     pub master_volume: Option<f32>,
     pub music_volume: Option<f32>,
     pub sfx_volume: Option<f32>,
+    pub ui_volume: Option<f32>,
     pub music_enabled: Option<bool>,
     pub sfx_enabled: Option<bool>,
 }
@@ -91,10 +117,304 @@ pub struct SoundDto {
 #[serde(default)]
 pub struct GameplayDto {
     pub reversible_pushwalls: Option<bool>,
+    pub restart_level_pistol_start: Option<bool>,
+    pub damage_flash_over_weapon: Option<bool>,
+    pub damage_direction_indicator: Option<bool>,
+    pub skip_splash: Option<bool>,
+    pub screen_fade: Option<bool>,
+    pub psyched_screen: Option<String>,
+    pub hold_use_doors: Option<bool>,
+    pub secret_notice: Option<bool>,
+    /// "off" | "subtle" | "normal" | "strong"
+    pub viewmodel_recoil: Option<String>,
+    pub checkpoint_respawn: Option<bool>,
+    pub true_3d_aim: Option<bool>,
+    pub health_regen: Option<bool>,
+    pub empty_weapon_switch: Option<bool>,
+    /// "off" | "classic" | "generous"
+    pub auto_aim: Option<String>,
 }
 
 // ---- Stable Enum <-> String Maps (Reorder-Proof) ----
 
+/// Saved Name of Each Rebindable Action, in KeyBindings Index Order
+const KEY_BINDING_ACTIONS: [&str; KeyBindings::COUNT] = [
+    "move_forward",
+    "move_backward",
+    "strafe_left",
+    "strafe_right",
+    "turn_left",
+    "turn_right",
+    "fire",
+    "use",
+    "run",
+    "weapon_1",
+    "weapon_2",
+    "weapon_3",
+    "weapon_4",
+];
+
+/// Stable Names for the Keys a Binding Can Be Saved as: Every KeyCode but
+/// Escape, Which Cancels a Rebind. An Unidentified Key Has no Portable Name and
+/// Is Simply Not Written, so That Action Loads Back at its Default
+const KEY_NAMES: &[(KeyCode, &str)] = &[
+    (KeyCode::KeyA, "a"),
+    (KeyCode::KeyB, "b"),
+    (KeyCode::KeyC, "c"),
+    (KeyCode::KeyD, "d"),
+    (KeyCode::KeyE, "e"),
+    (KeyCode::KeyF, "f"),
+    (KeyCode::KeyG, "g"),
+    (KeyCode::KeyH, "h"),
+    (KeyCode::KeyI, "i"),
+    (KeyCode::KeyJ, "j"),
+    (KeyCode::KeyK, "k"),
+    (KeyCode::KeyL, "l"),
+    (KeyCode::KeyM, "m"),
+    (KeyCode::KeyN, "n"),
+    (KeyCode::KeyO, "o"),
+    (KeyCode::KeyP, "p"),
+    (KeyCode::KeyQ, "q"),
+    (KeyCode::KeyR, "r"),
+    (KeyCode::KeyS, "s"),
+    (KeyCode::KeyT, "t"),
+    (KeyCode::KeyU, "u"),
+    (KeyCode::KeyV, "v"),
+    (KeyCode::KeyW, "w"),
+    (KeyCode::KeyX, "x"),
+    (KeyCode::KeyY, "y"),
+    (KeyCode::KeyZ, "z"),
+    (KeyCode::Digit0, "digit_0"),
+    (KeyCode::Digit1, "digit_1"),
+    (KeyCode::Digit2, "digit_2"),
+    (KeyCode::Digit3, "digit_3"),
+    (KeyCode::Digit4, "digit_4"),
+    (KeyCode::Digit5, "digit_5"),
+    (KeyCode::Digit6, "digit_6"),
+    (KeyCode::Digit7, "digit_7"),
+    (KeyCode::Digit8, "digit_8"),
+    (KeyCode::Digit9, "digit_9"),
+    (KeyCode::F1, "f1"),
+    (KeyCode::F2, "f2"),
+    (KeyCode::F3, "f3"),
+    (KeyCode::F4, "f4"),
+    (KeyCode::F5, "f5"),
+    (KeyCode::F6, "f6"),
+    (KeyCode::F7, "f7"),
+    (KeyCode::F8, "f8"),
+    (KeyCode::F9, "f9"),
+    (KeyCode::F10, "f10"),
+    (KeyCode::F11, "f11"),
+    (KeyCode::F12, "f12"),
+    (KeyCode::ArrowUp, "arrow_up"),
+    (KeyCode::ArrowDown, "arrow_down"),
+    (KeyCode::ArrowLeft, "arrow_left"),
+    (KeyCode::ArrowRight, "arrow_right"),
+    (KeyCode::Space, "space"),
+    (KeyCode::Enter, "enter"),
+    (KeyCode::Tab, "tab"),
+    (KeyCode::Backspace, "backspace"),
+    (KeyCode::ShiftLeft, "shift_left"),
+    (KeyCode::ShiftRight, "shift_right"),
+    (KeyCode::ControlLeft, "control_left"),
+    (KeyCode::ControlRight, "control_right"),
+    (KeyCode::AltLeft, "alt_left"),
+    (KeyCode::AltRight, "alt_right"),
+    (KeyCode::Insert, "insert"),
+    (KeyCode::Delete, "delete"),
+    (KeyCode::Home, "home"),
+    (KeyCode::End, "end"),
+    (KeyCode::PageUp, "page_up"),
+    (KeyCode::PageDown, "page_down"),
+    (KeyCode::Comma, "comma"),
+    (KeyCode::Period, "period"),
+    (KeyCode::Slash, "slash"),
+    (KeyCode::Semicolon, "semicolon"),
+    (KeyCode::Quote, "quote"),
+    (KeyCode::BracketLeft, "bracket_left"),
+    (KeyCode::BracketRight, "bracket_right"),
+    (KeyCode::Backslash, "backslash"),
+    (KeyCode::Minus, "minus"),
+    (KeyCode::Equal, "equal"),
+    (KeyCode::Backquote, "backquote"),
+    (KeyCode::NumpadEnter, "numpad_enter"),
+    (KeyCode::NumpadAdd, "numpad_add"),
+    (KeyCode::NumpadSubtract, "numpad_subtract"),
+    (KeyCode::NumpadMultiply, "numpad_multiply"),
+    (KeyCode::NumpadDivide, "numpad_divide"),
+    (KeyCode::NumpadDecimal, "numpad_decimal"),
+    (KeyCode::Numpad0, "numpad_0"),
+    (KeyCode::Numpad1, "numpad_1"),
+    (KeyCode::Numpad2, "numpad_2"),
+    (KeyCode::Numpad3, "numpad_3"),
+    (KeyCode::Numpad4, "numpad_4"),
+    (KeyCode::Numpad5, "numpad_5"),
+    (KeyCode::Numpad6, "numpad_6"),
+    (KeyCode::Numpad7, "numpad_7"),
+    (KeyCode::Numpad8, "numpad_8"),
+    (KeyCode::Numpad9, "numpad_9"),
+    // Every Other Key the Rebind Screen Can Capture
+    (KeyCode::IntlBackslash, "intl_backslash"),
+    (KeyCode::IntlRo, "intl_ro"),
+    (KeyCode::IntlYen, "intl_yen"),
+    (KeyCode::CapsLock, "caps_lock"),
+    (KeyCode::ContextMenu, "context_menu"),
+    (KeyCode::SuperLeft, "super_left"),
+    (KeyCode::SuperRight, "super_right"),
+    (KeyCode::Convert, "convert"),
+    (KeyCode::KanaMode, "kana_mode"),
+    (KeyCode::Lang1, "lang_1"),
+    (KeyCode::Lang2, "lang_2"),
+    (KeyCode::Lang3, "lang_3"),
+    (KeyCode::Lang4, "lang_4"),
+    (KeyCode::Lang5, "lang_5"),
+    (KeyCode::NonConvert, "non_convert"),
+    (KeyCode::Help, "help"),
+    (KeyCode::NumLock, "num_lock"),
+    (KeyCode::NumpadBackspace, "numpad_backspace"),
+    (KeyCode::NumpadClear, "numpad_clear"),
+    (KeyCode::NumpadClearEntry, "numpad_clear_entry"),
+    (KeyCode::NumpadComma, "numpad_comma"),
+    (KeyCode::NumpadEqual, "numpad_equal"),
+    (KeyCode::NumpadHash, "numpad_hash"),
+    (KeyCode::NumpadMemoryAdd, "numpad_memory_add"),
+    (KeyCode::NumpadMemoryClear, "numpad_memory_clear"),
+    (KeyCode::NumpadMemoryRecall, "numpad_memory_recall"),
+    (KeyCode::NumpadMemoryStore, "numpad_memory_store"),
+    (KeyCode::NumpadMemorySubtract, "numpad_memory_subtract"),
+    (KeyCode::NumpadParenLeft, "numpad_paren_left"),
+    (KeyCode::NumpadParenRight, "numpad_paren_right"),
+    (KeyCode::NumpadStar, "numpad_star"),
+    (KeyCode::Fn, "fn"),
+    (KeyCode::FnLock, "fn_lock"),
+    (KeyCode::PrintScreen, "print_screen"),
+    (KeyCode::ScrollLock, "scroll_lock"),
+    (KeyCode::Pause, "pause"),
+    (KeyCode::BrowserBack, "browser_back"),
+    (KeyCode::BrowserFavorites, "browser_favorites"),
+    (KeyCode::BrowserForward, "browser_forward"),
+    (KeyCode::BrowserHome, "browser_home"),
+    (KeyCode::BrowserRefresh, "browser_refresh"),
+    (KeyCode::BrowserSearch, "browser_search"),
+    (KeyCode::BrowserStop, "browser_stop"),
+    (KeyCode::Eject, "eject"),
+    (KeyCode::LaunchApp1, "launch_app_1"),
+    (KeyCode::LaunchApp2, "launch_app_2"),
+    (KeyCode::LaunchMail, "launch_mail"),
+    (KeyCode::MediaPlayPause, "media_play_pause"),
+    (KeyCode::MediaSelect, "media_select"),
+    (KeyCode::MediaStop, "media_stop"),
+    (KeyCode::MediaTrackNext, "media_track_next"),
+    (KeyCode::MediaTrackPrevious, "media_track_previous"),
+    (KeyCode::Power, "power"),
+    (KeyCode::Sleep, "sleep"),
+    (KeyCode::AudioVolumeDown, "audio_volume_down"),
+    (KeyCode::AudioVolumeMute, "audio_volume_mute"),
+    (KeyCode::AudioVolumeUp, "audio_volume_up"),
+    (KeyCode::WakeUp, "wake_up"),
+    (KeyCode::Meta, "meta"),
+    (KeyCode::Hyper, "hyper"),
+    (KeyCode::Turbo, "turbo"),
+    (KeyCode::Abort, "abort"),
+    (KeyCode::Resume, "resume"),
+    (KeyCode::Suspend, "suspend"),
+    (KeyCode::Again, "again"),
+    (KeyCode::Copy, "copy"),
+    (KeyCode::Cut, "cut"),
+    (KeyCode::Find, "find"),
+    (KeyCode::Open, "open"),
+    (KeyCode::Paste, "paste"),
+    (KeyCode::Props, "props"),
+    (KeyCode::Select, "select"),
+    (KeyCode::Undo, "undo"),
+    (KeyCode::Hiragana, "hiragana"),
+    (KeyCode::Katakana, "katakana"),
+    (KeyCode::F13, "f13"),
+    (KeyCode::F14, "f14"),
+    (KeyCode::F15, "f15"),
+    (KeyCode::F16, "f16"),
+    (KeyCode::F17, "f17"),
+    (KeyCode::F18, "f18"),
+    (KeyCode::F19, "f19"),
+    (KeyCode::F20, "f20"),
+    (KeyCode::F21, "f21"),
+    (KeyCode::F22, "f22"),
+    (KeyCode::F23, "f23"),
+    (KeyCode::F24, "f24"),
+    (KeyCode::F25, "f25"),
+    (KeyCode::F26, "f26"),
+    (KeyCode::F27, "f27"),
+    (KeyCode::F28, "f28"),
+    (KeyCode::F29, "f29"),
+    (KeyCode::F30, "f30"),
+    (KeyCode::F31, "f31"),
+    (KeyCode::F32, "f32"),
+    (KeyCode::F33, "f33"),
+    (KeyCode::F34, "f34"),
+    (KeyCode::F35, "f35"),
+];
+
+fn binding_to_str(b: InputBinding) -> Option<String> {
+    match b {
+        InputBinding::Key(k) => KEY_NAMES.iter().find(|(key, _)| *key == k).map(|(_, n)| n.to_string()),
+        InputBinding::Mouse(MouseButton::Left) => Some("mouse_left".to_string()),
+        InputBinding::Mouse(MouseButton::Right) => Some("mouse_right".to_string()),
+        InputBinding::Mouse(MouseButton::Middle) => Some("mouse_middle".to_string()),
+        InputBinding::Mouse(MouseButton::Back) => Some("mouse_back".to_string()),
+        InputBinding::Mouse(MouseButton::Forward) => Some("mouse_forward".to_string()),
+        InputBinding::Mouse(MouseButton::Other(n)) => Some(format!("mouse_{n}")),
+    }
+}
+
+fn binding_from_str(s: &str) -> Option<InputBinding> {
+    if let Some((key, _)) = KEY_NAMES.iter().find(|(_, n)| *n == s) {
+        return Some(InputBinding::Key(*key));
+    }
+    let button = match s.strip_prefix("mouse_")? {
+        "left" => MouseButton::Left,
+        "right" => MouseButton::Right,
+        "middle" => MouseButton::Middle,
+        "back" => MouseButton::Back,
+        "forward" => MouseButton::Forward,
+        n => MouseButton::Other(n.parse().ok()?),
+    };
+    Some(InputBinding::Mouse(button))
+}
+
+fn key_bindings_to_map(bindings: &KeyBindings) -> BTreeMap<String, String> {
+    KEY_BINDING_ACTIONS
+        .iter()
+        .enumerate()
+        .filter_map(|(i, action)| Some((action.to_string(), binding_to_str(bindings.binding_at(i))?)))
+        .collect()
+}
+
+/// Saved Bindings Laid Over the Current Ones. Where Two Actions Would Share an
+/// Input, Which the Rebind Screen Never Allows, the Later Action Falls Back to
+/// its Current Binding (or the Earlier One if the Later Already Has), so One
+/// Bad Entry Costs Only the Actions it Clashes With
+fn key_bindings_from_map(map: &BTreeMap<String, String>, current: KeyBindings) -> KeyBindings {
+    let mut bindings = current;
+    for (i, action) in KEY_BINDING_ACTIONS.iter().enumerate() {
+        if let Some(b) = map.get(*action).and_then(|s| binding_from_str(s.as_str())) {
+            bindings.set_at(i, b);
+        }
+    }
+
+    // Each Pass Reverts One Saved Binding, so This Ends Once the Set Is Clean
+    // or Only Clashes the Current Set Already Had Are Left
+    while let Some((earlier, later)) = (0..KeyBindings::COUNT)
+        .find_map(|j| bindings.conflict(bindings.binding_at(j), j).filter(|&i| i < j).map(|i| (i, j)))
+    {
+        let revert = [later, earlier]
+            .into_iter()
+            .find(|&i| bindings.binding_at(i) != current.binding_at(i));
+        let Some(revert) = revert else { break; };
+        bindings.set_at(revert, current.binding_at(revert));
+    }
+    bindings
+}
+
 fn display_mode_to_str(m: DisplayMode) -> &'static str {
     match m {
         DisplayMode::Windowed => "windowed",
@@ -146,6 +466,150 @@ fn render_scale_from_str(s: &str) -> Option<RenderScale> {
     }
 }
 
+fn crosshair_to_str(c: CrosshairStyle) -> &'static str {
+    match c {
+        CrosshairStyle::Off => "off",
+        CrosshairStyle::Dot => "dot",
+        CrosshairStyle::Cross => "cross",
+    }
+}
+
+fn crosshair_from_str(s: &str) -> Option<CrosshairStyle> {
+    match s {
+        "off" => Some(CrosshairStyle::Off),
+        "dot" => Some(CrosshairStyle::Dot),
+        "cross" => Some(CrosshairStyle::Cross),
+        _ => None,
+    }
+}
+
+fn fps_cap_to_str(c: FpsCap) -> &'static str {
+    match c {
+        FpsCap::Off => "off",
+        FpsCap::Fps30 => "30",
+        FpsCap::Fps60 => "60",
+        FpsCap::Fps120 => "120",
+        FpsCap::Fps144 => "144",
+        FpsCap::Fps240 => "240",
+    }
+}
+
+fn fps_cap_from_str(s: &str) -> Option<FpsCap> {
+    match s {
+        "off" => Some(FpsCap::Off),
+        "30" => Some(FpsCap::Fps30),
+        "60" => Some(FpsCap::Fps60),
+        "120" => Some(FpsCap::Fps120),
+        "144" => Some(FpsCap::Fps144),
+        "240" => Some(FpsCap::Fps240),
+        _ => None,
+    }
+}
+
+fn fov_mode_to_str(m: FovMode) -> &'static str {
+    match m {
+        FovMode::Vertical => "vertical",
+        FovMode::Horizontal => "horizontal",
+    }
+}
+
+fn fov_mode_from_str(s: &str) -> Option<FovMode> {
+    match s {
+        "vertical" => Some(FovMode::Vertical),
+        "horizontal" => Some(FovMode::Horizontal),
+        _ => None,
+    }
+}
+
+fn psyched_screen_to_str(p: PsychedScreen) -> &'static str {
+    match p {
+        PsychedScreen::Classic => "classic",
+        PsychedScreen::Short => "short",
+        PsychedScreen::Instant => "instant",
+        PsychedScreen::Off => "off",
+    }
+}
+
+fn psyched_screen_from_str(s: &str) -> Option<PsychedScreen> {
+    match s {
+        "classic" => Some(PsychedScreen::Classic),
+        "short" => Some(PsychedScreen::Short),
+        "instant" => Some(PsychedScreen::Instant),
+        "off" => Some(PsychedScreen::Off),
+        _ => None,
+    }
+}
+
+fn viewmodel_recoil_to_str(r: ViewmodelRecoil) -> &'static str {
+    match r {
+        ViewmodelRecoil::Off => "off",
+        ViewmodelRecoil::Subtle => "subtle",
+        ViewmodelRecoil::Normal => "normal",
+        ViewmodelRecoil::Strong => "strong",
+    }
+}
+
+fn viewmodel_recoil_from_str(s: &str) -> Option<ViewmodelRecoil> {
+    match s {
+        "off" => Some(ViewmodelRecoil::Off),
+        "subtle" => Some(ViewmodelRecoil::Subtle),
+        "normal" => Some(ViewmodelRecoil::Normal),
+        "strong" => Some(ViewmodelRecoil::Strong),
+        _ => None,
+    }
+}
+
+fn auto_aim_to_str(a: AutoAim) -> &'static str {
+    match a {
+        AutoAim::Off => "off",
+        AutoAim::Classic => "classic",
+        AutoAim::Generous => "generous",
+    }
+}
+
+fn auto_aim_from_str(s: &str) -> Option<AutoAim> {
+    match s {
+        "off" => Some(AutoAim::Off),
+        "classic" => Some(AutoAim::Classic),
+        "generous" => Some(AutoAim::Generous),
+        _ => None,
+    }
+}
+
+/// Menu Memory Rather Than an Option: What Continue Reopens and Where New Game
+/// Starts Its Cursor
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct SessionDto {
+    pub last_slot: Option<u32>,
+    /// Zero-Based Episode Row
+    pub episode: Option<usize>,
+    /// Zero-Based Skill Row
+    pub skill: Option<usize>,
+}
+
+impl SessionDto {
+    pub fn from_session(session: &LastSession) -> Self {
+        Self {
+            last_slot: session.slot,
+            episode: Some(session.episode),
+            skill: Some(session.skill),
+        }
+    }
+
+    pub fn apply(&self, session: &mut LastSession) {
+        if self.last_slot.is_some() {
+            session.slot = self.last_slot;
+        }
+        if let Some(e) = self.episode {
+            session.episode = e;
+        }
+        if let Some(s) = self.skill {
+            session.skill = s;
+        }
+    }
+}
+
 impl SettingsFile {
     /// Capture the Current Live Resources Into a Fully-Populated DTO (All Some).
     /// Called on Save
@@ -159,12 +623,19 @@ impl SettingsFile {
             version: SETTINGS_FORMAT_VERSION,
             video: VideoDto {
                 vsync: Some(video.vsync),
+                fps_cap: Some(fps_cap_to_str(video.fps_cap).to_string()),
                 display_mode: Some(display_mode_to_str(video.display_mode).to_string()),
                 resolution: Some([video.resolution.0, video.resolution.1]),
                 fov: Some(video.fov),
+                fov_mode: Some(fov_mode_to_str(video.fov_mode).to_string()),
                 view_size: Some(video.view_size),
                 msaa: Some(msaa_to_str(video.msaa).to_string()),
                 render_scale: Some(render_scale_to_str(video.render_scale).to_string()),
+                integer_scale: Some(video.integer_scale),
+                textured_floor: Some(video.textured_floor),
+                crosshair: Some(crosshair_to_str(video.crosshair).to_string()),
+                gore: Some(video.gore),
+                screen_shake: Some(video.screen_shake),
             },
             control: ControlDto {
                 mouse_sensitivity: Some(control.mouse_sensitivity),
@@ -173,17 +644,36 @@ impl SettingsFile {
                 gamepad_enabled: Some(control.gamepad_enabled),
                 gamepad_sensitivity: Some(control.gamepad_sensitivity),
                 gamepad_deadzone: Some(control.gamepad_deadzone),
+                always_run: Some(control.always_run),
+                key_bindings: Some(key_bindings_to_map(&control.key_bindings)),
             },
             sound: SoundDto {
                 master_volume: Some(sound.master_volume),
                 music_volume: Some(sound.music_volume),
                 sfx_volume: Some(sound.sfx_volume),
+                ui_volume: Some(sound.ui_volume),
                 music_enabled: Some(sound.music_enabled),
                 sfx_enabled: Some(sound.sfx_enabled),
             },
             gameplay: GameplayDto {
                 reversible_pushwalls: Some(gameplay.reversible_pushwalls),
+                restart_level_pistol_start: Some(gameplay.restart_level_pistol_start),
+                damage_flash_over_weapon: Some(gameplay.damage_flash_over_weapon),
+                damage_direction_indicator: Some(gameplay.damage_direction_indicator),
+                skip_splash: Some(gameplay.skip_splash),
+                screen_fade: Some(gameplay.screen_fade),
+                psyched_screen: Some(psyched_screen_to_str(gameplay.psyched_screen).to_string()),
+                hold_use_doors: Some(gameplay.hold_use_doors),
+                secret_notice: Some(gameplay.secret_notice),
+                viewmodel_recoil: Some(viewmodel_recoil_to_str(gameplay.viewmodel_recoil).to_string()),
+                checkpoint_respawn: Some(gameplay.checkpoint_respawn),
+                true_3d_aim: Some(gameplay.true_3d_aim),
+                health_regen: Some(gameplay.health_regen),
+                empty_weapon_switch: Some(gameplay.empty_weapon_switch),
+                auto_aim: Some(auto_aim_to_str(gameplay.auto_aim).to_string()),
             },
+            // Filled by the Caller, Which Owns the LastSession Resource
+            session: SessionDto::default(),
         }
     }
 
@@ -202,6 +692,11 @@ impl SettingsFile {
         if let Some(v) = self.video.vsync {
             video.vsync = v;
         }
+        if let Some(s) = &self.video.fps_cap {
+            if let Some(c) = fps_cap_from_str(s) {
+                video.fps_cap = c;
+            }
+        }
         if let Some(s) = &self.video.display_mode {
             if let Some(m) = display_mode_from_str(s) {
                 video.display_mode = m;
@@ -210,11 +705,17 @@ impl SettingsFile {
         if let Some(r) = self.video.resolution {
             video.resolution = (r[0], r[1]);
         }
-        if let Some(f) = self.video.fov {
-            video.fov = f;
+        // Hand-Edited Files Can Hold Anything, Keep the Menu's Ranges
+        if let Some(f) = self.video.fov.filter(|f| f.is_finite()) {
+            video.fov = f.clamp(40.0, 120.0);
+        }
+        if let Some(s) = &self.video.fov_mode {
+            if let Some(m) = fov_mode_from_str(s) {
+                video.fov_mode = m;
+            }
         }
         if let Some(vs) = self.video.view_size {
-            video.view_size = vs;
+            video.view_size = vs.clamp(5, 20);
         }
         if let Some(s) = &self.video.msaa {
             if let Some(m) = msaa_from_str(s) {
@@ -226,10 +727,27 @@ impl SettingsFile {
                 video.render_scale = r;
             }
         }
+        if let Some(v) = self.video.integer_scale {
+            video.integer_scale = v;
+        }
+        if let Some(v) = self.video.textured_floor {
+            video.textured_floor = v;
+        }
+        if let Some(s) = &self.video.crosshair {
+            if let Some(c) = crosshair_from_str(s) {
+                video.crosshair = c;
+            }
+        }
+        if let Some(v) = self.video.gore {
+            video.gore = v;
+        }
+        if let Some(v) = self.video.screen_shake {
+            video.screen_shake = v;
+        }
 
-        // --- Control (Key Bindings Excluded for Now) ---
+        // --- Control ---
         if let Some(v) = self.control.mouse_sensitivity {
-            control.mouse_sensitivity = v;
+            control.mouse_sensitivity = ControlSettings::sanitize_sensitivity(v);
         }
         if let Some(v) = self.control.invert_y {
             control.invert_y = v;
@@ -240,11 +758,17 @@ impl SettingsFile {
         if let Some(v) = self.control.gamepad_enabled {
             control.gamepad_enabled = v;
         }
+        if let Some(v) = self.control.always_run {
+            control.always_run = v;
+        }
         if let Some(v) = self.control.gamepad_sensitivity {
-            control.gamepad_sensitivity = v;
+            control.gamepad_sensitivity = ControlSettings::sanitize_sensitivity(v);
+        }
+        if let Some(v) = self.control.gamepad_deadzone.filter(|v| v.is_finite()) {
+            control.gamepad_deadzone = v.clamp(0.0, 0.5);
         }
-        if let Some(v) = self.control.gamepad_deadzone {
-            control.gamepad_deadzone = v;
+        if let Some(map) = &self.control.key_bindings {
+            control.key_bindings = key_bindings_from_map(map, control.key_bindings);
         }
 
         // --- Sound ---
@@ -257,6 +781,9 @@ impl SettingsFile {
         if let Some(v) = self.sound.sfx_volume {
             sound.sfx_volume = v;
         }
+        if let Some(v) = self.sound.ui_volume {
+            sound.ui_volume = v;
+        }
         if let Some(v) = self.sound.music_enabled {
             sound.music_enabled = v;
         }
@@ -268,5 +795,214 @@ impl SettingsFile {
         if let Some(v) = self.gameplay.reversible_pushwalls {
             gameplay.reversible_pushwalls = v;
         }
+        if let Some(v) = self.gameplay.restart_level_pistol_start {
+            gameplay.restart_level_pistol_start = v;
+        }
+        if let Some(v) = self.gameplay.damage_flash_over_weapon {
+            gameplay.damage_flash_over_weapon = v;
+        }
+        if let Some(v) = self.gameplay.damage_direction_indicator {
+            gameplay.damage_direction_indicator = v;
+        }
+        if let Some(v) = self.gameplay.skip_splash {
+            gameplay.skip_splash = v;
+        }
+        if let Some(v) = self.gameplay.screen_fade {
+            gameplay.screen_fade = v;
+        }
+        if let Some(s) = &self.gameplay.psyched_screen {
+            if let Some(p) = psyched_screen_from_str(s) {
+                gameplay.psyched_screen = p;
+            }
+        }
+        if let Some(v) = self.gameplay.hold_use_doors {
+            gameplay.hold_use_doors = v;
+        }
+        if let Some(v) = self.gameplay.secret_notice {
+            gameplay.secret_notice = v;
+        }
+        if let Some(s) = &self.gameplay.viewmodel_recoil {
+            if let Some(r) = viewmodel_recoil_from_str(s) {
+                gameplay.viewmodel_recoil = r;
+            }
+        }
+        if let Some(v) = self.gameplay.checkpoint_respawn {
+            gameplay.checkpoint_respawn = v;
+        }
+        if let Some(v) = self.gameplay.true_3d_aim {
+            gameplay.true_3d_aim = v;
+        }
+        if let Some(v) = self.gameplay.health_regen {
+            gameplay.health_regen = v;
+        }
+        if let Some(v) = self.gameplay.empty_weapon_switch {
+            gameplay.empty_weapon_switch = v;
+        }
+        if let Some(s) = &self.gameplay.auto_aim {
+            if let Some(a) = auto_aim_from_str(s) {
+                gameplay.auto_aim = a;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fps_cap_survives_save_and_load() {
+        let video = VideoSettings { vsync: false, fps_cap: FpsCap::Fps144, ..Default::default() };
+        let file = SettingsFile::from_resources(
+            &video,
+            &ControlSettings::default(),
+            &SoundSettings::default(),
+            &GameplaySettings::default(),
+        );
+
+        let text = ron::ser::to_string_pretty(&file, ron::ser::PrettyConfig::default()).unwrap();
+        let loaded: SettingsFile = ron::from_str(&text).unwrap();
+
+        let mut restored = VideoSettings::default();
+        loaded.apply(
+            &mut restored,
+            &mut ControlSettings::default(),
+            &mut SoundSettings::default(),
+            &mut GameplaySettings::default(),
+        );
+        assert_eq!(restored.fps_cap, FpsCap::Fps144);
+        assert!(!restored.vsync);
+    }
+
+    #[test]
+    fn auto_aim_survives_save_and_load() {
+        let gameplay = GameplaySettings { auto_aim: AutoAim::Generous, ..Default::default() };
+        let file = SettingsFile::from_resources(
+            &VideoSettings::default(),
+            &ControlSettings::default(),
+            &SoundSettings::default(),
+            &gameplay,
+        );
+
+        let text = ron::ser::to_string_pretty(&file, ron::ser::PrettyConfig::default()).unwrap();
+        let loaded: SettingsFile = ron::from_str(&text).unwrap();
+
+        let mut restored = GameplaySettings::default();
+        loaded.apply(
+            &mut VideoSettings::default(),
+            &mut ControlSettings::default(),
+            &mut SoundSettings::default(),
+            &mut restored,
+        );
+        assert_eq!(restored.auto_aim, AutoAim::Generous);
+    }
+
+    #[test]
+    fn out_of_range_fov_and_view_size_load_clamped() {
+        let text = "(video: (fov: Some(300.0), view_size: Some(1)))";
+        let loaded: SettingsFile = ron::from_str(text).unwrap();
+
+        let mut video = VideoSettings::default();
+        loaded.apply(
+            &mut video,
+            &mut ControlSettings::default(),
+            &mut SoundSettings::default(),
+            &mut GameplaySettings::default(),
+        );
+        assert_eq!(video.fov, 120.0);
+        assert_eq!(video.view_size, 5);
+    }
+
+    #[test]
+    fn rebound_keys_survive_save_and_load() {
+        let mut control = ControlSettings::default();
+        control.key_bindings.use_door = MouseButton::Right.into();
+        control.key_bindings.fire = KeyCode::KeyF.into();
+
+        let file = SettingsFile::from_resources(
+            &VideoSettings::default(),
+            &control,
+            &SoundSettings::default(),
+            &GameplaySettings::default(),
+        );
+        let text = ron::ser::to_string_pretty(&file, ron::ser::PrettyConfig::default()).unwrap();
+        let loaded: SettingsFile = ron::from_str(&text).unwrap();
+
+        let mut restored = ControlSettings::default();
+        loaded.apply(
+            &mut VideoSettings::default(),
+            &mut restored,
+            &mut SoundSettings::default(),
+            &mut GameplaySettings::default(),
+        );
+        assert_eq!(restored.key_bindings, control.key_bindings);
+
+        // A Hand-Edited File Binding Two Actions to One Key Only Loses That Entry
+        let mut clash = loaded.clone();
+        let map = clash.control.key_bindings.as_mut().unwrap();
+        map.insert("fire".to_string(), "w".to_string());
+        let mut kept = ControlSettings::default();
+        clash.apply(
+            &mut VideoSettings::default(),
+            &mut kept,
+            &mut SoundSettings::default(),
+            &mut GameplaySettings::default(),
+        );
+        let defaults = KeyBindings::default();
+        assert_eq!(kept.key_bindings.fire, defaults.fire);
+        assert_eq!(kept.key_bindings.move_forward, defaults.move_forward);
+        assert_eq!(kept.key_bindings.use_door, control.key_bindings.use_door);
+    }
+
+    #[test]
+    fn swapped_bindings_load_and_clashes_fall_back_per_action() {
+        let defaults = KeyBindings::default();
+        let map = |pairs: &[(&str, &str)]| -> BTreeMap<String, String> {
+            pairs.iter().map(|(a, k)| (a.to_string(), k.to_string())).collect()
+        };
+
+        // Swapping Two Keys Clashes With the Defaults Midway but Not at the End
+        let swapped = key_bindings_from_map(
+            &map(&[("move_forward", "s"), ("move_backward", "w")]),
+            defaults,
+        );
+        assert_eq!(swapped.move_forward, KeyCode::KeyS.into());
+        assert_eq!(swapped.move_backward, KeyCode::KeyW.into());
+
+        // Two Saved Actions on One Key: the Later Falls Back, the Rest Still Load
+        let clashing = key_bindings_from_map(
+            &map(&[("fire", "f"), ("use", "f"), ("run", "caps_lock")]),
+            defaults,
+        );
+        assert_eq!(clashing.fire, KeyCode::KeyF.into());
+        assert_eq!(clashing.use_door, defaults.use_door);
+        assert_eq!(clashing.run, KeyCode::CapsLock.into());
+    }
+
+    #[test]
+    fn every_capturable_key_has_a_unique_name_that_loads_back() {
+        let mut seen = std::collections::HashSet::new();
+        for &(key, name) in KEY_NAMES {
+            assert!(seen.insert(name), "{name} named twice");
+            assert_eq!(binding_to_str(InputBinding::Key(key)).as_deref(), Some(name));
+            assert_eq!(binding_from_str(name), Some(InputBinding::Key(key)));
+        }
+        assert!(binding_to_str(KeyCode::F35.into()).is_some());
+        assert!(binding_to_str(KeyCode::Escape.into()).is_none());
+    }
+
+    #[test]
+    fn unknown_fps_cap_keeps_default() {
+        let mut file = SettingsFile::default();
+        file.video.fps_cap = Some("999".to_string());
+
+        let mut video = VideoSettings::default();
+        file.apply(
+            &mut video,
+            &mut ControlSettings::default(),
+            &mut SoundSettings::default(),
+            &mut GameplaySettings::default(),
+        );
+        assert_eq!(video.fps_cap, FpsCap::Off);
     }
 }
@@ -6,6 +6,13 @@ use bevy::prelude::*;
 
 /// Selected Skill Level (Difficulty)
 /// Maps to Wolfenstein 3-D's 4 Difficulty Settings
+///
+/// | Skill                 | Spawn Bands   | Guards      | Damage Taken | Starting Ammo | Enemy Fire Rate |
+/// |-----------------------|---------------|-------------|--------------|---------------|-----------------|
+/// | Can I Play, Daddy?    | Base          | Every Other | 0.5x         | 16            | 1.0x            |
+/// | Don't Hurt Me         | Base          | All         | 0.75x        | 8             | 1.0x            |
+/// | Bring 'Em On!         | Base + Medium | All         | 1.0x         | 8             | 1.0x            |
+/// | I Am Death Incarnate! | All Three     | All         | 1.25x        | 8             | 1.5x            |
 #[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SkillLevel(pub u8);
 
@@ -34,6 +41,7 @@ impl SkillLevel {
     /// - Easy (Levels 0 - 1): Offset 0
     /// - Medium (Level 2): Offset 36
     /// - Hard (Level 3): Offset 72
+    /// Can I Play Daddy Then Thins its Guards Further, See guard_stride
     pub fn spawn_offset(&self) -> u16 {
         match self.0 {
             0 | 1 => 0,   // Easy Difficulties use Base Spawn Codes
@@ -43,14 +51,53 @@ impl SkillLevel {
         }
     }
 
+    /// Keep One Guard in Every `n` of the Banded Spawns, in Map Order
+    /// - Can I Play Daddy: 2 (Every Other Guard Stays Home)
+    /// - Every Other Skill: 1 (All of Them)
+    pub fn guard_stride(&self) -> usize {
+        match self.0 {
+            0 => 2,
+            _ => 1,
+        }
+    }
+
     /// Get Damage Multiplier for Difficulty
-    /// Wolfenstein 3-D Reduces Enemy Damage on Easier Difficulties
+    /// Wolfenstein 3-D Reduces Enemy Damage on Easier Difficulties, Death
+    /// Incarnate Hits Harder Than the Original. Applied to Every Hit the Player Takes via scale_damage
     pub fn damage_multiplier(&self) -> f32 {
         match self.0 {
             0 => 0.5,  // Can I Play Daddy: 50% damage
             1 => 0.75, // Don't Hurt Me: 75% damage
             2 => 1.0,  // Bring 'Em On: 100% damage
-            3 => 1.0,  // Death Incarnate: 100% damage
+            3 => 1.25, // Death Incarnate: 125% damage
+            _ => 1.0,
+        }
+    }
+
+    /// Damage a Hit of `points` Actually Deals at This Skill, Hitscan and
+    /// Projectiles Alike. Truncates Toward Zero, so a Graze Can Round Down to
+    /// Nothing on the Easier Skills
+    pub fn scale_damage(&self, points: i32) -> i32 {
+        (points as f32 * self.damage_multiplier()) as i32
+    }
+
+    /// Rounds the Player Starts a Run (and Each Life) With
+    /// - Can I Play Daddy: 16
+    /// - Every Other Skill: the Classic 8
+    pub fn starting_ammo(&self) -> i32 {
+        match self.0 {
+            0 => 16,
+            _ => 8,
+        }
+    }
+
+    /// How Fast an Enemy's Wait Between Shots Runs Down. The Shot Animation
+    /// Itself Keeps its Length, Only the Pause Before the Next Shot Shrinks
+    /// - Death Incarnate: 1.5x (a Third Less Wait)
+    /// - Every Other Skill: 1.0x
+    pub fn fire_rate(&self) -> f32 {
+        match self.0 {
+            3 => 1.5,
             _ => 1.0,
         }
     }
@@ -60,6 +107,17 @@ impl SkillLevel {
         self.0 >= 3
     }
 
+    /// Per-Tic Odds (out of 256) That a Soldier Holding to Shoot Side-Steps
+    /// Instead. Never on the Easiest Skill, Roughly Once a Second on the Hardest
+    pub fn side_step_chance(&self) -> i32 {
+        match self.0 {
+            0 => 0,
+            1 => 1,
+            2 => 2,
+            _ => 4,
+        }
+    }
+
     /// Get Difficulty Name
     pub fn name(&self) -> &'static str {
         match self.0 {
@@ -94,6 +152,23 @@ mod tests {
         assert_eq!(SkillLevel(0).damage_multiplier(), 0.5);
         assert_eq!(SkillLevel(1).damage_multiplier(), 0.75);
         assert_eq!(SkillLevel(2).damage_multiplier(), 1.0);
-        assert_eq!(SkillLevel(3).damage_multiplier(), 1.0);
+        assert_eq!(SkillLevel(3).damage_multiplier(), 1.25);
+    }
+
+    #[test]
+    fn scaled_damage_truncates_per_skill() {
+        assert_eq!(SkillLevel(0).scale_damage(30), 15);
+        assert_eq!(SkillLevel(1).scale_damage(30), 22);
+        assert_eq!(SkillLevel(2).scale_damage(30), 30);
+        assert_eq!(SkillLevel(3).scale_damage(30), 37);
+        assert_eq!(SkillLevel(0).scale_damage(1), 0);
+    }
+
+    #[test]
+    fn easiest_skill_starts_with_more_ammo_and_hardest_shoots_faster() {
+        assert_eq!(SkillLevel(0).starting_ammo(), 16);
+        assert_eq!(SkillLevel(3).starting_ammo(), 8);
+        assert_eq!(SkillLevel(2).fire_rate(), 1.0);
+        assert_eq!(SkillLevel(3).fire_rate(), 1.5);
     }
 }
@@ -0,0 +1,41 @@
+/*
+Davenstein - by David Petnick
+
+Wolfenstein 3-D Tic Timing
+
+The Original Advances All Timed Logic in Tics of 1/70 Second. Gameplay
+Durations (Weapon Cooldowns, Door Waits, Pain Frames, Intermission Counts)
+Are Written Here in Tics so They Read Like the Original Tables, and Converted
+to Seconds Only Where a Bevy Timer Needs Them. FixedUpdate Runs at TIC_HZ, so
+One Fixed Step Is Exactly One Tic
+*/
+
+/// Original Game Tic Rate
+pub const TIC_HZ: f32 = 70.0;
+
+/// Length of One Tic in Seconds
+pub const TIC: f32 = 1.0 / TIC_HZ;
+
+/// Seconds Spanned by 'n' Tics
+pub const fn tics(n: u32) -> f32 {
+    n as f32 * TIC
+}
+
+/// How Long a Door Stays Open Before Trying to Close (OPENTICS, WL_ACT1.C)
+pub const DOOR_OPEN_TICS: u32 = 300;
+
+/// Tics for a Door to Slide Fully Open or Shut. The Original Adds tics << 10
+/// to a 16-Bit Door Position, so a Full Slide Takes 65536 / 1024 = 64 Tics
+pub const DOOR_SLIDE_TICS: u32 = 64;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tics_convert_to_seconds() {
+        assert_eq!(tics(0), 0.0);
+        assert!((tics(70) - 1.0).abs() < 1e-6);
+        assert!((tics(DOOR_OPEN_TICS) - 300.0 / 70.0).abs() < 1e-6);
+    }
+}
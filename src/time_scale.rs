@@ -0,0 +1,160 @@
+/*
+Davenstein - by David Petnick
+
+Debug Time Scale (Slow Motion)
+
+TimeScale Drives the Relative Speed of Time<Virtual>. FixedUpdate Accumulates
+Virtual Time, so Every Tic-Driven Gameplay System (Doors, Enemy Walk / Pain /
+Dying Frames, Projectiles) Slows Uniformly While Each Step Still Advances
+Exactly One Tic. Update Systems Reading Res<Time> (Weapon Viewmodel Frames)
+Scale With it, While Menus and Overlays Read Time<Real> and Stay Responsive
+
+A Scale of Zero Pauses Virtual Time Outright Rather Than Setting a Zero Speed,
+so No Step Runs and No Interval Math Ever Divides by the Scale
+
+Bracket Keys Step Through the Presets in Debug Builds Only
+*/
+
+use bevy::prelude::*;
+
+pub const TIME_SCALE_SLOWER_KEY: KeyCode = KeyCode::BracketLeft;
+pub const TIME_SCALE_FASTER_KEY: KeyCode = KeyCode::BracketRight;
+pub const TIME_SCALE_RESET_KEY: KeyCode = KeyCode::Backslash;
+
+/// Preset Speeds the Debug Keys Step Through, Slowest First
+pub const TIME_SCALE_PRESETS: [f32; 7] = [0.0, 0.1, 0.25, 0.5, 1.0, 2.0, 4.0];
+
+const TIME_SCALE_MAX: f32 = 4.0;
+
+/// Multiplier on Simulation Speed, 1.0 is Normal and 0.0 Freezes Gameplay
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct TimeScale(pub f32);
+
+impl Default for TimeScale {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
+impl TimeScale {
+    /// Scale Clamped to a Usable, Finite Range
+    pub fn effective(self) -> f32 {
+        if self.0.is_finite() {
+            self.0.clamp(0.0, TIME_SCALE_MAX)
+        } else {
+            1.0
+        }
+    }
+
+    /// Next Preset Strictly Slower (-1) or Faster (+1) Than the Current Scale
+    pub fn stepped(self, dir: i32) -> Self {
+        let cur = self.effective();
+        let next = if dir < 0 {
+            TIME_SCALE_PRESETS.iter().rev().find(|&&s| s < cur - 1e-4)
+        } else {
+            TIME_SCALE_PRESETS.iter().find(|&&s| s > cur + 1e-4)
+        };
+
+        Self(next.copied().unwrap_or(cur))
+    }
+}
+
+pub struct TimeScalePlugin;
+
+impl Plugin for TimeScalePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TimeScale>()
+            .add_systems(First, apply_time_scale);
+
+        if cfg!(debug_assertions) {
+            app.add_systems(Update, time_scale_keys);
+        }
+    }
+}
+
+fn time_scale_keys(keys: Res<ButtonInput<KeyCode>>, mut scale: ResMut<TimeScale>) {
+    let next = if keys.just_pressed(TIME_SCALE_RESET_KEY) {
+        TimeScale::default()
+    } else if keys.just_pressed(TIME_SCALE_SLOWER_KEY) {
+        scale.stepped(-1)
+    } else if keys.just_pressed(TIME_SCALE_FASTER_KEY) {
+        scale.stepped(1)
+    } else {
+        return;
+    };
+
+    if next != *scale {
+        info!("Time scale: {:.2}x", next.effective());
+        *scale = next;
+    }
+}
+
+fn apply_time_scale(scale: Res<TimeScale>, mut virt: ResMut<Time<Virtual>>) {
+    if !scale.is_changed() {
+        return;
+    }
+
+    let s = scale.effective();
+    if s <= 0.0 {
+        virt.pause();
+    } else {
+        virt.unpause();
+        virt.set_relative_speed(s);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::time::TimeUpdateStrategy;
+    use std::time::Duration;
+
+    #[derive(Resource, Default)]
+    struct Steps(u32);
+
+    fn count_step(mut steps: ResMut<Steps>) {
+        steps.0 += 1;
+    }
+
+    fn fixed_steps_over_one_second(scale: f32) -> u32 {
+        let mut app = App::new();
+        app.add_plugins((bevy::time::TimePlugin, TimeScalePlugin))
+            .insert_resource(Time::<Fixed>::from_hz(crate::tics::TIC_HZ as f64))
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+                crate::tics::TIC,
+            )))
+            .insert_resource(TimeScale(scale))
+            .init_resource::<Steps>()
+            .add_systems(FixedUpdate, count_step);
+
+        // One Extra Frame Since Bevy's First Frame Carries No Delta
+        for _ in 0..=crate::tics::TIC_HZ as u32 {
+            app.update();
+        }
+
+        app.world().resource::<Steps>().0
+    }
+
+    #[test]
+    fn half_scale_runs_half_as_many_fixed_steps() {
+        let full = fixed_steps_over_one_second(1.0);
+        let half = fixed_steps_over_one_second(0.5);
+
+        assert!((69..=70).contains(&full), "full speed ran {full} steps");
+        assert!(half.abs_diff(full / 2) <= 1, "half speed ran {half} steps");
+    }
+
+    #[test]
+    fn zero_scale_pauses_simulation() {
+        assert_eq!(fixed_steps_over_one_second(0.0), 0);
+    }
+
+    #[test]
+    fn presets_step_and_saturate() {
+        assert_eq!(TimeScale(1.0).stepped(-1), TimeScale(0.5));
+        assert_eq!(TimeScale(1.0).stepped(1), TimeScale(2.0));
+        assert_eq!(TimeScale(0.0).stepped(-1), TimeScale(0.0));
+        assert_eq!(TimeScale(4.0).stepped(1), TimeScale(4.0));
+        assert_eq!(TimeScale(f32::NAN).effective(), 1.0);
+    }
+}
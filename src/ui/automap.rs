@@ -0,0 +1,330 @@
+/*
+Davenstein - by David Petnick
+
+Automap Overlay
+
+Holding Tab During Gameplay Shows the Level From Above Over the 3D View:
+Walls, Doors and Floor Around Every Tile the Player Has Stood On, With the
+Player's Tile and Facing Marked. Rooms Fill in as They Are Explored and a Fresh
+Copy of the Level Starts Unexplored Again
+
+The Map Is One Small Image Redrawn Only While Shown, Scaled With Nearest
+Sampling to Fit the View Area Above the Status Bar. It Never Shows Over Menus,
+the Death Overlay or Anything Else That Holds the Player Lock
+*/
+
+use bevy::prelude::*;
+
+use davelib::level::LEVEL_SIZE;
+use davelib::map::{MapGrid, Tile};
+use davelib::player::{Player, PlayerControlLock, PlayerMotion};
+
+use super::hud::ViewArea;
+use super::sync::{AdvanceLevelRequested, NewGameRequested, RestartLevelRequested, RestartRequested};
+use super::{DeathOverlay, SplashStep};
+use super::view_overlay::{overlay_image, view_area_without};
+use crate::save::LoadGameRequested;
+
+pub const AUTOMAP_KEY: KeyCode = KeyCode::Tab;
+
+/// Image Pixels per Map Tile
+const CELL: usize = 4;
+const MAP_PX: usize = LEVEL_SIZE * CELL;
+
+// RGBA, Unexplored Tiles Stay Transparent Over the Dimmed View
+const UNSEEN: [u8; 4] = [0, 0, 0, 0];
+const FLOOR: [u8; 4] = [48, 48, 48, 255];
+const WALL: [u8; 4] = [0, 136, 136, 255];
+const DOOR: [u8; 4] = [200, 160, 0, 255];
+const PLAYER: [u8; 4] = [255, 0, 0, 255];
+const FACING: [u8; 4] = [255, 255, 255, 255];
+
+/// Facing Tick Length in Image Pixels, Just Over One Tile
+const FACING_PX: usize = 5;
+
+#[derive(Component)]
+pub(super) struct AutomapOverlay;
+
+#[derive(Resource)]
+pub(super) struct AutomapImage(Handle<Image>);
+
+/// Tiles Revealed on the Current Level
+#[derive(Resource, Debug, Clone, Default)]
+pub(crate) struct AutomapVisited {
+    width: usize,
+    height: usize,
+    seen: Vec<bool>,
+}
+
+impl AutomapVisited {
+    /// Reveal a Tile and its Eight Neighbors, so the Walls Around a Room Show
+    /// as Soon as the Player Walks Through it
+    pub fn reveal_around(&mut self, tile: IVec2, width: usize, height: usize) {
+        if (self.width, self.height) != (width, height) {
+            *self = Self { width, height, seen: vec![false; width * height] };
+        }
+        for dz in -1..=1 {
+            for dx in -1..=1 {
+                let (x, z) = (tile.x + dx, tile.y + dz);
+                if x >= 0 && z >= 0 && (x as usize) < width && (z as usize) < height {
+                    self.seen[z as usize * width + x as usize] = true;
+                }
+            }
+        }
+    }
+
+    pub fn is_revealed(&self, x: usize, z: usize) -> bool {
+        x < self.width && z < self.height && self.seen[z * self.width + x]
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+}
+
+/// RGBA Pixels of the Map Image: Revealed Tiles by Kind, Then the Player's Dot
+/// and a Short Tick Along `forward` (World XZ, Tiles Centered on Integers)
+pub(crate) fn automap_pixels(grid: &MapGrid, visited: &AutomapVisited, player: Vec2, forward: Vec2) -> Vec<u8> {
+    let mut px = vec![0u8; MAP_PX * MAP_PX * 4];
+    let mut put = |x: usize, y: usize, c: [u8; 4]| {
+        if x < MAP_PX && y < MAP_PX {
+            let i = (y * MAP_PX + x) * 4;
+            px[i..i + 4].copy_from_slice(&c);
+        }
+    };
+
+    for z in 0..grid.height.min(LEVEL_SIZE) {
+        for x in 0..grid.width.min(LEVEL_SIZE) {
+            let color = if !visited.is_revealed(x, z) {
+                UNSEEN
+            } else {
+                match grid.tile(x, z) {
+                    Tile::Wall => WALL,
+                    Tile::DoorClosed | Tile::DoorOpen => DOOR,
+                    Tile::Empty => FLOOR,
+                }
+            };
+            for cy in 0..CELL {
+                for cx in 0..CELL {
+                    put(x * CELL + cx, z * CELL + cy, color);
+                }
+            }
+        }
+    }
+
+    // Pixel Center of the Player, Tile n Spans n - 0.5 .. n + 0.5
+    let center = (player + Vec2::splat(0.5)) * CELL as f32;
+    if center.x < 0.0 || center.y < 0.0 {
+        return px;
+    }
+
+    let dir = forward.normalize_or_zero();
+    for step in 1..=FACING_PX {
+        let p = center + dir * step as f32;
+        if p.x >= 0.0 && p.y >= 0.0 {
+            put(p.x as usize, p.y as usize, FACING);
+        }
+    }
+
+    let (cx, cy) = (center.x as usize, center.y as usize);
+    for (dx, dy) in [(0, 0), (1, 0), (0, 1), (1, 1)] {
+        put((cx + dx).saturating_sub(1), (cy + dy).saturating_sub(1), PLAYER);
+    }
+
+    px
+}
+
+pub(super) fn setup_automap_image(mut commands: Commands, mut images: ResMut<Assets<Image>>) {
+    commands.insert_resource(AutomapImage(overlay_image(&mut images, MAP_PX, &UNSEEN)));
+}
+
+/// Attaches the (Hidden) Overlay to the HUD's View Area Once the HUD Exists,
+/// Last Among its Children so it Draws Over the Weapon
+pub(super) fn ensure_automap_overlay(
+    mut commands: Commands,
+    map_image: Res<AutomapImage>,
+    q_existing: Query<(), With<AutomapOverlay>>,
+    q_view: Query<Entity, With<ViewArea>>,
+) {
+    let Some(view) = view_area_without(&q_existing, &q_view) else { return; };
+
+    commands.entity(view).with_children(|view| {
+        view.spawn((
+            AutomapOverlay,
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(0.0),
+                top: Val::Px(0.0),
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.75)),
+            Visibility::Hidden,
+        ))
+        .with_children(|overlay| {
+            overlay.spawn((
+                ImageNode::new(map_image.0.clone()),
+                Node {
+                    height: Val::Percent(96.0),
+                    aspect_ratio: Some(1.0),
+                    ..default()
+                },
+            ));
+        });
+    });
+}
+
+/// Reveals Around the Player's Tile Whenever the Player Actually Moved This
+/// Tic, Plus Once on a Fresh Map so the Spawn Room Shows Before the First Step.
+/// Drift Inside the Motion Deadzone Never Reaches Across a Tile Edge
+pub(super) fn reveal_automap_tiles(
+    grid: Option<Res<MapGrid>>,
+    motion: Res<PlayerMotion>,
+    q_player: Query<&Transform, With<Player>>,
+    mut visited: ResMut<AutomapVisited>,
+) {
+    let Some(grid) = grid else { return; };
+    let Some(tf) = q_player.iter().next() else { return; };
+    if !motion.is_moving() && !visited.seen.is_empty() {
+        return;
+    }
+
+    let tile = IVec2::new(
+        (tf.translation.x + 0.5).floor() as i32,
+        (tf.translation.z + 0.5).floor() as i32,
+    );
+    visited.reveal_around(tile, grid.width, grid.height);
+}
+
+/// Any Level Rebuild Starts the Map Over
+pub(super) fn clear_automap_on_rebuild(
+    restart: Res<RestartRequested>,
+    new_game: Res<NewGameRequested>,
+    advance: Res<AdvanceLevelRequested>,
+    restart_level: Res<RestartLevelRequested>,
+    load: Res<LoadGameRequested>,
+    mut visited: ResMut<AutomapVisited>,
+) {
+    if restart.0 || new_game.0 || advance.0 || restart_level.0 || load.0.is_some() {
+        visited.clear();
+    }
+}
+
+pub(super) fn show_automap(
+    keys: Res<ButtonInput<KeyCode>>,
+    step: Res<SplashStep>,
+    lock: Res<PlayerControlLock>,
+    death: Res<DeathOverlay>,
+    grid: Option<Res<MapGrid>>,
+    visited: Res<AutomapVisited>,
+    q_player: Query<&Transform, With<Player>>,
+    map_image: Res<AutomapImage>,
+    mut images: ResMut<Assets<Image>>,
+    mut q_overlay: Query<&mut Visibility, With<AutomapOverlay>>,
+) {
+    let Some(mut vis) = q_overlay.iter_mut().next() else { return; };
+
+    let shown = keys.pressed(AUTOMAP_KEY)
+        && *step == SplashStep::Done
+        && !lock.0
+        && !death.active
+        && grid.is_some();
+    vis.set_if_neq(if shown { Visibility::Inherited } else { Visibility::Hidden });
+    if !shown {
+        return;
+    }
+
+    let (Some(grid), Some(tf)) = (grid, q_player.iter().next()) else { return; };
+    let forward = tf.rotation * Vec3::NEG_Z;
+    let pixels = automap_pixels(
+        &grid,
+        &visited,
+        Vec2::new(tf.translation.x, tf.translation.z),
+        Vec2::new(forward.x, forward.z),
+    );
+
+    if let Some(mut image) = images.get_mut(&map_image.0) {
+        image.data = Some(pixels);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pixel(px: &[u8], tile_x: usize, tile_z: usize) -> [u8; 4] {
+        // Sample a Cell Corner, Away From the Player Dot and Facing Tick
+        let i = ((tile_z * CELL) * MAP_PX + tile_x * CELL) * 4;
+        px[i..i + 4].try_into().unwrap()
+    }
+
+    #[test]
+    fn automap_shows_only_tiles_around_where_the_player_walked() {
+        let (grid, _, _) = MapGrid::from_ascii(&[
+            "#########",
+            "#...D...#",
+            "#########",
+        ]);
+        let mut visited = AutomapVisited::default();
+        visited.reveal_around(IVec2::new(2, 1), grid.width, grid.height);
+
+        let px = automap_pixels(&grid, &visited, Vec2::new(2.0, 1.0), Vec2::X);
+        assert_eq!(pixel(&px, 1, 0), WALL);
+        assert_eq!(pixel(&px, 1, 1), FLOOR);
+        assert_eq!(pixel(&px, 4, 1), UNSEEN);
+        assert_eq!(pixel(&px, 6, 1), UNSEEN);
+
+        // Walking Up to the Door Reveals it, a Rebuild Forgets Everything
+        visited.reveal_around(IVec2::new(3, 1), grid.width, grid.height);
+        let px = automap_pixels(&grid, &visited, Vec2::new(3.0, 1.0), Vec2::X);
+        assert_eq!(pixel(&px, 4, 1), DOOR);
+
+        visited.clear();
+        assert!(!visited.is_revealed(3, 1));
+    }
+
+    #[test]
+    fn player_dot_and_facing_tick_are_drawn() {
+        let (grid, _, _) = MapGrid::from_ascii(&["#####", "#...#", "#####"]);
+        let mut visited = AutomapVisited::default();
+        visited.reveal_around(IVec2::new(2, 1), grid.width, grid.height);
+
+        let px = automap_pixels(&grid, &visited, Vec2::new(2.0, 1.0), Vec2::X);
+        let at = |x: usize, y: usize| -> [u8; 4] { px[(y * MAP_PX + x) * 4..][..4].try_into().unwrap() };
+
+        // Tile (2, 1) Spans Pixels 8..12 x 4..8, Center (10, 6)
+        assert_eq!(at(10, 6), PLAYER);
+        assert_eq!(at(13, 6), FACING);
+        assert_eq!(at(10, 4), FLOOR);
+    }
+
+    #[test]
+    fn automap_reveals_on_spawn_and_then_only_when_the_player_moves() {
+        let (grid, _, _) = MapGrid::from_ascii(&["##########", "#........#", "##########"]);
+
+        let mut app = App::new();
+        app.insert_resource(grid)
+            .init_resource::<PlayerMotion>()
+            .init_resource::<AutomapVisited>()
+            .add_systems(Update, reveal_automap_tiles);
+        let player = app.world_mut().spawn((Player, Transform::from_xyz(1.0, 0.5, 1.0))).id();
+
+        // Fresh Map: the Spawn Room Shows Before Any Step
+        app.update();
+        assert!(app.world().resource::<AutomapVisited>().is_revealed(2, 1));
+
+        // Standing Still Reveals Nothing New, Even if the Transform Jumps
+        app.world_mut().get_mut::<Transform>(player).unwrap().translation.x = 6.0;
+        app.update();
+        assert!(!app.world().resource::<AutomapVisited>().is_revealed(6, 1));
+
+        // A Real Step Does
+        *app.world_mut().resource_mut::<PlayerMotion>() =
+            PlayerMotion::from_tic(Vec3::new(5.9, 0.5, 1.0), Vec3::new(6.0, 0.5, 1.0));
+        app.update();
+        assert!(app.world().resource::<AutomapVisited>().is_revealed(6, 1));
+    }
+}
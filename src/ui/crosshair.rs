@@ -0,0 +1,173 @@
+/*
+Davenstein - by David Petnick
+
+Crosshair Overlay
+
+An Optional Aiming Reticle Centered in the 3D View (VideoSettings::crosshair)
+Drawn as One Tiny Image Scaled by Whole HUD Pixels With Nearest Sampling so it
+Stays Crisp. The View-Size Border Is Symmetric, so the Middle of the View Area
+Is the Middle of the 3D Viewport at Every Size
+
+It Shows Only in Plain Gameplay: Never Over Menus, Get Psyched, the Death
+Overlay or the Automap. A Shot That Wounds or Kills an Enemy Flashes it Red
+*/
+
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+use davelib::options::{ui_ref_dims, CrosshairStyle, VideoSettings, WorldCanvas};
+use davelib::player::PlayerControlLock;
+
+use super::automap::AUTOMAP_KEY;
+use super::hud::ViewArea;
+use super::view_overlay::{hud_scale, overlay_image, view_area_without};
+use super::{DeathOverlay, SplashStep};
+
+/// Image Size in HUD Pixels, Odd so There Is a Center Pixel
+const CROSSHAIR_PX: usize = 7;
+
+const CLEAR: [u8; 4] = [0, 0, 0, 0];
+const INK: [u8; 4] = [220, 220, 220, 230];
+
+/// How Long a Confirmed Hit Tints the Reticle
+const HIT_FLASH_SECS: f32 = 0.15;
+const HIT_TINT: Color = Color::srgb(1.0, 0.2, 0.2);
+
+#[derive(Component)]
+pub(super) struct CrosshairOverlay;
+
+#[derive(Resource)]
+pub(super) struct CrosshairImage(Handle<Image>);
+
+/// RGBA Pixels of the Reticle, Empty for 'Off'
+pub(crate) fn crosshair_pixels(style: CrosshairStyle) -> Vec<u8> {
+    let mut px = vec![0u8; CROSSHAIR_PX * CROSSHAIR_PX * 4];
+    let c = CROSSHAIR_PX / 2;
+
+    for y in 0..CROSSHAIR_PX {
+        for x in 0..CROSSHAIR_PX {
+            let lit = match style {
+                CrosshairStyle::Off => false,
+                CrosshairStyle::Dot => x == c && y == c,
+                // Arms Stop One Pixel Short of the Center so the Target Stays Visible
+                CrosshairStyle::Cross => (x == c) != (y == c) && x.abs_diff(c) + y.abs_diff(c) > 1,
+            };
+            let i = (y * CROSSHAIR_PX + x) * 4;
+            px[i..i + 4].copy_from_slice(if lit { &INK } else { &CLEAR });
+        }
+    }
+
+    px
+}
+
+pub(super) fn setup_crosshair_image(mut commands: Commands, mut images: ResMut<Assets<Image>>) {
+    commands.insert_resource(CrosshairImage(overlay_image(&mut images, CROSSHAIR_PX, &CLEAR)));
+}
+
+/// Attaches the (Hidden) Crosshair to the HUD's View Area Once the HUD Exists
+pub(super) fn ensure_crosshair_overlay(
+    mut commands: Commands,
+    crosshair_image: Res<CrosshairImage>,
+    q_existing: Query<(), With<CrosshairOverlay>>,
+    q_view: Query<Entity, With<ViewArea>>,
+) {
+    let Some(view) = view_area_without(&q_existing, &q_view) else { return; };
+
+    commands.entity(view).with_children(|view| {
+        view.spawn((
+            CrosshairOverlay,
+            ImageNode::new(crosshair_image.0.clone()),
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Percent(50.0),
+                top: Val::Percent(50.0),
+                ..default()
+            },
+            Visibility::Hidden,
+        ));
+    });
+}
+
+pub(super) fn update_crosshair(
+    time: Res<Time>,
+    mut hits: MessageReader<crate::combat::GuardHit>,
+    keys: Res<ButtonInput<KeyCode>>,
+    step: Res<SplashStep>,
+    lock: Res<PlayerControlLock>,
+    death: Res<DeathOverlay>,
+    video: Res<VideoSettings>,
+    canvas: Option<Res<WorldCanvas>>,
+    q_win: Query<&Window, With<PrimaryWindow>>,
+    crosshair_image: Res<CrosshairImage>,
+    mut images: ResMut<Assets<Image>>,
+    mut drawn: Local<Option<CrosshairStyle>>,
+    mut hit_flash: Local<f32>,
+    mut q_overlay: Query<(&mut Node, &mut ImageNode, &mut Visibility), With<CrosshairOverlay>>,
+) {
+    if hits.read().count() > 0 {
+        *hit_flash = HIT_FLASH_SECS;
+    } else {
+        *hit_flash = (*hit_flash - time.delta_secs()).max(0.0);
+    }
+
+    let Some((mut node, mut img, mut vis)) = q_overlay.iter_mut().next() else { return; };
+    let tint = if *hit_flash > 0.0 { HIT_TINT } else { Color::WHITE };
+    if img.color != tint {
+        img.color = tint;
+    }
+
+    let shown = video.crosshair != CrosshairStyle::Off
+        && *step == SplashStep::Done
+        && !lock.0
+        && !death.active
+        && !keys.pressed(AUTOMAP_KEY);
+    vis.set_if_neq(if shown { Visibility::Inherited } else { Visibility::Hidden });
+    if !shown {
+        return;
+    }
+
+    if *drawn != Some(video.crosshair) {
+        if let Some(mut image) = images.get_mut(&crosshair_image.0) {
+            image.data = Some(crosshair_pixels(video.crosshair));
+            *drawn = Some(video.crosshair);
+        }
+    }
+
+    let (ui_w, _) = ui_ref_dims(canvas.as_deref(), &q_win);
+    let hud_scale = hud_scale(ui_w);
+
+    let size = CROSSHAIR_PX as f32 * hud_scale;
+    let offset = Val::Px(-(size / 2.0).floor());
+    if node.width != Val::Px(size) {
+        node.width = Val::Px(size);
+        node.height = Val::Px(size);
+        node.margin = UiRect { left: offset, top: offset, ..default() };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lit(px: &[u8], x: usize, y: usize) -> bool {
+        px[(y * CROSSHAIR_PX + x) * 4 + 3] != 0
+    }
+
+    #[test]
+    fn crosshair_styles_draw_a_dot_or_an_open_cross() {
+        let c = CROSSHAIR_PX / 2;
+
+        let dot = crosshair_pixels(CrosshairStyle::Dot);
+        assert!(lit(&dot, c, c));
+        assert!(!lit(&dot, c + 1, c));
+
+        let cross = crosshair_pixels(CrosshairStyle::Cross);
+        assert!(!lit(&cross, c, c));
+        assert!(!lit(&cross, c + 1, c));
+        assert!(lit(&cross, c + 2, c));
+        assert!(lit(&cross, c, 0));
+        assert!(!lit(&cross, 0, 0));
+
+        assert!(crosshair_pixels(CrosshairStyle::Off).iter().all(|&b| b == 0));
+    }
+}
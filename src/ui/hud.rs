@@ -7,8 +7,9 @@ use bevy::prelude::*;
 use bevy::ui::UiTargetCamera;
 use bevy::ui::widget::NodeImageMode;
 use bevy::window::PrimaryWindow;
-use davelib::options::{MenuUiCameraRef, WorldCanvas, ui_ref_dims};
+use davelib::options::{GameplaySettings, MenuUiCameraRef, WorldCanvas, ui_ref_dims};
 
+use super::state::DamageSide;
 use super::{
     HudState,
     DeathOverlay,
@@ -26,9 +27,21 @@ use davelib::level::CurrentLevel;
 #[derive(Component)]
 pub(super) struct DamageFlashOverlay;
 
+/// The 3D View Above the Status Bar, Parent of Every Overlay Drawn Over it
+#[derive(Component)]
+pub(super) struct ViewArea;
+
 #[derive(Component)]
 pub(super) struct PickupFlashOverlay;
 
+/// One Edge Bar of the Directional Damage Cue
+#[derive(Component)]
+pub(super) struct DamageDirectionBar(DamageSide);
+
+/// "A SECRET!" Banner Near the Top of the View
+#[derive(Component)]
+pub(super) struct SecretNoticeText;
+
 #[derive(Component)]
 pub(super) struct DeathOverlayOverlay;
 
@@ -72,12 +85,26 @@ pub(super) struct HudHpRow;
 #[derive(Component)]
 pub(super) struct HudAmmoRow;
 
+/// Frames per Viewmodel Weapon: Idle Then Four Firing Frames
+pub(crate) const VIEWMODEL_FRAMES: usize = 5;
+/// Viewmodel Weapon Names as They Appear in Frame File Names
+pub(crate) const VIEWMODEL_WEAPONS: [&str; 4] = ["knife", "pistol", "machinegun", "chaingun"];
+pub(crate) const HUD_DIGIT_BLANK_PATH: &str = "textures/hud/digits/digit_blank.png";
+
+pub(crate) fn viewmodel_frame_path(weapon: &str, frame: usize) -> String {
+    format!("textures/weapons/{weapon}_{frame}.png")
+}
+
+pub(crate) fn hud_digit_path(digit: usize) -> String {
+    format!("textures/hud/digits/digit_{digit}.png")
+}
+
 #[derive(Resource, Clone)]
 pub(crate) struct ViewModelSprites {
-    pub knife: [Handle<Image>; 5],
-    pub pistol: [Handle<Image>; 5],
-    pub machinegun: [Handle<Image>; 5],
-    pub chaingun: [Handle<Image>; 5],
+    pub knife: [Handle<Image>; VIEWMODEL_FRAMES],
+    pub pistol: [Handle<Image>; VIEWMODEL_FRAMES],
+    pub machinegun: [Handle<Image>; VIEWMODEL_FRAMES],
+    pub chaingun: [Handle<Image>; VIEWMODEL_FRAMES],
 }
 
 #[derive(Resource, Clone)]
@@ -237,7 +264,7 @@ pub(crate) struct MissionBjCardAnim {
 
 impl Default for MissionBjCardAnim {
     fn default() -> Self {
-        const TIC: f32 = 1.0 / 70.0;
+        use davelib::tics::TIC;
         const BJ_FRAME_TICS: f32 = 10.0;
 
         Self {
@@ -413,7 +440,7 @@ pub(crate) struct WeaponState {
 
 impl Default for WeaponState {
     fn default() -> Self {
-        const TIC: f32 = 1.0 / 70.0;
+        use davelib::tics::TIC;
         const PISTOL_COOLDOWN_TICS: f32 = 20.0;
         const PISTOL_FLASH_TICS: f32 = 12.0;
 
@@ -432,6 +459,16 @@ impl Default for WeaponState {
     }
 }
 
+impl WeaponState {
+    /// Back to the Resting Pose With No Flash or Firing Cycle in Progress
+    /// The Cooldown Is Left Running so Pausing Can't Be Used to Skip It
+    pub fn reset_to_idle(&mut self) {
+        self.showing_fire = false;
+        self.fire_cycle = 0;
+        self.flash.reset();
+    }
+}
+
 fn split_3_right_aligned(n: i32) -> [Option<usize>; 3] {
     let n = n.clamp(0, 999) as u32;
     let h = (n / 100) as usize;
@@ -495,10 +532,8 @@ pub(crate) fn sync_viewmodel_size(
     // Play Area = Everything Above the Status Bar
     let play_h = (ui_h - status_h_px).max(1.0);
 
-    // View-Size Inset (Same (20 - vs) / 32 Fraction as the Camera Viewport)
-    let vs = settings.view_size.clamp(5, 20) as f32;
-    let inset_frac = (20.0 - vs) / 32.0;
-    let inset_y = (play_h * inset_frac).round();
+    // View-Size Inset (Same Fraction as the Camera Viewport)
+    let inset_y = (play_h * settings.view_inset_frac()).round();
 
     // 'viewheight': the Inset 3-D View Window Height
     let view_h = (play_h - inset_y * 2.0).max(1.0);
@@ -512,20 +547,145 @@ pub(crate) fn sync_viewmodel_size(
     node.margin = UiRect::bottom(Val::Px(inset_y));
 }
 
+/// Pushes the Viewmodel Down by the Current Recoil Kick. Scaled Off the Node
+/// Height Set by 'sync_viewmodel_size', so the Kick Tracks the Gun's Size
+pub(crate) fn apply_viewmodel_recoil(
+    recoil: Res<RecoilState>,
+    mut q_vm: Query<&mut Node, With<ViewModelImage>>,
+) {
+    const SPRITE_PX: f32 = 64.0;
+
+    for mut node in q_vm.iter_mut() {
+        let gun_h = match node.height {
+            Val::Px(h) => h,
+            _ => continue,
+        };
+        let top = Val::Px((recoil.current() * gun_h / SPRITE_PX).round());
+        if node.top != top {
+            node.top = top;
+        }
+    }
+}
+
+/// Direction a Shot Travels. Classic Aim Drops the Pitch so Shots Stay Level
+/// Like the Original's 2-D Hitscan, Whatever the Player Is Looking at. Looking
+/// Straight up or Down Leaves No Horizontal Part, so Yaw Alone Decides Then
+pub(crate) fn fire_direction(rotation: Quat, true_3d_aim: bool) -> Vec3 {
+    let forward = rotation * Vec3::NEG_Z;
+    if true_3d_aim {
+        return forward.normalize();
+    }
+
+    let flat = Vec3::new(forward.x, 0.0, forward.z);
+    if flat.length_squared() > 1e-6 {
+        return flat.normalize();
+    }
+    let (yaw, _, _) = rotation.to_euler(EulerRot::YXZ);
+    Quat::from_rotation_y(yaw) * Vec3::NEG_Z
+}
+
+/// Per-Weapon Firing Parameters, Timings in Tics
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct WeaponParams {
+    pub cooldown_tics: u32,
+    pub flash_tics: u32,
+    pub ammo_cost: i32,
+    pub max_dist: f32,
+    /// Viewmodel Kick per Shot in Native Sprite Pixels (Before the Setting's Scale)
+    pub recoil: f32,
+}
+
+pub(crate) fn weapon_params(slot: crate::combat::WeaponSlot) -> WeaponParams {
+    use crate::combat::WeaponSlot;
+
+    const BULLET_MAX_DIST: f32 = 10_000.0;
+
+    let (cooldown_tics, flash_tics, ammo_cost, max_dist, recoil) = match slot {
+        WeaponSlot::Knife => (10, 12, 0, 1.5, 0.0),
+        WeaponSlot::Pistol => (24, 24, 1, BULLET_MAX_DIST, 2.0),
+        WeaponSlot::MachineGun => (12, 6, 1, BULLET_MAX_DIST, 2.5),
+        WeaponSlot::Chaingun => (6, 8, 1, BULLET_MAX_DIST, 3.0),
+    };
+
+    WeaponParams { cooldown_tics, flash_tics, ammo_cost, max_dist, recoil }
+}
+
+/// Tics for a Kick to Settle Back to Rest. Longer Than the Chaingun's 6-Tic
+/// Refire so Sustained Fire Stacks Kick (Up to RECOIL_MAX_PX)
+const RECOIL_DECAY_TICS: u32 = 8;
+/// Most the Viewmodel Can Be Pushed Down, in Native Sprite Pixels (of 64)
+const RECOIL_MAX_PX: f32 = 6.0;
+
+/// Downward Kick on the Viewmodel, Layered on Top of the Firing Frames
+/// 'offset' Is the Kick at the Last Shot, Decaying Linearly Over 'timer'
+#[derive(Resource)]
+pub(crate) struct RecoilState {
+    pub offset: f32,
+    pub timer: Timer,
+}
+
+impl Default for RecoilState {
+    fn default() -> Self {
+        let mut timer = Timer::from_seconds(davelib::tics::tics(RECOIL_DECAY_TICS), TimerMode::Once);
+        timer.set_elapsed(timer.duration());
+        Self { offset: 0.0, timer }
+    }
+}
+
+impl RecoilState {
+    /// Current Kick in Native Sprite Pixels
+    pub fn current(&self) -> f32 {
+        if self.timer.is_finished() {
+            return 0.0;
+        }
+        self.offset * (1.0 - self.timer.fraction())
+    }
+
+    /// Add a Shot's Kick to Whatever Is Left of the Last One, Capped
+    pub fn kick(&mut self, amount: f32) {
+        if amount <= 0.0 {
+            return;
+        }
+        self.offset = (self.current() + amount).min(RECOIL_MAX_PX);
+        self.timer.reset();
+    }
+
+    pub fn reset(&mut self) {
+        self.offset = 0.0;
+        let dur = self.timer.duration();
+        self.timer.set_elapsed(dur);
+    }
+}
+
 #[derive(Default)]
 pub(super) struct WeaponFireLocals {
     armed: bool,
     fire_anim_accum: f32,
     last_weapon: Option<crate::combat::WeaponSlot>,
     auto_linger: f32,
+    // Set Once the Empty Click Has Played, Cleared by Ammo or Releasing the Trigger
+    dry_clicked: bool,
+    // Gun That Ran Dry and Dropped to the Knife, Redrawn When Ammo Comes Back
+    emptied_weapon: Option<crate::combat::WeaponSlot>,
+}
+
+impl WeaponFireLocals {
+    fn reset(&mut self, selected: crate::combat::WeaponSlot) {
+        self.fire_anim_accum = 0.0;
+        self.last_weapon = Some(selected);
+        self.auto_linger = 0.0;
+    }
 }
 
 pub(crate) fn weapon_fire_and_viewmodel(
     time: Res<Time>,
     intent: Res<PlayerIntent>,
     lock: Res<PlayerControlLock>,
+    suppress: Res<davelib::input::GameplayInputSuppress>,
     sprites: Option<Res<ViewModelSprites>>,
     mut weapon: ResMut<WeaponState>,
+    mut recoil: ResMut<RecoilState>,
+    gameplay: Res<GameplaySettings>,
     mut hud: ResMut<HudState>,
     mut vm_q: Query<&mut ImageNode, With<ViewModelImage>>,
     q_player: Query<&Transform, With<Player>>,
@@ -545,17 +705,21 @@ pub(crate) fn weapon_fire_and_viewmodel(
     // Level End). lock.0 Is Set True by Every Menu / Splash Step and on Death,
     // so It Is the Authoritative "Not Playing" Signal. Cursor Capture Is No
     // Longer Used to Gate Firing, so Keyboard-Only Play (Mouselook Off) Fires
-    if lock.0 {
+    //
+    // A Suppressed Frame Is the One Where a Menu Handed Control Back; Treating
+    // It as Locked Keeps the Click or Key That Dismissed the Menu From Firing
+    if lock.0 || suppress.0 {
         // Re-Arm the First-Frame Guard so the Input That Dismissed a Menu Does
-        // Not Also Fire on the Frame Gameplay Resumes
-        locals.armed = false;
-        locals.fire_anim_accum = 0.0;
-        locals.last_weapon = Some(hud.selected);
-        locals.auto_linger = 0.0;
+        // Not Also Fire on the Frame Gameplay Resumes. That Covers a Menu That
+        // Unlocks After This System Ran; One That Unlocked Earlier in the Frame
+        // Is Already Swallowed Here by the Suppressed Frame, so Stay Armed
+        locals.armed = !lock.0;
 
-        weapon.fire_cycle = 0;
-        weapon.showing_fire = false;
-        weapon.flash.reset();
+        // Held at Idle Every Locked Frame, so Gameplay Resumes From a Clean Pose
+        // of Whatever Weapon Is Selected by Then
+        locals.reset(hud.selected);
+        weapon.reset_to_idle();
+        recoil.reset();
 
         if let Ok(mut img) = vm_q.single_mut() {
             img.image = sprites.idle(hud.selected);
@@ -575,15 +739,21 @@ pub(crate) fn weapon_fire_and_viewmodel(
     // Weapon Selection From PlayerIntent. weapon_select Is a Device-Neutral
     // 1..=4 Index (the Key Binding Lives in keyboard_mouse::contribute), Positional:
     // 1 = Knife, 2 = Pistol, 3 = MachineGun, 4 = Chaingun
-    if let Some(slot) = intent.weapon_select.and_then(|n| match n {
+    // weapon_cycle Steps Through Owned Weapons When No Slot Was Picked Directly
+    let picked = intent.weapon_select.and_then(|n| match n {
         1 => Some(WeaponSlot::Knife),
         2 => Some(WeaponSlot::Pistol),
         3 => Some(WeaponSlot::MachineGun),
         4 => Some(WeaponSlot::Chaingun),
         _ => None,
-    }) {
+    });
+    let cycled = (intent.weapon_cycle != 0)
+        .then(|| hud.cycle_weapon(intent.weapon_cycle))
+        .filter(|&w| w != hud.selected);
+    if let Some(slot) = picked.or(cycled) {
         if hud.owns(slot) {
             hud.selected = slot;
+            locals.emptied_weapon = None;
             weapon.showing_fire = false;
             weapon.fire_cycle = 0;
             weapon.flash.reset();
@@ -598,6 +768,17 @@ pub(crate) fn weapon_fire_and_viewmodel(
         }
     }
 
+    // Back to the Gun That Ran Dry Once There Is Ammo for it Again. Anything
+    // Else Putting a Different Weapon in Hand Forgets it
+    if let Some(gun) = locals.emptied_weapon {
+        if hud.selected != WeaponSlot::Knife || !hud.owns(gun) {
+            locals.emptied_weapon = None;
+        } else if hud.ammo > 0 {
+            hud.selected = gun;
+            locals.emptied_weapon = None;
+        }
+    }
+
     // If Weapon Changed Externally Somehow, Reset Anim Accumulator
     if locals.last_weapon.map(|w| w != hud.selected).unwrap_or(true) {
         locals.fire_anim_accum = 0.0;
@@ -611,15 +792,11 @@ pub(crate) fn weapon_fire_and_viewmodel(
     }
 
     // Per-Weapon Paramaters
-    const TIC: f32 = 1.0 / 70.0;
-    const BULLET_MAX_DIST: f32 = 10_000.0;
+    use davelib::tics::{TIC, tics};
 
-    let (cooldown_secs, flash_secs, ammo_cost, max_dist) = match hud.selected {
-        WeaponSlot::Knife => (10.0 * TIC, 12.0 * TIC, 0, 1.5),
-        WeaponSlot::Pistol => (24.0 * TIC, 24.0 * TIC, 1, BULLET_MAX_DIST),
-        WeaponSlot::MachineGun => (12.0 * TIC, 6.0 * TIC, 1, BULLET_MAX_DIST),
-        WeaponSlot::Chaingun => (6.0 * TIC, 8.0 * TIC, 1, BULLET_MAX_DIST),
-    };
+    let params = weapon_params(hud.selected);
+    let (cooldown_secs, flash_secs) = (tics(params.cooldown_tics), tics(params.flash_tics));
+    let (ammo_cost, max_dist) = (params.ammo_cost, params.max_dist);
 
     // Ensure Timers Match Current Weapon
     if (weapon.cooldown.duration().as_secs_f32() - cooldown_secs).abs() > f32::EPSILON {
@@ -635,13 +812,15 @@ pub(crate) fn weapon_fire_and_viewmodel(
     let is_chaingun = hud.selected == WeaponSlot::Chaingun;
     let is_full_auto = is_machinegun || is_chaingun;
 
-    // Fire Comes From PlayerIntent, Which ORs the kb.fire Binding With the Left
-    // Mouse Button, so Both Mouse and Keyboard-Only Play Trigger the Weapon
+    // Fire Comes From PlayerIntent, Which ORs the kb.fire Binding (Key or Mouse
+    // Button) With the Left Mouse Button, so Both Mouse and Keyboard-Only Play
+    // Trigger the Weapon
     let trigger_down = intent.fire;
     let trigger_pressed = intent.fire_pressed;
 
-    // Tick Cooldown
+    // Tick Cooldown and Recoil Decay
     weapon.cooldown.tick(dt);
+    recoil.timer.tick(dt);
 
     // Ammo Check
     let mut has_ammo = ammo_cost == 0 || hud.ammo >= ammo_cost;
@@ -804,6 +983,7 @@ pub(crate) fn weapon_fire_and_viewmodel(
 
         weapon.cooldown.reset();
         weapon.flash.reset();
+        recoil.kick(params.recoil * gameplay.viewmodel_recoil.scale());
 
         // --- MachineGun: Show Muzzle Flash EXACTLY on the Shot Moment (Syncs With Sound) ---
         if is_machinegun {
@@ -852,7 +1032,7 @@ pub(crate) fn weapon_fire_and_viewmodel(
         // Emit SFX + FireShot (Synced to Each Bullet)
         if let Ok(tf) = q_player.single() {
             let origin = tf.translation;
-            let dir = (tf.rotation * Vec3::NEG_Z).normalize();
+            let dir = fire_direction(tf.rotation, gameplay.true_3d_aim);
             let sfx_pos = Vec3::new(origin.x, 0.6, origin.z);
 
             match hud.selected {
@@ -886,6 +1066,23 @@ pub(crate) fn weapon_fire_and_viewmodel(
 
         has_ammo = ammo_cost == 0 || hud.ammo >= ammo_cost;
     }
+
+    // Out of Ammo: One Click per Trigger Pull, the Knife Never Clicks
+    let dry = !has_ammo;
+    if dry && wants_fire && !locals.dry_clicked {
+        locals.dry_clicked = true;
+        let pos = q_player.single().map(|tf| tf.translation).unwrap_or(Vec3::ZERO);
+        sfx.write(PlaySfx { kind: SfxKind::DryFire, pos });
+    }
+    if !dry || !trigger_down {
+        locals.dry_clicked = false;
+    }
+
+    // A Gun That Just Fired its Last Round Drops to the Knife
+    if dry && shots_fired_this_frame > 0 && gameplay.empty_weapon_switch {
+        locals.emptied_weapon = Some(hud.selected);
+        hud.selected = WeaponSlot::Knife;
+    }
 }
 
 pub(crate) fn sync_hud_hp_digits(
@@ -951,6 +1148,27 @@ pub(crate) fn sync_hud_score_digits(
     }
 }
 
+pub(crate) fn flash_hud_lives_digits(
+    time: Res<Time>,
+    mut flash: ResMut<super::state::LivesFlash>,
+    mut q: Query<&mut Visibility, With<HudLivesDigit>>,
+) {
+    // Idle Once Finished and the Final Visible State Has Been Written
+    if flash.timer.is_finished() && !flash.is_changed() {
+        return;
+    }
+    if !flash.timer.is_finished() {
+        flash.timer.tick(time.delta());
+    }
+
+    let want = if flash.digits_hidden() { Visibility::Hidden } else { Visibility::Inherited };
+    for mut vis in q.iter_mut() {
+        if *vis != want {
+            *vis = want;
+        }
+    }
+}
+
 pub(crate) fn sync_hud_lives_digits(
     hud: Res<HudState>,
     digits: Option<Res<HudDigitSprites>>,
@@ -1073,22 +1291,33 @@ pub fn sync_hud_face(
 pub(crate) fn sync_hud_icons(
     hud: Res<HudState>,
     icons: Res<HudIconSprites>,
+    time: Res<Time>,
+    mut locked_out: MessageReader<davelib::player::DoorLocked>,
+    mut key_flash: ResMut<super::state::LockedKeyFlash>,
     mut q_weapon: Query<&mut ImageNode, With<HudWeaponIcon>>,
     mut q_keys: Query<
         (&mut Visibility, Option<&HudGoldKeyIcon>, Option<&HudSilverKeyIcon>),
         Or<(With<HudGoldKeyIcon>, With<HudSilverKeyIcon>)>,
     >,
 ) {
+    // A Locked Door Blinks the Key it Wants in its Empty Slot
+    if let Some(ev) = locked_out.read().last() {
+        key_flash.trigger(ev.gold);
+    }
+    if !key_flash.timer.is_finished() {
+        key_flash.timer.tick(time.delta());
+    }
+
     // Keys
     for (mut vis, is_gold, is_silver) in &mut q_keys {
         if is_gold.is_some() {
-            *vis = if hud.key_gold {
+            *vis = if hud.key_gold || key_flash.icon_shown(true) {
                 Visibility::Visible
             } else {
                 Visibility::Hidden
             };
         } else if is_silver.is_some() {
-            *vis = if hud.key_silver {
+            *vis = if hud.key_silver || key_flash.icon_shown(false) {
                 Visibility::Visible
             } else {
                 Visibility::Hidden
@@ -1197,6 +1426,93 @@ pub(crate) fn tick_damage_flash(
     }
 }
 
+/// Points the Damage Cue at the Nearest Attacker That Landed a Hit This Frame
+pub(crate) fn record_damage_direction(
+    gameplay: Res<GameplaySettings>,
+    lock: Res<PlayerControlLock>,
+    mut enemy_fire: MessageReader<davelib::ai::EnemyFire>,
+    q_player: Query<&GlobalTransform, With<Player>>,
+    mut dir: ResMut<super::DamageDirection>,
+) {
+    let hits: Vec<Vec3> = enemy_fire
+        .read()
+        .filter(|ev| ev.damage > 0)
+        .map(|ev| ev.origin)
+        .collect();
+
+    if !gameplay.damage_direction_indicator || lock.0 || hits.is_empty() {
+        return;
+    }
+    let Some(player_gt) = q_player.iter().next() else { return; };
+    let player_pos = player_gt.translation();
+
+    let nearest = hits
+        .into_iter()
+        .min_by(|a, b| a.distance_squared(player_pos).total_cmp(&b.distance_squared(player_pos)));
+    if let Some(source) = nearest {
+        dir.trigger(DamageSide::from_source(player_pos, *player_gt.forward(), source));
+    }
+}
+
+pub(crate) fn tick_damage_direction(
+    time: Res<Time>,
+    mut dir: ResMut<super::DamageDirection>,
+    mut q: Query<(&DamageDirectionBar, &mut BackgroundColor)>,
+) {
+    dir.timer.tick(time.delta());
+
+    let a = dir.alpha();
+    for (bar, mut bg) in q.iter_mut() {
+        let bar_a = if dir.side == Some(bar.0) { a } else { 0.0 };
+        *bg = BackgroundColor(Srgba::new(1.0, 0.0, 0.0, bar_a).into());
+    }
+}
+
+/// Shows the Secret Banner and Plays the Chime for Every Newly Credited Secret
+pub(crate) fn notify_secret_found(
+    gameplay: Res<GameplaySettings>,
+    mut found: MessageReader<davelib::pushwalls::SecretFound>,
+    mut notice: ResMut<super::SecretNotice>,
+    mut sfx: MessageWriter<PlaySfx>,
+) {
+    for ev in found.read() {
+        if !gameplay.secret_notice {
+            continue;
+        }
+        notice.trigger();
+        sfx.write(PlaySfx {
+            kind: SfxKind::SecretFound,
+            pos: Vec3::new(ev.tile.x as f32, 0.5, ev.tile.y as f32),
+        });
+    }
+}
+
+pub(crate) fn tick_secret_notice(
+    time: Res<Time>,
+    mut notice: ResMut<super::SecretNotice>,
+    mut q: Query<&mut Visibility, With<SecretNoticeText>>,
+) {
+    notice.timer.tick(time.delta());
+
+    let vis = if notice.visible() { Visibility::Inherited } else { Visibility::Hidden };
+    for mut v in q.iter_mut() {
+        v.set_if_neq(vis);
+    }
+}
+
+/// Keeps the Damage Flash Behind or Over the Weapon Sprite per GameplaySettings
+pub(crate) fn sync_damage_flash_layer(
+    gameplay: Res<GameplaySettings>,
+    mut q: Query<&mut ZIndex, With<DamageFlashOverlay>>,
+) {
+    let z = if gameplay.damage_flash_over_weapon { 1 } else { 0 };
+    for mut zi in q.iter_mut() {
+        if zi.0 != z {
+            zi.0 = z;
+        }
+    }
+}
+
 pub(crate) fn tick_death_overlay(
     time: Res<Time>,
     mut death: ResMut<DeathOverlay>,
@@ -1225,12 +1541,12 @@ pub(crate) fn sync_game_over_overlay_visibility(
     }
 }
 
-/// Maps HP (1..100) to Stage 0..6. HP<=0 Handled Separately as Dead
-/// 7 Injury Stages + Dead = 8 Total States
+/// Damage Stage of the Face, One Step per 16 HP Lost Like Wolf3D's
+/// FACE1APIC + 3 * ((100 - health) / 16), so Full Health Holds Down to 85
 fn stage_from_hp(hp: i32) -> usize {
     let hp = hp.clamp(1, 100);
     let dmg = 100 - hp;              // 0..99
-    ((dmg * 7) / 100) as usize       // 0..6
+    (dmg / 16) as usize              // 0..6
 }
 
 /// Returns (Row, Col) for Base Face Given HP + Dir
@@ -1311,12 +1627,10 @@ fn load_hud_setup_assets(
     hud: &HudState,
 ) -> HudSetupAssets {
     // Viewmodel Sprites
-    let sprites = ViewModelSprites {
-        knife: std::array::from_fn(|i| asset_server.load(format!("textures/weapons/knife_{i}.png"))),
-        pistol: std::array::from_fn(|i| asset_server.load(format!("textures/weapons/pistol_{i}.png"))),
-        machinegun: std::array::from_fn(|i| asset_server.load(format!("textures/weapons/machinegun_{i}.png"))),
-        chaingun: std::array::from_fn(|i| asset_server.load(format!("textures/weapons/chaingun_{i}.png"))),
-    };
+    let [knife, pistol, machinegun, chaingun] = VIEWMODEL_WEAPONS.map(|weapon| {
+        std::array::from_fn(|i| asset_server.load(viewmodel_frame_path(weapon, i)))
+    });
+    let sprites = ViewModelSprites { knife, pistol, machinegun, chaingun };
     commands.insert_resource(sprites.clone());
 
     // Starting Viewmodel Based on Selected Weapon
@@ -1324,8 +1638,8 @@ fn load_hud_setup_assets(
 
     // HUD Digit Sprites
     let hud_digits = HudDigitSprites {
-        digits: std::array::from_fn(|i| asset_server.load(format!("textures/hud/digits/digit_{i}.png"))),
-        blank: asset_server.load("textures/hud/digits/digit_blank.png"),
+        digits: std::array::from_fn(|i| asset_server.load(hud_digit_path(i))),
+        blank: asset_server.load(HUD_DIGIT_BLANK_PATH),
     };
     commands.insert_resource(hud_digits.clone());
 
@@ -1518,14 +1832,33 @@ fn spawn_view_area(
 ) {
     commands.entity(parent).with_children(|ui| {
         // View Area: fill remaining space above the status bar
-        ui.spawn(Node {
-            width: Val::Percent(100.0),
-            flex_grow: 1.0,
-            justify_content: JustifyContent::Center,
-            align_items: AlignItems::Center,
-            ..default()
-        })
+        ui.spawn((
+            ViewArea,
+            Node {
+                width: Val::Percent(100.0),
+                flex_grow: 1.0,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+        ))
         .with_children(|view| {
+            // Damage flash covers the whole 3D view. Spawned before the viewmodel
+            // so the weapon draws over it unless sync_damage_flash_layer raises it
+            view.spawn((
+                DamageFlashOverlay,
+                Node {
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(0.0),
+                    top: Val::Px(0.0),
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    ..default()
+                },
+                BackgroundColor(Color::NONE),
+                ZIndex(0),
+            ));
+
             // Bottom-centered viewmodel container
             view.spawn(Node {
                 width: Val::Percent(100.0),
@@ -1546,22 +1879,51 @@ fn spawn_view_area(
                     },
                 ));
             });
-        });
 
-        // Full-screen overlays (required by damage flash + death overlay + pickup flash attachment)
-        ui.spawn((
-            DamageFlashOverlay,
-            Node {
-                position_type: PositionType::Absolute,
-                left: Val::Px(0.0),
-                top: Val::Px(0.0),
-                width: Val::Percent(100.0),
-                height: Val::Percent(100.0),
-                ..default()
-            },
-            BackgroundColor(Color::NONE),
-        ));
+            // Directional damage cue: one bar per edge, spawned after the
+            // viewmodel so it stays visible over the weapon
+            for side in [DamageSide::Front, DamageSide::Right, DamageSide::Behind, DamageSide::Left] {
+                let (left, top, width, height) = match side {
+                    DamageSide::Front => (25.0, 0.0, 50.0, 3.0),
+                    DamageSide::Behind => (25.0, 97.0, 50.0, 3.0),
+                    DamageSide::Left => (0.0, 25.0, 2.0, 50.0),
+                    DamageSide::Right => (98.0, 25.0, 2.0, 50.0),
+                };
+                view.spawn((
+                    DamageDirectionBar(side),
+                    Node {
+                        position_type: PositionType::Absolute,
+                        left: Val::Percent(left),
+                        top: Val::Percent(top),
+                        width: Val::Percent(width),
+                        height: Val::Percent(height),
+                        ..default()
+                    },
+                    BackgroundColor(Color::NONE),
+                ));
+            }
+
+            // Secret notice banner, hidden until a pushwall secret is credited
+            view.spawn((
+                SecretNoticeText,
+                crate::ui::level_end_font::LevelEndBitmapText {
+                    text: "A SECRET!".to_string(),
+                    scale: 1.0,
+                },
+                Node {
+                    position_type: PositionType::Absolute,
+                    left: Val::Percent(0.0),
+                    top: Val::Percent(8.0),
+                    width: Val::Percent(100.0),
+                    flex_direction: FlexDirection::Row,
+                    justify_content: JustifyContent::Center,
+                    ..default()
+                },
+                Visibility::Hidden,
+            ));
+        });
 
+        // Full-screen overlays (required by death overlay + pickup flash attachment)
         ui.spawn((
             DeathOverlayOverlay,
             Node {
@@ -2086,7 +2448,14 @@ fn spawn_mission_success_overlay(
     bj_pistol_0: Handle<Image>,
 ) {
     use bevy::prelude::ChildSpawnerCommands;
-    use crate::level_complete::{MissionStatKind, MissionStatRightAlign, MissionStatText, MissionSuccessOverlay};
+    use crate::level_complete::{
+        MissionNormalPage,
+        MissionSecretPage,
+        MissionStatKind,
+        MissionStatRightAlign,
+        MissionStatText,
+        MissionSuccessOverlay,
+    };
     use crate::ui::level_end_font::LevelEndBitmapText;
 
     const VIEW_W: f32 = 320.0;
@@ -2258,127 +2627,146 @@ fn spawn_mission_success_overlay(
                     },
                 ));
 
-                let x_label = 110.0;
-                let x_ratio = 152.0;
-                let x_right = 304.0;
-
-                let x_ratio_left = 32.0;
-                let y_floor     = 12.0;
-                let y_completed = 32.0;
-                let y_bonus     = 56.0;
-                let y_time      = 72.0;
-                let y_par       = 88.0;
-
-                let y_kill      = 104.0;
-                let y_secret    = 120.0;
-                let y_treasure  = 136.0;
-
-                let floor_text = format!("{}", start_floor_num);
-                let x_floor_val = x_label + text_w_native_px("FLOOR ");
-
-                spawn_bt(c, "FLOOR", TEXT_SCALE, overlay_scale, bt_mul, x_label, y_floor);
-                spawn_bt_tagged(
-                    c,
-                    MissionStatKind::Title,
-                    &floor_text,
-                    TEXT_SCALE,
-                    overlay_scale,
-                    bt_mul,
-                    x_floor_val,
-                    y_floor,
-                );
-
-                spawn_bt(c, "COMPLETED", TEXT_SCALE, overlay_scale, bt_mul, x_label, y_completed);
-
-                spawn_bt(c, "BONUS", TEXT_SCALE, overlay_scale, bt_mul, x_label, y_bonus);
-                let x_bonus_val = right_align_x_native("0", x_right);
-                spawn_bt_tagged_right_aligned(
-                    c,
-                    MissionStatKind::Bonus,
-                    "0",
-                    TEXT_SCALE,
-                    overlay_scale,
-                    bt_mul,
-                    x_bonus_val,
-                    y_bonus,
-                    x_right,
-                );
-
-                spawn_bt(c, "TIME", TEXT_SCALE, overlay_scale, bt_mul, x_label, y_time);
-                let time_text = "0:30";
-                let x_time_val = right_align_x_native(time_text, x_right);
-                spawn_bt_tagged_right_aligned(
-                    c,
-                    MissionStatKind::Time,
-                    time_text,
-                    TEXT_SCALE,
-                    overlay_scale,
-                    bt_mul,
-                    x_time_val,
-                    y_time,
-                    x_right,
-                );
-
-                spawn_bt(c, "PAR", TEXT_SCALE, overlay_scale, bt_mul, x_label, y_par);
-                let par_text = "0:00";
-                let x_par_val = right_align_x_native(par_text, x_right);
-                spawn_bt_tagged_right_aligned(
-                    c,
-                    MissionStatKind::Par,
-                    par_text,
-                    TEXT_SCALE,
-                    overlay_scale,
-                    bt_mul,
-                    x_par_val,
-                    y_par,
-                    x_right,
-                );
+                // Ordinary Floors Tally Time and Ratios. A Secret Floor Shows Only
+                // its Flat Bonus, Like the Original; the Visibility Sync Picks One
+                let page = || Node {
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(0.0),
+                    top: Val::Px(0.0),
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    ..default()
+                };
+
+                c.spawn((MissionNormalPage, page())).with_children(|c| {
+                    let x_label = 110.0;
+                    let x_ratio = 152.0;
+                    let x_right = 304.0;
+
+                    let x_ratio_left = 32.0;
+                    let y_floor     = 12.0;
+                    let y_completed = 32.0;
+                    let y_bonus     = 56.0;
+                    let y_time      = 72.0;
+                    let y_par       = 88.0;
+
+                    let y_kill      = 104.0;
+                    let y_secret    = 120.0;
+                    let y_treasure  = 136.0;
+
+                    let floor_text = format!("{}", start_floor_num);
+                    let x_floor_val = x_label + text_w_native_px("FLOOR ");
+
+                    spawn_bt(c, "FLOOR", TEXT_SCALE, overlay_scale, bt_mul, x_label, y_floor);
+                    spawn_bt_tagged(
+                        c,
+                        MissionStatKind::Title,
+                        &floor_text,
+                        TEXT_SCALE,
+                        overlay_scale,
+                        bt_mul,
+                        x_floor_val,
+                        y_floor,
+                    );
+
+                    spawn_bt(c, "COMPLETED", TEXT_SCALE, overlay_scale, bt_mul, x_label, y_completed);
+
+                    spawn_bt(c, "BONUS", TEXT_SCALE, overlay_scale, bt_mul, x_label, y_bonus);
+                    let x_bonus_val = right_align_x_native("0", x_right);
+                    spawn_bt_tagged_right_aligned(
+                        c,
+                        MissionStatKind::Bonus,
+                        "0",
+                        TEXT_SCALE,
+                        overlay_scale,
+                        bt_mul,
+                        x_bonus_val,
+                        y_bonus,
+                        x_right,
+                    );
+
+                    spawn_bt(c, "TIME", TEXT_SCALE, overlay_scale, bt_mul, x_label, y_time);
+                    let time_text = "0:30";
+                    let x_time_val = right_align_x_native(time_text, x_right);
+                    spawn_bt_tagged_right_aligned(
+                        c,
+                        MissionStatKind::Time,
+                        time_text,
+                        TEXT_SCALE,
+                        overlay_scale,
+                        bt_mul,
+                        x_time_val,
+                        y_time,
+                        x_right,
+                    );
+
+                    spawn_bt(c, "PAR", TEXT_SCALE, overlay_scale, bt_mul, x_label, y_par);
+                    let par_text = "0:00";
+                    let x_par_val = right_align_x_native(par_text, x_right);
+                    spawn_bt_tagged_right_aligned(
+                        c,
+                        MissionStatKind::Par,
+                        par_text,
+                        TEXT_SCALE,
+                        overlay_scale,
+                        bt_mul,
+                        x_par_val,
+                        y_par,
+                        x_right,
+                    );
                 
-                spawn_bt(c, "KILL", TEXT_SCALE, overlay_scale, bt_mul, x_ratio_left, y_kill);
-                spawn_bt(c, "SECRET", TEXT_SCALE, overlay_scale, bt_mul, x_ratio_left, y_secret);
-                spawn_bt(c, "TREASURE", TEXT_SCALE, overlay_scale, bt_mul, x_ratio_left, y_treasure);
-
-                spawn_bt(c, "RATIO", TEXT_SCALE, overlay_scale, bt_mul, x_ratio, y_kill);
-                let x_kill_val = right_align_x_native("27%", x_right);
-                spawn_bt_tagged_right_aligned(
-                    c,
-                    MissionStatKind::KillRatio,
-                    "27%",
-                    TEXT_SCALE,
-                    overlay_scale,
-                    bt_mul,
-                    x_kill_val,
-                    y_kill,
-                    x_right,
-                );
-
-                spawn_bt(c, "RATIO", TEXT_SCALE, overlay_scale, bt_mul, x_ratio, y_secret);
-                let x_secret_val = right_align_x_native("00%", x_right);
-                spawn_bt_tagged_right_aligned(
-                    c,
-                    MissionStatKind::SecretRatio,
-                    "00%",
-                    TEXT_SCALE,
-                    overlay_scale,
-                    bt_mul,
-                    x_secret_val,
-                    y_secret,
-                    x_right,
-                );
-
-                spawn_bt(c, "RATIO", TEXT_SCALE, overlay_scale, bt_mul, x_ratio, y_treasure);
-                let x_treasure_val = right_align_x_native("00%", x_right);
-                spawn_bt_tagged_right_aligned(
-                    c,
-                    MissionStatKind::TreasureRatio,
-                    "00%",
-                    TEXT_SCALE,
-                    overlay_scale,
-                    bt_mul,
-                    x_treasure_val,
-                    y_treasure,
-                    x_right,
-                );
+                    spawn_bt(c, "KILL", TEXT_SCALE, overlay_scale, bt_mul, x_ratio_left, y_kill);
+                    spawn_bt(c, "SECRET", TEXT_SCALE, overlay_scale, bt_mul, x_ratio_left, y_secret);
+                    spawn_bt(c, "TREASURE", TEXT_SCALE, overlay_scale, bt_mul, x_ratio_left, y_treasure);
+
+                    spawn_bt(c, "RATIO", TEXT_SCALE, overlay_scale, bt_mul, x_ratio, y_kill);
+                    let x_kill_val = right_align_x_native("27%", x_right);
+                    spawn_bt_tagged_right_aligned(
+                        c,
+                        MissionStatKind::KillRatio,
+                        "27%",
+                        TEXT_SCALE,
+                        overlay_scale,
+                        bt_mul,
+                        x_kill_val,
+                        y_kill,
+                        x_right,
+                    );
+
+                    spawn_bt(c, "RATIO", TEXT_SCALE, overlay_scale, bt_mul, x_ratio, y_secret);
+                    let x_secret_val = right_align_x_native("00%", x_right);
+                    spawn_bt_tagged_right_aligned(
+                        c,
+                        MissionStatKind::SecretRatio,
+                        "00%",
+                        TEXT_SCALE,
+                        overlay_scale,
+                        bt_mul,
+                        x_secret_val,
+                        y_secret,
+                        x_right,
+                    );
+
+                    spawn_bt(c, "RATIO", TEXT_SCALE, overlay_scale, bt_mul, x_ratio, y_treasure);
+                    let x_treasure_val = right_align_x_native("00%", x_right);
+                    spawn_bt_tagged_right_aligned(
+                        c,
+                        MissionStatKind::TreasureRatio,
+                        "00%",
+                        TEXT_SCALE,
+                        overlay_scale,
+                        bt_mul,
+                        x_treasure_val,
+                        y_treasure,
+                        x_right,
+                    );
+                });
+
+                c.spawn((MissionSecretPage, page(), Visibility::Hidden)).with_children(|c| {
+                    spawn_bt(c, "SECRET FLOOR", TEXT_SCALE, overlay_scale, bt_mul, 110.0, 28.0);
+                    spawn_bt(c, " COMPLETED!", TEXT_SCALE, overlay_scale, bt_mul, 110.0, 48.0);
+                    spawn_bt(c, "15000 BONUS!", TEXT_SCALE, overlay_scale, bt_mul, 80.0, 124.0);
+                });
             });
         });
 }
@@ -2488,7 +2876,7 @@ pub(crate) fn sync_view_size_border(
         return;
     }
 
-    let inset_frac = (20.0 - vs as f32) / 32.0;
+    let inset_frac = settings.view_inset_frac();
     let inset_x = (win_w * inset_frac).round();
     let inset_y = (play_h * inset_frac).round();
 
@@ -2840,3 +3228,337 @@ pub(crate) fn setup_hud(
         assets.bj_pistol_0.clone(),
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::combat::WeaponSlot;
+    use davelib::tics::{TIC, tics};
+
+    #[test]
+    fn weapon_cooldown_is_tic_count_times_tic() {
+        let pistol = weapon_params(WeaponSlot::Pistol);
+        assert_eq!(pistol.cooldown_tics, 24);
+        assert!((tics(pistol.cooldown_tics) - 24.0 * TIC).abs() < 1e-7);
+
+        let chaingun = weapon_params(WeaponSlot::Chaingun);
+        assert!((tics(chaingun.cooldown_tics) - 6.0 / 70.0).abs() < 1e-7);
+    }
+
+    #[test]
+    fn face_stage_steps_every_sixteen_hp_like_wolf3d() {
+        assert_eq!(stage_from_hp(100), 0);
+        assert_eq!(stage_from_hp(85), 0);
+        assert_eq!(stage_from_hp(84), 1);
+        assert_eq!(stage_from_hp(20), 5);
+        assert_eq!(stage_from_hp(4), 6);
+        assert_eq!(stage_from_hp(1), 6);
+
+        assert_eq!(coords_for(50, FaceDir::Right), (0, 11));
+        assert_eq!(coords_for(10, FaceDir::Forward), (1, 3));
+        assert_eq!(coords_for(0, FaceDir::Left), (1, 10));
+    }
+
+    #[test]
+    fn canvas_resize_rescales_hud_in_place() {
+        let mut app = App::new();
+        app.insert_resource(WorldCanvas { handle: Handle::default(), size: UVec2::new(640, 400) })
+            .add_systems(Update, sync_hud_layout_on_window_change);
+
+        let digit = app.world_mut().spawn((HudHpDigit(2), Node::default())).id();
+        let bar = app.world_mut().spawn((HudStatusBarOuter, Node::default())).id();
+
+        // 640 Wide Canvas: Integer Scale 2
+        app.update();
+        assert_eq!(app.world().get::<Node>(digit).unwrap().width, Val::Px(16.0));
+        assert_eq!(app.world().get::<Node>(digit).unwrap().height, Val::Px(32.0));
+        assert_eq!(app.world().get::<Node>(bar).unwrap().height, Val::Px(88.0));
+
+        // Resize to 1000 Wide: Integer Scale 3, Same Entities so HUD Values Survive
+        app.world_mut().resource_mut::<WorldCanvas>().size = UVec2::new(1000, 600);
+        app.update();
+        assert_eq!(app.world().get::<Node>(digit).unwrap().width, Val::Px(24.0));
+        assert_eq!(app.world().get::<Node>(digit).unwrap().height, Val::Px(48.0));
+        assert_eq!(app.world().get::<Node>(bar).unwrap().height, Val::Px(132.0));
+        assert_eq!(app.world().get::<HudHpDigit>(digit).unwrap().0, 2);
+    }
+
+    fn test_viewmodel_sprites(images: &mut Assets<Image>) -> ViewModelSprites {
+        let mut frames = || std::array::from_fn(|_| images.add(Image::default()));
+        ViewModelSprites {
+            knife: frames(),
+            pistol: frames(),
+            machinegun: frames(),
+            chaingun: frames(),
+        }
+    }
+
+    /// Everything weapon_fire_and_viewmodel Reads, at Defaults With Controls
+    /// Unlocked. Tests Add Their Systems and Override Only What They Vary
+    fn weapon_test_app(hud: HudState) -> App {
+        let mut images = Assets::<Image>::default();
+
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<PlayerIntent>()
+            .init_resource::<PlayerControlLock>()
+            .init_resource::<davelib::input::GameplayInputSuppress>()
+            .init_resource::<WeaponState>()
+            .init_resource::<RecoilState>()
+            .init_resource::<GameplaySettings>()
+            .init_resource::<davelib::ai::PlayerNoise>()
+            .insert_resource(test_viewmodel_sprites(&mut images))
+            .insert_resource(hud)
+            .add_message::<PlaySfx>()
+            .add_message::<crate::combat::FireShot>();
+        app
+    }
+
+    #[test]
+    fn viewmodel_returns_to_idle_after_pause_menu() {
+        let mut hud = HudState::default();
+        hud.grant(WeaponSlot::MachineGun);
+        hud.selected = WeaponSlot::Pistol;
+        hud.ammo = 50;
+
+        let mut app = weapon_test_app(hud);
+        app.add_systems(Update, weapon_fire_and_viewmodel);
+        let sprites = app.world().resource::<ViewModelSprites>().clone();
+
+        let vm = app.world_mut().spawn((ViewModelImage, ImageNode::default())).id();
+        let image = |app: &App| app.world().get::<ImageNode>(vm).unwrap().image.clone();
+
+        // First Gameplay Frame Only Arms the Trigger
+        app.update();
+
+        // Shoot: Pistol Shows Its Muzzle Flash Frame
+        app.world_mut().resource_mut::<PlayerIntent>().fire_pressed = true;
+        app.update();
+        assert_eq!(image(&app), sprites.pistol_frame(2));
+
+        // Pause Mid-Shot, Then Swap Weapons While the Menu Is Up
+        app.world_mut().resource_mut::<PlayerIntent>().fire_pressed = false;
+        app.world_mut().resource_mut::<PlayerControlLock>().0 = true;
+        app.update();
+        app.world_mut().resource_mut::<HudState>().selected = WeaponSlot::MachineGun;
+        app.update();
+
+        // Close the Menu: Idle Frame of the Selected Weapon, Nothing in Flight
+        app.world_mut().resource_mut::<PlayerControlLock>().0 = false;
+        app.update();
+        assert_eq!(image(&app), sprites.idle(WeaponSlot::MachineGun));
+
+        let weapon = app.world().resource::<WeaponState>();
+        assert!(!weapon.showing_fire);
+        assert_eq!(weapon.fire_cycle, 0);
+    }
+
+    #[derive(Resource, Default)]
+    struct ShotsFired(u32);
+
+    fn count_shots(mut ev: MessageReader<crate::combat::FireShot>, mut shots: ResMut<ShotsFired>) {
+        shots.0 += ev.read().count() as u32;
+    }
+
+    // Menu Confirm That Unlocks Controls Mid-Frame
+    fn unlock_from_menu(mut lock: ResMut<PlayerControlLock>) {
+        lock.0 = false;
+    }
+
+    fn held_trigger_app(unlock_first: bool) -> App {
+        let mut hud = HudState::default();
+        hud.selected = WeaponSlot::Pistol;
+        hud.ammo = 50;
+
+        let mut app = weapon_test_app(hud);
+        app.insert_resource(PlayerIntent { fire: true, fire_pressed: true, ..default() })
+            .insert_resource(PlayerControlLock(true))
+            .init_resource::<ShotsFired>()
+            .add_systems(First, davelib::input::suppress::snapshot_control_lock);
+        if unlock_first {
+            app.add_systems(Update, (unlock_from_menu, weapon_fire_and_viewmodel, count_shots).chain());
+        } else {
+            app.add_systems(Update, (weapon_fire_and_viewmodel, count_shots, unlock_from_menu).chain());
+        }
+        app.world_mut().spawn((ViewModelImage, ImageNode::default()));
+        app.world_mut().spawn((Player, Transform::default()));
+        app
+    }
+
+    #[test]
+    fn click_that_closes_a_menu_does_not_fire() {
+        // Menu Unlocks Before the Weapon Runs: the Suppressed Frame Holds Fire,
+        // and the Still-Held Trigger Shoots on the Very Next Frame
+        let mut app = held_trigger_app(true);
+        app.update();
+        assert_eq!(app.world().resource::<ShotsFired>().0, 0);
+        app.update();
+        assert_eq!(app.world().resource::<ShotsFired>().0, 1);
+
+        // Menu Unlocks After the Weapon Ran: Nothing Is Suppressed, so the
+        // First-Frame Guard Holds Fire on the First Unlocked Frame Instead
+        let mut app = held_trigger_app(false);
+        app.update();
+        app.update();
+        assert_eq!(app.world().resource::<ShotsFired>().0, 0);
+        app.update();
+        assert_eq!(app.world().resource::<ShotsFired>().0, 1);
+    }
+
+    #[derive(Resource, Default)]
+    struct DryClicks(u32);
+
+    fn count_dry_clicks(mut ev: MessageReader<PlaySfx>, mut clicks: ResMut<DryClicks>) {
+        clicks.0 += ev.read().filter(|e| e.kind == SfxKind::DryFire).count() as u32;
+    }
+
+    #[test]
+    fn empty_gun_clicks_once_then_drops_to_knife_until_ammo_returns() {
+        let mut hud = HudState::default();
+        hud.grant(WeaponSlot::Chaingun);
+        hud.selected = WeaponSlot::Chaingun;
+        hud.ammo = 1;
+
+        let mut app = weapon_test_app(hud);
+        app.init_resource::<DryClicks>()
+            .add_systems(Update, (weapon_fire_and_viewmodel, count_dry_clicks).chain());
+        app.world_mut().spawn((ViewModelImage, ImageNode::default()));
+
+        // Last Round Fired While Holding the Trigger: One Click, Knife in Hand
+        app.update();
+        app.world_mut().resource_mut::<PlayerIntent>().fire = true;
+        for _ in 0..5 {
+            app.update();
+        }
+        assert_eq!(app.world().resource::<HudState>().ammo, 0);
+        assert_eq!(app.world().resource::<HudState>().selected, WeaponSlot::Knife);
+        assert_eq!(app.world().resource::<DryClicks>().0, 1);
+
+        // Picking Up Ammo Brings the Chaingun Back
+        app.world_mut().resource_mut::<HudState>().ammo = 8;
+        app.update();
+        assert_eq!(app.world().resource::<HudState>().selected, WeaponSlot::Chaingun);
+
+        // Option Off: the Empty Gun Stays Drawn and Clicks Again per Pull Only
+        app.world_mut().resource_mut::<GameplaySettings>().empty_weapon_switch = false;
+        app.world_mut().resource_mut::<HudState>().ammo = 0;
+        for _ in 0..5 {
+            app.update();
+        }
+        app.world_mut().resource_mut::<PlayerIntent>().fire = false;
+        app.update();
+        app.world_mut().resource_mut::<PlayerIntent>().fire = true;
+        app.update();
+        assert_eq!(app.world().resource::<HudState>().selected, WeaponSlot::Chaingun);
+        assert_eq!(app.world().resource::<DryClicks>().0, 3);
+    }
+
+    #[derive(Resource, Default)]
+    struct Chimes(u32);
+
+    fn count_chimes(mut ev: MessageReader<PlaySfx>, mut chimes: ResMut<Chimes>) {
+        chimes.0 += ev.read().filter(|e| e.kind == SfxKind::SecretFound).count() as u32;
+    }
+
+    #[test]
+    fn each_quick_secret_find_notifies() {
+        use davelib::pushwalls::SecretFound;
+
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<GameplaySettings>()
+            .init_resource::<super::super::SecretNotice>()
+            .init_resource::<Chimes>()
+            .add_message::<SecretFound>()
+            .add_message::<PlaySfx>()
+            .add_systems(Update, (notify_secret_found, tick_secret_notice, count_chimes).chain());
+
+        let banner = app.world_mut().spawn((SecretNoticeText, Visibility::Hidden)).id();
+
+        // Two Secrets Credited on Back-to-Back Frames
+        app.world_mut().write_message(SecretFound { tile: IVec2::new(3, 4) });
+        app.update();
+        app.world_mut().write_message(SecretFound { tile: IVec2::new(3, 6) });
+        app.update();
+
+        assert_eq!(app.world().resource::<Chimes>().0, 2);
+        assert_eq!(app.world().get::<Visibility>(banner), Some(&Visibility::Inherited));
+
+        // Turned Off: Further Finds Stay Silent
+        app.world_mut().resource_mut::<GameplaySettings>().secret_notice = false;
+        app.world_mut().write_message(SecretFound { tile: IVec2::new(9, 9) });
+        app.update();
+        assert_eq!(app.world().resource::<Chimes>().0, 2);
+    }
+
+    #[test]
+    fn pistol_kick_offsets_viewmodel_then_decays() {
+        use std::time::Duration;
+
+        let mut hud = HudState::default();
+        hud.selected = WeaponSlot::Pistol;
+        hud.ammo = 50;
+
+        let mut app = weapon_test_app(hud);
+        app.insert_resource(GameplaySettings {
+                viewmodel_recoil: davelib::options::ViewmodelRecoil::Normal,
+                ..Default::default()
+            })
+            .add_systems(Update, (weapon_fire_and_viewmodel, apply_viewmodel_recoil).chain());
+
+        // Gun Drawn 3x Native Size (192 px Tall)
+        let node = Node { height: Val::Px(192.0), ..default() };
+        let vm = app.world_mut().spawn((ViewModelImage, ImageNode::default(), node)).id();
+        let top = |app: &App| match app.world().get::<Node>(vm).unwrap().top {
+            Val::Px(px) => px,
+            _ => 0.0,
+        };
+
+        // One Shot: Full 2 px Pistol Kick at 3x
+        app.update();
+        app.world_mut().resource_mut::<PlayerIntent>().fire_pressed = true;
+        app.update();
+        assert_eq!(top(&app), 6.0);
+
+        // Halfway Through the Decay, Half the Kick Remains
+        app.world_mut().resource_mut::<PlayerIntent>().fire_pressed = false;
+        let half = Duration::from_secs_f32(davelib::tics::tics(RECOIL_DECAY_TICS) / 2.0);
+        app.world_mut().resource_mut::<Time>().advance_by(half);
+        app.update();
+        assert_eq!(top(&app), 3.0);
+
+        // Back at Rest
+        app.update();
+        assert_eq!(top(&app), 0.0);
+    }
+
+    #[test]
+    fn sustained_fire_kick_is_capped() {
+        let mut recoil = RecoilState::default();
+        for _ in 0..50 {
+            recoil.kick(5.0);
+        }
+        assert_eq!(recoil.current(), RECOIL_MAX_PX);
+    }
+
+    #[test]
+    fn classic_aim_fires_level_while_pitched_up() {
+        let yaw = 0.7;
+        let pitched_up = Quat::from_euler(EulerRot::YXZ, yaw, 0.5, 0.0);
+
+        let dir = fire_direction(pitched_up, false);
+        assert!(dir.y.abs() < 1e-6);
+        assert!((dir.length() - 1.0).abs() < 1e-5);
+        let level = Quat::from_rotation_y(yaw) * Vec3::NEG_Z;
+        assert!(dir.distance(level) < 1e-5);
+
+        // True 3D Keeps the Pitch
+        assert!(fire_direction(pitched_up, true).y > 0.4);
+
+        // Straight up Still Yields a Level, Unit Direction From Yaw
+        let straight_up = Quat::from_euler(EulerRot::YXZ, yaw, std::f32::consts::FRAC_PI_2, 0.0);
+        let dir = fire_direction(straight_up, false);
+        assert!(dir.y.abs() < 1e-6);
+        assert!(dir.distance(level) < 1e-3);
+    }
+}
@@ -2,19 +2,26 @@
 Davenstein - by David Petnick
 */
 
+mod automap;
+mod crosshair;
 mod hud;
 pub(crate) mod level_end_font;
+mod radar;
 mod splash;
 mod state;
 pub mod sync;
+mod view_overlay;
 
 use bevy::prelude::*;
 
+pub use state::DamageDirection;
 pub use state::DamageFlash;
 pub use state::DeathOverlay;
 pub use state::GameOver;
 pub use state::HudState;
 pub use state::PickupFlash;
+pub use state::ScreenFade;
+pub use state::SecretNotice;
 
 pub use splash::SplashStep;
 
@@ -22,26 +29,55 @@ pub use splash::SplashStep;
 // Them Without Making Splash Module Public
 pub(crate) use splash::EpisodeEndImages;
 
+// Menu Font Paths for Startup Asset Validation
+pub(crate) use splash::{
+    MENU_FONT_BLACK_PATH,
+    MENU_FONT_GRAY_PATH,
+    MENU_FONT_WHITE_PATH,
+    MENU_FONT_YELLOW_PATH,
+};
+
 pub(crate) use hud::HudFaceOverride;
 
+// Viewmodel and HUD Digit Paths for Startup Asset Validation
+pub(crate) use hud::{
+    HUD_DIGIT_BLANK_PATH,
+    VIEWMODEL_FRAMES,
+    VIEWMODEL_WEAPONS,
+    hud_digit_path,
+    viewmodel_frame_path,
+};
+
 pub struct UiPlugin;
 
 impl Plugin for UiPlugin {
 	fn build(&self, app: &mut App) {
 		app.init_resource::<HudState>()
 			.init_resource::<DamageFlash>()
+			.init_resource::<state::ScreenShake>()
+			.init_resource::<DamageDirection>()
+			.init_resource::<state::LivesFlash>()
+			.init_resource::<state::LockedKeyFlash>()
 			.init_resource::<PickupFlash>()
+			.init_resource::<ScreenFade>()
+			.init_resource::<SecretNotice>()
 			.init_resource::<DeathOverlay>()
 			.init_resource::<GameOver>()
 			.init_resource::<sync::DeathDelay>()
 			.init_resource::<sync::RestartRequested>()
+			.init_resource::<sync::RespawnLevelScore>()
 			.init_resource::<sync::NewGameRequested>()
 			.init_resource::<hud::HudFacePrevHp>()
 			.init_resource::<hud::HudFaceLook>()
 			.init_resource::<hud::WeaponState>()
+			.init_resource::<hud::RecoilState>()
+			.init_resource::<automap::AutomapVisited>()
 			.add_plugins(splash::SplashPlugin)
 			.add_systems(Startup, hud::setup_hud)
 			.add_systems(Startup, splash::setup_splash)
+			.add_systems(Startup, automap::setup_automap_image)
+			.add_systems(Startup, radar::setup_radar_image)
+			.add_systems(Startup, crosshair::setup_crosshair_image)
 			// Keep Window-Space UI (Menus, Splash, Intermission, Overlays) on the
 			// Persistent Menu Camera so It Never Falls Into the Low-Res World Canvas.
 			//
@@ -58,7 +94,15 @@ impl Plugin for UiPlugin {
 				hud::route_window_ui_to_menu_camera.before(bevy::ui::UiSystems::Layout),
 			)
 			// Core State / Sync Systems
+			.add_systems(PostUpdate, sync::sync_game_paused)
 			.add_systems(Update, sync::apply_enemy_fire_to_player_vitals)
+			// Screen Shake: Visual Only, Added After Movement and Removed Before it
+			.add_systems(Update, sync::shake_on_nearby_explosions)
+			.add_systems(First, sync::remove_screen_shake)
+			.add_systems(
+				PostUpdate,
+				sync::apply_screen_shake.before(bevy::transform::TransformSystems::Propagate),
+			)
 			.add_systems(Update, sync::sync_player_hp_with_hud)
 			.add_systems(Update, sync::handle_player_death_once)
 			.add_systems(Update, sync::tick_death_delay_and_request_restart)
@@ -69,10 +113,19 @@ impl Plugin for UiPlugin {
 			.add_systems(Update, hud::sync_viewmodel_size)
 			.add_systems(Update, hud::sync_viewmodel_visibility)
 			.add_systems(Update, hud::weapon_fire_and_viewmodel)
+			.add_systems(
+				Update,
+				hud::apply_viewmodel_recoil
+					.after(hud::weapon_fire_and_viewmodel)
+					.after(hud::sync_viewmodel_size),
+			)
 			.add_systems(Update, hud::sync_hud_hp_digits)
 			.add_systems(Update, hud::sync_hud_ammo_digits)
 			.add_systems(Update, hud::sync_hud_score_digits)
+			.add_systems(Update, sync::award_kill_points)
+			.add_systems(Update, sync::award_extra_lives_on_score)
 			.add_systems(Update, hud::sync_hud_lives_digits)
+			.add_systems(Update, hud::flash_hud_lives_digits)
 			.add_systems(Update, hud::sync_hud_floor_digits)
 			.add_systems(Update, hud::sync_hud_icons)
 			.add_systems(Update, hud::tick_hud_face_timers)
@@ -82,8 +135,25 @@ impl Plugin for UiPlugin {
 			// Overlay Systems
 			.add_systems(Update, hud::flash_on_hp_drop)
 			.add_systems(Update, hud::ensure_pickup_flash_overlay)
+			// Automap (Hold Tab)
+			.add_systems(Update, automap::ensure_automap_overlay)
+			.add_systems(Update, automap::reveal_automap_tiles)
+			.add_systems(Update, automap::show_automap.after(automap::reveal_automap_tiles))
+			.add_systems(Update, radar::ensure_radar_overlay)
+			.add_systems(Update, radar::update_radar)
+			.add_systems(Update, crosshair::ensure_crosshair_overlay)
+			.add_systems(Update, crosshair::update_crosshair)
+			.add_systems(
+				PostUpdate,
+				automap::clear_automap_on_rebuild.before(crate::restart::restart_despawn_level),
+			)
 			.add_systems(Update, hud::tick_pickup_flash)
 			.add_systems(Update, hud::tick_damage_flash)
+			.add_systems(Update, hud::sync_damage_flash_layer)
+			.add_systems(Update, hud::record_damage_direction)
+			.add_systems(Update, hud::tick_damage_direction.after(hud::record_damage_direction))
+			.add_systems(Update, hud::notify_secret_found)
+			.add_systems(Update, hud::tick_secret_notice.after(hud::notify_secret_found))
 			.add_systems(Update, hud::tick_death_overlay)
 			.add_systems(Update, hud::sync_game_over_overlay_visibility)
 			.add_systems(Update, level_end_font::sync_level_end_bitmap_text)
@@ -0,0 +1,247 @@
+/*
+Davenstein - by David Petnick
+
+Corner Radar
+
+A Small Circular Radar in the Top-Left of the 3D View Showing the Walls and
+Doors Around the Player and Every Living Enemy Nearby, Turned so the Player's
+Facing Is Always Up. Enemies the Player Has No Line of Sight to Are Drawn Faded
+
+Like the Automap it Is One Small Image Redrawn Each Frame, Sized in HUD Pixels
+so it Grows With the Status Bar. It Hides With the Automap, Menus and Anything
+Else That Is Not Plain Gameplay
+*/
+
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+use davelib::actors::Dead;
+use davelib::ai::has_line_of_sight;
+use davelib::enemies::EnemyKind;
+use davelib::map::{MapGrid, Tile};
+use davelib::options::{ui_ref_dims, VideoSettings, WorldCanvas};
+use davelib::player::Player;
+
+use super::automap::AUTOMAP_KEY;
+use super::hud::ViewArea;
+use super::view_overlay::{hud_scale, overlay_image, view_area_without};
+use super::SplashStep;
+
+/// Tiles Shown Each Way From the Player
+const RADIUS_TILES: f32 = 5.5;
+/// Image Pixels per Tile
+const CELL: f32 = 4.0;
+const RADAR_PX: usize = (RADIUS_TILES * 2.0 * CELL) as usize;
+
+/// On-Screen Size and Corner Inset in 320x200 HUD Pixels
+const RADAR_HUD_PX: f32 = 44.0;
+const RADAR_MARGIN_HUD_PX: f32 = 4.0;
+
+// RGBA, Outside the Circle Stays Transparent
+const OUTSIDE: [u8; 4] = [0, 0, 0, 0];
+const BACKDROP: [u8; 4] = [0, 0, 0, 140];
+const WALL: [u8; 4] = [0, 136, 136, 220];
+const DOOR: [u8; 4] = [200, 160, 0, 220];
+const PLAYER: [u8; 4] = [255, 255, 255, 255];
+const ENEMY_SEEN: [u8; 4] = [255, 48, 48, 255];
+const ENEMY_HIDDEN: [u8; 4] = [255, 48, 48, 90];
+
+#[derive(Component)]
+pub(super) struct RadarOverlay;
+
+#[derive(Resource)]
+pub(super) struct RadarImage(Handle<Image>);
+
+/// Tile Under a World XZ Position, Tiles Centered on Integers
+fn tile_of(p: Vec2) -> IVec2 {
+    IVec2::new((p.x + 0.5).floor() as i32, (p.y + 0.5).floor() as i32)
+}
+
+/// RGBA Pixels of the Radar Image With `forward` Pointing Up. Each Enemy Is its
+/// World XZ Position and Whether the Player Can See it
+pub(crate) fn radar_pixels(grid: &MapGrid, player: Vec2, forward: Vec2, enemies: &[(Vec2, bool)]) -> Vec<u8> {
+    let mut px = vec![0u8; RADAR_PX * RADAR_PX * 4];
+    let mut put = |x: i32, y: i32, c: [u8; 4]| {
+        if x >= 0 && y >= 0 && (x as usize) < RADAR_PX && (y as usize) < RADAR_PX {
+            let i = (y as usize * RADAR_PX + x as usize) * 4;
+            px[i..i + 4].copy_from_slice(&c);
+        }
+    };
+
+    let forward = forward.try_normalize().unwrap_or(Vec2::NEG_Y);
+    // Bevy Looks Down -Z With +X to the Right, so Right Is Forward Turned Clockwise
+    let right = Vec2::new(-forward.y, forward.x);
+    let center = RADAR_PX as f32 / 2.0;
+
+    for y in 0..RADAR_PX {
+        for x in 0..RADAR_PX {
+            // Radar Space in Tiles: +X Right, +Y Down (Behind the Player)
+            let local = (Vec2::new(x as f32 + 0.5, y as f32 + 0.5) - Vec2::splat(center)) / CELL;
+            let color = if local.length() > RADIUS_TILES {
+                OUTSIDE
+            } else {
+                let t = tile_of(player + right * local.x - forward * local.y);
+                let inside = t.x >= 0 && t.y >= 0 && (t.x as usize) < grid.width && (t.y as usize) < grid.height;
+                match inside.then(|| grid.tile(t.x as usize, t.y as usize)) {
+                    Some(Tile::Wall) => WALL,
+                    Some(Tile::DoorClosed | Tile::DoorOpen) => DOOR,
+                    _ => BACKDROP,
+                }
+            };
+            put(x as i32, y as i32, color);
+        }
+    }
+
+    let dot = |put: &mut dyn FnMut(i32, i32, [u8; 4]), at: Vec2, c: [u8; 4]| {
+        let (x, y) = (at.x.floor() as i32, at.y.floor() as i32);
+        for (dx, dy) in [(-1, -1), (0, -1), (-1, 0), (0, 0)] {
+            put(x + dx, y + dy, c);
+        }
+    };
+
+    for &(pos, seen) in enemies {
+        let d = pos - player;
+        let local = Vec2::new(d.dot(right), -d.dot(forward));
+        if local.length() > RADIUS_TILES - 0.25 {
+            continue;
+        }
+        dot(&mut put, Vec2::splat(center) + local * CELL, if seen { ENEMY_SEEN } else { ENEMY_HIDDEN });
+    }
+
+    // Player Dot With a One-Pixel Nose Pointing Up
+    dot(&mut put, Vec2::splat(center), PLAYER);
+    put(center as i32 - 1, center as i32 - 3, PLAYER);
+    put(center as i32, center as i32 - 3, PLAYER);
+
+    px
+}
+
+pub(super) fn setup_radar_image(mut commands: Commands, mut images: ResMut<Assets<Image>>) {
+    commands.insert_resource(RadarImage(overlay_image(&mut images, RADAR_PX, &OUTSIDE)));
+}
+
+/// Attaches the (Hidden) Radar to the HUD's View Area Once the HUD Exists
+pub(super) fn ensure_radar_overlay(
+    mut commands: Commands,
+    radar_image: Res<RadarImage>,
+    q_existing: Query<(), With<RadarOverlay>>,
+    q_view: Query<Entity, With<ViewArea>>,
+) {
+    let Some(view) = view_area_without(&q_existing, &q_view) else { return; };
+
+    commands.entity(view).with_children(|view| {
+        view.spawn((
+            RadarOverlay,
+            ImageNode::new(radar_image.0.clone()),
+            Node {
+                position_type: PositionType::Absolute,
+                ..default()
+            },
+            Visibility::Hidden,
+        ));
+    });
+}
+
+pub(super) fn update_radar(
+    keys: Res<ButtonInput<KeyCode>>,
+    step: Res<SplashStep>,
+    video: Res<VideoSettings>,
+    canvas: Option<Res<WorldCanvas>>,
+    q_win: Query<&Window, With<PrimaryWindow>>,
+    grid: Option<Res<MapGrid>>,
+    q_player: Query<&Transform, With<Player>>,
+    q_enemies: Query<&GlobalTransform, (With<EnemyKind>, Without<Dead>)>,
+    radar_image: Res<RadarImage>,
+    mut images: ResMut<Assets<Image>>,
+    mut q_overlay: Query<(&mut Node, &mut Visibility), With<RadarOverlay>>,
+) {
+    let Some((mut node, mut vis)) = q_overlay.iter_mut().next() else { return; };
+
+    let shown = *step == SplashStep::Done && !keys.pressed(AUTOMAP_KEY);
+    let (Some(grid), Some(tf), true) = (grid, q_player.iter().next(), shown) else {
+        vis.set_if_neq(Visibility::Hidden);
+        return;
+    };
+    vis.set_if_neq(Visibility::Inherited);
+
+    // Same HUD Scale and View-Size Inset as the Viewmodel and Camera Viewport
+    const STATUS_H: f32 = 44.0;
+    let (ui_w, ui_h) = ui_ref_dims(canvas.as_deref(), &q_win);
+    let hud_scale = hud_scale(ui_w);
+    let play_h = (ui_h - STATUS_H * hud_scale).max(1.0);
+    let inset_frac = video.view_inset_frac();
+
+    let size = Val::Px(RADAR_HUD_PX * hud_scale);
+    let left = Val::Px((ui_w * inset_frac).round() + RADAR_MARGIN_HUD_PX * hud_scale);
+    let top = Val::Px((play_h * inset_frac).round() + RADAR_MARGIN_HUD_PX * hud_scale);
+    if node.width != size || node.left != left || node.top != top {
+        node.width = size;
+        node.height = size;
+        node.left = left;
+        node.top = top;
+    }
+
+    let player = Vec2::new(tf.translation.x, tf.translation.z);
+    let player_tile = tile_of(player);
+    let enemies: Vec<(Vec2, bool)> = q_enemies
+        .iter()
+        .map(|gt| Vec2::new(gt.translation().x, gt.translation().z))
+        .filter(|pos| pos.distance(player) <= RADIUS_TILES)
+        .map(|pos| (pos, has_line_of_sight(&grid, player_tile, tile_of(pos))))
+        .collect();
+
+    let forward = tf.rotation * Vec3::NEG_Z;
+    let pixels = radar_pixels(&grid, player, Vec2::new(forward.x, forward.z), &enemies);
+
+    if let Some(mut image) = images.get_mut(&radar_image.0) {
+        image.data = Some(pixels);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(px: &[u8], x: usize, y: usize) -> [u8; 4] {
+        px[(y * RADAR_PX + x) * 4..][..4].try_into().unwrap()
+    }
+
+    #[test]
+    fn radar_turns_with_the_player_so_facing_is_up() {
+        let (grid, _, _) = MapGrid::from_ascii(&[
+            "###########",
+            "#.........#",
+            "#.........#",
+            "#.........#",
+            "#.........#",
+            "#.........#",
+            "###########",
+        ]);
+        let player = Vec2::new(5.0, 3.0);
+        let c = RADAR_PX / 2;
+
+        // Facing -Z the North Wall, Three Tiles Away, Sits Straight Above
+        let px = radar_pixels(&grid, player, Vec2::NEG_Y, &[]);
+        assert_eq!(at(&px, c, c - 3 * CELL as usize - 1), WALL);
+        assert_eq!(at(&px, c, c + 2 * CELL as usize), BACKDROP);
+
+        // Facing +X the Same Wall Is Now to the Left
+        let px = radar_pixels(&grid, player, Vec2::X, &[]);
+        assert_eq!(at(&px, c - 3 * CELL as usize - 1, c), WALL);
+        assert_eq!(at(&px, 0, 0), OUTSIDE);
+    }
+
+    #[test]
+    fn enemies_out_of_sight_are_faded() {
+        let (grid, _, _) = MapGrid::from_ascii(&["#######", "#.....#", "#######"]);
+        let player = Vec2::new(1.0, 1.0);
+        let c = RADAR_PX / 2;
+
+        // Two Tiles Ahead When Facing +X Is Two Tiles Up on the Radar
+        let px = radar_pixels(&grid, player, Vec2::X, &[(Vec2::new(3.0, 1.0), true)]);
+        assert_eq!(at(&px, c, c - 2 * CELL as usize), ENEMY_SEEN);
+
+        let px = radar_pixels(&grid, player, Vec2::X, &[(Vec2::new(3.0, 1.0), false)]);
+        assert_eq!(at(&px, c, c - 2 * CELL as usize), ENEMY_HIDDEN);
+    }
+}
@@ -16,6 +16,7 @@ use std::sync::OnceLock;
 use crate::ui::{
     DeathOverlay,
     GameOver,
+    ScreenFade,
     level_end_font::LevelEndBitmapText,
 };
 use davelib::audio::{
@@ -32,7 +33,9 @@ use davelib::options::{
     SoundSettings,
     ControlSettings,
     GameplaySettings,
+    InputBinding,
     KeyBindings,
+    PsychedScreen,
 };
 
 pub const SPLASH_0_PATH: &str = "textures/ui/splash0.png";
@@ -56,15 +59,59 @@ const MENU_FONT_MAP_JSON: &str = include_str!("../../assets/textures/ui/menu_fon
 const EPISODE_THUMBS_ATLAS_PATH: &str = "textures/ui/episode_thumbs_atlas.png";
 pub const MENU_FONT_BLACK_PATH: &str = "textures/ui/episode_end/menu_font_black.png";
 
+// Scores Screen Key That Opens the Reset High Scores Prompt
+const SCORES_RESET_KEY: KeyCode = KeyCode::Delete;
+
+// Attract Loop: Seconds Idle on the Main Menu Before the Title and High Scores
+// Start Cycling, and How Long Each of Those Pages Stays Up
+const ATTRACT_IDLE_SECS: f32 = 30.0;
+const ATTRACT_PAGE_SECS: f32 = 10.0;
+
 const EP_THUMB_W: f32 = 48.0;
 const EP_THUMB_H: f32 = 24.0;
+// episode_thumbs_atlas.png Is a 144x48 Sheet: 3 Columns x 2 Rows of 48x24 Cells
+const EP_THUMB_ATLAS_COLS: usize = 3;
+const EP_THUMB_ATLAS_ROWS: usize = 2;
+
+/// One Row of the Episode Selector. 'atlas_index' Counts Cells Left to Right,
+/// Top to Bottom; None (or an Index Past the Sheet) Draws a Placeholder Box
+struct EpisodeEntry {
+    title: &'static str,
+    subtitle: &'static str,
+    atlas_index: Option<usize>,
+}
+
+/// The Six Wolfenstein 3-D Episodes, in Selector Order
+const EPISODES: [EpisodeEntry; 6] = [
+    EpisodeEntry { title: "Episode 1", subtitle: "Escape from Wolfenstein", atlas_index: Some(0) },
+    EpisodeEntry { title: "Episode 2", subtitle: "Operation: Eisenfaust", atlas_index: Some(1) },
+    EpisodeEntry { title: "Episode 3", subtitle: "Die, Fuhrer, Die!", atlas_index: Some(2) },
+    EpisodeEntry { title: "Episode 4", subtitle: "A Dark Secret", atlas_index: Some(3) },
+    EpisodeEntry { title: "Episode 5", subtitle: "Trail of the Madman", atlas_index: Some(4) },
+    EpisodeEntry { title: "Episode 6", subtitle: "Confrontation", atlas_index: Some(5) },
+];
+
+/// Atlas Sub-Rect for a Thumbnail Cell, or None When the Cell Is Missing so the
+/// Caller Never Samples Outside the Sheet
+fn episode_thumb_rect(atlas_index: Option<usize>) -> Option<Rect> {
+    let i = atlas_index?;
+    if i >= EP_THUMB_ATLAS_COLS * EP_THUMB_ATLAS_ROWS {
+        return None;
+    }
+
+    let col = (i % EP_THUMB_ATLAS_COLS) as f32;
+    let row = (i / EP_THUMB_ATLAS_COLS) as f32;
+    Some(Rect::from_corners(
+        Vec2::new(col * EP_THUMB_W, row * EP_THUMB_H),
+        Vec2::new((col + 1.0) * EP_THUMB_W, (row + 1.0) * EP_THUMB_H),
+    ))
+}
 
 const EP_TITLE_TOP: f32 = 10.0;
 const EP_LIST_TOP: f32 = 32.0;
 const EP_ROW_H: f32 = 24.0;
 
 const BASE_HUD_H: f32 = 44.0;
-const PSYCHED_DURATION_SECS: f32 = 2.5;
 const PSYCHED_SPR_W: f32 = 220.0;
 const PSYCHED_SPR_H: f32 = 40.0;
 
@@ -84,6 +131,9 @@ const BASE_H: f32 = 200.0;
 
 const MENU_CURSOR_TOP: f32 = 62.0;
 const MENU_ITEM_H: f32 = 13.0;
+// Pause Menu Carries One More Row Than the Main Menu, Tighten it so the
+// Last Item Still Clears the Bottom Hint
+const MENU_ITEM_H_PAUSE: f32 = 12.0;
 const MENU_FONT_HEIGHT: f32 = 20.0;
 const MENU_FONT_SPACE_W: f32 = 8.0;
 
@@ -129,6 +179,7 @@ struct SplashResources<'w> {
     psyched: ResMut<'w, PsychedLoad>,
     name_entry: ResMut<'w, davelib::high_score::NameEntryState>,
     high_scores: ResMut<'w, davelib::high_score::HighScores>,
+    cheats: Res<'w, davelib::player::CheatState>,
     death_overlay: Res<'w, DeathOverlay>,
     game_over: Res<'w, GameOver>,
     video_settings: ResMut<'w, VideoSettings>,
@@ -138,6 +189,10 @@ struct SplashResources<'w> {
     gameplay_settings: ResMut<'w, GameplaySettings>,
     load_req: ResMut<'w, crate::save::LoadGameRequested>,
     save_req: ResMut<'w, crate::save::SaveGameRequested>,
+    restart_level: ResMut<'w, crate::ui::sync::RestartLevelRequested>,
+    last_session: ResMut<'w, crate::settings::LastSession>,
+    continue_entry: Res<'w, ContinueEntry>,
+    video_confirm: ResMut<'w, PendingVideoConfirm>,
 }
 
 #[derive(SystemParam)]
@@ -620,6 +675,11 @@ struct LoadSlotItem {
 #[derive(Component)]
 struct SplashImage;
 
+/// A Menu Screen's Canvas, Laid Out at the Integer Menu Scale. Its Background
+/// Is Widened to the Title Pictures' Fractional Fit by fit_menu_canvases
+#[derive(Component)]
+struct MenuCanvas;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Resource)]
 pub enum SplashStep {
     Splash0,
@@ -640,6 +700,9 @@ pub enum SplashStep {
     ControlOptions,
     GameplayOptions,
     KeyBindings,
+    RestartConfirm,
+    ResetScoresConfirm,
+    VideoConfirm,
     Done,
 }
 
@@ -696,6 +759,113 @@ struct ChangeViewLocalState {
     hold_ticks: u32,
 }
 
+/// Seconds a Display Mode or Resolution Change Waits to be Kept Before it
+/// Reverts on its Own, Like an OS Display Dialog
+const VIDEO_CONFIRM_SECS: f32 = 10.0;
+
+/// Display Change Awaiting Confirmation, Holding the Settings to Go Back To if
+/// the Player Declines or Never Answers (an Unusable Mode May Show Nothing)
+#[derive(Clone, Copy)]
+struct VideoConfirm {
+    previous: VideoSettings,
+    secs_left: f32,
+}
+
+impl VideoConfirm {
+    fn new(previous: VideoSettings) -> Self {
+        Self { previous, secs_left: VIDEO_CONFIRM_SECS }
+    }
+
+    /// Counts Down, Returning the Settings to Restore Once Time Runs Out
+    fn tick(&mut self, dt: f32) -> Option<VideoSettings> {
+        self.secs_left -= dt;
+        (self.secs_left <= 0.0).then_some(self.previous)
+    }
+
+    /// Whole Seconds Shown in the Prompt
+    fn seconds_shown(&self) -> u32 {
+        self.secs_left.ceil().max(0.0) as u32
+    }
+}
+
+/// Display Mode / Resolution Change Waiting on "Keep These Settings?"
+#[derive(Resource, Default)]
+struct PendingVideoConfirm(Option<VideoConfirm>);
+
+/// Counts the "Keep These Settings?" Prompt Down and Takes its Answer. Keeping
+/// Goes Back to Change View, Declining or Running Out of Time Restores the
+/// Previous Display Settings for the Apply System to Pick Up. The Prompt's UI
+/// Is Cleared on an Answer and Whenever the Shown Seconds Change, so
+/// splash_advance_on_any_input Redraws it
+fn answer_video_confirm(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    nav: Res<davelib::input::MenuNav>,
+    mut step: ResMut<SplashStep>,
+    mut pending: ResMut<PendingVideoConfirm>,
+    mut video: ResMut<VideoSettings>,
+    mut sfx: MessageWriter<PlaySfx>,
+    q_splash_roots: Query<Entity, (With<SplashUi>, Without<ChildOf>)>,
+) {
+    if *step != SplashStep::VideoConfirm {
+        return;
+    }
+    let Some(confirm) = pending.0.as_mut() else { return; };
+
+    let shown = confirm.seconds_shown();
+    let expired = confirm.tick(time.delta_secs());
+    let redraw = confirm.seconds_shown() != shown;
+
+    let yes = keyboard.just_pressed(KeyCode::KeyY)
+        || keyboard.just_pressed(KeyCode::Enter)
+        || keyboard.just_pressed(KeyCode::NumpadEnter)
+        || nav.confirm;
+    let no = keyboard.just_pressed(KeyCode::KeyN)
+        || keyboard.just_pressed(KeyCode::Escape)
+        || nav.cancel;
+
+    if yes {
+        sfx.write(PlaySfx { kind: SfxKind::MenuSelect, pos: Vec3::ZERO });
+        pending.0 = None;
+        *step = SplashStep::ChangeView;
+    } else if no || expired.is_some() {
+        sfx.write(PlaySfx { kind: SfxKind::MenuBack, pos: Vec3::ZERO });
+        // The Apply System Picks the Old Mode up From the Change, and Change
+        // View Redraws Again Once the Window Has Resized
+        *video = confirm.previous;
+        pending.0 = None;
+        *step = SplashStep::ChangeView;
+    } else if !redraw {
+        return;
+    }
+
+    for e in q_splash_roots.iter() {
+        commands.entity(e).try_despawn();
+    }
+}
+
+/// Option List Row Height, Shrunk Toward the Font Height When a Long List
+/// Would Otherwise Overflow Its Panel
+fn options_row_h(ui_scale: f32, panel_h: f32, item_count: usize) -> f32 {
+    let full = (16.0 * ui_scale).round();
+    let fit = (panel_h / item_count.max(1) as f32).floor();
+    let min = (MENU_FONT_HEIGHT * MENU_FONT_DRAW_SCALE * ui_scale).round();
+    full.min(fit).max(min).max(1.0)
+}
+
+/// Most Option Rows a Panel Holds, Each at the Minimum Row Height
+fn options_rows_per_page(ui_scale: f32, panel_h: f32) -> usize {
+    let min = (MENU_FONT_HEIGHT * MENU_FONT_DRAW_SCALE * ui_scale).round().max(1.0);
+    ((panel_h / min).floor() as usize).max(1)
+}
+
+/// Rows Drawn for a List Too Long for its Panel: the Page Holding the Selection
+fn options_page(selection: usize, item_count: usize, per_page: usize) -> std::ops::Range<usize> {
+    let start = selection / per_page.max(1) * per_page.max(1);
+    start.min(item_count)..(start + per_page).min(item_count)
+}
+
 /// Initial Delay Before Hold Repeat Starts (Seconds)
 const HOLD_REPEAT_INITIAL: f32 = 0.35;
 /// Fastest Repeat Interval (Seconds)
@@ -852,10 +1022,12 @@ struct SkillLocalState {
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum MenuAction {
+    Continue,
     BackToGame,
     NewGame,
     LoadGame,
     SaveGame,
+    RestartLevel,
     Sound,
     Control,
     Gameplay,
@@ -873,10 +1045,69 @@ const MENU_ACTIONS_MAIN: [MenuAction; 7] = [
     MenuAction::Quit,
 ];
 
-const MENU_ACTIONS_PAUSE: [MenuAction; 9] = [
+/// Main Menu Continue Row. Hidden Until a Game Has Been Saved or Loaded, Grayed
+/// Out (Never Loaded) When That Save Is Missing, Corrupt or From Another Version
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+enum ContinueEntry {
+    #[default]
+    Hidden,
+    Ready(u32),
+    Unreadable,
+}
+
+impl ContinueEntry {
+    fn for_slot(slot: Option<u32>, loadable: impl Fn(u32) -> bool) -> Self {
+        match slot {
+            None => Self::Hidden,
+            Some(s) if loadable(s) => Self::Ready(s),
+            Some(_) => Self::Unreadable,
+        }
+    }
+}
+
+/// Rows of the Main or Pause Menu, in Display Order
+fn menu_actions(from_pause: bool, cont: ContinueEntry) -> Vec<MenuAction> {
+    if from_pause {
+        return MENU_ACTIONS_PAUSE.to_vec();
+    }
+
+    let mut actions = Vec::with_capacity(MENU_ACTIONS_MAIN.len() + 1);
+    if cont != ContinueEntry::Hidden {
+        actions.push(MenuAction::Continue);
+    }
+    actions.extend(MENU_ACTIONS_MAIN);
+    actions
+}
+
+fn menu_labels(from_pause: bool, cont: ContinueEntry) -> Vec<&'static str> {
+    if from_pause {
+        return MENU_LABELS_PAUSE.to_vec();
+    }
+
+    let mut labels = Vec::with_capacity(MENU_LABELS_MAIN.len() + 1);
+    if cont != ContinueEntry::Hidden {
+        labels.push("Continue");
+    }
+    labels.extend(MENU_LABELS_MAIN);
+    labels
+}
+
+/// Re-Probe the Continue Slot Whenever LastSession Changes (Startup Load, Save,
+/// Load), so the Menu Never Reads the Disk per Frame
+fn refresh_continue_entry(
+    session: Res<crate::settings::LastSession>,
+    mut entry: ResMut<ContinueEntry>,
+) {
+    if session.is_changed() {
+        *entry = ContinueEntry::for_slot(session.slot, crate::save::storage::slot_loadable);
+    }
+}
+
+const MENU_ACTIONS_PAUSE: [MenuAction; 10] = [
     MenuAction::NewGame,
     MenuAction::LoadGame,
     MenuAction::SaveGame,
+    MenuAction::RestartLevel,
     MenuAction::Sound,
     MenuAction::Control,
     MenuAction::Gameplay,
@@ -895,10 +1126,11 @@ const MENU_LABELS_MAIN: [&str; 7] = [
     "Quit",
 ];
 
-const MENU_LABELS_PAUSE: [&str; 9] = [
+const MENU_LABELS_PAUSE: [&str; 10] = [
     "New Game",
     "Load Game",
     "Save Game",
+    "Restart Level",
     "Sound",
     "Control",
     "Gameplay",
@@ -965,6 +1197,10 @@ struct PsychedBar {
     target_w: f32,
 }
 
+/// Top-Most Black Node Whose Alpha Follows ScreenFade
+#[derive(Component)]
+struct ScreenFadeOverlay;
+
 #[derive(Resource)]
 struct PsychedLoad {
     timer: Timer,
@@ -975,7 +1211,7 @@ impl Default for PsychedLoad {
     fn default() -> Self {
         Self {
             timer: Timer::from_seconds(
-                PSYCHED_DURATION_SECS,
+                PsychedScreen::Classic.duration_secs(),
                 TimerMode::Once,
             ),
             active: false,
@@ -989,11 +1225,34 @@ impl Default for SplashStep {
     }
 }
 
+/// Command Line Flag That Skips the Title Screens for One Launch
+pub const SKIP_SPLASH_FLAG: &str = "--skip-splash";
+
+/// First Step Shown at Startup. The Main Menu Step Spawns Its Own UI and Sets the
+/// Menu Music Mode, so Starting There Needs Nothing Else From the Splash Steps
+fn startup_splash_step(skip_setting: bool, mut args: impl Iterator<Item = String>) -> SplashStep {
+    if skip_setting || args.any(|a| a == SKIP_SPLASH_FLAG) {
+        SplashStep::Menu
+    } else {
+        SplashStep::Splash0
+    }
+}
+
+/// Runs in Startup, After settings.ron Was Loaded in PreStartup. setup_splash
+/// Still Loads SplashImages / EpisodeEndImages Either Way
+fn apply_startup_splash_step(gameplay: Res<GameplaySettings>, mut step: ResMut<SplashStep>) {
+    *step = startup_splash_step(gameplay.skip_splash, std::env::args().skip(1));
+}
+
 #[derive(Default)]
 struct MenuLocalState {
     selection: usize,
     blink: Timer,
     blink_light: bool,
+    // Seconds Without Input on the Main Menu
+    idle: f32,
+    // Seconds on the Current Attract Page, None Outside the Attract Loop
+    attract: Option<f32>,
 }
 
 impl MenuLocalState {
@@ -1007,6 +1266,50 @@ impl MenuLocalState {
     }
 }
 
+/// One Frame of the Attract Loop: the Page to Switch to, if Any. Idle on the
+/// Main Menu Starts on the Title, Pages Then Alternate Title / High Scores,
+/// and Any Input on Either Page Returns to the Menu
+fn tick_attract(menu: &mut MenuLocalState, step: SplashStep, any_key: bool, dt: f32) -> Option<SplashStep> {
+    let on_page = matches!(step, SplashStep::Splash1 | SplashStep::Scores);
+
+    if menu.attract.is_some() && (any_key || !on_page) {
+        menu.attract = None;
+        menu.idle = 0.0;
+        if on_page {
+            return Some(SplashStep::Menu);
+        }
+    }
+
+    if let Some(shown) = menu.attract.as_mut() {
+        *shown += dt;
+        if *shown >= ATTRACT_PAGE_SECS {
+            *shown = 0.0;
+            return Some(if step == SplashStep::Splash1 { SplashStep::Scores } else { SplashStep::Splash1 });
+        }
+    } else if step == SplashStep::Menu && !any_key {
+        menu.idle += dt;
+        if menu.idle >= ATTRACT_IDLE_SECS {
+            menu.idle = 0.0;
+            menu.attract = Some(0.0);
+            return Some(SplashStep::Splash1);
+        }
+    } else {
+        menu.idle = 0.0;
+    }
+
+    None
+}
+
+/// Title Pages Play the Splash Track, Except Inside the Attract Loop Where the
+/// Menu Track Keeps Playing Across Every Page
+fn title_music(menu: &MenuLocalState) -> MusicModeKind {
+    if menu.attract.is_some() {
+        MusicModeKind::Menu
+    } else {
+        MusicModeKind::Splash
+    }
+}
+
 fn clear_splash_ui(
     commands: &mut Commands,
     q_splash_roots: &Query<Entity, (With<SplashUi>, Without<ChildOf>)>,
@@ -1023,11 +1326,18 @@ fn clear_splash_ui(
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum ChangeViewKind {
     Vsync,
+    FpsCap,
     DisplayMode,
     Resolution,
     RenderScale,
+    IntegerScale,
+    Floor,
     Fov,
+    FovMode,
     ViewSize,
+    Crosshair,
+    Gore,
+    ScreenShake,
     Back,
 }
 
@@ -1041,6 +1351,12 @@ fn build_change_view_items(
     let vsync_label = if video.vsync { "VSync: ON" } else { "VSync: OFF" };
     items.push((ChangeViewKind::Vsync, vsync_label.to_string()));
 
+    // FPS Cap (Only Takes Effect With VSync Off, VSync Already Paces Frames)
+    items.push((
+        ChangeViewKind::FpsCap,
+        format!("FPS Cap: {}", video.fps_cap.label()),
+    ));
+
     // Display Mode
     items.push((
         ChangeViewKind::DisplayMode,
@@ -1069,18 +1385,46 @@ fn build_change_view_items(
         format!("Render Scale: {}", video.render_scale.label()),
     ));
 
+    // Integer Scale: Letterbox to an Exact Multiple of 320x200
+    let integer_label = if video.integer_scale { "Integer Scale: ON" } else { "Integer Scale: OFF" };
+    items.push((ChangeViewKind::IntegerScale, integer_label.to_string()));
+
+    // Floor: Textured or the Original's Flat Gray
+    let floor_label = if video.textured_floor { "Floor: Textured" } else { "Floor: Flat" };
+    items.push((ChangeViewKind::Floor, floor_label.to_string()));
+
     // FOV
     items.push((
         ChangeViewKind::Fov,
         format!("FOV: {}", video.fov_label()),
     ));
 
+    // FOV Mode (Whether the FOV Above Is Vertical or Horizontal)
+    items.push((
+        ChangeViewKind::FovMode,
+        format!("FOV Mode: {}", video.fov_mode.label()),
+    ));
+
     // View Size
     items.push((
         ChangeViewKind::ViewSize,
         format!("View Size: {}", video.view_size_label()),
     ));
 
+    // Crosshair: Off / Dot / Cross
+    items.push((
+        ChangeViewKind::Crosshair,
+        format!("Crosshair: {}", video.crosshair.label()),
+    ));
+
+    // Gore: Blood Burst on Kills
+    let gore_label = if video.gore { "Gore: ON" } else { "Gore: OFF" };
+    items.push((ChangeViewKind::Gore, gore_label.to_string()));
+
+    // Screen Shake on Damage and Explosions
+    let shake_label = if video.screen_shake { "Screen Shake: ON" } else { "Screen Shake: OFF" };
+    items.push((ChangeViewKind::ScreenShake, shake_label.to_string()));
+
     // Back
     items.push((ChangeViewKind::Back, "Back".to_string()));
 
@@ -1093,6 +1437,7 @@ enum SoundOptionKind {
     MasterVolume,
     MusicVolume,
     SfxVolume,
+    UiVolume,
     MusicEnabled,
     SfxEnabled,
     Back,
@@ -1122,6 +1467,13 @@ fn build_sound_options_items(sound: &SoundSettings) -> Vec<(SoundOptionKind, Str
         format!("SFX Volume: {}%", sfx_pct),
     ));
 
+    // UI Volume (0-100%)
+    let ui_pct = (sound.ui_volume * 100.0).round() as i32;
+    items.push((
+        SoundOptionKind::UiVolume,
+        format!("UI Volume: {}%", ui_pct),
+    ));
+
     // Music Enabled
     let music_label = if sound.music_enabled { "Music: ON" } else { "Music: OFF" };
     items.push((SoundOptionKind::MusicEnabled, music_label.to_string()));
@@ -1142,6 +1494,7 @@ enum ControlOptionKind {
     MouseSensitivity,
     Mouselook,
     InvertY,
+    AlwaysRun,
     GamepadEnabled,
     GamepadSensitivity,
     GamepadDeadzone,
@@ -1171,6 +1524,10 @@ fn build_control_options_items(control: &ControlSettings) -> Vec<(ControlOptionK
     let invert_label = if control.invert_y { "Invert Y: ON" } else { "Invert Y: OFF" };
     items.push((ControlOptionKind::InvertY, invert_label.to_string()));
 
+    // Always Run (Holding Run Walks When ON)
+    let run_label = if control.always_run { "Always Run: ON" } else { "Always Run: OFF" };
+    items.push((ControlOptionKind::AlwaysRun, run_label.to_string()));
+
     // Gamepad Toggle (Enables or Disables All Gamepad Input)
     let gamepad_label = if control.gamepad_enabled { "Gamepad: ON" } else { "Gamepad: OFF" };
     items.push((ControlOptionKind::GamepadEnabled, gamepad_label.to_string()));
@@ -1228,6 +1585,19 @@ fn key_display(k: KeyCode) -> String {
     }
 }
 
+/// Format a Binding for the Bindings Screen, Mouse Buttons Read as "Mouse L"
+fn binding_display(b: InputBinding) -> String {
+    match b {
+        InputBinding::Key(k) => key_display(k),
+        InputBinding::Mouse(MouseButton::Left) => "Mouse L".to_string(),
+        InputBinding::Mouse(MouseButton::Right) => "Mouse R".to_string(),
+        InputBinding::Mouse(MouseButton::Middle) => "Mouse M".to_string(),
+        InputBinding::Mouse(MouseButton::Back) => "Mouse 4".to_string(),
+        InputBinding::Mouse(MouseButton::Forward) => "Mouse 5".to_string(),
+        InputBinding::Mouse(MouseButton::Other(n)) => format!("Mouse {}", n),
+    }
+}
+
 /// Build the Key Bindings Rows. Each Action Shows Its Bound Key, or a Prompt
 /// While That Row Is Capturing. A Trailing Reset Row Restores the Defaults
 fn build_key_bindings_items(control: &ControlSettings, capturing: Option<usize>) -> Vec<String> {
@@ -1238,7 +1608,7 @@ fn build_key_bindings_items(control: &ControlSettings, capturing: Option<usize>)
         if capturing == Some(i) {
             items.push(format!("{}: Press a Key", label));
         } else {
-            items.push(format!("{}: {}", label, key_display(control.key_bindings.key_at(i))));
+            items.push(format!("{}: {}", label, binding_display(control.key_bindings.binding_at(i))));
         }
     }
 
@@ -1252,9 +1622,47 @@ fn build_key_bindings_items(control: &ControlSettings, capturing: Option<usize>)
 enum GameplayOptionKind {
     ChangeView,
     ReversiblePushwalls,
+    RestartLevelLoadout,
+    DamageFlashLayer,
+    DamageDirection,
+    SkipSplash,
+    ScreenFade,
+    PsychedScreen,
+    HoldUseDoors,
+    SecretNotice,
+    ViewmodelRecoil,
+    CheckpointRespawn,
+    True3dAim,
+    HealthRegen,
+    EmptyWeaponSwitch,
+    AutoAim,
     Back,
 }
 
+/// Flip or Cycle the Setting Behind a Gameplay Options Row. False for Rows
+/// That Are Not a Setting (Change View, Back)
+fn toggle_gameplay_option(kind: GameplayOptionKind, gameplay: &mut GameplaySettings) -> bool {
+    match kind {
+        GameplayOptionKind::ReversiblePushwalls => gameplay.reversible_pushwalls ^= true,
+        GameplayOptionKind::RestartLevelLoadout => gameplay.restart_level_pistol_start ^= true,
+        GameplayOptionKind::DamageFlashLayer => gameplay.damage_flash_over_weapon ^= true,
+        GameplayOptionKind::DamageDirection => gameplay.damage_direction_indicator ^= true,
+        GameplayOptionKind::SkipSplash => gameplay.skip_splash ^= true,
+        GameplayOptionKind::ScreenFade => gameplay.screen_fade ^= true,
+        GameplayOptionKind::PsychedScreen => gameplay.psyched_screen = gameplay.psyched_screen.next(),
+        GameplayOptionKind::HoldUseDoors => gameplay.hold_use_doors ^= true,
+        GameplayOptionKind::SecretNotice => gameplay.secret_notice ^= true,
+        GameplayOptionKind::ViewmodelRecoil => gameplay.viewmodel_recoil = gameplay.viewmodel_recoil.next(),
+        GameplayOptionKind::CheckpointRespawn => gameplay.checkpoint_respawn ^= true,
+        GameplayOptionKind::True3dAim => gameplay.true_3d_aim ^= true,
+        GameplayOptionKind::HealthRegen => gameplay.health_regen ^= true,
+        GameplayOptionKind::EmptyWeaponSwitch => gameplay.empty_weapon_switch ^= true,
+        GameplayOptionKind::AutoAim => gameplay.auto_aim = gameplay.auto_aim.next(),
+        GameplayOptionKind::ChangeView | GameplayOptionKind::Back => return false,
+    }
+    true
+}
+
 fn build_gameplay_options_items(gameplay: &GameplaySettings) -> Vec<(GameplayOptionKind, String)> {
     let mut items = Vec::new();
 
@@ -1271,6 +1679,112 @@ fn build_gameplay_options_items(gameplay: &GameplaySettings) -> Vec<(GameplayOpt
     };
     items.push((GameplayOptionKind::ReversiblePushwalls, pushwall_label.to_string()));
 
+    // Restart Level Loadout: Pistol Start or the Inventory Carried Into the Level
+    let restart_label = if gameplay.restart_level_pistol_start {
+        "Restart Level: Pistol Start"
+    } else {
+        "Restart Level: Keep Items"
+    };
+    items.push((GameplayOptionKind::RestartLevelLoadout, restart_label.to_string()));
+
+    // Damage Flash: Behind Keeps the Weapon Sprite Untinted / Over Tints It Too
+    let flash_label = if gameplay.damage_flash_over_weapon {
+        "Damage Flash: Over Weapon"
+    } else {
+        "Damage Flash: Behind Weapon"
+    };
+    items.push((GameplayOptionKind::DamageFlashLayer, flash_label.to_string()));
+
+    // Damage Direction: Edge Bar Pointing at the Attacker
+    let direction_label = if gameplay.damage_direction_indicator {
+        "Damage Direction: On"
+    } else {
+        "Damage Direction: Off"
+    };
+    items.push((GameplayOptionKind::DamageDirection, direction_label.to_string()));
+
+    // Startup: Title Screens Shows Both Splash Images / Main Menu Skips Them
+    let startup_label = if gameplay.skip_splash {
+        "Startup: Main Menu"
+    } else {
+        "Startup: Title Screens"
+    };
+    items.push((GameplayOptionKind::SkipSplash, startup_label.to_string()));
+
+    // Screen Fades: Cut to Black and Fade In on Screen Changes / Instant Switch
+    let fade_label = if gameplay.screen_fade {
+        "Screen Fades: On"
+    } else {
+        "Screen Fades: Off"
+    };
+    items.push((GameplayOptionKind::ScreenFade, fade_label.to_string()));
+
+    // Get Psyched: Classic / Short / Instant Loading Bar, or Off to Skip It
+    items.push((
+        GameplayOptionKind::PsychedScreen,
+        format!("Get Psyched: {}", gameplay.psyched_screen.label()),
+    ));
+
+    // Door Use: Classic One Press per Door / Hold Keeps Opening Doors Ahead
+    let door_use_label = if gameplay.hold_use_doors {
+        "Door Use: Hold"
+    } else {
+        "Door Use: Press"
+    };
+    items.push((GameplayOptionKind::HoldUseDoors, door_use_label.to_string()));
+
+    // Secret Notice: Banner + Chime When a Pushwall Secret Is Found
+    let secret_label = if gameplay.secret_notice {
+        "Secret Notice: On"
+    } else {
+        "Secret Notice: Off"
+    };
+    items.push((GameplayOptionKind::SecretNotice, secret_label.to_string()));
+
+    // Weapon Kick: Off / Subtle / Normal / Strong Viewmodel Recoil per Shot
+    items.push((
+        GameplayOptionKind::ViewmodelRecoil,
+        format!("Weapon Kick: {}", gameplay.viewmodel_recoil.label()),
+    ));
+
+    // On Death: Classic Full Level Restart / Respawn at the Last Checkpoint
+    let death_label = if gameplay.checkpoint_respawn {
+        "On Death: Checkpoint"
+    } else {
+        "On Death: Restart"
+    };
+    items.push((GameplayOptionKind::CheckpointRespawn, death_label.to_string()));
+
+    // Aim: Classic Flattens Shots to the Horizontal, True 3D Follows the Pitch
+    let aim_label = if gameplay.true_3d_aim {
+        "Aim: True 3D"
+    } else {
+        "Aim: Classic"
+    };
+    items.push((GameplayOptionKind::True3dAim, aim_label.to_string()));
+
+    // Health Regen: Off Is the Classic Game
+    let regen_label = if gameplay.health_regen {
+        "Health Regen: On"
+    } else {
+        "Health Regen: Off"
+    };
+    items.push((GameplayOptionKind::HealthRegen, regen_label.to_string()));
+
+    // Out of Ammo: Drop to the Knife Like the Original / Keep the Empty Gun
+    let empty_label = if gameplay.empty_weapon_switch {
+        "Out of Ammo: Knife"
+    } else {
+        "Out of Ammo: Keep Gun"
+    };
+    items.push((GameplayOptionKind::EmptyWeaponSwitch, empty_label.to_string()));
+
+    // Auto Aim: Off / Classic / Generous Hitbox Forgiveness
+    items.push((
+        GameplayOptionKind::AutoAim,
+        format!("Auto Aim: {}", gameplay.auto_aim.label()),
+    ));
+
     // Back
     items.push((GameplayOptionKind::Back, "Back".to_string()));
 
@@ -1313,6 +1827,7 @@ fn spawn_change_view_ui(
     let canvas = commands
         .spawn((
             SplashUi,
+            MenuCanvas,
             Node {
                 width: Val::Px(w),
                 height: Val::Px(h),
@@ -1470,7 +1985,7 @@ fn spawn_change_view_ui(
 
     let cursor_w = (19.0 * ui_scale).round();
     let cursor_h = (10.0 * ui_scale).round();
-    let row_h = (16.0 * ui_scale).round().max(1.0);
+    let row_h = options_row_h(ui_scale, panel_h, item_count);
 
     let mut max_item_w = 0.0f32;
     for t in &item_labels {
@@ -1610,6 +2125,7 @@ fn spawn_sound_options_ui(
     let canvas = commands
         .spawn((
             SplashUi,
+            MenuCanvas,
             Node {
                 width: Val::Px(w),
                 height: Val::Px(h),
@@ -1902,6 +2418,7 @@ fn spawn_control_options_ui(
     let canvas = commands
         .spawn((
             SplashUi,
+            MenuCanvas,
             Node {
                 width: Val::Px(w),
                 height: Val::Px(h),
@@ -2196,6 +2713,7 @@ fn spawn_key_bindings_ui(
     let canvas = commands
         .spawn((
             SplashUi,
+            MenuCanvas,
             Node {
                 width: Val::Px(w),
                 height: Val::Px(h),
@@ -2501,6 +3019,7 @@ fn spawn_gameplay_options_ui(
     let canvas = commands
         .spawn((
             SplashUi,
+            MenuCanvas,
             Node {
                 width: Val::Px(w),
                 height: Val::Px(h),
@@ -2653,21 +3172,25 @@ fn spawn_gameplay_options_ui(
 
     let cursor_w = (19.0 * ui_scale).round();
     let cursor_h = (10.0 * ui_scale).round();
-    let row_h = (16.0 * ui_scale).round().max(1.0);
+
+    // More Rows Than Fit Are Split Into Pages, Every Page Laid Out Like the First
+    let page_rows = options_rows_per_page(ui_scale, panel_h).min(item_count);
+    let page = options_page(selection, item_count, page_rows);
+    let row_h = options_row_h(ui_scale, panel_h, page_rows);
 
     let mut max_item_w = 0.0f32;
     for t in &item_labels {
         max_item_w = max_item_w.max(measure_menu_text_width(ui_scale, t));
     }
 
-    let list_h = (item_count as f32 * row_h).round();
+    let list_h = (page_rows as f32 * row_h).round();
     let list_top = (panel_top + ((panel_h - list_h) * 0.5)).round();
 
     let text_x = (panel_left + ((panel_w - max_item_w) * 0.5)).round().max(0.0);
     let cursor_x = (text_x - cursor_w - (8.0 * ui_scale).round()).round().max(0.0);
 
-    for idx in 0..item_count {
-        let y = (list_top + idx as f32 * row_h).round();
+    for idx in page.clone() {
+        let y = (list_top + (idx - page.start) as f32 * row_h).round();
         let is_selected = idx == selection;
 
         let gray_run = spawn_menu_bitmap_text(
@@ -2705,7 +3228,7 @@ fn spawn_gameplay_options_ui(
     let cursor_light = asset_server.load(MENU_CURSOR_LIGHT_PATH);
     let cursor_dark = asset_server.load(MENU_CURSOR_DARK_PATH);
 
-    let cursor_y = (list_top + selection as f32 * row_h + ((row_h - cursor_h) * 0.5)).round();
+    let cursor_y = (list_top + (selection - page.start) as f32 * row_h + ((row_h - cursor_h) * 0.5)).round();
 
     commands.spawn((
         SplashUi,
@@ -2793,6 +3316,7 @@ fn spawn_resolution_submenu_ui(
     let canvas = commands
         .spawn((
             SplashUi,
+            MenuCanvas,
             Node {
                 width: Val::Px(w),
                 height: Val::Px(h),
@@ -3102,7 +3626,7 @@ fn spawn_episode_score_ui(
 }
 
 fn tick_episode_victory_tally(
-    time: Res<Time>,
+    time: Res<Time<Real>>,
     step: Res<SplashStep>,
     mut tally: ResMut<EpisodeVictoryTally>,
     mut sfx: MessageWriter<PlaySfx>,
@@ -3250,6 +3774,7 @@ fn spawn_episode_end_text_ui(
     let canvas = commands
         .spawn((
             SplashUi,
+            MenuCanvas,
             Node {
                 width: Val::Px(w),
                 height: Val::Px(h),
@@ -3655,8 +4180,12 @@ pub struct SplashPlugin;
 impl Plugin for SplashPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<SplashStep>();
+        app.add_systems(Startup, apply_startup_splash_step);
         app.init_resource::<PsychedLoad>();
         app.init_resource::<EpisodeVictoryTally>();
+        app.init_resource::<ContinueEntry>();
+        app.init_resource::<PendingVideoConfirm>();
+        app.add_systems(Update, refresh_continue_entry.before(splash_advance_on_any_input));
         app.configure_sets(
             Update,
             (SplashUpdateSet::AdvanceInput, SplashUpdateSet::PsychedLoading).chain_ignore_deferred(),
@@ -3665,6 +4194,12 @@ impl Plugin for SplashPlugin {
             Update,
             splash_advance_on_any_input.after(davelib::input::InputGather),
         );
+        app.add_systems(
+            Update,
+            answer_video_confirm
+                .after(davelib::input::InputGather)
+                .before(splash_advance_on_any_input),
+        );
         app.add_systems(
             Update,
             tick_episode_victory_tally.after(splash_advance_on_any_input),
@@ -3685,14 +4220,63 @@ impl Plugin for SplashPlugin {
             Update,
             splash_resize_on_window_change.in_set(SplashUpdateSet::PsychedLoading),
         );
+        app.add_systems(PostUpdate, fit_menu_canvases.before(bevy::ui::UiSystems::Layout));
+        app.add_systems(Startup, spawn_screen_fade_overlay);
+        app.add_systems(
+            Update,
+            (trigger_screen_fade, tick_screen_fade)
+                .chain()
+                .after(SplashUpdateSet::PsychedLoading)
+                .after(sync_episode_victory_score_text),
+        );
     }
 }
 
+/// Menu Canvas: the Largest Whole Multiple of 320x200 That Fits the Window, so
+/// Every Menu's Bitmap Text Lands on Crisp Integer Pixels. 1x Is the Floor; a
+/// Window Smaller Than That Still Gets a 1x Canvas Centered Over it
 fn compute_scaled_size(win_w: f32, win_h: f32) -> (f32, f32) {
     let scale = (win_w / BASE_W).min(win_h / BASE_H).floor().max(1.0);
     (BASE_W * scale, BASE_H * scale)
 }
 
+/// Title Picture and Menu Background Size: Fills the Window's Limiting Edge at
+/// a Fractional Scale, so a Tall or Narrow Window Between Two Integer Steps Is
+/// Not Left With an Image Little Bigger Than 1x. Text Drawn Over it Still Floors
+/// to an Integer Scale. Never Smaller Than the Integer Menu Canvas
+fn compute_fit_size(win_w: f32, win_h: f32) -> (f32, f32) {
+    let scale = (win_w / BASE_W).min(win_h / BASE_H).max(1.0);
+    ((BASE_W * scale).floor(), (BASE_H * scale).floor())
+}
+
+/// Border Width (Sides, Top / Bottom) That Grows the Integer Menu Canvas Out to
+/// the Title Fit. Absolutely Placed Children Start Inside the Border, so the
+/// Menu Keeps its Integer Layout, Centered in a Background as Large as the Title
+fn menu_fit_border(win_w: f32, win_h: f32) -> (f32, f32) {
+    let (menu_w, menu_h) = compute_scaled_size(win_w, win_h);
+    let (fit_w, fit_h) = compute_fit_size(win_w, win_h);
+    (((fit_w - menu_w) * 0.5).floor(), ((fit_h - menu_h) * 0.5).floor())
+}
+
+/// Gives Each Freshly Spawned Menu Canvas its Fit Border, Colored Like the
+/// Canvas. Menus Respawn on a Resize, so Only New Canvases Need it
+fn fit_menu_canvases(
+    mut commands: Commands,
+    q_win: Query<&Window, With<PrimaryWindow>>,
+    mut q_canvas: Query<(Entity, &mut Node, &BackgroundColor), Added<MenuCanvas>>,
+) {
+    let Some(win) = q_win.iter().next() else { return; };
+    let (menu_w, menu_h) = compute_scaled_size(win.width(), win.height());
+    let (side, top) = menu_fit_border(win.width(), win.height());
+
+    for (e, mut node, bg) in q_canvas.iter_mut() {
+        node.width = Val::Px(menu_w + side * 2.0);
+        node.height = Val::Px(menu_h + top * 2.0);
+        node.border = UiRect::axes(Val::Px(side), Val::Px(top));
+        commands.entity(e).insert(BorderColor::all(bg.0));
+    }
+}
+
 fn spawn_episode_select_ui(
     commands: &mut Commands,
     asset_server: &Res<AssetServer>,
@@ -3723,6 +4307,7 @@ fn spawn_episode_select_ui(
     let canvas = commands
         .spawn((
             SplashUi,
+            MenuCanvas,
             Node {
                 width: Val::Px(w),
                 height: Val::Px(h),
@@ -3892,41 +4477,27 @@ fn spawn_episode_select_ui(
     ));
 
     // ---- Episodes ----
-    const EP_TEXT: [&str; 6] = [
-        "Episode 1\nEscape from Wolfenstein",
-        "Episode 2\nOperation: Eisenfaust",
-        "Episode 3\nDie, Fuhrer, Die!",
-        "Episode 4\nA Dark Secret",
-        "Episode 5\nTrail of the Madman",
-        "Episode 6\nConfrontation",
-    ];
-
-    for idx in 0..6 {
+    for (idx, entry) in EPISODES.iter().enumerate() {
         let row_top = (EP_LIST_TOP + idx as f32 * EP_ROW_H) * ui_scale;
+        let ep_text = format!("{}\n{}", entry.title, entry.subtitle);
 
-        let col = (idx % 3) as f32;
-        let row = (idx / 3) as f32;
-
-        let rect = Rect::from_corners(
-            Vec2::new(col * EP_THUMB_W, row * EP_THUMB_H),
-            Vec2::new((col + 1.0) * EP_THUMB_W, (row + 1.0) * EP_THUMB_H),
-        );
-
-        let mut img = ImageNode::new(imgs.episode_thumbs_atlas.clone());
-        img.rect = Some(rect);
+        let thumb_node = Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(thumb_x),
+            top: Val::Px(row_top.round()),
+            width: Val::Px((EP_THUMB_W * ui_scale).round()),
+            height: Val::Px((EP_THUMB_H * ui_scale).round()),
+            ..default()
+        };
 
-        commands.spawn((
-            Node {
-                position_type: PositionType::Absolute,
-                left: Val::Px(thumb_x),
-                top: Val::Px(row_top.round()),
-                width: Val::Px((EP_THUMB_W * ui_scale).round()),
-                height: Val::Px((EP_THUMB_H * ui_scale).round()),
-                ..default()
-            },
-            img,
-            ChildOf(canvas),
-        ));
+        if let Some(rect) = episode_thumb_rect(entry.atlas_index) {
+            let mut img = ImageNode::new(imgs.episode_thumbs_atlas.clone());
+            img.rect = Some(rect);
+            commands.spawn((thumb_node, img, ChildOf(canvas)));
+        } else {
+            // Missing Thumbnail: Plain Dark Box the Size of a Cell
+            commands.spawn((thumb_node, BackgroundColor(Color::srgb(0.0, 0.0, 0.0)), ChildOf(canvas)));
+        }
 
         let text_top = (row_top + (1.8 * ui_scale)).round();
         let is_selected = idx == selection;
@@ -3938,7 +4509,7 @@ fn spawn_episode_select_ui(
             text_x,
             text_top,
             ui_scale,
-            EP_TEXT[idx],
+            &ep_text,
             if is_selected { Visibility::Hidden } else { Visibility::Visible },
         );
         commands
@@ -3952,7 +4523,7 @@ fn spawn_episode_select_ui(
             text_x,
             text_top,
             ui_scale,
-            EP_TEXT[idx],
+            &ep_text,
             if is_selected { Visibility::Visible } else { Visibility::Hidden },
         );
         commands
@@ -4016,6 +4587,40 @@ fn spawn_episode_select_ui(
     ));
 }
 
+/// One Line Under the Skill List Describing What the Highlighted Skill Changes
+fn skill_blurb(skill: davelib::skill::SkillLevel) -> &'static str {
+    use davelib::skill::SkillLevel;
+
+    match skill.0 {
+        SkillLevel::DADDY => "Half the guards, and their shots hurt much less",
+        SkillLevel::DONT_HURT_ME => "Light patrols, shots hurt a bit less",
+        SkillLevel::BRING_EM_ON => "More enemies on patrol, full damage",
+        _ => "Every enemy in the castle, and they hit harder",
+    }
+}
+
+/// Greedy Word Wrap so 'text' Never Measures Wider Than 'max_w'
+/// A Single Word Longer Than the Limit Gets a Line to Itself
+fn wrap_menu_text(text: &str, max_w: f32, measure: impl Fn(&str) -> f32) -> String {
+    let mut out = String::new();
+    let mut line = String::new();
+
+    for word in text.split_whitespace() {
+        let candidate = if line.is_empty() { word.to_string() } else { format!("{line} {word}") };
+
+        if line.is_empty() || measure(&candidate) <= max_w {
+            line = candidate;
+        } else {
+            out.push_str(&line);
+            out.push('\n');
+            line = word.to_string();
+        }
+    }
+
+    out.push_str(&line);
+    out
+}
+
 fn spawn_skill_select_ui(
     commands: &mut Commands,
     asset_server: &Res<AssetServer>,
@@ -4048,6 +4653,7 @@ fn spawn_skill_select_ui(
     let canvas = commands
         .spawn((
             SplashUi,
+            MenuCanvas,
             Node {
                 width: Val::Px(w),
                 height: Val::Px(h),
@@ -4277,6 +4883,35 @@ fn spawn_skill_select_ui(
             .insert((SkillItem { idx }, SkillTextVariant { selected: true }));
     }
 
+    // Skill Blurbs: One Per Skill Below the Panel, Tagged as a Selected Variant
+    // so the Existing Selection Sync Shows Only the Highlighted Skill's Line
+    let blurb_max_w = (panel_w - (8.0 * ui_scale).round()).max(1.0);
+    let blurb_top = (panel_top + panel_h + (4.0 * ui_scale).round()).round();
+
+    for idx in 0..4 {
+        let blurb = wrap_menu_text(
+            skill_blurb(davelib::skill::SkillLevel::from_selection(idx)),
+            blurb_max_w,
+            |t| measure_menu_text_width(ui_scale, t),
+        );
+        let blurb_w = measure_menu_text_width(ui_scale, &blurb);
+        let blurb_x = ((w - blurb_w) * 0.5).round().max(0.0);
+
+        let run = spawn_menu_bitmap_text(
+            commands,
+            canvas,
+            imgs.menu_font_gray.clone(),
+            blurb_x,
+            blurb_top,
+            ui_scale,
+            &blurb,
+            if idx == selection { Visibility::Visible } else { Visibility::Hidden },
+        );
+        commands
+            .entity(run)
+            .insert((SkillItem { idx }, SkillTextVariant { selected: true }));
+    }
+
     // Gun cursor
     let cursor_light = asset_server.load(MENU_CURSOR_LIGHT_PATH);
     let cursor_dark = asset_server.load(MENU_CURSOR_DARK_PATH);
@@ -4494,6 +5129,7 @@ fn spawn_name_entry_ui(
     let canvas = commands
         .spawn((
             SplashUi,
+            MenuCanvas,
             Node {
                 width: Val::Px(w),
                 height: Val::Px(h),
@@ -4640,6 +5276,7 @@ fn spawn_scores_ui(
     let canvas = commands
         .spawn((
             SplashUi,
+            MenuCanvas,
             Node {
                 width: Val::Px(w),
                 height: Val::Px(h),
@@ -4841,6 +5478,7 @@ fn spawn_load_select_ui(
     let canvas = commands
         .spawn((
             SplashUi,
+            MenuCanvas,
             Node {
                 width: Val::Px(w),
                 height: Val::Px(h),
@@ -5002,34 +5640,172 @@ fn spawn_load_select_ui(
     ));
 }
 
-fn spawn_menu_hint(
+/// Row Pitch of the Root Menu in Native Pixels
+fn menu_item_h(from_pause: bool) -> f32 {
+    if from_pause { MENU_ITEM_H_PAUSE } else { MENU_ITEM_H }
+}
+
+/// Spawn the Restart Level Confirmation Over the Pause Menu
+fn spawn_restart_confirm_ui(
     commands: &mut Commands,
-    asset_server: &AssetServer,
     w: f32,
     h: f32,
     imgs: &SplashImages,
-    from_pause: bool,
 ) {
-    let banner = asset_server.load(MENU_BANNER_PATH);
-    let hint = asset_server.load(MENU_HINT_PATH);
-    let cursor_light = asset_server.load(MENU_CURSOR_LIGHT_PATH);
-    let cursor_dark = asset_server.load(MENU_CURSOR_DARK_PATH);
+    spawn_confirm_box(commands, w, h, imgs, "Restart this level?\nPress Y or N.");
+}
 
-    let ui_scale = (w / BASE_W).round().max(1.0);
+/// Spawn the Keep Display Settings Countdown Over the Change View Screen
+fn spawn_video_confirm_ui(
+    commands: &mut Commands,
+    w: f32,
+    h: f32,
+    imgs: &SplashImages,
+    secs: u32,
+) {
+    let text = format!("Keep these settings?\nReverting in {secs}.\nPress Y or N.");
+    spawn_confirm_box(commands, w, h, imgs, &text);
+}
 
-    // Banner Geometry
-    let banner_native_h = 48.0;
-    let top_red = (3.0 * ui_scale).round();
+/// Spawn the Reset High Scores Confirmation Over the Scores Screen
+fn spawn_reset_scores_confirm_ui(
+    commands: &mut Commands,
+    w: f32,
+    h: f32,
+    imgs: &SplashImages,
+) {
+    spawn_confirm_box(commands, w, h, imgs, "Reset high scores?\nPress Y or N.");
+}
 
-    let banner_x = 0.0;
-    let banner_y = top_red;
-    let banner_w = w;
-    let banner_h = (banner_native_h * ui_scale).round();
+/// Small Framed Message Box Like the Original's "Are You Sure" Prompts
+fn spawn_confirm_box(
+    commands: &mut Commands,
+    w: f32,
+    h: f32,
+    imgs: &SplashImages,
+    text: &str,
+) {
+    let ui_scale = (w / BASE_W).round().max(1.0);
 
-    // Hint Placement
-    let hint_native_w = 103.0;
-    let hint_native_h = 12.0;
-    let hint_bottom_pad = 6.0;
+    let s = (ui_scale * MENU_FONT_DRAW_SCALE).max(0.01);
+    let line_h = ((MENU_FONT_HEIGHT * s) + s).round().max(1.0);
+    let mut text_w = 0.0f32;
+    for line in text.lines() {
+        let mut line_w = 0.0f32;
+        for ch in line.chars() {
+            if ch == ' ' { line_w += (MENU_FONT_SPACE_W * s).round(); continue; }
+            if let Some(g) = menu_glyph(ch) { line_w += (g.advance * s).round(); }
+        }
+        text_w = text_w.max(line_w);
+    }
+    let text_h = (line_h * text.lines().count() as f32).round();
+
+    let pad = (8.0 * ui_scale).round();
+    let border_w = (2.0 * ui_scale).round().max(1.0);
+    let box_w = (text_w + pad * 2.0).round();
+    let box_h = (text_h + pad * 2.0).round();
+    let box_left = ((w - box_w) * 0.5).round().max(0.0);
+    let box_top = ((h - box_h) * 0.5).round().max(0.0);
+
+    let root = commands
+        .spawn((
+            SplashUi,
+            ZIndex(1002),
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                position_type: PositionType::Absolute,
+                left: Val::Px(0.0),
+                top: Val::Px(0.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::NONE),
+        ))
+        .id();
+
+    let canvas = commands
+        .spawn((
+            SplashUi,
+            Node {
+                width: Val::Px(w),
+                height: Val::Px(h),
+                position_type: PositionType::Relative,
+                ..default()
+            },
+            BackgroundColor(Color::NONE),
+            ChildOf(root),
+        ))
+        .id();
+
+    // Raised Frame: Light Outer Edge, Gray Body
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(box_left - border_w),
+            top: Val::Px(box_top - border_w),
+            width: Val::Px(box_w + border_w * 2.0),
+            height: Val::Px(box_h + border_w * 2.0),
+            ..default()
+        },
+        BackgroundColor(Color::srgb(0.85, 0.85, 0.85)),
+        ChildOf(canvas),
+    ));
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(box_left),
+            top: Val::Px(box_top),
+            width: Val::Px(box_w),
+            height: Val::Px(box_h),
+            ..default()
+        },
+        BackgroundColor(Color::srgb(0.55, 0.55, 0.55)),
+        ChildOf(canvas),
+    ));
+
+    spawn_menu_bitmap_text(
+        commands,
+        canvas,
+        imgs.menu_font_black.clone(),
+        box_left + pad,
+        box_top + pad,
+        ui_scale,
+        text,
+        Visibility::Visible,
+    );
+}
+
+fn spawn_menu_hint(
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+    w: f32,
+    h: f32,
+    imgs: &SplashImages,
+    from_pause: bool,
+    cont: ContinueEntry,
+) {
+    let banner = asset_server.load(MENU_BANNER_PATH);
+    let hint = asset_server.load(MENU_HINT_PATH);
+    let cursor_light = asset_server.load(MENU_CURSOR_LIGHT_PATH);
+    let cursor_dark = asset_server.load(MENU_CURSOR_DARK_PATH);
+
+    let ui_scale = (w / BASE_W).round().max(1.0);
+
+    // Banner Geometry
+    let banner_native_h = 48.0;
+    let top_red = (3.0 * ui_scale).round();
+
+    let banner_x = 0.0;
+    let banner_y = top_red;
+    let banner_w = w;
+    let banner_h = (banner_native_h * ui_scale).round();
+
+    // Hint Placement
+    let hint_native_w = 103.0;
+    let hint_native_h = 12.0;
+    let hint_bottom_pad = 6.0;
 
     let hint_w = (hint_native_w * ui_scale).round();
     let hint_h = (hint_native_h * ui_scale).round();
@@ -5037,11 +5813,7 @@ fn spawn_menu_hint(
     let hint_y = ((BASE_H - hint_native_h - hint_bottom_pad) * ui_scale).round();
 
     // Menu Panel + Items
-    let labels: &[&str] = if from_pause {
-        &MENU_LABELS_PAUSE
-    } else {
-        &MENU_LABELS_MAIN
-    };
+    let labels = menu_labels(from_pause, cont);
 
     let row_count = labels.len();
 
@@ -5056,7 +5828,7 @@ fn spawn_menu_hint(
     let cursor_y0 = (MENU_CURSOR_TOP * ui_scale).round();
 
     let text_x = (cursor_x + cursor_w + (6.0 * ui_scale).round()).round();
-    let row_h = (MENU_ITEM_H * ui_scale).round();
+    let row_h = (menu_item_h(from_pause) * ui_scale).round();
     let text_y0 = (cursor_y0 - (2.0 * ui_scale).round()).round();
 
     let pad_y = (8.0 * ui_scale).round();
@@ -5089,6 +5861,7 @@ fn spawn_menu_hint(
     let canvas = commands
         .spawn((
             SplashUi,
+            MenuCanvas,
             Node {
                 width: Val::Px(w),
                 height: Val::Px(h),
@@ -5209,10 +5982,18 @@ fn spawn_menu_hint(
         // Default Cursor Starts at Top
         let is_selected = row_idx == 0;
 
+        // A Continue Whose Save No Longer Loads Stays Dark Even Under the Cursor
+        let disabled = !from_pause && row_idx == 0 && cont == ContinueEntry::Unreadable;
+        let (font_idle, font_selected) = if disabled {
+            (&imgs.menu_font_black, &imgs.menu_font_black)
+        } else {
+            (&imgs.menu_font_gray, &imgs.menu_font_white)
+        };
+
         let gray_run = spawn_menu_bitmap_text(
             commands,
             canvas,
-            imgs.menu_font_gray.clone(),
+            font_idle.clone(),
             text_x,
             y,
             ui_scale,
@@ -5226,7 +6007,7 @@ fn spawn_menu_hint(
         let white_run = spawn_menu_bitmap_text(
             commands,
             canvas,
-            imgs.menu_font_white.clone(),
+            font_selected.clone(),
             text_x,
             y,
             ui_scale,
@@ -5289,7 +6070,7 @@ fn splash_advance_on_any_input(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     input: SplashAdvanceInput,
-    time: Res<Time>,
+    time: Res<Time<Real>>,
     mut resources: SplashResources,
     mut menu: Local<MenuLocalState>,
     mut new_game: ResMut<crate::ui::sync::NewGameRequested>,
@@ -5329,6 +6110,30 @@ fn splash_advance_on_any_input(
         || nav.confirm || nav.cancel || nav.pause
         || nav.up || nav.down || nav.left || nav.right;
 
+    // Attract Loop: Left Idle on the Main Menu the Title and High Scores Take
+    // Turns Like the Original. Any Input Goes Straight Back to the Menu
+    if let Some(imgs) = resources.imgs.as_ref() {
+        if let Some(next) = tick_attract(&mut menu, *resources.step, any_key, time.delta_secs()) {
+            for e in q.q_splash_roots.iter() { commands.entity(e).try_despawn(); }
+            match next {
+                SplashStep::Menu => {
+                    spawn_menu_hint(&mut commands, &asset_server, w, h, imgs, false, *resources.continue_entry);
+                    menu.reset();
+                    resources.music_mode.0 = MusicModeKind::Menu;
+                }
+                SplashStep::Scores => {
+                    spawn_scores_ui(&mut commands, asset_server.as_ref(), w, h, imgs, &resources.high_scores);
+                }
+                _ => {
+                    let (fit_w, fit_h) = compute_fit_size(win.width(), win.height());
+                    spawn_splash_ui(&mut commands, imgs.splash1.clone(), fit_w, fit_h, None);
+                }
+            }
+            *resources.step = next;
+            return;
+        }
+    }
+
     match *resources.step {
         SplashStep::Splash0 => {
             resources.lock.0 = true;
@@ -5336,36 +6141,38 @@ fn splash_advance_on_any_input(
 
             let Some(imgs) = resources.imgs.as_ref() else { return; };
 
+            let (fit_w, fit_h) = compute_fit_size(win.width(), win.height());
             if q.q_splash_roots.iter().next().is_none() {
                 spawn_splash_ui(
                     &mut commands,
                     imgs.splash0.clone(),
-                    w,
-                    h,
+                    fit_w,
+                    fit_h,
                     Some(imgs.menu_font_white.clone()),
                 );
             }
 
             if any_key {
                 for e in q.q_splash_roots.iter() { commands.entity(e).try_despawn(); }
-                spawn_splash_ui(&mut commands, imgs.splash1.clone(), w, h, None);
+                spawn_splash_ui(&mut commands, imgs.splash1.clone(), fit_w, fit_h, None);
                 *resources.step = SplashStep::Splash1;
             }
         }
 
         SplashStep::Splash1 => {
             resources.lock.0 = true;
-            resources.music_mode.0 = MusicModeKind::Splash;
+            resources.music_mode.0 = title_music(&menu);
 
             let Some(imgs) = resources.imgs.as_ref() else { return; };
 
             if q.q_splash_roots.iter().next().is_none() {
-                spawn_splash_ui(&mut commands, imgs.splash1.clone(), w, h, None);
+                let (fit_w, fit_h) = compute_fit_size(win.width(), win.height());
+                spawn_splash_ui(&mut commands, imgs.splash1.clone(), fit_w, fit_h, None);
             }
 
             if any_key {
                 for e in q.q_splash_roots.iter() { commands.entity(e).try_despawn(); }
-                spawn_menu_hint(&mut commands, &asset_server, w, h, imgs, false);
+                spawn_menu_hint(&mut commands, &asset_server, w, h, imgs, false, *resources.continue_entry);
                 menu.reset();
                 *resources.step = SplashStep::Menu;
                 resources.music_mode.0 = MusicModeKind::Menu;
@@ -5380,11 +6187,8 @@ fn splash_advance_on_any_input(
 
             let is_pause = *resources.step == SplashStep::PauseMenu;
 
-            let item_count = if is_pause {
-                MENU_ACTIONS_PAUSE.len()
-            } else {
-                MENU_ACTIONS_MAIN.len()
-            };
+            let actions = menu_actions(is_pause, *resources.continue_entry);
+            let item_count = actions.len();
 
             if item_count == 0 {
                 return;
@@ -5404,7 +6208,7 @@ fn splash_advance_on_any_input(
 
             // Ensure Menu UI Exists
             if q.q_splash_roots.iter().next().is_none() {
-                spawn_menu_hint(&mut commands, &asset_server, w, h, imgs, is_pause);
+                spawn_menu_hint(&mut commands, &asset_server, w, h, imgs, is_pause, *resources.continue_entry);
                 menu.reset();
                 menu.selection = menu.selection.min(item_count - 1);
             }
@@ -5448,7 +6252,7 @@ fn splash_advance_on_any_input(
             let cursor_w = (19.0 * ui_scale).round();
             let cursor_x = (panel_left + (18.0 * ui_scale).round()).round();
 
-            let row_h = (MENU_ITEM_H * ui_scale).round();
+            let row_h = (menu_item_h(is_pause) * ui_scale).round();
             let cursor_y0 = (MENU_CURSOR_TOP * ui_scale).round();
             let cursor_y = (cursor_y0 + menu.selection as f32 * row_h).round();
 
@@ -5471,15 +6275,47 @@ fn splash_advance_on_any_input(
                 || keyboard.just_pressed(KeyCode::Space)
                 || nav.confirm
             {
-                sfx.write(PlaySfx { kind: SfxKind::MenuSelect, pos: Vec3::ZERO });
+                let action = actions[menu.selection];
 
-                let action = if is_pause {
-                    MENU_ACTIONS_PAUSE[menu.selection]
-                } else {
-                    MENU_ACTIONS_MAIN[menu.selection]
-                };
+                // A Continue That Cannot Load Just Refuses
+                if action == MenuAction::Continue && *resources.continue_entry == ContinueEntry::Unreadable {
+                    sfx.write(PlaySfx { kind: SfxKind::NoWay, pos: Vec3::ZERO });
+                    return;
+                }
+
+                sfx.write(PlaySfx { kind: SfxKind::MenuSelect, pos: Vec3::ZERO });
 
                 match action {
+                    MenuAction::Continue => {
+                        let ContinueEntry::Ready(slot) = *resources.continue_entry else { return; };
+
+                        // Same Path as Picking the Slot on the Load Screen
+                        if !crate::save::begin_load(
+                            slot,
+                            &mut *resources.load_req,
+                            &mut *current_level,
+                            &mut *skill_level,
+                        ) {
+                            return;
+                        }
+
+                        for e in q.q_splash_roots.iter() { commands.entity(e).try_despawn(); }
+
+                        begin_get_psyched_loading(
+                            &mut commands,
+                            &asset_server,
+                            win,
+                            &resources.gameplay_settings,
+                            &mut *resources.psyched,
+                            &mut *resources.lock,
+                            &mut *resources.music_mode,
+                        );
+
+                        resources.lock.0 = false;
+                        resources.music_mode.0 = MusicModeKind::Gameplay;
+                        *resources.step = SplashStep::Done;
+                    }
+
                     MenuAction::BackToGame => {
                         for e in q.q_splash_roots.iter() { commands.entity(e).try_despawn(); }
                         *resources.step = SplashStep::Done;
@@ -5490,7 +6326,8 @@ fn splash_advance_on_any_input(
                     MenuAction::NewGame => {
                         for e in q.q_splash_roots.iter() { commands.entity(e).try_despawn(); }
 
-                        episode.selection = 0;
+                        // Cursor Starts on the Episode Last Played
+                        episode.selection = resources.last_session.episode.min(EPISODES.len() - 1);
                         episode.from_pause = is_pause;
 
                         if let Some(imgs) = resources.imgs.as_ref() {
@@ -5539,6 +6376,12 @@ fn splash_advance_on_any_input(
                         *resources.step = SplashStep::SaveSelect;
                     }
 
+                    MenuAction::RestartLevel => {
+                        // Leave the Pause Menu Up Behind the Prompt
+                        spawn_restart_confirm_ui(&mut commands, w, h, imgs);
+                        *resources.step = SplashStep::RestartConfirm;
+                    }
+
                     MenuAction::Sound => {
                         for e in q.q_splash_roots.iter() { commands.entity(e).try_despawn(); }
 
@@ -5658,7 +6501,7 @@ fn splash_advance_on_any_input(
                     let back_to_pause = episode.from_pause;
                     episode.from_pause = false;
 
-                    spawn_menu_hint(&mut commands, &asset_server, w, h, imgs, back_to_pause);
+                    spawn_menu_hint(&mut commands, &asset_server, w, h, imgs, back_to_pause, *resources.continue_entry);
                     menu.reset();
                     *resources.step = if back_to_pause { SplashStep::PauseMenu } else { SplashStep::Menu };
                 }
@@ -5668,11 +6511,11 @@ fn splash_advance_on_any_input(
             let mut moved = false;
 
             if keyboard.just_pressed(KeyCode::ArrowUp) || keyboard.just_pressed(KeyCode::KeyW) || nav.up {
-                if episode.selection > 0 { episode.selection -= 1; } else { episode.selection = 5; }
+                if episode.selection > 0 { episode.selection -= 1; } else { episode.selection = EPISODES.len() - 1; }
                 moved = true;
             }
             if keyboard.just_pressed(KeyCode::ArrowDown) || keyboard.just_pressed(KeyCode::KeyS) || nav.down {
-                episode.selection = (episode.selection + 1) % 6;
+                episode.selection = (episode.selection + 1) % EPISODES.len();
                 moved = true;
             }
             if moved {
@@ -5716,7 +6559,7 @@ fn splash_advance_on_any_input(
 
                 for e in q.q_splash_roots.iter() { commands.entity(e).try_despawn(); }
 
-                skill.selection = 2;
+                skill.selection = resources.last_session.skill.min(3);
                 skill.episode_num = episode_num;
 
                 if let Some(imgs) = resources.imgs.as_ref() {
@@ -5832,12 +6675,16 @@ fn splash_advance_on_any_input(
 
                 *skill_level = davelib::skill::SkillLevel::from_selection(skill.selection);
                 new_game.0 = true;
+
+                resources.last_session.episode = (episode_num - 1) as usize;
+                resources.last_session.skill = skill.selection;
                 current_level.0 = davelib::level::LevelId::first_level_of_episode(episode_num);
 
                 begin_get_psyched_loading(
                     &mut commands,
                     &asset_server,
                     win,
+                    &resources.gameplay_settings,
                     &mut *resources.psyched,
                     &mut *resources.lock,
                     &mut *resources.music_mode,
@@ -5867,6 +6714,10 @@ fn splash_advance_on_any_input(
                     options.change_view.selection,
                     &resources.video_settings, &resources.res_list,
                 );
+                if let Some(pending) = resources.video_confirm.0 {
+                    spawn_video_confirm_ui(&mut commands, w, h, imgs, pending.seconds_shown());
+                    *resources.step = SplashStep::VideoConfirm;
+                }
                 return;
             }
 
@@ -5882,6 +6733,11 @@ fn splash_advance_on_any_input(
                     options.change_view.selection,
                     &resources.video_settings, &resources.res_list,
                 );
+                // A Display Change Now Showing at its New Size Asks to be Kept
+                if let Some(pending) = resources.video_confirm.0 {
+                    spawn_video_confirm_ui(&mut commands, w, h, imgs, pending.seconds_shown());
+                    *resources.step = SplashStep::VideoConfirm;
+                }
                 return;
             }
 
@@ -5936,6 +6792,9 @@ fn splash_advance_on_any_input(
                     sfx.write(PlaySfx { kind: SfxKind::MenuSelect, pos: Vec3::ZERO });
 
                     if let Some(&(rw, rh)) = resources.res_list.entries.get(options.change_view.res_submenu_idx) {
+                        if resources.video_settings.resolution != (rw, rh) {
+                            resources.video_confirm.0 = Some(VideoConfirm::new(*resources.video_settings));
+                        }
                         resources.video_settings.resolution = (rw, rh);
                         resources.video_settings.set_changed();
                     }
@@ -6106,6 +6965,7 @@ fn splash_advance_on_any_input(
             if left_pressed || right_pressed {
                 match current_kind {
                     Some(ChangeViewKind::DisplayMode) => {
+                        resources.video_confirm.0 = Some(VideoConfirm::new(*resources.video_settings));
                         resources.video_settings.display_mode = if right_pressed {
                             resources.video_settings.display_mode.next()
                         } else {
@@ -6130,6 +6990,70 @@ fn splash_advance_on_any_input(
                         sfx.write(PlaySfx { kind: SfxKind::MenuMove, pos: Vec3::ZERO });
                         return;
                     }
+                    Some(ChangeViewKind::FovMode) => {
+                        resources.video_settings.fov_mode = resources.video_settings.fov_mode.toggled();
+                        resources.video_settings.set_changed(); // Explicitly Mark as Changed
+                        // Respawn so the Row Label Reflects the New Mode
+                        options.change_view.needs_respawn = true;
+                        sfx.write(PlaySfx { kind: SfxKind::MenuMove, pos: Vec3::ZERO });
+                        return;
+                    }
+                    Some(ChangeViewKind::IntegerScale) => {
+                        resources.video_settings.integer_scale = !resources.video_settings.integer_scale;
+                        resources.video_settings.set_changed(); // Explicitly Mark as Changed
+                        // Respawn so the Row Label Reflects the New Setting
+                        options.change_view.needs_respawn = true;
+                        sfx.write(PlaySfx { kind: SfxKind::MenuMove, pos: Vec3::ZERO });
+                        return;
+                    }
+                    Some(ChangeViewKind::Floor) => {
+                        resources.video_settings.textured_floor = !resources.video_settings.textured_floor;
+                        resources.video_settings.set_changed(); // Explicitly Mark as Changed
+                        // Respawn so the Row Label Reflects the New Setting
+                        options.change_view.needs_respawn = true;
+                        sfx.write(PlaySfx { kind: SfxKind::MenuMove, pos: Vec3::ZERO });
+                        return;
+                    }
+                    Some(ChangeViewKind::Gore) => {
+                        resources.video_settings.gore = !resources.video_settings.gore;
+                        resources.video_settings.set_changed(); // Explicitly Mark as Changed
+                        // Respawn so the Row Label Reflects the New Setting
+                        options.change_view.needs_respawn = true;
+                        sfx.write(PlaySfx { kind: SfxKind::MenuMove, pos: Vec3::ZERO });
+                        return;
+                    }
+                    Some(ChangeViewKind::ScreenShake) => {
+                        resources.video_settings.screen_shake = !resources.video_settings.screen_shake;
+                        resources.video_settings.set_changed(); // Explicitly Mark as Changed
+                        // Respawn so the Row Label Reflects the New Setting
+                        options.change_view.needs_respawn = true;
+                        sfx.write(PlaySfx { kind: SfxKind::MenuMove, pos: Vec3::ZERO });
+                        return;
+                    }
+                    Some(ChangeViewKind::FpsCap) => {
+                        resources.video_settings.fps_cap = if right_pressed {
+                            resources.video_settings.fps_cap.next()
+                        } else {
+                            resources.video_settings.fps_cap.prev()
+                        };
+                        resources.video_settings.set_changed(); // Explicitly Mark as Changed
+                        // Respawn so the Row Label Reflects the New Cap
+                        options.change_view.needs_respawn = true;
+                        sfx.write(PlaySfx { kind: SfxKind::MenuMove, pos: Vec3::ZERO });
+                        return;
+                    }
+                    Some(ChangeViewKind::Crosshair) => {
+                        resources.video_settings.crosshair = if right_pressed {
+                            resources.video_settings.crosshair.next()
+                        } else {
+                            resources.video_settings.crosshair.prev()
+                        };
+                        resources.video_settings.set_changed(); // Explicitly Mark as Changed
+                        // Respawn so the Row Label Reflects the New Style
+                        options.change_view.needs_respawn = true;
+                        sfx.write(PlaySfx { kind: SfxKind::MenuMove, pos: Vec3::ZERO });
+                        return;
+                    }
                     _ => {}
                 }
             }
@@ -6214,7 +7138,7 @@ fn splash_advance_on_any_input(
 
                 let cursor_w = (19.0 * ui_scale).round();
                 let cursor_h = (10.0 * ui_scale).round();
-                let row_h = (16.0 * ui_scale).round().max(1.0);
+                let row_h = options_row_h(ui_scale, panel_h, item_count);
 
                 let list_h = (item_count as f32 * row_h).round();
                 let list_top = (panel_top + ((panel_h - list_h) * 0.5)).round();
@@ -6265,6 +7189,90 @@ fn splash_advance_on_any_input(
                         );
                     }
 
+                    Some(ChangeViewKind::FovMode) => {
+                        resources.video_settings.fov_mode = resources.video_settings.fov_mode.toggled();
+                        // Explicitly Mark as Changed
+                        resources.video_settings.set_changed();
+
+                        for e in q.q_splash_roots.iter() { commands.entity(e).try_despawn(); }
+                        spawn_change_view_ui(
+                            &mut commands, &asset_server,
+                            w, h, scale, imgs,
+                            options.change_view.selection,
+                            &resources.video_settings, &resources.res_list,
+                        );
+                    }
+
+                    Some(ChangeViewKind::IntegerScale) => {
+                        resources.video_settings.integer_scale = !resources.video_settings.integer_scale;
+                        // Explicitly Mark as Changed
+                        resources.video_settings.set_changed();
+
+                        for e in q.q_splash_roots.iter() { commands.entity(e).try_despawn(); }
+                        spawn_change_view_ui(
+                            &mut commands, &asset_server,
+                            w, h, scale, imgs,
+                            options.change_view.selection,
+                            &resources.video_settings, &resources.res_list,
+                        );
+                    }
+
+                    Some(ChangeViewKind::Floor) => {
+                        resources.video_settings.textured_floor = !resources.video_settings.textured_floor;
+                        // Explicitly Mark as Changed
+                        resources.video_settings.set_changed();
+
+                        for e in q.q_splash_roots.iter() { commands.entity(e).try_despawn(); }
+                        spawn_change_view_ui(
+                            &mut commands, &asset_server,
+                            w, h, scale, imgs,
+                            options.change_view.selection,
+                            &resources.video_settings, &resources.res_list,
+                        );
+                    }
+
+                    Some(ChangeViewKind::Gore) => {
+                        resources.video_settings.gore = !resources.video_settings.gore;
+                        // Explicitly Mark as Changed
+                        resources.video_settings.set_changed();
+
+                        for e in q.q_splash_roots.iter() { commands.entity(e).try_despawn(); }
+                        spawn_change_view_ui(
+                            &mut commands, &asset_server,
+                            w, h, scale, imgs,
+                            options.change_view.selection,
+                            &resources.video_settings, &resources.res_list,
+                        );
+                    }
+
+                    Some(ChangeViewKind::ScreenShake) => {
+                        resources.video_settings.screen_shake = !resources.video_settings.screen_shake;
+                        // Explicitly Mark as Changed
+                        resources.video_settings.set_changed();
+
+                        for e in q.q_splash_roots.iter() { commands.entity(e).try_despawn(); }
+                        spawn_change_view_ui(
+                            &mut commands, &asset_server,
+                            w, h, scale, imgs,
+                            options.change_view.selection,
+                            &resources.video_settings, &resources.res_list,
+                        );
+                    }
+
+                    Some(ChangeViewKind::Crosshair) => {
+                        resources.video_settings.crosshair = resources.video_settings.crosshair.next();
+                        // Explicitly Mark as Changed
+                        resources.video_settings.set_changed();
+
+                        for e in q.q_splash_roots.iter() { commands.entity(e).try_despawn(); }
+                        spawn_change_view_ui(
+                            &mut commands, &asset_server,
+                            w, h, scale, imgs,
+                            options.change_view.selection,
+                            &resources.video_settings, &resources.res_list,
+                        );
+                    }
+
                     Some(ChangeViewKind::Resolution) => {
                         // Open Resolution Sub Menu
                         options.change_view.res_submenu_open = true;
@@ -6296,7 +7304,7 @@ fn splash_advance_on_any_input(
                         *resources.step = SplashStep::GameplayOptions;
                     }
 
-                    // DisplayMode, RenderScale, FOV, ViewSize are Adjusted by Left / Right, Enter Does Nothing Extra
+                    // DisplayMode, FpsCap, RenderScale, FOV, ViewSize are Adjusted by Left / Right, Enter Does Nothing Extra
                     _ => {}
                 }
             }
@@ -6336,7 +7344,7 @@ fn splash_advance_on_any_input(
 
                 let back_to_pause = options.sound.from_pause;
                 options.sound.from_pause = false;
-                spawn_menu_hint(&mut commands, &asset_server, w, h, imgs, back_to_pause);
+                spawn_menu_hint(&mut commands, &asset_server, w, h, imgs, back_to_pause, *resources.continue_entry);
                 menu.reset();
                 *resources.step = if back_to_pause { SplashStep::PauseMenu } else { SplashStep::Menu };
                 return;
@@ -6362,7 +7370,10 @@ fn splash_advance_on_any_input(
 
             let is_nudgeable = matches!(
                 current_kind,
-                Some(SoundOptionKind::MasterVolume) | Some(SoundOptionKind::MusicVolume) | Some(SoundOptionKind::SfxVolume)
+                Some(SoundOptionKind::MasterVolume)
+                    | Some(SoundOptionKind::MusicVolume)
+                    | Some(SoundOptionKind::SfxVolume)
+                    | Some(SoundOptionKind::UiVolume)
             );
 
             if is_nudgeable && (left_held || right_held || left_just || right_just) {
@@ -6427,8 +7438,15 @@ fn splash_advance_on_any_input(
                         // Explicitly Mark as Changed
                         resources.sound_settings.set_changed();
                     }
-                    _ => {}
-                }
+                    Some(SoundOptionKind::UiVolume) => {
+                        for _ in 0..nudge_ticks {
+                            resources.sound_settings.ui_volume = (resources.sound_settings.ui_volume + delta).clamp(0.0, 1.0);
+                        }
+                        // Explicitly Mark as Changed
+                        resources.sound_settings.set_changed();
+                    }
+                    _ => {}
+                }
 
                 // Respawn UI with Updated Labels
                 for e in q.q_splash_roots.iter() { commands.entity(e).try_despawn(); }
@@ -6547,7 +7565,7 @@ fn splash_advance_on_any_input(
 
                         let back_to_pause = options.sound.from_pause;
                         options.sound.from_pause = false;
-                        spawn_menu_hint(&mut commands, &asset_server, w, h, imgs, back_to_pause);
+                        spawn_menu_hint(&mut commands, &asset_server, w, h, imgs, back_to_pause, *resources.continue_entry);
                         menu.reset();
                         *resources.step = if back_to_pause { SplashStep::PauseMenu } else { SplashStep::Menu };
                     }
@@ -6591,7 +7609,7 @@ fn splash_advance_on_any_input(
 
                 let back_to_pause = options.control.from_pause;
                 options.control.from_pause = false;
-                spawn_menu_hint(&mut commands, &asset_server, w, h, imgs, back_to_pause);
+                spawn_menu_hint(&mut commands, &asset_server, w, h, imgs, back_to_pause, *resources.continue_entry);
                 menu.reset();
                 *resources.step = if back_to_pause { SplashStep::PauseMenu } else { SplashStep::Menu };
                 return;
@@ -6660,18 +7678,16 @@ fn splash_advance_on_any_input(
             if nudge_ticks > 0 {
                 match current_kind {
                     Some(ControlOptionKind::MouseSensitivity) => {
-                        let delta = if options.control.hold_dir > 0 { 0.1 } else { -0.1 };
-                        for _ in 0..nudge_ticks {
-                            resources.control_settings.mouse_sensitivity = (resources.control_settings.mouse_sensitivity + delta).clamp(0.1, 10.0);
-                        }
+                        let steps = if options.control.hold_dir > 0 { nudge_ticks as i32 } else { -(nudge_ticks as i32) };
+                        resources.control_settings.mouse_sensitivity =
+                            ControlSettings::nudge_sensitivity(resources.control_settings.mouse_sensitivity, steps);
                         // Explicitly Mark as Changed
                         resources.control_settings.set_changed();
                     }
                     Some(ControlOptionKind::GamepadSensitivity) => {
-                        let delta = if options.control.hold_dir > 0 { 0.1 } else { -0.1 };
-                        for _ in 0..nudge_ticks {
-                            resources.control_settings.gamepad_sensitivity = (resources.control_settings.gamepad_sensitivity + delta).clamp(0.1, 10.0);
-                        }
+                        let steps = if options.control.hold_dir > 0 { nudge_ticks as i32 } else { -(nudge_ticks as i32) };
+                        resources.control_settings.gamepad_sensitivity =
+                            ControlSettings::nudge_sensitivity(resources.control_settings.gamepad_sensitivity, steps);
                         // Explicitly Mark as Changed
                         resources.control_settings.set_changed();
                     }
@@ -6783,6 +7799,19 @@ fn splash_advance_on_any_input(
                         );
                     }
 
+                    Some(ControlOptionKind::AlwaysRun) => {
+                        resources.control_settings.always_run = !resources.control_settings.always_run;
+                        resources.control_settings.set_changed(); // Explicitly Mark as Changed
+
+                        for e in q.q_splash_roots.iter() { commands.entity(e).try_despawn(); }
+                        spawn_control_options_ui(
+                            &mut commands, &asset_server,
+                            w, h, scale, imgs,
+                            options.control.selection,
+                            &resources.control_settings,
+                        );
+                    }
+
                     Some(ControlOptionKind::Mouselook) => {
                         resources.control_settings.mouselook_enabled = !resources.control_settings.mouselook_enabled;
                         resources.control_settings.set_changed(); // Explicitly Mark as Changed
@@ -6832,7 +7861,7 @@ fn splash_advance_on_any_input(
 
                         let back_to_pause = options.control.from_pause;
                         options.control.from_pause = false;
-                        spawn_menu_hint(&mut commands, &asset_server, w, h, imgs, back_to_pause);
+                        spawn_menu_hint(&mut commands, &asset_server, w, h, imgs, back_to_pause, *resources.continue_entry);
                         menu.reset();
                         *resources.step = if back_to_pause { SplashStep::PauseMenu } else { SplashStep::Menu };
                     }
@@ -6876,12 +7905,26 @@ fn splash_advance_on_any_input(
 
                 let back_to_pause = options.gameplay.from_pause;
                 options.gameplay.from_pause = false;
-                spawn_menu_hint(&mut commands, &asset_server, w, h, imgs, back_to_pause);
+                spawn_menu_hint(&mut commands, &asset_server, w, h, imgs, back_to_pause, *resources.continue_entry);
                 menu.reset();
                 *resources.step = if back_to_pause { SplashStep::PauseMenu } else { SplashStep::Menu };
                 return;
             }
 
+            // Panel Geometry, Shared by Paging and Cursor Placement Below
+            let ui_scale = (w / BASE_W).round().max(1.0);
+            let hint_native_h = 12.0;
+            let hint_bottom_pad = 6.0;
+            let hint_y = ((BASE_H - hint_native_h - hint_bottom_pad) * ui_scale).round();
+            let panel_left = (18.0 * ui_scale).round();
+            let panel_top = ((EP_LIST_TOP - 4.0) * ui_scale).round();
+            let panel_right = ((BASE_W - 18.0) * ui_scale).round();
+            let panel_w = (panel_right - panel_left).max(1.0);
+            let panel_bottom = (hint_y - (2.0 * ui_scale).round()).max(panel_top + 1.0);
+            let panel_h = (panel_bottom - panel_top).max(1.0);
+            let page_rows = options_rows_per_page(ui_scale, panel_h).min(item_count);
+            let page_before = options_page(options.gameplay.selection, item_count, page_rows);
+
             let mut moved = false;
 
             if keyboard.just_pressed(KeyCode::ArrowUp) || keyboard.just_pressed(KeyCode::KeyW) || nav.up {
@@ -6898,6 +7941,19 @@ fn splash_advance_on_any_input(
                 sfx.write(PlaySfx { kind: SfxKind::MenuMove, pos: Vec3::ZERO });
             }
 
+            // Stepping Onto Another Page Redraws the List
+            let page = options_page(options.gameplay.selection, item_count, page_rows);
+            if page != page_before {
+                for e in q.q_splash_roots.iter() { commands.entity(e).try_despawn(); }
+                spawn_gameplay_options_ui(
+                    &mut commands, &asset_server,
+                    w, h, scale, imgs,
+                    options.gameplay.selection,
+                    &resources.gameplay_settings,
+                );
+                return;
+            }
+
             // Update Item Visibility. Reuses the Shared ControlOptions Item
             // Markers so Only One Options Screen Exists at a Time
             for (item, variant, mut vis) in q.q_control_options_items.iter_mut() {
@@ -6919,20 +7975,10 @@ fn splash_advance_on_any_input(
             }
 
             // Cursor Positioning
-            let ui_scale = (w / BASE_W).round().max(1.0);
-            let hint_native_h = 12.0;
-            let hint_bottom_pad = 6.0;
-            let hint_y = ((BASE_H - hint_native_h - hint_bottom_pad) * ui_scale).round();
-            let panel_left = (18.0 * ui_scale).round();
-            let panel_top = ((EP_LIST_TOP - 4.0) * ui_scale).round();
-            let panel_right = ((BASE_W - 18.0) * ui_scale).round();
-            let panel_w = (panel_right - panel_left).max(1.0);
-            let panel_bottom = (hint_y - (2.0 * ui_scale).round()).max(panel_top + 1.0);
-            let panel_h = (panel_bottom - panel_top).max(1.0);
             let cursor_w = (19.0 * ui_scale).round();
             let cursor_h = (10.0 * ui_scale).round();
-            let row_h = (16.0 * ui_scale).round().max(1.0);
-            let list_h = (item_count as f32 * row_h).round();
+            let row_h = options_row_h(ui_scale, panel_h, page_rows);
+            let list_h = (page_rows as f32 * row_h).round();
             let list_top = (panel_top + ((panel_h - list_h) * 0.5)).round();
 
             let measure_menu_text_width = |ui_scale: f32, text: &str| -> f32 {
@@ -6952,7 +7998,7 @@ fn splash_advance_on_any_input(
 
             let text_x = (panel_left + ((panel_w - max_item_w) * 0.5)).round().max(0.0);
             let cursor_x = (text_x - cursor_w - (8.0 * ui_scale).round()).round().max(0.0);
-            let cursor_y = (list_top + options.gameplay.selection as f32 * row_h + ((row_h - cursor_h) * 0.5)).round();
+            let cursor_y = (list_top + (options.gameplay.selection - page.start) as f32 * row_h + ((row_h - cursor_h) * 0.5)).round();
 
             for mut node in q.q_node.iter_mut() {
                 node.left = Val::Px(cursor_x);
@@ -6991,8 +8037,18 @@ fn splash_advance_on_any_input(
                         *resources.step = SplashStep::ChangeView;
                     }
 
-                    Some(GameplayOptionKind::ReversiblePushwalls) => {
-                        resources.gameplay_settings.reversible_pushwalls = !resources.gameplay_settings.reversible_pushwalls;
+                    Some(GameplayOptionKind::Back) => {
+                        for e in q.q_splash_roots.iter() { commands.entity(e).try_despawn(); }
+
+                        let back_to_pause = options.gameplay.from_pause;
+                        options.gameplay.from_pause = false;
+                        spawn_menu_hint(&mut commands, &asset_server, w, h, imgs, back_to_pause, *resources.continue_entry);
+                        menu.reset();
+                        *resources.step = if back_to_pause { SplashStep::PauseMenu } else { SplashStep::Menu };
+                    }
+
+                    Some(kind) => {
+                        toggle_gameplay_option(kind, &mut resources.gameplay_settings);
                         resources.gameplay_settings.set_changed(); // Explicitly Mark as Changed
 
                         for e in q.q_splash_roots.iter() { commands.entity(e).try_despawn(); }
@@ -7004,21 +8060,74 @@ fn splash_advance_on_any_input(
                         );
                     }
 
-                    Some(GameplayOptionKind::Back) => {
-                        for e in q.q_splash_roots.iter() { commands.entity(e).try_despawn(); }
-
-                        let back_to_pause = options.gameplay.from_pause;
-                        options.gameplay.from_pause = false;
-                        spawn_menu_hint(&mut commands, &asset_server, w, h, imgs, back_to_pause);
-                        menu.reset();
-                        *resources.step = if back_to_pause { SplashStep::PauseMenu } else { SplashStep::Menu };
-                    }
-
                     None => {}
                 }
             }
         }
 
+        SplashStep::RestartConfirm => {
+            resources.lock.0 = true;
+            resources.music_mode.0 = MusicModeKind::Menu;
+
+            let Some(imgs) = resources.imgs.as_ref() else { return; };
+
+            let restart_idx = MENU_ACTIONS_PAUSE
+                .iter()
+                .position(|a| *a == MenuAction::RestartLevel)
+                .unwrap_or(0);
+
+            // Auto Respawn UI After Window Resize
+            if q.q_splash_roots.iter().next().is_none() {
+                spawn_menu_hint(&mut commands, &asset_server, w, h, imgs, true, *resources.continue_entry);
+                spawn_restart_confirm_ui(&mut commands, w, h, imgs);
+                return;
+            }
+
+            let yes = keyboard.just_pressed(KeyCode::KeyY)
+                || keyboard.just_pressed(KeyCode::Enter)
+                || keyboard.just_pressed(KeyCode::NumpadEnter)
+                || nav.confirm;
+            let no = keyboard.just_pressed(KeyCode::KeyN)
+                || keyboard.just_pressed(KeyCode::Escape)
+                || nav.cancel;
+
+            if no {
+                sfx.write(PlaySfx { kind: SfxKind::MenuBack, pos: Vec3::ZERO });
+
+                for e in q.q_splash_roots.iter() { commands.entity(e).try_despawn(); }
+                spawn_menu_hint(&mut commands, &asset_server, w, h, imgs, true, *resources.continue_entry);
+                menu.reset();
+                menu.selection = restart_idx;
+                *resources.step = SplashStep::PauseMenu;
+                return;
+            }
+
+            if yes {
+                sfx.write(PlaySfx { kind: SfxKind::MenuSelect, pos: Vec3::ZERO });
+
+                for e in q.q_splash_roots.iter() { commands.entity(e).try_despawn(); }
+
+                // Same LevelId, Rebuilt Through the Shared Level Reload Path
+                resources.restart_level.0 = true;
+
+                begin_get_psyched_loading(
+                    &mut commands,
+                    &asset_server,
+                    win,
+                    &resources.gameplay_settings,
+                    &mut *resources.psyched,
+                    &mut *resources.lock,
+                    &mut *resources.music_mode,
+                );
+
+                resources.lock.0 = false;
+                resources.music_mode.0 = MusicModeKind::Gameplay;
+
+                menu.reset();
+                *resources.step = SplashStep::Done;
+            }
+        }
+
         SplashStep::KeyBindings => {
             resources.lock.0 = true;
             resources.music_mode.0 = MusicModeKind::Menu;
@@ -7044,7 +8153,7 @@ fn splash_advance_on_any_input(
                 options.key_bindings.selection = item_count.saturating_sub(1);
             }
 
-            // Capture Mode: Waiting for the Next Key to Rebind the Selected Action
+            // Capture Mode: Waiting for the Next Key or Mouse Button to Rebind the Selected Action
             if let Some(cap_idx) = options.key_bindings.capturing {
                 // Escape Cancels the Capture and Keeps the Old Binding
                 if keyboard.just_pressed(KeyCode::Escape) {
@@ -7062,16 +8171,22 @@ fn splash_advance_on_any_input(
                     return;
                 }
 
-                // Take the First Key Pressed This Frame, if Any
-                if let Some(&new_key) = keyboard.get_just_pressed().next() {
-                    // Reject a Key Already Bound to Another Action, With a No Sound
-                    if resources.control_settings.key_bindings.conflict(new_key, cap_idx).is_some() {
+                // Take the First Key or Mouse Button Pressed This Frame, if Any
+                let pressed = keyboard
+                    .get_just_pressed()
+                    .next()
+                    .map(|&k| InputBinding::Key(k))
+                    .or_else(|| mouse.get_just_pressed().next().map(|&b| InputBinding::Mouse(b)));
+                if let Some(new_binding) = pressed {
+                    // Reject an Input Already Bound to Another Action (Fire and Use
+                    // on One Button Included), With a No Sound
+                    if resources.control_settings.key_bindings.conflict(new_binding, cap_idx).is_some() {
                         sfx.write(PlaySfx { kind: SfxKind::NoWay, pos: Vec3::ZERO });
                         // Stay in Capture so the Player Can Try a Different Key
                         return;
                     }
 
-                    resources.control_settings.key_bindings.set_at(cap_idx, new_key);
+                    resources.control_settings.key_bindings.set_at(cap_idx, new_binding);
                     resources.control_settings.set_changed();
                     options.key_bindings.capturing = None;
                     sfx.write(PlaySfx { kind: SfxKind::MenuSelect, pos: Vec3::ZERO });
@@ -7329,7 +8444,7 @@ fn splash_advance_on_any_input(
                 let score = resources.name_entry.score;
                 let episode_num = resources.name_entry.episode;
 
-                resources.high_scores.add(name, score, episode_num);
+                resources.high_scores.add(name, score, episode_num, resources.cheats.used);
 
                 resources.name_entry.active = false;
                 resources.name_entry.name.clear();
@@ -7361,6 +8476,16 @@ fn splash_advance_on_any_input(
                 return;
             }
 
+            // Delete Asks to Wipe the Table Instead of Leaving the Screen
+            if keyboard.just_pressed(SCORES_RESET_KEY) {
+                let Some(imgs) = resources.imgs.as_ref() else { return; };
+
+                sfx.write(PlaySfx { kind: SfxKind::MenuSelect, pos: Vec3::ZERO });
+                spawn_reset_scores_confirm_ui(&mut commands, w, h, imgs);
+                *resources.step = SplashStep::ResetScoresConfirm;
+                return;
+            }
+
             if any_key {
                 let Some(imgs) = resources.imgs.as_ref() else { return; };
 
@@ -7371,7 +8496,7 @@ fn splash_advance_on_any_input(
                     commands.entity(e).despawn();
                 }
 
-                spawn_menu_hint(&mut commands, &asset_server, w, h, imgs, back_to_pause);
+                spawn_menu_hint(&mut commands, &asset_server, w, h, imgs, back_to_pause, *resources.continue_entry);
                 menu.reset();
 
                 *resources.step = if back_to_pause { SplashStep::PauseMenu } else { SplashStep::Menu };
@@ -7380,6 +8505,66 @@ fn splash_advance_on_any_input(
             }
         }
 
+        SplashStep::VideoConfirm => {
+            resources.lock.0 = true;
+            resources.music_mode.0 = MusicModeKind::Menu;
+
+            let Some(imgs) = resources.imgs.as_ref() else { return; };
+            let Some(pending) = resources.video_confirm.0 else {
+                *resources.step = SplashStep::ChangeView;
+                return;
+            };
+
+            // Auto Respawn UI After Window Resize, and Each Time the Countdown
+            // Ticks (answer_video_confirm Clears the Prompt for That)
+            if q.q_splash_roots.iter().next().is_none() {
+                spawn_change_view_ui(
+                    &mut commands, &asset_server,
+                    w, h, scale, imgs,
+                    options.change_view.selection,
+                    &resources.video_settings, &resources.res_list,
+                );
+                spawn_video_confirm_ui(&mut commands, w, h, imgs, pending.seconds_shown());
+            }
+        }
+
+        SplashStep::ResetScoresConfirm => {
+            let Some(imgs) = resources.imgs.as_ref() else { return; };
+
+            // Auto Respawn UI After Window Resize
+            if q.q_splash_roots.iter().next().is_none() {
+                spawn_scores_ui(&mut commands, asset_server.as_ref(), w, h, imgs, &resources.high_scores);
+                spawn_reset_scores_confirm_ui(&mut commands, w, h, imgs);
+                return;
+            }
+
+            let yes = keyboard.just_pressed(KeyCode::KeyY)
+                || keyboard.just_pressed(KeyCode::Enter)
+                || keyboard.just_pressed(KeyCode::NumpadEnter)
+                || nav.confirm;
+            let no = keyboard.just_pressed(KeyCode::KeyN)
+                || keyboard.just_pressed(KeyCode::Escape)
+                || nav.cancel;
+
+            if !yes && !no {
+                return;
+            }
+
+            if yes {
+                sfx.write(PlaySfx { kind: SfxKind::MenuSelect, pos: Vec3::ZERO });
+                resources.high_scores.clear();
+                resources.high_scores.save();
+            } else {
+                sfx.write(PlaySfx { kind: SfxKind::MenuBack, pos: Vec3::ZERO });
+            }
+
+            // Either Way Drop the Prompt and Redraw the (Possibly Empty) Table
+            for e in q.q_splash_roots.iter() { commands.entity(e).try_despawn(); }
+            let Some(imgs) = resources.imgs.as_ref() else { return; };
+            spawn_scores_ui(&mut commands, asset_server.as_ref(), w, h, imgs, &resources.high_scores);
+            *resources.step = SplashStep::Scores;
+        }
+
         SplashStep::LoadSelect => {
             resources.lock.0 = true;
             resources.music_mode.0 = MusicModeKind::Menu;
@@ -7405,7 +8590,7 @@ fn splash_advance_on_any_input(
                     let back_to_pause = episode.from_pause;
                     episode.from_pause = false;
 
-                    spawn_menu_hint(&mut commands, &asset_server, w, h, imgs, back_to_pause);
+                    spawn_menu_hint(&mut commands, &asset_server, w, h, imgs, back_to_pause, *resources.continue_entry);
                     menu.reset();
                     *resources.step = if back_to_pause { SplashStep::PauseMenu } else { SplashStep::Menu };
                 }
@@ -7468,6 +8653,7 @@ fn splash_advance_on_any_input(
                     &mut *skill_level,
                 );
                 if !ok { return; }
+                resources.last_session.slot = Some(slot);
 
                 sfx.write(PlaySfx { kind: SfxKind::MenuSelect, pos: Vec3::ZERO });
 
@@ -7477,6 +8663,7 @@ fn splash_advance_on_any_input(
                     &mut commands,
                     &asset_server,
                     win,
+                    &resources.gameplay_settings,
                     &mut *resources.psyched,
                     &mut *resources.lock,
                     &mut *resources.music_mode,
@@ -7513,7 +8700,7 @@ fn splash_advance_on_any_input(
 
                 if let Some(imgs) = resources.imgs.as_ref() {
                     episode.from_pause = false;
-                    spawn_menu_hint(&mut commands, &asset_server, w, h, imgs, true);
+                    spawn_menu_hint(&mut commands, &asset_server, w, h, imgs, true, *resources.continue_entry);
                     menu.reset();
                     *resources.step = SplashStep::PauseMenu;
                 }
@@ -7667,7 +8854,7 @@ fn splash_advance_on_any_input(
 
                 let score = resources.hud.score;
 
-                if resources.high_scores.qualifies(score) {
+                if resources.high_scores.qualifies_run(score, resources.cheats.used) {
                     resources.name_entry.active = true;
                     resources.name_entry.rank = high_score_rank_for(&resources.high_scores, score);
                     resources.name_entry.score = score;
@@ -7698,7 +8885,7 @@ fn splash_advance_on_any_input(
 
                 for e in q.q_splash_roots.iter() { commands.entity(e).try_despawn(); }
 
-                spawn_menu_hint(&mut commands, &asset_server, w, h, imgs, true);
+                spawn_menu_hint(&mut commands, &asset_server, w, h, imgs, true, *resources.continue_entry);
                 menu.reset();
                 *resources.step = SplashStep::PauseMenu;
             }
@@ -7719,7 +8906,8 @@ fn splash_resize_on_window_change(
         return;
     };
 
-    let (w, h) = compute_scaled_size(last.width, last.height);
+    // Only the Title Pictures Carry SplashImage, so They Take the Fractional Fit
+    let (w, h) = compute_fit_size(last.width, last.height);
     for mut n in q_node.iter_mut() {
         n.width = Val::Px(w);
         n.height = Val::Px(h);
@@ -7837,10 +9025,13 @@ fn spawn_get_psyched_ui(commands: &mut Commands, asset_server: &AssetServer, win
         });
 }
 
+/// Starts the Level Load Hold. The Lock and Music Switch Happen for Every
+/// PsychedScreen Mode, Off Only Skips Drawing the Screen
 fn begin_get_psyched_loading(
     commands: &mut Commands,
     asset_server: &AssetServer,
     win: &Window,
+    gameplay: &GameplaySettings,
     psyched: &mut PsychedLoad,
     lock: &mut PlayerControlLock,
     music_mode: &mut MusicMode,
@@ -7848,16 +9039,24 @@ fn begin_get_psyched_loading(
     lock.0 = true;
     music_mode.0 = MusicModeKind::Gameplay;
 
+    let mode = gameplay.psyched_screen;
     psyched.active = true;
+    psyched.timer.set_duration(std::time::Duration::from_secs_f32(mode.duration_secs()));
     psyched.timer.reset();
-    spawn_get_psyched_ui(commands, asset_server, win.width(), win.height());
+
+    if mode.shows_screen() {
+        spawn_get_psyched_ui(commands, asset_server, win.width(), win.height());
+    }
 }
 
 fn tick_get_psyched_loading(
     mut commands: Commands,
-    time: Res<Time>,
+    time: Res<Time<Real>>,
     mut lock: ResMut<PlayerControlLock>,
     mut psyched: ResMut<PsychedLoad>,
+    grid: Option<Res<davelib::map::MapGrid>>,
+    solid: Option<Res<davelib::decorations::SolidStatics>>,
+    markers: Option<Res<davelib::pushwalls::PushwallMarkers>>,
     q_loading_roots: Query<Entity, (With<LoadingUi>, Without<bevy::prelude::ChildOf>)>,
     mut q_bar: Query<(&mut Node, &PsychedBar)>,
 ) {
@@ -7871,13 +9070,16 @@ fn tick_get_psyched_loading(
 
     psyched.timer.tick(time.delta());
 
-    let t = (psyched.timer.elapsed_secs() / psyched.timer.duration().as_secs_f32()).clamp(0.0, 1.0);
+    let t = (psyched.timer.elapsed_secs() / psyched.timer.duration().as_secs_f32().max(1e-6)).clamp(0.0, 1.0);
 
     if let Some((mut node, bar)) = q_bar.iter_mut().next() {
         node.width = Val::Px((bar.target_w * t).floor());
     }
 
-    if psyched.timer.is_finished() && psyched.timer.just_finished() {
+    // A Short or Skipped Screen Still Holds the Lock Until the Level Exists
+    let ready = grid.is_some() && solid.is_some() && markers.is_some();
+
+    if psyched.timer.is_finished() && ready {
         for e in q_loading_roots.iter() {
             commands.entity(e).despawn();
         }
@@ -7892,6 +9094,7 @@ fn auto_get_psyched_on_level_start(
     asset_server: Res<AssetServer>,
     q_win: Single<&Window, With<PrimaryWindow>>,
     step: Res<SplashStep>,
+    gameplay: Res<GameplaySettings>,
     level: Res<davelib::level::CurrentLevel>,
     grid: Option<Res<davelib::map::MapGrid>>,
     solid: Option<Res<davelib::decorations::SolidStatics>>,
@@ -7923,9 +9126,407 @@ fn auto_get_psyched_on_level_start(
             &mut commands,
             &asset_server,
             win,
+            &gameplay,
             &mut *psyched,
             &mut *lock,
             &mut *music_mode,
         );
     }
 }
+
+fn spawn_screen_fade_overlay(mut commands: Commands) {
+    // Above Every Menu, Loading and Intermission Root
+    commands.spawn((
+        ScreenFadeOverlay,
+        ZIndex(5000),
+        Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            position_type: PositionType::Absolute,
+            left: Val::Px(0.0),
+            top: Val::Px(0.0),
+            ..default()
+        },
+        BackgroundColor(Color::NONE),
+    ));
+}
+
+/// Whether Moving Between Two Steps Replaces the Whole Screen. The Y/N Confirm
+/// Boxes Sit Over the Screen Beneath Them, and the Pause Menu Opens and Closes
+/// Over the Game View, so Neither Counts
+fn is_screen_transition(prev: SplashStep, now: SplashStep) -> bool {
+    let overlay = |s: SplashStep| {
+        matches!(
+            s,
+            SplashStep::RestartConfirm | SplashStep::ResetScoresConfirm | SplashStep::VideoConfirm
+        )
+    };
+    let pause_toggle = matches!(
+        (prev, now),
+        (SplashStep::Done, SplashStep::PauseMenu) | (SplashStep::PauseMenu, SplashStep::Done)
+    );
+
+    prev != now && !overlay(prev) && !overlay(now) && !pause_toggle
+}
+
+/// Starts a Fade on Every Major Screen Change: a New Splash / Menu Screen, Get
+/// Psyched Handing Over to Gameplay, and the Intermission Appearing
+fn trigger_screen_fade(
+    gameplay: Res<GameplaySettings>,
+    step: Res<SplashStep>,
+    psyched: Res<PsychedLoad>,
+    level_complete: Res<crate::level_complete::LevelComplete>,
+    mut last: Local<Option<(SplashStep, bool, bool)>>,
+    mut fade: ResMut<ScreenFade>,
+) {
+    let now = (*step, psyched.active, level_complete.0);
+    let prev = last.replace(now);
+
+    if !gameplay.screen_fade {
+        return;
+    }
+
+    let Some((prev_step, prev_psyched, prev_complete)) = prev else {
+        return;
+    };
+
+    let step_changed = is_screen_transition(prev_step, now.0);
+    let psyched_done = prev_psyched && !now.1;
+    let intermission = !prev_complete && now.2;
+
+    if step_changed || psyched_done || intermission {
+        fade.trigger();
+    }
+}
+
+fn tick_screen_fade(
+    time: Res<Time<Real>>,
+    gameplay: Res<GameplaySettings>,
+    mut fade: ResMut<ScreenFade>,
+    mut q_overlay: Query<&mut BackgroundColor, With<ScreenFadeOverlay>>,
+) {
+    if !gameplay.screen_fade && !fade.timer.is_finished() {
+        let done = fade.timer.duration();
+        fade.timer.set_elapsed(done);
+    }
+
+    if !fade.timer.is_finished() {
+        fade.timer.tick(time.delta());
+    }
+
+    let a = fade.alpha();
+    for mut bg in q_overlay.iter_mut() {
+        let want = Color::srgba(0.0, 0.0, 0.0, a);
+        if bg.0 != want {
+            bg.0 = want;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn skill_blurbs_wrap_to_the_panel() {
+        // Fixed 8 px Per Character Stand-In for the Bitmap Font
+        let measure = |t: &str| t.chars().count() as f32 * 8.0;
+
+        for idx in 0..4 {
+            let blurb = skill_blurb(davelib::skill::SkillLevel::from_selection(idx));
+            let wrapped = wrap_menu_text(blurb, 160.0, measure);
+
+            assert_eq!(wrapped.replace('\n', " "), blurb);
+            assert!(wrapped.lines().all(|l| measure(l) <= 160.0), "{wrapped:?}");
+        }
+
+        assert_eq!(wrap_menu_text("short", 160.0, measure), "short");
+    }
+
+    #[test]
+    fn skill_blurbs_match_the_damage_each_skill_deals() {
+        for idx in 0..4 {
+            let skill = davelib::skill::SkillLevel::from_selection(idx);
+            let blurb = skill_blurb(skill);
+            let mult = skill.damage_multiplier();
+
+            assert_eq!(blurb.contains("less"), mult < 1.0, "{blurb:?} at {mult}x");
+            assert_eq!(blurb.contains("full damage"), mult == 1.0, "{blurb:?} at {mult}x");
+            assert_eq!(blurb.contains("harder"), mult > 1.0, "{blurb:?} at {mult}x");
+        }
+    }
+
+    #[test]
+    fn skipped_psyched_screen_holds_lock_until_level_ready() {
+        let mut app = App::new();
+        app.init_resource::<Time<Real>>()
+            .insert_resource(PlayerControlLock(true))
+            .insert_resource(PsychedLoad {
+                timer: Timer::from_seconds(PsychedScreen::Off.duration_secs(), TimerMode::Once),
+                active: true,
+            })
+            .add_systems(Update, tick_get_psyched_loading);
+
+        // Level Still Loading: Zero Duration Is Up but Controls Stay Locked
+        app.update();
+        app.update();
+        assert!(app.world().resource::<PlayerControlLock>().0);
+        assert!(app.world().resource::<PsychedLoad>().active);
+
+        let (grid, _, _) = davelib::map::MapGrid::from_ascii(&["###", "#.#", "###"]);
+        let (w, h) = (grid.width, grid.height);
+        app.insert_resource(grid)
+            .insert_resource(davelib::decorations::SolidStatics::new(w, h))
+            .insert_resource(davelib::pushwalls::PushwallMarkers::empty(w, h));
+
+        app.update();
+        assert!(!app.world().resource::<PlayerControlLock>().0);
+        assert!(!app.world().resource::<PsychedLoad>().active);
+    }
+
+    #[test]
+    fn unconfirmed_display_change_reverts_after_timeout() {
+        let before = VideoSettings::default();
+        let mut video = before;
+
+        let mut pending = VideoConfirm::new(video);
+        video.resolution = (320, 240);
+
+        // Still Waiting Just Short of the Deadline
+        assert!(pending.tick(VIDEO_CONFIRM_SECS - 0.5).is_none());
+        assert_eq!(pending.seconds_shown(), 1);
+
+        // No Answer: the Pre-Change Settings Come Back
+        if let Some(previous) = pending.tick(1.0) {
+            video = previous;
+        }
+        assert!(video == before);
+    }
+
+    fn video_confirm_app(previous: VideoSettings, current: VideoSettings) -> App {
+        let mut app = App::new();
+        app.init_resource::<Time<Real>>()
+            .init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<davelib::input::MenuNav>()
+            .insert_resource(SplashStep::VideoConfirm)
+            .insert_resource(PendingVideoConfirm(Some(VideoConfirm::new(previous))))
+            .insert_resource(current)
+            .add_message::<PlaySfx>()
+            .add_systems(Update, answer_video_confirm);
+        app
+    }
+
+    #[test]
+    fn display_change_prompt_counts_down_then_reverts_unless_kept() {
+        use std::time::Duration;
+
+        let before = VideoSettings::default();
+        let mut changed = before;
+        changed.resolution = (320, 240);
+
+        // Each Second Shown Clears the Prompt for a Redraw
+        let mut app = video_confirm_app(before, changed);
+        let prompt = app.world_mut().spawn(SplashUi).id();
+        app.world_mut().resource_mut::<Time<Real>>().advance_by(Duration::from_secs(1));
+        app.update();
+        assert!(app.world().get_entity(prompt).is_err());
+        assert_eq!(*app.world().resource::<SplashStep>(), SplashStep::VideoConfirm);
+        assert!(*app.world().resource::<VideoSettings>() == changed);
+
+        // No Answer Before the Deadline: the Old Settings Come Back
+        app.world_mut().resource_mut::<Time<Real>>().advance_by(Duration::from_secs(9));
+        app.update();
+        assert!(*app.world().resource::<VideoSettings>() == before);
+        assert_eq!(*app.world().resource::<SplashStep>(), SplashStep::ChangeView);
+        assert!(app.world().resource::<PendingVideoConfirm>().0.is_none());
+
+        // Y Keeps the New Settings
+        let mut app = video_confirm_app(before, changed);
+        app.world_mut().resource_mut::<ButtonInput<KeyCode>>().press(KeyCode::KeyY);
+        app.update();
+        assert!(*app.world().resource::<VideoSettings>() == changed);
+        assert_eq!(*app.world().resource::<SplashStep>(), SplashStep::ChangeView);
+        assert!(app.world().resource::<PendingVideoConfirm>().0.is_none());
+    }
+
+    #[test]
+    fn continue_shows_only_with_a_last_game() {
+        // No Game Saved or Loaded Yet
+        let none = ContinueEntry::for_slot(None, |_| true);
+        assert_eq!(none, ContinueEntry::Hidden);
+        assert_eq!(menu_actions(false, none), MENU_ACTIONS_MAIN.to_vec());
+
+        // Last Slot Still Loads: Continue Leads the Main Menu
+        let ready = ContinueEntry::for_slot(Some(3), |_| true);
+        assert_eq!(ready, ContinueEntry::Ready(3));
+        let actions = menu_actions(false, ready);
+        assert_eq!(actions[0], MenuAction::Continue);
+        assert_eq!(actions.len(), menu_labels(false, ready).len());
+
+        // Corrupt or Mismatched Save: Row Stays, Grayed Out
+        let broken = ContinueEntry::for_slot(Some(3), |_| false);
+        assert_eq!(broken, ContinueEntry::Unreadable);
+        assert_eq!(menu_actions(false, broken)[0], MenuAction::Continue);
+
+        // Never on the Pause Menu
+        assert!(!menu_actions(true, ready).contains(&MenuAction::Continue));
+    }
+
+    #[test]
+    fn splash_scaling_stays_readable_across_window_shapes() {
+        // 16:9 Desktop: Menus at 5x, Title Fills the Height
+        assert_eq!(compute_scaled_size(1920.0, 1080.0), (1600.0, 1000.0));
+        assert_eq!(compute_fit_size(1920.0, 1080.0), (1728.0, 1080.0));
+
+        // Tall, Narrow Window Just Under 3x Wide: Title Fills the Width
+        assert_eq!(compute_scaled_size(900.0, 1800.0), (640.0, 400.0));
+        assert_eq!(compute_fit_size(900.0, 1800.0), (900.0, 562.0));
+
+        // Exact Multiple: Both Agree
+        assert_eq!(compute_fit_size(640.0, 400.0), compute_scaled_size(640.0, 400.0));
+
+        // Smaller Than 1x Clamps to 1x Rather Than Shrinking Further
+        assert_eq!(compute_scaled_size(200.0, 120.0), (BASE_W, BASE_H));
+        assert_eq!(compute_fit_size(200.0, 120.0), (BASE_W, BASE_H));
+
+        // The Fit Is Never Smaller Than the Menu Canvas
+        for (w, h) in [(1000.0, 700.0), (333.0, 999.0), (2560.0, 1440.0), (1280.0, 1024.0)] {
+            let (mw, mh) = compute_scaled_size(w, h);
+            let (fw, fh) = compute_fit_size(w, h);
+            assert!(fw >= mw && fh >= mh, "{w}x{h}");
+            assert!(mw <= w.max(BASE_W) && mh <= h.max(BASE_H), "{w}x{h}");
+
+            // Menus Keep the Integer Canvas Inside a Border Reaching the Fit
+            let (side, top) = menu_fit_border(w, h);
+            assert!(mw + side * 2.0 <= fw && mh + top * 2.0 <= fh, "{w}x{h}");
+            assert!(fw - (mw + side * 2.0) < 2.0 && fh - (mh + top * 2.0) < 2.0, "{w}x{h}");
+        }
+    }
+
+    #[test]
+    fn long_option_lists_page_instead_of_overflowing() {
+        // 1x Scale: the 152 px Panel Holds 15 Rows at the 10 px Minimum
+        let per_page = options_rows_per_page(1.0, 152.0);
+        assert_eq!(per_page, 15);
+
+        let items = build_gameplay_options_items(&GameplaySettings::default()).len();
+        assert!(items > per_page);
+        for selection in 0..items {
+            let page = options_page(selection, items, per_page);
+            assert!(page.contains(&selection));
+            assert!(page.len() as f32 * options_row_h(1.0, 152.0, per_page) <= 152.0);
+        }
+
+        assert_eq!(options_page(14, items, per_page), 0..15);
+        assert_eq!(options_page(15, items, per_page), 15..items);
+    }
+
+    #[test]
+    fn screen_fade_skips_confirm_boxes_and_the_pause_toggle() {
+        assert!(is_screen_transition(SplashStep::Menu, SplashStep::SoundOptions));
+        assert!(is_screen_transition(SplashStep::PauseMenu, SplashStep::LoadSelect));
+        assert!(is_screen_transition(SplashStep::Splash1, SplashStep::Menu));
+
+        assert!(!is_screen_transition(SplashStep::PauseMenu, SplashStep::RestartConfirm));
+        assert!(!is_screen_transition(SplashStep::RestartConfirm, SplashStep::PauseMenu));
+        assert!(!is_screen_transition(SplashStep::Scores, SplashStep::ResetScoresConfirm));
+        assert!(!is_screen_transition(SplashStep::VideoConfirm, SplashStep::ChangeView));
+        assert!(!is_screen_transition(SplashStep::Done, SplashStep::PauseMenu));
+        assert!(!is_screen_transition(SplashStep::PauseMenu, SplashStep::Done));
+        assert!(!is_screen_transition(SplashStep::Menu, SplashStep::Menu));
+    }
+
+    #[test]
+    fn attract_loop_keeps_the_menu_music() {
+        let mut menu = MenuLocalState::default();
+        let mut step = SplashStep::Menu;
+        let mut music = MusicModeKind::Menu;
+        let mut pages = Vec::new();
+
+        // Mirrors splash_advance_on_any_input: Switch Page, Else Run the Page's Arm
+        let frame = |menu: &mut MenuLocalState, step: &mut SplashStep, music: &mut MusicModeKind, any_key: bool| {
+            match tick_attract(menu, *step, any_key, 1.0) {
+                Some(SplashStep::Menu) => {
+                    *step = SplashStep::Menu;
+                    *music = MusicModeKind::Menu;
+                }
+                Some(next) => *step = next,
+                None if *step == SplashStep::Splash1 => *music = title_music(menu),
+                None => {}
+            }
+        };
+
+        // Idle Past the Timeout, Then Through Two Full Title / Scores Cycles
+        let frames = ATTRACT_IDLE_SECS as usize + 4 * ATTRACT_PAGE_SECS as usize;
+        for _ in 0..frames {
+            frame(&mut menu, &mut step, &mut music, false);
+            assert_eq!(music, MusicModeKind::Menu, "{step:?}");
+            if pages.last() != Some(&step) {
+                pages.push(step);
+            }
+        }
+        assert_eq!(
+            pages,
+            [
+                SplashStep::Menu,
+                SplashStep::Splash1,
+                SplashStep::Scores,
+                SplashStep::Splash1,
+                SplashStep::Scores,
+                SplashStep::Splash1,
+            ],
+        );
+
+        // Any Key Returns to the Menu and Leaves the Loop
+        frame(&mut menu, &mut step, &mut music, true);
+        assert_eq!(step, SplashStep::Menu);
+        assert_eq!(music, MusicModeKind::Menu);
+        assert!(menu.attract.is_none());
+
+        // A Title Page Outside the Loop Still Plays the Splash Track
+        assert_eq!(title_music(&menu), MusicModeKind::Splash);
+    }
+
+    #[test]
+    fn skip_splash_starts_at_the_main_menu() {
+        let startup_step = |skip_splash: bool| {
+            let mut app = App::new();
+            app.insert_resource(GameplaySettings {
+                    skip_splash,
+                    ..Default::default()
+                })
+                .init_resource::<SplashStep>()
+                .add_systems(Startup, apply_startup_splash_step);
+            app.update();
+            *app.world().resource::<SplashStep>()
+        };
+
+        // The Setting Alone Skips Both Splash Screens, the Default Keeps Them
+        assert_eq!(startup_step(true), SplashStep::Menu);
+        assert_eq!(startup_step(false), SplashStep::Splash0);
+
+        // So Does the Command Line Flag, Wherever it Sits Among the Args
+        let args = |a: &[&str]| a.iter().map(|s| s.to_string()).collect::<Vec<_>>().into_iter();
+        assert_eq!(startup_splash_step(false, args(&["--windowed", SKIP_SPLASH_FLAG])), SplashStep::Menu);
+        assert_eq!(startup_splash_step(false, args(&["--windowed"])), SplashStep::Splash0);
+    }
+
+    #[test]
+    fn every_gameplay_setting_row_toggles_its_own_setting() {
+        let defaults = GameplaySettings::default();
+
+        for (kind, _) in build_gameplay_options_items(&defaults) {
+            let mut gameplay = defaults;
+            let is_setting = toggle_gameplay_option(kind, &mut gameplay);
+
+            assert_eq!(is_setting, !matches!(kind, GameplayOptionKind::ChangeView | GameplayOptionKind::Back));
+            assert_eq!(gameplay != defaults, is_setting, "{kind:?}");
+
+            // Only That Row's Label Changes
+            let before = build_gameplay_options_items(&defaults);
+            let after = build_gameplay_options_items(&gameplay);
+            let changed: Vec<_> = before.iter().zip(&after).filter(|(a, b)| a != b).map(|(a, _)| a.0).collect();
+            assert_eq!(changed, if is_setting { vec![kind] } else { vec![] });
+        }
+    }
+}
@@ -6,6 +6,19 @@ use bevy::prelude::*;
 
 use crate::combat::WeaponSlot;
 
+/// Score Interval That Awards an Extra Life (Original EXTRAPOINTS)
+pub const EXTRA_LIFE_POINTS: i32 = 40_000;
+/// Classic Lives Cap (Original GiveExtraMan Stops at 9)
+pub const MAX_LIVES: i32 = 9;
+
+/// Every Weapon in Slot (and Owned-Mask Bit) Order
+const WEAPON_SLOTS: [WeaponSlot; 4] = [
+    WeaponSlot::Knife,
+    WeaponSlot::Pistol,
+    WeaponSlot::MachineGun,
+    WeaponSlot::Chaingun,
+];
+
 #[derive(Resource, Debug, Clone)]
 pub struct HudState {
     pub hp: i32,
@@ -20,6 +33,8 @@ pub struct HudState {
     pub selected: WeaponSlot,
     // Bits For Owned Weapons
     pub owned_mask: u8,
+    // Score at Which the Next Extra Life is Awarded (Original gamestate.nextextra)
+    pub next_extra: i32,
 }
 
 impl HudState {
@@ -34,6 +49,65 @@ impl HudState {
         let bit = 1u8 << (w as u8);
         self.owned_mask |= bit;
     }
+
+    pub fn grant_all(&mut self) {
+        for w in WEAPON_SLOTS {
+            self.grant(w);
+        }
+    }
+
+    /// Highest Owned Slot, the Original bestweapon. The Knife if the Mask Is Empty
+    pub fn best_owned(&self) -> WeaponSlot {
+        WEAPON_SLOTS
+            .into_iter()
+            .rev()
+            .find(|&w| self.owns(w))
+            .unwrap_or(WeaponSlot::Knife)
+    }
+
+    /// Whether Nothing Better Than `w` Is Owned Yet, the bestweapon Check
+    /// GiveWeapon Uses to Decide if a Pickup Takes Over the Player's Hands
+    pub fn is_upgrade(&self, w: WeaponSlot) -> bool {
+        let better = !((2u8 << (w as u8)) - 1);
+        (self.owned_mask & better) == 0
+    }
+
+    /// The Owned Weapon `step` Places Past the Selected One in Slot Order,
+    /// Wrapping Around. The Selected Weapon Itself When Nothing Else Is Owned
+    pub fn cycle_weapon(&self, step: i8) -> WeaponSlot {
+        let n = WEAPON_SLOTS.len();
+        let dir = if step < 0 { n - 1 } else { 1 };
+        let mut i = self.selected as usize;
+        for _ in 1..n {
+            i = (i + dir) % n;
+            if self.owns(WEAPON_SLOTS[i]) {
+                return WEAPON_SLOTS[i];
+            }
+        }
+        self.selected
+    }
+
+    /// One More Life, up to the Classic Cap
+    pub fn give_extra_life(&mut self) {
+        self.lives = (self.lives + 1).min(MAX_LIVES);
+    }
+
+    /// Point next_extra at the First Boundary Above the Current Score. Called
+    /// Whenever Score is Set Directly (Restart Level, Load) Rather Than Earned
+    pub fn rebase_extra_life(&mut self) {
+        self.next_extra = (self.score.max(0) / EXTRA_LIFE_POINTS + 1) * EXTRA_LIFE_POINTS;
+    }
+
+    /// Grant One Life per Boundary the Score Has Reached, Returns How Many
+    pub fn award_extra_lives(&mut self) -> u32 {
+        let mut awarded = 0;
+        while self.score >= self.next_extra {
+            self.give_extra_life();
+            self.next_extra += EXTRA_LIFE_POINTS;
+            awarded += 1;
+        }
+        awarded
+    }
 }
 
 impl Default for HudState {
@@ -45,6 +119,7 @@ impl Default for HudState {
             lives: 3,
             selected: WeaponSlot::Pistol,
             owned_mask: 0,
+            next_extra: EXTRA_LIFE_POINTS,
 
             key_gold: false,
             key_silver: false,
@@ -57,6 +132,55 @@ impl Default for HudState {
     }
 }
 
+/// Trauma Lost per Second, so a Full Shake Settles in Well Under a Second
+const SHAKE_DECAY_PER_SEC: f32 = 1.6;
+/// Largest Camera Offset (Tiles) and Roll (Radians) at Full Trauma
+const SHAKE_MAX_OFFSET: f32 = 0.035;
+const SHAKE_MAX_ROLL: f32 = 0.02;
+/// Damage That Alone Would Max Out the Trauma
+const SHAKE_FULL_DAMAGE: f32 = 60.0;
+
+/// Camera Shake From Damage and Nearby Explosions. Trauma (0..1) Decays Over
+/// Time and the Shake Scales With its Square, so Light Hits Stay Subtle
+#[derive(Resource, Debug, Clone, Default)]
+pub struct ScreenShake {
+    pub trauma: f32,
+    /// Noise Clock, Only Advanced While Shaking
+    clock: f32,
+    /// World Offset and Roll Put on the Camera This Frame, Taken Back Next Frame
+    pub applied: Option<(Vec3, Quat)>,
+}
+
+impl ScreenShake {
+    pub fn add_trauma(&mut self, amount: f32) {
+        self.trauma = (self.trauma + amount.max(0.0)).min(1.0);
+    }
+
+    pub fn add_damage(&mut self, dmg: i32) {
+        self.add_trauma(dmg as f32 / SHAKE_FULL_DAMAGE);
+    }
+
+    /// Advance by `dt` Seconds and Return This Frame's View-Space Offset and
+    /// Roll. Sums of Unrelated Sines Stand in for Noise: Smooth, Never Repeating
+    /// Visibly and Centered on Zero
+    pub fn step(&mut self, dt: f32) -> (Vec3, f32) {
+        if self.trauma <= 0.0 {
+            return (Vec3::ZERO, 0.0);
+        }
+
+        self.clock += dt;
+        let amount = self.trauma * self.trauma;
+        self.trauma = (self.trauma - SHAKE_DECAY_PER_SEC * dt).max(0.0);
+
+        let t = self.clock;
+        let noise = |freq: f32, phase: f32| {
+            ((t * freq + phase).sin() + 0.5 * (t * freq * 2.3 + phase * 1.7).sin()) / 1.5
+        };
+        let offset = Vec3::new(noise(23.0, 0.0), noise(29.0, 1.3), 0.0) * SHAKE_MAX_OFFSET * amount;
+        (offset, noise(17.0, 2.9) * SHAKE_MAX_ROLL * amount)
+    }
+}
+
 #[derive(Resource, Debug, Clone)]
 pub struct DamageFlash {
     pub timer: Timer,
@@ -89,6 +213,184 @@ impl DamageFlash {
     }
 }
 
+/// Screen Edge a Directional Damage Cue Is Drawn On, Relative to the Player's Facing
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DamageSide {
+    Front,
+    Right,
+    Behind,
+    Left,
+}
+
+impl DamageSide {
+    /// Sector of 'source' as Seen From 'player_pos' Looking Along 'forward'
+    /// Only the XZ Plane Counts, so Camera Pitch Never Skews the Result
+    pub fn from_source(player_pos: Vec3, forward: Vec3, source: Vec3) -> Self {
+        let fwd = Vec2::new(forward.x, forward.z).normalize_or(Vec2::NEG_Y);
+        let right = Vec2::new(-fwd.y, fwd.x);
+        let to = Vec2::new(source.x - player_pos.x, source.z - player_pos.z);
+
+        let angle = to.dot(right).atan2(to.dot(fwd));
+        let quarter = std::f32::consts::FRAC_PI_4;
+        if angle.abs() <= quarter {
+            DamageSide::Front
+        } else if angle.abs() >= 3.0 * quarter {
+            DamageSide::Behind
+        } else if angle > 0.0 {
+            DamageSide::Right
+        } else {
+            DamageSide::Left
+        }
+    }
+}
+
+/// Brief Edge Cue Pointing Toward the Last Attacker, Alongside the Red Flash
+#[derive(Resource, Debug, Clone)]
+pub struct DamageDirection {
+    pub timer: Timer,
+    pub side: Option<DamageSide>,
+}
+
+impl Default for DamageDirection {
+    fn default() -> Self {
+        let mut t = Timer::from_seconds(0.6, TimerMode::Once);
+        t.set_elapsed(t.duration());
+        Self { timer: t, side: None }
+    }
+}
+
+impl DamageDirection {
+    pub fn trigger(&mut self, side: DamageSide) {
+        self.side = Some(side);
+        self.timer.reset();
+    }
+
+    pub fn alpha(&self) -> f32 {
+        if self.timer.is_finished() || self.side.is_none() {
+            return 0.0;
+        }
+        let dur = self.timer.duration().as_secs_f32().max(0.0001);
+        let t = (self.timer.elapsed_secs() / dur).clamp(0.0, 1.0);
+        (1.0 - t) * 0.8
+    }
+}
+
+/// "A SECRET!" Banner Shown Briefly When a Pushwall Secret is Credited
+#[derive(Resource, Debug, Clone)]
+pub struct SecretNotice {
+    pub timer: Timer,
+}
+
+impl Default for SecretNotice {
+    fn default() -> Self {
+        let mut t = Timer::from_seconds(1.5, TimerMode::Once);
+        t.set_elapsed(t.duration());
+        Self { timer: t }
+    }
+}
+
+impl SecretNotice {
+    /// Restarts the Banner, so a Second Find Mid-Banner Gets Its Own Full Showing
+    pub fn trigger(&mut self) {
+        self.timer.reset();
+    }
+
+    pub fn visible(&self) -> bool {
+        !self.timer.is_finished()
+    }
+}
+
+/// Blinks the Lives Digits Briefly After an Extra Life is Awarded
+#[derive(Resource, Debug, Clone)]
+pub struct LivesFlash {
+    pub timer: Timer,
+}
+
+impl Default for LivesFlash {
+    fn default() -> Self {
+        let mut t = Timer::from_seconds(1.0, TimerMode::Once);
+        t.set_elapsed(t.duration());
+        Self { timer: t }
+    }
+}
+
+impl LivesFlash {
+    pub fn trigger(&mut self) {
+        self.timer.reset();
+    }
+
+    /// Digits Hidden on Alternate 0.1 s Steps While the Flash Runs
+    pub fn digits_hidden(&self) -> bool {
+        !self.timer.is_finished() && (self.timer.elapsed_secs() / 0.1) as u32 % 2 == 0
+    }
+}
+
+/// Blinks the Missing Key's HUD Slot After Use is Pressed on a Locked Door
+#[derive(Resource, Debug, Clone)]
+pub struct LockedKeyFlash {
+    pub timer: Timer,
+    pub gold: bool,
+}
+
+impl Default for LockedKeyFlash {
+    fn default() -> Self {
+        let mut t = Timer::from_seconds(1.0, TimerMode::Once);
+        t.set_elapsed(t.duration());
+        Self { timer: t, gold: false }
+    }
+}
+
+impl LockedKeyFlash {
+    pub fn trigger(&mut self, gold: bool) {
+        self.gold = gold;
+        self.timer.reset();
+    }
+
+    /// Key Icon Shown on Alternate 0.1 s Steps, Same Rhythm as LivesFlash
+    pub fn icon_shown(&self, gold: bool) -> bool {
+        self.gold == gold
+            && !self.timer.is_finished()
+            && (self.timer.elapsed_secs() / 0.1) as u32 % 2 == 0
+    }
+}
+
+/// Full-Screen Black Cover Over Major Screen Changes. Triggering Cuts to Black
+/// at Once (Hiding the Despawn / Spawn or Level Load Frame), Holds Briefly,
+/// Then Fades In. Retriggering Just Restarts the Timer and Nothing Waits on It,
+/// so Rapid Input Can Never Stall a Transition, Once the Timer Runs Out the
+/// Screen Is Always Fully Faded In
+#[derive(Resource, Debug, Clone)]
+pub struct ScreenFade {
+    pub timer: Timer,
+}
+
+impl Default for ScreenFade {
+    fn default() -> Self {
+        let mut t = Timer::from_seconds(Self::HOLD_SECS + Self::FADE_SECS, TimerMode::Once);
+        t.set_elapsed(t.duration());
+        Self { timer: t }
+    }
+}
+
+impl ScreenFade {
+    const HOLD_SECS: f32 = 0.08;
+    const FADE_SECS: f32 = 0.30;
+
+    pub fn trigger(&mut self) {
+        self.timer.reset();
+    }
+
+    pub fn alpha(&self) -> f32 {
+        if self.timer.is_finished() {
+            return 0.0;
+        }
+        let e = self.timer.elapsed_secs();
+        if e < Self::HOLD_SECS {
+            return 1.0;
+        }
+        (1.0 - (e - Self::HOLD_SECS) / Self::FADE_SECS).clamp(0.0, 1.0)
+    }
+}
 
 #[derive(Resource, Debug, Clone)]
 pub struct PickupFlash {
@@ -99,7 +401,7 @@ pub struct PickupFlash {
 impl Default for PickupFlash {
     fn default() -> Self {
         // Slightly faster than Wolf: 3 steps × 5 tics (instead of 6) at 70Hz.
-        const TIC_HZ: f32 = 70.0;
+        use davelib::tics::TIC_HZ;
         const NUM_STEPS: f32 = 3.0;
         const STEP_TICS: f32 = 3.0;
 
@@ -122,7 +424,7 @@ impl PickupFlash {
 
     pub fn alpha(&self) -> f32 {
         // 3 stepped levels, no fade-in. Only steps down then off
-        const TIC_HZ: f32 = 70.0;
+        use davelib::tics::TIC_HZ;
         const STEP_TICS: f32 = 3.0; // must match Default() above
         const STEP_SECS: f32 = STEP_TICS / TIC_HZ;
 
@@ -181,3 +483,165 @@ impl DeathOverlay {
 
 #[derive(Resource, Debug, Clone, Default)]
 pub struct GameOver(pub bool);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn weapon_pickups_only_take_over_when_they_are_an_upgrade() {
+        let mut hud = HudState::default();
+        assert!(hud.is_upgrade(WeaponSlot::MachineGun));
+        assert!(hud.is_upgrade(WeaponSlot::Chaingun));
+
+        // Holding the Chaingun, a First Machine Gun Is Kept but Not Drawn
+        hud.grant(WeaponSlot::Chaingun);
+        assert!(!hud.is_upgrade(WeaponSlot::MachineGun));
+        assert!(hud.is_upgrade(WeaponSlot::Chaingun));
+    }
+
+    #[test]
+    fn weapon_cycle_skips_unowned_slots_and_wraps() {
+        let mut hud = HudState::default();
+        hud.grant(WeaponSlot::Chaingun);
+        hud.selected = WeaponSlot::Pistol;
+
+        assert_eq!(hud.cycle_weapon(1), WeaponSlot::Chaingun);
+        assert_eq!(hud.cycle_weapon(-1), WeaponSlot::Knife);
+
+        hud.selected = WeaponSlot::Chaingun;
+        assert_eq!(hud.cycle_weapon(1), WeaponSlot::Knife);
+    }
+
+    #[test]
+    fn starting_loadout_never_cycles_onto_a_missing_gun() {
+        let mut hud = HudState::default();
+        assert_eq!(hud.best_owned(), WeaponSlot::Pistol);
+
+        for step in [1, -1, 1, 1, -1, -1, 1] {
+            hud.selected = hud.cycle_weapon(step);
+            assert!(matches!(hud.selected, WeaponSlot::Knife | WeaponSlot::Pistol));
+        }
+
+        hud.grant(WeaponSlot::Chaingun);
+        assert_eq!(hud.best_owned(), WeaponSlot::Chaingun);
+    }
+
+    #[test]
+    fn cycling_with_a_single_weapon_stays_put() {
+        let mut hud = HudState::default();
+        hud.owned_mask = 0;
+        hud.grant(WeaponSlot::Knife);
+        hud.selected = WeaponSlot::Knife;
+
+        assert_eq!(hud.cycle_weapon(1), WeaponSlot::Knife);
+        assert_eq!(hud.cycle_weapon(-1), WeaponSlot::Knife);
+    }
+
+    #[test]
+    fn screen_shake_is_bounded_and_settles() {
+        let mut shake = ScreenShake::default();
+        assert_eq!(shake.step(1.0 / 60.0), (Vec3::ZERO, 0.0));
+
+        shake.add_damage(200);
+        assert_eq!(shake.trauma, 1.0);
+
+        let mut frames = 0;
+        while shake.trauma > 0.0 {
+            let (offset, roll) = shake.step(1.0 / 144.0);
+            assert!(offset.abs().max_element() <= SHAKE_MAX_OFFSET);
+            assert!(roll.abs() <= SHAKE_MAX_ROLL);
+            frames += 1;
+        }
+
+        // Same Settle Time at Any Frame Rate: About 1 / SHAKE_DECAY_PER_SEC
+        assert!((89..=91).contains(&frames));
+        assert_eq!(shake.step(1.0 / 144.0), (Vec3::ZERO, 0.0));
+    }
+
+    #[test]
+    fn extra_life_awarded_once_per_boundary_crossed() {
+        let mut hud = HudState::default();
+        hud.score = 39_000;
+        assert_eq!(hud.award_extra_lives(), 0);
+
+        // Crown Pushes Score Past 40,000: Exactly One Life
+        hud.score += 5_000;
+        assert_eq!(hud.award_extra_lives(), 1);
+        assert_eq!(hud.lives, 4);
+        assert_eq!(hud.award_extra_lives(), 0);
+
+        // One Big Bonus Crossing Two Boundaries Grants Two
+        hud.score += 80_000;
+        assert_eq!(hud.award_extra_lives(), 2);
+        assert_eq!(hud.lives, 6);
+
+        // Lives Stay Capped at the Classic Nine
+        hud.score += 10 * EXTRA_LIFE_POINTS;
+        hud.award_extra_lives();
+        assert_eq!(hud.lives, MAX_LIVES);
+    }
+
+    #[test]
+    fn rebase_skips_boundaries_below_a_restored_score() {
+        let mut hud = HudState::default();
+        hud.score = 85_000;
+        hud.rebase_extra_life();
+        assert_eq!(hud.award_extra_lives(), 0);
+        assert_eq!(hud.next_extra, 120_000);
+    }
+
+    #[test]
+    fn locked_key_flash_blinks_only_the_wanted_key() {
+        use std::time::Duration;
+
+        let mut flash = LockedKeyFlash::default();
+        assert!(!flash.icon_shown(true) && !flash.icon_shown(false));
+
+        flash.trigger(false);
+        assert!(flash.icon_shown(false));
+        assert!(!flash.icon_shown(true));
+
+        flash.timer.tick(Duration::from_millis(150));
+        assert!(!flash.icon_shown(false));
+
+        flash.timer.tick(Duration::from_secs(1));
+        assert!(!flash.icon_shown(false));
+    }
+
+    #[test]
+    fn screen_fade_always_resolves_to_clear() {
+        use std::time::Duration;
+
+        let mut fade = ScreenFade::default();
+        assert_eq!(fade.alpha(), 0.0);
+
+        // Rapid Retriggers Restart the Cover but Never Hold It Past the Timer
+        for _ in 0..5 {
+            fade.trigger();
+            assert_eq!(fade.alpha(), 1.0);
+            fade.timer.tick(Duration::from_millis(50));
+        }
+
+        fade.timer.tick(Duration::from_millis(200));
+        assert!(fade.alpha() > 0.0 && fade.alpha() < 1.0);
+
+        fade.timer.tick(Duration::from_secs(1));
+        assert_eq!(fade.alpha(), 0.0);
+    }
+
+    #[test]
+    fn damage_side_follows_player_facing() {
+        let player = Vec3::new(10.0, 0.5, 10.0);
+        // Facing North (-Z), Right Hand Points East (+X)
+        let north = Vec3::NEG_Z;
+
+        assert_eq!(DamageSide::from_source(player, north, Vec3::new(10.0, 0.5, 4.0)), DamageSide::Front);
+        assert_eq!(DamageSide::from_source(player, north, Vec3::new(10.0, 0.5, 15.0)), DamageSide::Behind);
+        assert_eq!(DamageSide::from_source(player, north, Vec3::new(14.0, 0.5, 9.0)), DamageSide::Right);
+        assert_eq!(DamageSide::from_source(player, north, Vec3::new(6.0, 0.5, 11.0)), DamageSide::Left);
+
+        // Turning to Face East Moves the Same Northern Guard to the Left
+        assert_eq!(DamageSide::from_source(player, Vec3::X, Vec3::new(10.0, 0.5, 4.0)), DamageSide::Left);
+    }
+}
@@ -5,7 +5,9 @@ Davenstein - by David Petnick
 use bevy::prelude::*;
 
 use davelib::ai::EnemyFire;
+use davelib::audio::{PlaySfx, SfxKind};
 use davelib::player::{
+    GamePaused,
     Player,
     PlayerControlLock,
     PlayerDeathLatch,
@@ -16,6 +18,7 @@ use super::{
     HudState,
     GameOver,
 };
+use super::state::ScreenShake;
 
 #[derive(Resource, Debug, Clone)]
 pub struct DeathDelay {
@@ -35,6 +38,27 @@ impl Default for DeathDelay {
 #[derive(Resource, Debug, Clone, Default)]
 pub struct RestartRequested(pub bool);
 
+/// What a Death Leads to Once the Death Delay Runs Out
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeathOutcome {
+    /// Rebuild the Floor and Start Over at its Spawn, Keeping Score
+    Respawn,
+    /// No Lives Left: Game Over, Then High Score Name Entry if it Qualifies
+    GameOver,
+}
+
+impl DeathOutcome {
+    /// Lives Are Taken When the Player Dies, so This Is What Remains
+    pub fn for_lives_left(lives: i32) -> Self {
+        if lives > 0 { Self::Respawn } else { Self::GameOver }
+    }
+}
+
+/// Floor Score at the Moment of a Death That Respawns. restart_finish Keeps
+/// its Clock After the Rebuild Resets LevelScore
+#[derive(Resource, Debug, Clone, Default)]
+pub struct RespawnLevelScore(pub Option<davelib::level_score::LevelScore>);
+
 /// A Request to Start Fresh Run (Reset Score / Lives / etc)
 #[derive(Resource, Debug, Clone, Default)]
 pub struct NewGameRequested(pub bool);
@@ -44,6 +68,19 @@ pub struct NewGameRequested(pub bool);
 #[derive(Resource, Debug, Clone, Default)]
 pub struct AdvanceLevelRequested(pub bool);
 
+/// Request to Replay the Current Level From its Start (Pause Menu Restart Level)
+/// Loadout is Either a Pistol Start or the Level Entry Inventory, per GameplaySettings
+#[derive(Resource, Debug, Clone, Default)]
+pub struct RestartLevelRequested(pub bool);
+
+/// Any Menu Step Counts as Paused: the Pause Menu and Every Screen Opened From
+/// it (Options, Save, Load). Returning to the Game Always Lands on Done, so
+/// Both Back to Game and Escape Resume. Runs in PostUpdate so the Flag Flips in
+/// the Same Frame as the Menu's Control Lock
+pub fn sync_game_paused(step: Res<crate::ui::SplashStep>, mut paused: ResMut<GamePaused>) {
+    paused.set_if_neq(GamePaused(*step != crate::ui::SplashStep::Done));
+}
+
 pub fn sync_player_hp_with_hud(
     mut hud: ResMut<HudState>,
     q_player: Query<&davelib::player::PlayerVitals, With<davelib::player::Player>>,
@@ -52,12 +89,47 @@ pub fn sync_player_hp_with_hud(
     hud.hp = vitals.hp;
 }
 
+/// Kill Score and the Floor's Kill Ratio, Credited Once From the Hit That
+/// Drops the Enemy
+pub fn award_kill_points(
+    mut hits: MessageReader<crate::combat::GuardHit>,
+    q_kind: Query<&davelib::enemies::EnemyKind>,
+    mut hud: ResMut<HudState>,
+    mut level_score: ResMut<davelib::level_score::LevelScore>,
+) {
+    for hit in hits.read().filter(|h| h.killed) {
+        level_score.kills_found += 1;
+        if let Ok(kind) = q_kind.get(hit.entity) {
+            hud.score += kind.points();
+        }
+    }
+}
+
+/// Original GiveExtraMan: Every EXTRA_LIFE_POINTS of Score Earns a Life, However
+/// the Points Arrive (Treasure, Kills, Intermission Bonus)
+pub fn award_extra_lives_on_score(
+    mut hud: ResMut<HudState>,
+    mut lives_flash: ResMut<super::state::LivesFlash>,
+    mut sfx: MessageWriter<PlaySfx>,
+) {
+    if !hud.is_changed() || hud.score < hud.next_extra {
+        return;
+    }
+
+    if hud.award_extra_lives() > 0 {
+        lives_flash.trigger();
+        sfx.write(PlaySfx { kind: SfxKind::PickupOneUp, pos: Vec3::ZERO });
+    }
+}
+
 pub fn apply_enemy_fire_to_player_vitals(
     mut q_player: Query<&mut davelib::player::PlayerVitals, With<davelib::player::Player>>,
     lock: Res<PlayerControlLock>,
     latch: Res<PlayerDeathLatch>,
     god: Res<davelib::player::GodMode>,
+    skill: Res<davelib::skill::SkillLevel>,
     mut enemy_fire: MessageReader<EnemyFire>,
+    mut shake: ResMut<ScreenShake>,
 ) {
     // God Mode: Ignore Damage (But Drain Events)
     if god.0 {
@@ -79,10 +151,70 @@ pub fn apply_enemy_fire_to_player_vitals(
         if ev.damage <= 0 {
             continue;
         }
-        vitals.hp = (vitals.hp - ev.damage).max(0);
+        let dmg = skill.scale_damage(ev.damage);
+        vitals.hp = (vitals.hp - dmg).max(0);
+        shake.add_damage(dmg);
     }
 }
 
+/// Explosions Within This Many Tiles Shake the View, Harder When Closer
+const EXPLOSION_SHAKE_RADIUS: f32 = 4.0;
+const EXPLOSION_SHAKE_TRAUMA: f32 = 0.5;
+
+pub fn shake_on_nearby_explosions(
+    q_new: Query<&Transform, Added<crate::combat::projectiles::RocketImpact>>,
+    q_player: Query<&Transform, (With<Player>, Without<crate::combat::projectiles::RocketImpact>)>,
+    mut shake: ResMut<ScreenShake>,
+) {
+    let Some(player) = q_player.iter().next() else { return; };
+
+    for tf in q_new.iter() {
+        let closeness = 1.0 - tf.translation.distance(player.translation) / EXPLOSION_SHAKE_RADIUS;
+        if closeness > 0.0 {
+            shake.add_trauma(closeness * EXPLOSION_SHAKE_TRAUMA);
+        }
+    }
+}
+
+/// Runs in First: Take Back Last Frame's Shake Before Anything Reads the Player
+/// Transform, so Movement, Collision and Aim Never See it
+pub fn remove_screen_shake(
+    mut shake: ResMut<ScreenShake>,
+    mut q_player: Query<&mut Transform, With<Player>>,
+) {
+    let Some((offset, roll)) = shake.applied.take() else { return; };
+    let Some(mut tf) = q_player.iter_mut().next() else { return; };
+
+    tf.translation -= offset;
+    tf.rotation *= roll.inverse();
+}
+
+/// Runs Late in PostUpdate, After Movement and Render Interpolation, so the
+/// Shake Is Drawn but Lives Only Until remove_screen_shake Next Frame
+pub fn apply_screen_shake(
+    time: Res<Time>,
+    video: Res<davelib::options::VideoSettings>,
+    mut shake: ResMut<ScreenShake>,
+    mut q_player: Query<&mut Transform, With<Player>>,
+) {
+    if !video.screen_shake {
+        shake.trauma = 0.0;
+        return;
+    }
+
+    let (offset, roll) = shake.step(time.delta_secs());
+    if offset == Vec3::ZERO && roll == 0.0 {
+        return;
+    }
+    let Some(mut tf) = q_player.iter_mut().next() else { return; };
+
+    let offset = tf.rotation * offset;
+    let roll = Quat::from_rotation_z(roll);
+    tf.translation += offset;
+    tf.rotation *= roll;
+    shake.applied = Some((offset, roll));
+}
+
 pub fn handle_player_death_once(
     q_vitals: Query<&PlayerVitals, With<Player>>,
     mut hud: ResMut<HudState>,
@@ -116,10 +248,12 @@ pub fn tick_death_delay_and_request_restart(
     q_vitals: Query<&PlayerVitals, With<Player>>,
     hud: Res<HudState>,
     current_level: Res<davelib::level::CurrentLevel>,
+    level_score: Res<davelib::level_score::LevelScore>,
     lock: Res<PlayerControlLock>,
     latch: Res<PlayerDeathLatch>,
     mut death: ResMut<DeathDelay>,
     mut restart: ResMut<RestartRequested>,
+    mut respawn_score: ResMut<RespawnLevelScore>,
     mut game_over: ResMut<GameOver>,
     mut death_overlay: ResMut<DeathOverlay>,
 ) {
@@ -152,16 +286,20 @@ pub fn tick_death_delay_and_request_restart(
 
     death.active = false;
 
-    if hud.lives > 0 {
-        restart.0 = true;
-    } else {
-        // Check for High Score Before Showing Game Over
-        commands.insert_resource(davelib::high_score::CheckHighScore {
-            score: hud.score,
-            episode: current_level.0.episode(),
-            checked: false,
-        });
-        game_over.0 = true;
+    match DeathOutcome::for_lives_left(hud.lives) {
+        DeathOutcome::Respawn => {
+            respawn_score.0 = Some(level_score.clone());
+            restart.0 = true;
+        }
+        DeathOutcome::GameOver => {
+            // Check for High Score Before Showing Game Over
+            commands.insert_resource(davelib::high_score::CheckHighScore {
+                score: hud.score,
+                episode: current_level.0.episode(),
+                checked: false,
+            });
+            game_over.0 = true;
+        }
     }
 }
 
@@ -173,6 +311,7 @@ pub fn game_over_input(
     hud: Res<HudState>,
     current_level: Res<davelib::level::CurrentLevel>,
     high_scores: Res<davelib::high_score::HighScores>,
+    cheats: Res<davelib::player::CheatState>,
     mut new_game: ResMut<NewGameRequested>,
     mut splash_step: ResMut<crate::ui::SplashStep>,
     mut name_entry: ResMut<davelib::high_score::NameEntryState>,
@@ -191,7 +330,7 @@ pub fn game_over_input(
     }
 
     // Check if Score Qualifies for High Scores
-    if high_scores.qualifies(hud.score) {
+    if high_scores.qualifies_run(hud.score, cheats.used) {
         // Find Rank
         let rank = high_scores
             .entries
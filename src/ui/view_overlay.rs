@@ -0,0 +1,56 @@
+/*
+Davenstein - by David Petnick
+
+View Overlays
+
+Shared Plumbing for the Small Pictures Drawn Over the 3D View (Automap, Radar,
+Crosshair). Each Is One Square RGBA Image Made at Startup, Scaled With Nearest
+Sampling, and Shown Through a Hidden Node Attached Under the HUD's View Area
+Once the HUD Exists. The HUD Can Be Rebuilt, so Attaching Is Retried Whenever
+the Overlay Is Missing
+*/
+
+use bevy::asset::RenderAssetUsages;
+use bevy::image::ImageSampler;
+use bevy::prelude::*;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+
+use super::hud::ViewArea;
+
+/// Width of the Original 320x200 HUD, What Whole HUD Pixels Are Counted Against
+const HUD_W: f32 = 320.0;
+
+/// A `px` x `px` Image Filled With `fill`, Sampled Nearest so it Stays Crisp
+pub(super) fn overlay_image(images: &mut Assets<Image>, px: usize, fill: &[u8; 4]) -> Handle<Image> {
+    let mut image = Image::new_fill(
+        Extent3d {
+            width: px as u32,
+            height: px as u32,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        fill,
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::default(),
+    );
+    image.sampler = ImageSampler::nearest();
+
+    images.add(image)
+}
+
+/// The View Area to Attach an Overlay Marked `M` Under, None While the HUD
+/// Is Not Built Yet or the Overlay Is Already There
+pub(super) fn view_area_without<M: Component>(
+    q_existing: &Query<(), With<M>>,
+    q_view: &Query<Entity, With<ViewArea>>,
+) -> Option<Entity> {
+    if !q_existing.is_empty() {
+        return None;
+    }
+    q_view.iter().next()
+}
+
+/// Whole HUD Pixels per 320x200 Pixel, the Same Scale as the Status Bar and Weapon
+pub(super) fn hud_scale(ui_w: f32) -> f32 {
+    (ui_w / HUD_W).floor().max(1.0)
+}
@@ -23,7 +23,8 @@ use crate::map::{
 	Tile,
 };
 use crate::player::{LookAngles, Player, PlayerKeys};
-use crate::pushwalls::PushwallMarkers;
+
+pub const WALL_ATLAS_PATH: &str = "textures/walls/wolf_walls.png";
 
 const TILE_SIZE: f32 = 1.0;
 const WALL_H: f32 = 1.0;
@@ -59,6 +60,46 @@ pub struct WallRenderCache {
     pub jamb_mat: Handle<StandardMaterial>,
 }
 
+/// Marker on the Floor Plane so the Floor Style Can Follow VideoSettings Live
+#[derive(Component)]
+pub struct FloorPlane;
+
+/// Textured Floor, or the Original's Flat Gray With No Texture
+fn apply_floor_style(
+    mat: &mut StandardMaterial,
+    textured: bool,
+    level: crate::level::LevelId,
+    floor_tex: &Handle<Image>,
+) {
+    if textured {
+        mat.base_color = Color::WHITE;
+        mat.base_color_texture = Some(floor_tex.clone());
+    } else {
+        mat.base_color = level.floor_color();
+        mat.base_color_texture = None;
+    }
+}
+
+/// Restyles the Current Floor When the Setting Flips Mid-Level
+pub fn sync_floor_style(
+    video: Res<crate::options::VideoSettings>,
+    current_level: Res<crate::level::CurrentLevel>,
+    assets: Option<Res<GameAssets>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    q_floor: Query<&MeshMaterial3d<StandardMaterial>, With<FloorPlane>>,
+) {
+    if !video.is_changed() {
+        return;
+    }
+    let Some(assets) = assets else { return; };
+
+    for handle in q_floor.iter() {
+        if let Some(mut mat) = materials.get_mut(&handle.0) {
+            apply_floor_style(&mut mat, video.textured_floor, current_level.0, &assets.floor_tex);
+        }
+    }
+}
+
 #[derive(Resource)]
 pub struct GameAssets {
     pub wall_tex: Handle<Image>,
@@ -69,11 +110,44 @@ fn load_assets(asset_server: &AssetServer) -> GameAssets {
     GameAssets {
         // Wolfenstein 3-D Wall Sheet (Top Left 8x8 = the 64 Wall Textures in Index Order)
         // We Remap UVs Per Wall ID, so This is Shared by All Wall Materials
-        wall_tex: asset_server.load("textures/walls/wolf_walls.png"),
+        wall_tex: asset_server.load(WALL_ATLAS_PATH),
         floor_tex: asset_server.load("textures/floors/floor.png"),
     }
 }
 
+/// Base Yaw of a Door Panel (Before the Shared PI Flip)
+/// Wolfenstein 3-D Encodes the Axis in plane0: Even Codes (90, 92 .. 100) Are
+/// Vertical Doors Set Between Walls to the North and South, Odd Codes Are
+/// Horizontal Doors Between Walls to the East and West. Unknown Codes Fall Back
+/// to Counting Adjacent Walls
+fn door_yaw_base(code: u16, walls_x: u8, walls_z: u8) -> f32 {
+    match code {
+        90..=101 if code % 2 == 0 => FRAC_PI_2,
+        90..=101 => 0.0,
+        _ if walls_z > walls_x => FRAC_PI_2,
+        _ => 0.0,
+    }
+}
+
+/// Slide Direction That Retracts a Door at `tile` Into a Wall. The Preferred
+/// Direction Wins When it Has a Wall Pocket, Otherwise the Opposite Side is Used.
+/// Returns Whether Either Side Had a Wall to Hide the Panel
+fn door_pocket_axis(
+    preferred: Vec3,
+    tile: IVec2,
+    is_wall: impl Fn(i32, i32) -> bool,
+) -> (Vec3, bool) {
+    let step = IVec2::new(preferred.x.round() as i32, preferred.z.round() as i32);
+
+    if is_wall(tile.x + step.x, tile.y + step.y) {
+        (preferred, true)
+    } else if is_wall(tile.x - step.x, tile.y - step.y) {
+        (-preferred, true)
+    } else {
+        (preferred, false)
+    }
+}
+
 fn spawn_wall_faces_for_grid(
     commands: &mut Commands,
     grid: &MapGrid,
@@ -284,424 +358,55 @@ pub fn setup(
 	skill_level: Res<crate::skill::SkillLevel>,
 	canvas: Res<WorldCanvas>,
 	q_window: Query<&Window, With<PrimaryWindow>>,
+	stress_map: Res<crate::perf_overlay::StressMap>,
+	video: Res<crate::options::VideoSettings>,
 ) {
 	// Map Load (Wolfenstein 3-D Planes)
-	let (plane0_text, plane1_text) = match current_level.0 {
-		// Episode 1
-		crate::level::LevelId::E1M1 => (
-			include_str!("../assets/maps/episode1/e1m1_plane0_u16.txt"),
-			include_str!("../assets/maps/episode1/e1m1_plane1_u16.txt"),
-		),
-		crate::level::LevelId::E1M2 => (
-			include_str!("../assets/maps/episode1/e1m2_plane0_u16.txt"),
-			include_str!("../assets/maps/episode1/e1m2_plane1_u16.txt"),
-		),
-		crate::level::LevelId::E1M3 => (
-			include_str!("../assets/maps/episode1/e1m3_plane0_u16.txt"),
-			include_str!("../assets/maps/episode1/e1m3_plane1_u16.txt"),
-		),
-		crate::level::LevelId::E1M4 => (
-			include_str!("../assets/maps/episode1/e1m4_plane0_u16.txt"),
-			include_str!("../assets/maps/episode1/e1m4_plane1_u16.txt"),
-		),
-		crate::level::LevelId::E1M5 => (
-			include_str!("../assets/maps/episode1/e1m5_plane0_u16.txt"),
-			include_str!("../assets/maps/episode1/e1m5_plane1_u16.txt"),
-		),
-		crate::level::LevelId::E1M6 => (
-			include_str!("../assets/maps/episode1/e1m6_plane0_u16.txt"),
-			include_str!("../assets/maps/episode1/e1m6_plane1_u16.txt"),
-		),
-		crate::level::LevelId::E1M7 => (
-			include_str!("../assets/maps/episode1/e1m7_plane0_u16.txt"),
-			include_str!("../assets/maps/episode1/e1m7_plane1_u16.txt"),
-		),
-		crate::level::LevelId::E1M8 => (
-			include_str!("../assets/maps/episode1/e1m8_plane0_u16.txt"),
-			include_str!("../assets/maps/episode1/e1m8_plane1_u16.txt"),
-		),
-		crate::level::LevelId::E1M9 => (
-			include_str!("../assets/maps/episode1/e1m9_plane0_u16.txt"),
-			include_str!("../assets/maps/episode1/e1m9_plane1_u16.txt"),
-		),
-		crate::level::LevelId::E1M10 => (
-			include_str!("../assets/maps/episode1/e1m10_plane0_u16.txt"),
-			include_str!("../assets/maps/episode1/e1m10_plane1_u16.txt"),
-		),
-
-		// Episode 2
-		crate::level::LevelId::E2M1 => (
-			include_str!("../assets/maps/episode2/e2m1_plane0_u16.txt"),
-			include_str!("../assets/maps/episode2/e2m1_plane1_u16.txt"),
-		),
-		crate::level::LevelId::E2M2 => (
-			include_str!("../assets/maps/episode2/e2m2_plane0_u16.txt"),
-			include_str!("../assets/maps/episode2/e2m2_plane1_u16.txt"),
-		),
-		crate::level::LevelId::E2M3 => (
-			include_str!("../assets/maps/episode2/e2m3_plane0_u16.txt"),
-			include_str!("../assets/maps/episode2/e2m3_plane1_u16.txt"),
-		),
-		crate::level::LevelId::E2M4 => (
-			include_str!("../assets/maps/episode2/e2m4_plane0_u16.txt"),
-			include_str!("../assets/maps/episode2/e2m4_plane1_u16.txt"),
-		),
-		crate::level::LevelId::E2M5 => (
-			include_str!("../assets/maps/episode2/e2m5_plane0_u16.txt"),
-			include_str!("../assets/maps/episode2/e2m5_plane1_u16.txt"),
-		),
-		crate::level::LevelId::E2M6 => (
-			include_str!("../assets/maps/episode2/e2m6_plane0_u16.txt"),
-			include_str!("../assets/maps/episode2/e2m6_plane1_u16.txt"),
-		),
-		crate::level::LevelId::E2M7 => (
-			include_str!("../assets/maps/episode2/e2m7_plane0_u16.txt"),
-			include_str!("../assets/maps/episode2/e2m7_plane1_u16.txt"),
-		),
-		crate::level::LevelId::E2M8 => (
-			include_str!("../assets/maps/episode2/e2m8_plane0_u16.txt"),
-			include_str!("../assets/maps/episode2/e2m8_plane1_u16.txt"),
-		),
-		crate::level::LevelId::E2M9 => (
-			include_str!("../assets/maps/episode2/e2m9_plane0_u16.txt"),
-			include_str!("../assets/maps/episode2/e2m9_plane1_u16.txt"),
-		),
-		crate::level::LevelId::E2M10 => (
-			include_str!("../assets/maps/episode2/e2m10_plane0_u16.txt"),
-			include_str!("../assets/maps/episode2/e2m10_plane1_u16.txt"),
-		),
-
-		// Episode 3
-		crate::level::LevelId::E3M1 => (
-			include_str!("../assets/maps/episode3/e3m1_plane0_u16.txt"),
-			include_str!("../assets/maps/episode3/e3m1_plane1_u16.txt"),
-		),
-		crate::level::LevelId::E3M2 => (
-			include_str!("../assets/maps/episode3/e3m2_plane0_u16.txt"),
-			include_str!("../assets/maps/episode3/e3m2_plane1_u16.txt"),
-		),
-		crate::level::LevelId::E3M3 => (
-			include_str!("../assets/maps/episode3/e3m3_plane0_u16.txt"),
-			include_str!("../assets/maps/episode3/e3m3_plane1_u16.txt"),
-		),
-		crate::level::LevelId::E3M4 => (
-			include_str!("../assets/maps/episode3/e3m4_plane0_u16.txt"),
-			include_str!("../assets/maps/episode3/e3m4_plane1_u16.txt"),
-		),
-		crate::level::LevelId::E3M5 => (
-			include_str!("../assets/maps/episode3/e3m5_plane0_u16.txt"),
-			include_str!("../assets/maps/episode3/e3m5_plane1_u16.txt"),
-		),
-		crate::level::LevelId::E3M6 => (
-			include_str!("../assets/maps/episode3/e3m6_plane0_u16.txt"),
-			include_str!("../assets/maps/episode3/e3m6_plane1_u16.txt"),
-		),
-		crate::level::LevelId::E3M7 => (
-			include_str!("../assets/maps/episode3/e3m7_plane0_u16.txt"),
-			include_str!("../assets/maps/episode3/e3m7_plane1_u16.txt"),
-		),
-		crate::level::LevelId::E3M8 => (
-			include_str!("../assets/maps/episode3/e3m8_plane0_u16.txt"),
-			include_str!("../assets/maps/episode3/e3m8_plane1_u16.txt"),
-		),
-		crate::level::LevelId::E3M9 => (
-			include_str!("../assets/maps/episode3/e3m9_plane0_u16.txt"),
-			include_str!("../assets/maps/episode3/e3m9_plane1_u16.txt"),
-		),
-		crate::level::LevelId::E3M10 => (
-			include_str!("../assets/maps/episode3/e3m10_plane0_u16.txt"),
-			include_str!("../assets/maps/episode3/e3m10_plane1_u16.txt"),
-		),
-
-		// Episode 4
-		crate::level::LevelId::E4M1 => (
-			include_str!("../assets/maps/episode4/e4m1_plane0_u16.txt"),
-			include_str!("../assets/maps/episode4/e4m1_plane1_u16.txt"),
-		),
-		crate::level::LevelId::E4M2 => (
-			include_str!("../assets/maps/episode4/e4m2_plane0_u16.txt"),
-			include_str!("../assets/maps/episode4/e4m2_plane1_u16.txt"),
-		),
-		crate::level::LevelId::E4M3 => (
-			include_str!("../assets/maps/episode4/e4m3_plane0_u16.txt"),
-			include_str!("../assets/maps/episode4/e4m3_plane1_u16.txt"),
-		),
-		crate::level::LevelId::E4M4 => (
-			include_str!("../assets/maps/episode4/e4m4_plane0_u16.txt"),
-			include_str!("../assets/maps/episode4/e4m4_plane1_u16.txt"),
-		),
-		crate::level::LevelId::E4M5 => (
-			include_str!("../assets/maps/episode4/e4m5_plane0_u16.txt"),
-			include_str!("../assets/maps/episode4/e4m5_plane1_u16.txt"),
-		),
-		crate::level::LevelId::E4M6 => (
-			include_str!("../assets/maps/episode4/e4m6_plane0_u16.txt"),
-			include_str!("../assets/maps/episode4/e4m6_plane1_u16.txt"),
-		),
-		crate::level::LevelId::E4M7 => (
-			include_str!("../assets/maps/episode4/e4m7_plane0_u16.txt"),
-			include_str!("../assets/maps/episode4/e4m7_plane1_u16.txt"),
-		),
-		crate::level::LevelId::E4M8 => (
-			include_str!("../assets/maps/episode4/e4m8_plane0_u16.txt"),
-			include_str!("../assets/maps/episode4/e4m8_plane1_u16.txt"),
-		),
-		crate::level::LevelId::E4M9 => (
-			include_str!("../assets/maps/episode4/e4m9_plane0_u16.txt"),
-			include_str!("../assets/maps/episode4/e4m9_plane1_u16.txt"),
-		),
-		crate::level::LevelId::E4M10 => (
-			include_str!("../assets/maps/episode4/e4m10_plane0_u16.txt"),
-			include_str!("../assets/maps/episode4/e4m10_plane1_u16.txt"),
-		),
-
-		// Episode 5
-		crate::level::LevelId::E5M1 => (
-			include_str!("../assets/maps/episode5/e5m1_plane0_u16.txt"),
-			include_str!("../assets/maps/episode5/e5m1_plane1_u16.txt"),
-		),
-		crate::level::LevelId::E5M2 => (
-			include_str!("../assets/maps/episode5/e5m2_plane0_u16.txt"),
-			include_str!("../assets/maps/episode5/e5m2_plane1_u16.txt"),
-		),
-		crate::level::LevelId::E5M3 => (
-			include_str!("../assets/maps/episode5/e5m3_plane0_u16.txt"),
-			include_str!("../assets/maps/episode5/e5m3_plane1_u16.txt"),
-		),
-		crate::level::LevelId::E5M4 => (
-			include_str!("../assets/maps/episode5/e5m4_plane0_u16.txt"),
-			include_str!("../assets/maps/episode5/e5m4_plane1_u16.txt"),
-		),
-		crate::level::LevelId::E5M5 => (
-			include_str!("../assets/maps/episode5/e5m5_plane0_u16.txt"),
-			include_str!("../assets/maps/episode5/e5m5_plane1_u16.txt"),
-		),
-		crate::level::LevelId::E5M6 => (
-			include_str!("../assets/maps/episode5/e5m6_plane0_u16.txt"),
-			include_str!("../assets/maps/episode5/e5m6_plane1_u16.txt"),
-		),
-		crate::level::LevelId::E5M7 => (
-			include_str!("../assets/maps/episode5/e5m7_plane0_u16.txt"),
-			include_str!("../assets/maps/episode5/e5m7_plane1_u16.txt"),
-		),
-		crate::level::LevelId::E5M8 => (
-			include_str!("../assets/maps/episode5/e5m8_plane0_u16.txt"),
-			include_str!("../assets/maps/episode5/e5m8_plane1_u16.txt"),
-		),
-		crate::level::LevelId::E5M9 => (
-			include_str!("../assets/maps/episode5/e5m9_plane0_u16.txt"),
-			include_str!("../assets/maps/episode5/e5m9_plane1_u16.txt"),
-		),
-
-		crate::level::LevelId::E5M10 => (
-			include_str!("../assets/maps/episode5/e5m10_plane0_u16.txt"),
-			include_str!("../assets/maps/episode5/e5m10_plane1_u16.txt"),
-		),
-
-		// Episode 6
-		crate::level::LevelId::E6M1 => (
-			include_str!("../assets/maps/episode6/e6m1_plane0_u16.txt"),
-			include_str!("../assets/maps/episode6/e6m1_plane1_u16.txt"),
-		),
-		crate::level::LevelId::E6M2 => (
-			include_str!("../assets/maps/episode6/e6m2_plane0_u16.txt"),
-			include_str!("../assets/maps/episode6/e6m2_plane1_u16.txt"),
-		),
-		crate::level::LevelId::E6M3 => (
-			include_str!("../assets/maps/episode6/e6m3_plane0_u16.txt"),
-			include_str!("../assets/maps/episode6/e6m3_plane1_u16.txt"),
-		),
-		crate::level::LevelId::E6M4 => (
-			include_str!("../assets/maps/episode6/e6m4_plane0_u16.txt"),
-			include_str!("../assets/maps/episode6/e6m4_plane1_u16.txt"),
-		),
-		crate::level::LevelId::E6M5 => (
-			include_str!("../assets/maps/episode6/e6m5_plane0_u16.txt"),
-			include_str!("../assets/maps/episode6/e6m5_plane1_u16.txt"),
-		),
-		crate::level::LevelId::E6M6 => (
-			include_str!("../assets/maps/episode6/e6m6_plane0_u16.txt"),
-			include_str!("../assets/maps/episode6/e6m6_plane1_u16.txt"),
-		),
-		crate::level::LevelId::E6M7 => (
-			include_str!("../assets/maps/episode6/e6m7_plane0_u16.txt"),
-			include_str!("../assets/maps/episode6/e6m7_plane1_u16.txt"),
-		),
-		crate::level::LevelId::E6M8 => (
-			include_str!("../assets/maps/episode6/e6m8_plane0_u16.txt"),
-			include_str!("../assets/maps/episode6/e6m8_plane1_u16.txt"),
-		),
-		crate::level::LevelId::E6M9 => (
-			include_str!("../assets/maps/episode6/e6m9_plane0_u16.txt"),
-			include_str!("../assets/maps/episode6/e6m9_plane1_u16.txt"),
-		),
-		crate::level::LevelId::E6M10 => (
-			include_str!("../assets/maps/episode6/e6m10_plane0_u16.txt"),
-			include_str!("../assets/maps/episode6/e6m10_plane1_u16.txt"),
-		),
+	let (plane0_text, plane1_text) = if stress_map.0 {
+		(
+			include_str!("../assets/maps/debug/stress_plane0_u16.txt"),
+			include_str!("../assets/maps/debug/stress_plane1_u16.txt"),
+		)
+	} else {
+		crate::level::level_plane_text(current_level.0)
 	};
 
-	let plane0 = MapGrid::parse_u16_grid(plane0_text, 64, 64);
-	let plane1 = MapGrid::parse_u16_grid(plane1_text, 64, 64);
+	// Grid, Spawns and Totals Come From the ECS-Free Library Loader
+	let (plane0, plane1) = crate::level::parse_level_planes(plane0_text, plane1_text);
+	let level = crate::level::load_level_data(&plane0, &plane1, *skill_level);
 
 	// Make plane1 Available as Single Source of Truth for Decorations / Pickups Later
-	commands.insert_resource(crate::level::WolfPlane1(plane1.clone()));
-
-	let pushwall_markers = PushwallMarkers::from_wolf_plane1(64, 64, &plane1);
-	let (grid, spawn, guards, mutants, ss, officers, dogs, hans, gretel, mecha_hitler, ghost_hitler, schabbs, otto, general) =
-		MapGrid::from_wolf_planes(64, 64, &plane0, &plane1);
-
-	// Enemy Difficulty Selection
-	// Wolfenstein 3-D Codes Repeat in 3 Bands Spaced by +36
-	// Base = 0, Mid = 36, Hard = 72
-	let skill_off = skill_level.spawn_offset();
-
-	let idx = |t: IVec2| -> usize { (t.y as usize) * 64 + (t.x as usize) };
-
-	let guards: Vec<IVec2> = guards
-		.into_iter()
-		.filter(|&t| {
-			let code = plane1[idx(t)];
-			let base = (108..=115).contains(&code);
-			let med = (144..=151).contains(&code);
-			let hard = (180..=187).contains(&code);
-			match skill_off {
-				0 => base,
-				36 => base || med,
-				72 => base || med || hard,
-				_ => base,
-			}
-		})
-		.collect();
-
-	let mutants: Vec<IVec2> = mutants
-		.into_iter()
-		.filter(|&t| {
-			let code = plane1[idx(t)];
-			let base = (216..=223).contains(&code);
-			let med = (234..=241).contains(&code);
-			let hard = (252..=259).contains(&code);
-			match skill_off {
-				0 => base,
-				36 => base || med,
-				72 => base || med || hard,
-				_ => base,
-			}
-		})
-		.collect();
-
-	let ss: Vec<IVec2> = ss
-		.into_iter()
-		.filter(|&t| {
-			let code = plane1[idx(t)];
-			let base = (126..=133).contains(&code);
-			let med = (162..=169).contains(&code);
-			let hard = (198..=205).contains(&code);
-			match skill_off {
-				0 => base,
-				36 => base || med,
-				72 => base || med || hard,
-				_ => base,
-			}
-		})
-		.collect();
-
-	let officers: Vec<IVec2> = officers
-		.into_iter()
-		.filter(|&t| {
-			let code = plane1[idx(t)];
-			let base = (116..=123).contains(&code);
-			let med = (152..=159).contains(&code);
-			let hard = (188..=195).contains(&code);
-			match skill_off {
-				0 => base,
-				36 => base || med,
-				72 => base || med || hard,
-				_ => base,
-			}
-		})
-		.collect();
-
-	let dogs: Vec<IVec2> = dogs
-		.into_iter()
-		.filter(|&t| {
-			let code = plane1[idx(t)];
-			let base = (134..=141).contains(&code);
-			let med = (170..=177).contains(&code);
-			let hard = (206..=213).contains(&code);
-			match skill_off {
-				0 => base,
-				36 => base || med,
-				72 => base || med || hard,
-				_ => base,
-			}
-		})
-		.collect();
-
-	// Bosses
-	// Not Difficulty-Banded, Spawn Always If Present
-	let hans: Vec<IVec2> = hans
-		.into_iter()
-		.filter(|&t| plane1[idx(t)] == 214)
-		.collect();
-
-	let gretel: Vec<IVec2> = gretel
-		.into_iter()
-		.filter(|&t| plane1[idx(t)] == 197)
-		.collect();
-
-	let mecha_hitler: Vec<IVec2> = mecha_hitler
-		.into_iter()
-		.filter(|&t| plane1[idx(t)] == 178)
-		.collect();
-
-    let ghost_hitler: Vec<IVec2> = ghost_hitler
-        .into_iter()
-        .filter(|&t| plane1[idx(t)] == 160)
-        .collect();
-
-    let schabbs: Vec<IVec2> = schabbs
-        .into_iter()
-        .filter(|&t| plane1[idx(t)] == 196)
-        .collect();
-
-	let otto: Vec<IVec2> = otto
-        .into_iter()
-        .filter(|&t| plane1[idx(t)] == 215)
-        .collect();
-
-    let general: Vec<IVec2> = general
-        .into_iter()
-        .filter(|&t| plane1[idx(t)] == 179)
-        .collect();
-
-	let hitler_phase2_total = mecha_hitler.len();
-
-	// Intermission Screen Totals
-    // FIXME: Is this right? Should bosses be counted in this way?
-	let kills_total = guards.len()
-        + mutants.len()
-        + ss.len()
-        + officers.len()
-        + dogs.len()
-        + hans.len()
-		+ gretel.len()
-		+ mecha_hitler.len()
-    	+ hitler_phase2_total
-        + ghost_hitler.len()
-        + schabbs.len()
-		+ otto.len()
-        + general.len();
-
-	let secrets_total = plane1.iter().filter(|&&c| c == 98).count();
-	let treasure_total = plane1
-		.iter()
-		.filter(|&&c| matches!(c, 52 | 53 | 54 | 55))
-		.count();
+	commands.insert_resource(crate::level::WolfPlane1(level.plane1.clone()));
 
-	level_score.reset_for_level(kills_total, secrets_total, treasure_total);
+	level_score.reset_for_level(
+		level.enemies.kills_total(),
+		level.secrets_total,
+		level.treasure_total,
+	);
 
-	let (spawn, spawn_yaw) = spawn.unwrap_or((IVec2::new(1, 1), 0.0));
+	let crate::level::LevelData {
+		grid,
+		spawn,
+		spawn_yaw,
+		enemies,
+		dead_guards,
+		pushwalls: pushwall_markers,
+		..
+	} = level;
+	let crate::level::EnemySpawns {
+		guards,
+		mutants,
+		ss,
+		officers,
+		dogs,
+		hans,
+		gretel,
+		mecha_hitler,
+		ghost_hitler,
+		schabbs,
+		otto,
+		general,
+	} = enemies;
 
 	// Make Map Available for Collision / Doors / Raycasts
 	commands.insert_resource(grid.clone());
@@ -783,12 +488,13 @@ pub fn setup(
 		..default()
 	});
 
-	let floor_mat = materials.add(StandardMaterial {
-		base_color_texture: Some(floor_tex),
+	let mut floor = StandardMaterial {
 		unlit: true,
 		alpha_mode: world_alpha_mode,
 		..default()
-	});
+	};
+	apply_floor_style(&mut floor, video.textured_floor, current_level.0, &floor_tex);
+	let floor_mat = materials.add(floor);
 
 	// Center Helpers (Tiles Live at X,Z = 0..Width-1)
 	let room_center = Vec3::new(
@@ -820,6 +526,7 @@ pub fn setup(
     // Floor
     commands.spawn((
         Name::new("floor"),
+        FloorPlane,
         Mesh3d(floor_mesh.clone()),
         MeshMaterial3d(floor_mat),
         Transform::from_translation(room_center),
@@ -1000,7 +707,8 @@ pub fn setup(
 
 			let is_open = matches!(tile, Tile::DoorOpen);
 
-			// Determine Orientation From Adjacent Walls
+			// Orientation Comes From the Door Code, Adjacent Walls Are Only Used
+			// as a Fallback and for the Diagnostic Below
 			let left_wall = x > 0 && matches!(grid.tile(x - 1, z), Tile::Wall);
 			let right_wall = x + 1 < grid.width && matches!(grid.tile(x + 1, z), Tile::Wall);
 			let up_wall = z > 0 && matches!(grid.tile(x, z - 1), Tile::Wall);
@@ -1018,7 +726,7 @@ pub fn setup(
 				);
 			}
 
-			let yaw_base = if walls_z > walls_x { FRAC_PI_2 } else { 0.0 };
+			let yaw_base = door_yaw_base(code, walls_x, walls_z);
 			let yaw = yaw_base + PI;
 
 			let base = Quat::from_rotation_x(-FRAC_PI_2);
@@ -1044,8 +752,28 @@ pub fn setup(
 				slide_axis = -slide_axis;
 			}
 
+			// The Grid Has the Final Say: Slide Into Whichever Jamb Is a Wall
+			let (slide_axis, pocket) = door_pocket_axis(
+				slide_axis,
+				IVec2::new(x as i32, z as i32),
+				|xx, zz| {
+					xx >= 0 && zz >= 0
+						&& (xx as usize) < grid.width && (zz as usize) < grid.height
+						&& matches!(grid.tile(xx as usize, zz as usize), Tile::Wall)
+				},
+			);
+			if !pocket {
+				warn!("Door at ({},{}) has no wall to retract into", x, z);
+			}
+
 			let progress = if is_open { 1.0 } else { 0.0 };
-			let start_pos = center + slide_axis * (progress * TILE_SIZE);
+			let anim = DoorAnim {
+				progress,
+				closed_pos: center,
+				slide_axis,
+				pocket,
+			};
+			let (start_pos, start_scale) = anim.pose();
 			let vis = if is_open { Visibility::Hidden } else { Visibility::Visible };
 
 			// Choose Door Atlas Tile by Wolfenstein 3-D plane0 Door Code + Axis
@@ -1107,12 +835,8 @@ pub fn setup(
 						open_timer: 0.0,
 						want_open: is_open,
 					},
-					DoorAnim {
-						progress,
-						closed_pos: center,
-						slide_axis,
-					},
-					Transform::from_translation(start_pos),
+					anim,
+					Transform::from_translation(start_pos).with_scale(start_scale),
 					vis,
 				))
 				.with_children(|parent| {
@@ -1142,82 +866,79 @@ pub fn setup(
 	// Static Wall Faces (Includes Door Jamb Faces)
 	spawn_wall_faces_for_grid(&mut commands, &grid, &wall_cache, None);
 
+	// SpawnIndex Keeps Per-Kind Order; SpawnId Is the Origin Tile, Unique per Map
 	for (spawn_i, g) in guards.into_iter().enumerate() {
 		let _e = crate::enemies::spawn_guard(&mut commands, &mut meshes, &mut materials, &enemy_sprites.guards, g);
-		commands.entity(_e).insert(crate::enemies::SpawnIndex(spawn_i as u32));
+		commands.entity(_e).insert((crate::enemies::SpawnIndex(spawn_i as u32), crate::enemies::SpawnId(g)));
 	}
 
     for (spawn_i, m) in mutants.into_iter().enumerate() {
         let _e = crate::enemies::spawn_mutant(&mut commands, &mut meshes, &mut materials, &enemy_sprites.mutants, m);
-        commands.entity(_e).insert(crate::enemies::SpawnIndex(spawn_i as u32));
+        commands.entity(_e).insert((crate::enemies::SpawnIndex(spawn_i as u32), crate::enemies::SpawnId(m)));
     }
 
 	for (spawn_i, s) in ss.into_iter().enumerate() {
 		let _e = crate::enemies::spawn_ss(&mut commands, &mut meshes, &mut materials, &enemy_sprites.ss, s);
-		commands.entity(_e).insert(crate::enemies::SpawnIndex(spawn_i as u32));
+		commands.entity(_e).insert((crate::enemies::SpawnIndex(spawn_i as u32), crate::enemies::SpawnId(s)));
 	}
 
 	for (spawn_i, o) in officers.into_iter().enumerate() {
 		let _e = crate::enemies::spawn_officer(&mut commands, &mut meshes, &mut materials, &enemy_sprites.officers, o);
-		commands.entity(_e).insert(crate::enemies::SpawnIndex(spawn_i as u32));
+		commands.entity(_e).insert((crate::enemies::SpawnIndex(spawn_i as u32), crate::enemies::SpawnId(o)));
 	}
 
 	for (spawn_i, d) in dogs.into_iter().enumerate() {
 		let _e = crate::enemies::spawn_dog(&mut commands, &mut meshes, &mut materials, &enemy_sprites.dogs, d);
-		commands.entity(_e).insert(crate::enemies::SpawnIndex(spawn_i as u32));
+		commands.entity(_e).insert((crate::enemies::SpawnIndex(spawn_i as u32), crate::enemies::SpawnId(d)));
 	}
 
 	for (spawn_i, h) in hans.into_iter().enumerate() {
 		let _e = crate::enemies::spawn_hans(&mut commands, &mut meshes, &mut materials, &enemy_sprites.hans, h, &skill_level);
-		commands.entity(_e).insert(crate::enemies::SpawnIndex(spawn_i as u32));
+		commands.entity(_e).insert((crate::enemies::SpawnIndex(spawn_i as u32), crate::enemies::SpawnId(h)));
 	}
 
 	for (spawn_i, g) in gretel.into_iter().enumerate() {
 		let _e = crate::enemies::spawn_gretel(&mut commands, &mut meshes, &mut materials, &enemy_sprites.gretel, g, &skill_level);
-		commands.entity(_e).insert(crate::enemies::SpawnIndex(spawn_i as u32));
+		commands.entity(_e).insert((crate::enemies::SpawnIndex(spawn_i as u32), crate::enemies::SpawnId(g)));
 	}
 
 	for (spawn_i, mh) in mecha_hitler.into_iter().enumerate() {
 		let _e = crate::enemies::spawn_mecha_hitler(&mut commands, &mut meshes, &mut materials, &enemy_sprites.mecha_hitler, mh, &skill_level);
-		commands.entity(_e).insert(crate::enemies::SpawnIndex(spawn_i as u32));
+		commands.entity(_e).insert((crate::enemies::SpawnIndex(spawn_i as u32), crate::enemies::SpawnId(mh)));
 	}
 
     for (spawn_i, gh) in ghost_hitler.into_iter().enumerate() {
         let _e = crate::enemies::spawn_ghost_hitler(&mut commands, &mut meshes, &mut materials, &enemy_sprites.ghost_hitler, gh, &skill_level);
-        commands.entity(_e).insert(crate::enemies::SpawnIndex(spawn_i as u32));
+        commands.entity(_e).insert((crate::enemies::SpawnIndex(spawn_i as u32), crate::enemies::SpawnId(gh)));
     }
 
     for (spawn_i, sc) in schabbs.into_iter().enumerate() {
         let _e = crate::enemies::spawn_schabbs(&mut commands, &mut meshes, &mut materials, &enemy_sprites.schabbs, sc, &skill_level);
-        commands.entity(_e).insert(crate::enemies::SpawnIndex(spawn_i as u32));
+        commands.entity(_e).insert((crate::enemies::SpawnIndex(spawn_i as u32), crate::enemies::SpawnId(sc)));
     }
 
 	for (spawn_i, ot) in otto.into_iter().enumerate() {
         let _e = crate::enemies::spawn_otto(&mut commands, &mut meshes, &mut materials, &enemy_sprites.otto, ot, &skill_level);
-        commands.entity(_e).insert(crate::enemies::SpawnIndex(spawn_i as u32));
+        commands.entity(_e).insert((crate::enemies::SpawnIndex(spawn_i as u32), crate::enemies::SpawnId(ot)));
     }
 
     for (spawn_i, g) in general.into_iter().enumerate() {
         let _e = crate::enemies::spawn_general(&mut commands, &mut meshes, &mut materials, &enemy_sprites.general, g, &skill_level);
-        commands.entity(_e).insert(crate::enemies::SpawnIndex(spawn_i as u32));
+        commands.entity(_e).insert((crate::enemies::SpawnIndex(spawn_i as u32), crate::enemies::SpawnId(g)));
     }
 
     // Dead Guard Set Dressing (Wolf3D Object Code 124). The Map Parser Treats 124
-    // as Neither a Guard Spawn nor a 'statinfo' Static, so It Would Otherwise Be
-    // Dropped. We Consume It Straight From plane1 and Spawn an Inert Corpse at Each
-    // Tile. E1M1 Has One in the Opening Room to Show Where the Starting Pistol Came
-    // From; Scanning Every Level Keeps Any Other Placed Corpses Working Too
-    for i in 0..plane1.len() {
-        if plane1[i] == 124 {
-            let tile = IVec2::new((i % 64) as i32, (i / 64) as i32);
-            crate::enemies::spawn_dead_guard(
-                &mut commands,
-                &mut meshes,
-                &mut materials,
-                &enemy_sprites.guards,
-                tile,
-            );
-        }
+    // as Neither a Guard Spawn nor a 'statinfo' Static, so load_level_data Lists
+    // Those Tiles Separately and an Inert Corpse Is Spawned at Each. E1M1 Has One
+    // in the Opening Room to Show Where the Starting Pistol Came From
+    for tile in dead_guards {
+        crate::enemies::spawn_dead_guard(
+            &mut commands,
+            &mut meshes,
+            &mut materials,
+            &enemy_sprites.guards,
+            tile,
+        );
     }
 
 	let player_pos = Vec3::new(spawn.x as f32 * TILE_SIZE, 0.5, spawn.y as f32 * TILE_SIZE);
@@ -1291,8 +1012,13 @@ pub fn setup(
 	commands.spawn((
 		Camera2d::default(),
 		// Order 2: After the World Camera (0) and HUD Camera (1) Have Finished
-		// Writing the Canvas, so This Frame's Fully-Composited Canvas Is Sampled
-		Camera { order: 2, ..default() },
+		// Writing the Canvas, so This Frame's Fully-Composited Canvas Is Sampled.
+		// Black Clear Is the Letterbox Around the Canvas in Integer Scale Mode
+		Camera {
+			order: 2,
+			clear_color: Color::BLACK.into(),
+			..default()
+		},
 		// MSAA Off: This Camera Only Blits the Canvas to the Window With a
 		// Nearest-Neighbor Upscale. Multisampling It Would Waste Memory and
 		// Bandwidth and Soften the Deliberately Chunky Pixels. Keeping It at
@@ -1327,3 +1053,51 @@ pub fn setup(
 		WorldPresenter,
 	));
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn door_code_sets_orientation_over_neighbors() {
+        // T-Junction: Walls on Three Sides Would Fool a Neighbor Count
+        assert_eq!(door_yaw_base(90, 2, 1), FRAC_PI_2);
+        assert_eq!(door_yaw_base(91, 1, 2), 0.0);
+
+        // Locked and Elevator Pairs Follow the Same Even / Odd Rule
+        assert_eq!(door_yaw_base(94, 0, 0), FRAC_PI_2);
+        assert_eq!(door_yaw_base(101, 0, 2), 0.0);
+
+        // Unrecognized Code Falls Back to the Wall Heuristic
+        assert_eq!(door_yaw_base(0, 0, 2), FRAC_PI_2);
+        assert_eq!(door_yaw_base(0, 2, 0), 0.0);
+    }
+
+    #[test]
+    fn door_retracts_into_a_wall_or_shrinks_in_place() {
+        let tile = IVec2::new(5, 5);
+
+        // Pocket on the Preferred Side Keeps the Direction
+        let (axis, pocket) = door_pocket_axis(Vec3::X, tile, |x, z| (x, z) == (6, 5));
+        assert_eq!((axis, pocket), (Vec3::X, true));
+
+        // Pocket Only Behind Flips it so the Panel Never Crosses Open Floor
+        let (axis, pocket) = door_pocket_axis(Vec3::X, tile, |x, z| (x, z) == (4, 5));
+        assert_eq!((axis, pocket), (-Vec3::X, true));
+
+        // No Wall Either Side: the Panel Stays Within its Own Tile
+        let (axis, pocket) = door_pocket_axis(Vec3::Z, tile, |_, _| false);
+        assert!(!pocket);
+        let half_open = DoorAnim {
+            progress: 0.5,
+            closed_pos: Vec3::new(5.0, 0.5, 5.0),
+            slide_axis: axis,
+            pocket,
+        };
+        let (pos, scale) = half_open.pose();
+        let leading_edge = pos.z + scale.z * 0.5;
+        assert!((leading_edge - 5.5).abs() < 1e-5);
+        assert!((scale.z - 0.5).abs() < 1e-5);
+        assert_eq!(scale.x, 1.0);
+    }
+}